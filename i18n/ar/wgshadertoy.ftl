@@ -3,10 +3,46 @@
 menu_file = ملف
 menu_new = جديد
 menu_open = فتح
+menu_open_project = فتح مجلد المشروع
 menu_open_examples = فتح الأمثلة
 menu_save = حفظ
 menu_save_as = حفظ باسم
+menu_save_project_as = حفظ كمجلد مشروع
+menu_import_wgsl = استيراد WGSL
+menu_export_wgsl = تصدير WGSL
+menu_export_wgsl_fragment = مصدر الأجزاء فقط
+menu_export_wgsl_standalone = مظلل مستقل
+menu_export_rust_project = تصدير كمشروع Rust
+menu_convert = تحويل
+menu_convert_wgs_to_project = ‏.wgs إلى مجلد مشروع
+menu_convert_project_to_wgs = مجلد مشروع إلى ‎.wgs
+menu_close_tab = إغلاق علامة التبويب
 menu_quit = خروج
+## Menu Edit
+menu_edit = تحرير
+menu_undo = تراجع
+menu_redo = إعادة
+menu_take_snapshot = التقاط لقطة
+menu_snapshot_history = سجل اللقطات
+menu_find = بحث
+menu_replace = استبدال
+menu_format = تنسيق المستند
+menu_comment_line = تبديل تعليق السطر
+menu_comment_block = تبديل تعليق الكتلة
+menu_duplicate_line = تكرار السطر
+menu_move_line_up = نقل السطر لأعلى
+menu_move_line_down = نقل السطر لأسفل
+menu_delete_line = حذف السطر
+menu_outline = المخطط
+menu_fold_all = طي الكل
+menu_unfold_all = توسيع الكل
+menu_compare = مقارنة
+menu_compare_saved = مع النسخة المحفوظة
+menu_compare_tab = مع { $name }
+menu_compare_side_by_side = جنبًا إلى جنب
+menu_compare_wipe = تمرير
+menu_compare_show_diff = إظهار الاختلافات
+menu_compare_stop = إيقاف المقارنة
 ## Menu Peferences
 menu_preferences = التفضيلات
 menu_languages = اللغات
@@ -14,7 +50,27 @@ menu_language_system = النظام الافتراضي
 menu_theme = السمة
 menu_theme_light = فاتح
 menu_theme_dark = غامق
+menu_ui_scale = حجم الواجهة
+menu_editor = المحرر
+menu_auto_indent = مسافة بادئة تلقائية
+menu_auto_close = إغلاق الأقواس وعلامات الاقتباس تلقائيًا
+menu_format_on_save = التنسيق عند الحفظ
+menu_indent_width = مسافة بادئة بـ { $width } مسافات
+menu_keymap_default = المفاتيح الافتراضية
+menu_keymap_emacs = مفاتيح Emacs
+menu_keymap_vim = مفاتيح Vim
+menu_font_family = الخط
+menu_font_family_default = الافتراضي
+menu_font_family_ligatures = بحروف مركّبة (تُعرض من دونها)
+menu_font_family_others = خطوط أخرى
+menu_font_size = حجم الخط
+menu_line_height = ارتفاع السطر
+menu_zoom_in = تكبير
+menu_zoom_out = تصغير
+menu_zoom_reset = إعادة تعيين التكبير
 menu_record_fps = سجل إطارا في الثانية
+menu_color_schemes = أنظمة الألوان
+menu_shortcuts = اختصارات لوحة المفاتيح
 ## Menu Help
 menu_help = مساعدة
 menu_about = حول
@@ -28,9 +84,116 @@ control_pause = إيقاف مؤقت
 
 # Edit
 edit_name = الاسم
+edit_properties = خصائص المستند
+edit_author = المؤلف
+edit_description = الوصف
+edit_tags = الوسوم
+edit_license = الترخيص
+edit_created = تاريخ الإنشاء
+edit_modified = تاريخ التعديل
+edit_thumbnail = الصورة المصغرة
+edit_capture_thumbnail = التقاط صورة مصغرة من المعاينة
 edit_change_texture = تغيير النسيج
 edit_remove_texture = إزالة النسيج
 edit_add_texture = إضافة نسيج
+edit_references = المراجع
+edit_rename = إعادة تسمية الرمز
+edit_rename_err_conflict = يتعارض الاسم الجديد مع اسم آخر في النطاق
+edit_rename_err_invalid_name = ليس معرّف WGSL صالحًا
+edit_rename_err_predeclared = لا يمكن إعادة تسمية إلا الأسماء المعرّفة والمستخدمة في هذا المظلّل
+edit_find = بحث
+edit_find_case_sensitive = مطابقة حالة الأحرف
+edit_find_close = إغلاق
+edit_find_count = { $total } نتيجة
+edit_find_err_regex = تعبير نمطي غير صالح
+edit_find_next = التطابق التالي
+edit_find_no_results = لا توجد نتائج
+edit_find_position = { $current } من { $total }
+edit_find_previous = التطابق السابق
+edit_find_regex = استخدام تعبير نمطي
+edit_find_toggle_replace = إظهار الاستبدال أو إخفاؤه
+edit_find_whole_word = مطابقة الكلمة بأكملها
+tab_close = إغلاق علامة التبويب
+tab_new = علامة تبويب جديدة
+dialog_discard_title = تغييرات غير محفوظة
+dialog_discard = يحتوي { $name } على تغييرات غير محفوظة. هل تريد إغلاقه وتجاهلها؟
+compare_current = الحالية
+compare_diff = الاختلافات
+compare_frame_time = متوسط وقت وحدة معالجة الرسومات لكل إطار
+compare_frame_time_estimate = الوقت المقدّر لوحدة معالجة الرسومات لكل إطار، إذ لا تبلغ عنه
+compare_no_changes = لا توجد تغييرات
+compare_saved = المحفوظة
+compare_wipe = موضع التمرير
+snapshot_history = اللقطات
+snapshot_label = التسمية
+snapshot_take = التقاط
+snapshot_untitled = لقطة
+snapshot_diff = مقارنة
+snapshot_diff_hint = يعرض الاختلافات بين اللقطتين المحددتين
+snapshot_select = حدد للمقارنة
+snapshot_restore = استعادة
+snapshot_delete = حذف اللقطة
+outline_empty = لا توجد دوال أو هياكل أو ثوابت
+snapshot_none = تُلتقط اللقطات في كل مرة يُترجم فيها المظلل، أو يدويًا.
+command_capture_image = التقاط صورة
+command_capture_thumbnail = التقاط صورة مصغرة
+command_compare_saved = مقارنة مع النسخة المحفوظة
+command_compile = ترجمة وتشغيل
+command_complete = إظهار الإكمال
+command_convert_project_to_wgs = تحويل مجلد المشروع إلى .wgs
+command_convert_wgs_to_project = تحويل .wgs إلى مجلد مشروع
+command_export_wgsl_fragment = تصدير مصدر WGSL للجزء
+command_export_wgsl_standalone = تصدير مظلل WGSL مستقل
+command_find_references = البحث عن المراجع
+command_go_to_definition = الانتقال إلى التعريف
+command_indent = زيادة المسافة البادئة
+command_outdent = إنقاص المسافة البادئة
+command_palette = لوحة الأوامر
+command_pause = إيقاف مؤقت أو استئناف
+command_rename = إعادة تسمية الرمز
+command_restart = إعادة التشغيل
+command_tab_next = علامة التبويب التالية
+command_tab_previous = علامة التبويب السابقة
+command_texture_add = إضافة خامة
+shortcuts_title = اختصارات لوحة المفاتيح
+shortcuts_filter = تصفية الأوامر
+shortcuts_reset_all = إعادة تعيين الكل
+shortcuts_press = اضغط اختصارًا…
+shortcuts_record = انقر ثم اضغط الاختصار الجديد، أو Escape للإلغاء
+shortcuts_clear = إزالة الاختصار
+shortcuts_reset = إعادة التعيين إلى الافتراضي
+shortcuts_conflict = { $shortcut } مرتبط بالفعل بـ { $command }.
+shortcuts_reassign = إعادة التعيين
+shortcuts_cancel = إلغاء
+color_schemes_title = أنظمة الألوان
+color_schemes_dark = الوضع الداكن
+color_schemes_light = الوضع الفاتح
+color_schemes_edit = تحرير
+color_schemes_import = استيراد…
+color_schemes_import_hint = نظام ألوان بصيغة TOML، أو سمة VS Code أو TextMate بصيغة JSON
+color_schemes_is_dark = لخلفية داكنة
+color_schemes_preview = معاينة
+color_schemes_save = حفظ
+color_schemes_reset = إعادة تعيين
+color_schemes_delete = حذف
+color_schemes_new_name = اسم النسخة
+color_schemes_copy = نسخ
+token_builtin_function = دالة مدمجة
+token_comment = تعليق
+token_field = حقل
+token_function = استدعاء دالة
+token_function_definition = تعريف دالة
+token_global_variable = متغير عام
+token_keyword_other = كلمة مفتاحية
+token_keyword_type = نوع
+token_literal = نص
+token_local_variable = متغير محلي
+token_numeric = رقم
+token_struct = بنية
+palette_hint = اكتب أمرًا
+edit_replace = استبدال
+edit_replace_all = استبدال الكل
+edit_replace_one = استبدال
 
 # About
 about_homepage = الصفحة الرئيسية
@@ -38,7 +201,40 @@ about_homepage = الصفحة الرئيسية
 # Status
 status_compile_ok = تم تجميع الشيدر بنجاح!
 status_save_ok = تم حفظ الشيدر بنجاح!
+status_export_ok = تم تصدير المشروع بنجاح!
+status_convert_ok = تم تحويل المظلل بنجاح!
+status_color_scheme_imported = تم استيراد نظام الألوان { $name }
+status_cursor = سطر { $line }، عمود { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = العلامة محددة
+keymap_vim_insert = إدراج
+keymap_vim_normal = عادي
+keymap_vim_visual = مرئي
+keymap_vim_visual_line = سطر مرئي
 status_err_open_example = فشل في فتح المثال
 status_err_open_file = فشل في فتح الملف
 status_err_open_texture = فشل في فتح النسيج
+status_err_export = فشل تصدير المشروع
+status_err_convert = فشل تحويل المظلل
+status_err_import_color_scheme = فشل استيراد نظام الألوان
+status_err_save = فشل حفظ المظلل
 status_err_valid = خطأ في التحقق من صحة الشيدر
+
+# WGSL
+wgsl_uniform = قيم يحدّثها التطبيق في كل إطار.
+wgsl_uniform_cursor = موضع المؤشر فوق المعاينة، بالبكسل.
+wgsl_uniform_mouse_down = 1 أثناء الضغط على زر الفأرة فوق المعاينة، و0 خلاف ذلك.
+wgsl_uniform_mouse_press = موضع آخر ضغطة لزر الفأرة فوق المعاينة، بالبكسل.
+wgsl_uniform_mouse_release = موضع آخر تحرير لزر الفأرة فوق المعاينة، بالبكسل.
+wgsl_uniform_resolution = حجم المعاينة، بالبكسل.
+wgsl_uniform_time = الثواني منذ بدء تشغيل المظلّل.
+wgsl_image = يأخذ عيّنة من الخامة `t` باستخدام المُعايِن `spl` عند `uv`، مع وضع الأصل في أسفل اليسار.
+wgsl_section_array = الدوال المضمّنة للمصفوفات
+wgsl_section_atomic = الدوال المضمّنة الذرّية
+wgsl_section_derivative = الدوال المضمّنة للمشتقات
+wgsl_section_logical = الدوال المضمّنة المنطقية
+wgsl_section_numeric = الدوال المضمّنة العددية
+wgsl_section_packing = الدوال المضمّنة لحزم البيانات
+wgsl_section_synchronization = الدوال المضمّنة للمزامنة
+wgsl_section_texture = الدوال المضمّنة للخامات
+wgsl_section_unpacking = الدوال المضمّنة لفك حزم البيانات
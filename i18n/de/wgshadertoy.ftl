@@ -3,10 +3,46 @@
 menu_file = Datei
 menu_new = Neu
 menu_open = Öffnen
+menu_open_project = Projektverzeichnis öffnen
 menu_open_examples = Beispiele öffnen
 menu_save = Speichern
 menu_save_as = Speichern unter
+menu_save_project_as = Als Projektverzeichnis speichern
+menu_import_wgsl = WGSL importieren
+menu_export_wgsl = WGSL exportieren
+menu_export_wgsl_fragment = Nur Fragment-Quelltext
+menu_export_wgsl_standalone = Eigenständiger Shader
+menu_export_rust_project = Als Rust-Projekt exportieren
+menu_convert = Konvertieren
+menu_convert_wgs_to_project = .wgs in Projektverzeichnis
+menu_convert_project_to_wgs = Projektverzeichnis in .wgs
+menu_close_tab = Tab schließen
 menu_quit = Beenden
+## Menu Edit
+menu_edit = Bearbeiten
+menu_undo = Rückgängig
+menu_redo = Wiederholen
+menu_take_snapshot = Schnappschuss erstellen
+menu_snapshot_history = Schnappschussverlauf
+menu_find = Suchen
+menu_replace = Ersetzen
+menu_format = Dokument formatieren
+menu_comment_line = Zeilenkommentar umschalten
+menu_comment_block = Blockkommentar umschalten
+menu_duplicate_line = Zeile duplizieren
+menu_move_line_up = Zeile nach oben verschieben
+menu_move_line_down = Zeile nach unten verschieben
+menu_delete_line = Zeile löschen
+menu_outline = Gliederung
+menu_fold_all = Alle einklappen
+menu_unfold_all = Alle ausklappen
+menu_compare = Vergleichen
+menu_compare_saved = Mit gespeicherter Version
+menu_compare_tab = Mit { $name }
+menu_compare_side_by_side = Nebeneinander
+menu_compare_wipe = Überblenden
+menu_compare_show_diff = Unterschiede anzeigen
+menu_compare_stop = Vergleich beenden
 ## Menu Peferences
 menu_preferences = Einstellungen
 menu_languages = Sprachen
@@ -14,7 +50,27 @@ menu_language_system = Systemstandard
 menu_theme = Thema
 menu_theme_light = Hell
 menu_theme_dark = Dunkel
+menu_ui_scale = Skalierung der Oberfläche
+menu_editor = Editor
+menu_auto_indent = Automatisch einrücken
+menu_auto_close = Klammern und Anführungszeichen automatisch schließen
+menu_format_on_save = Beim Speichern formatieren
+menu_indent_width = Mit { $width } Leerzeichen einrücken
+menu_keymap_default = Standardtasten
+menu_keymap_emacs = Emacs-Tasten
+menu_keymap_vim = Vim-Tasten
+menu_font_family = Schriftart
+menu_font_family_default = Standard
+menu_font_family_ligatures = Mit Ligaturen (ohne sie dargestellt)
+menu_font_family_others = Andere Schriftarten
+menu_font_size = Schriftgröße
+menu_line_height = Zeilenhöhe
+menu_zoom_in = Vergrößern
+menu_zoom_out = Verkleinern
+menu_zoom_reset = Zoom zurücksetzen
 menu_record_fps = Nehmen Sie FPS auf
+menu_color_schemes = Farbschemata
+menu_shortcuts = Tastenkürzel
 ## Menu Help
 menu_help = Hilfe
 menu_about = Über
@@ -28,9 +84,116 @@ control_pause = Pause
 
 # Edit
 edit_name = Name
+edit_properties = Dokumenteigenschaften
+edit_author = Autor
+edit_description = Beschreibung
+edit_tags = Schlagwörter
+edit_license = Lizenz
+edit_created = Erstellt
+edit_modified = Geändert
+edit_thumbnail = Vorschaubild
+edit_capture_thumbnail = Vorschaubild aus der Vorschau aufnehmen
 edit_change_texture = Textur ändern
 edit_remove_texture = Textur entfernen
 edit_add_texture = Textur hinzufügen
+edit_references = Verweise
+edit_rename = Symbol umbenennen
+edit_rename_err_conflict = Der neue Name steht im Konflikt mit einem anderen Namen im Gültigkeitsbereich
+edit_rename_err_invalid_name = Kein gültiger WGSL-Bezeichner
+edit_rename_err_predeclared = Nur in diesem Shader deklarierte und verwendete Namen können umbenannt werden
+edit_find = Suchen
+edit_find_case_sensitive = Groß-/Kleinschreibung beachten
+edit_find_close = Schließen
+edit_find_count = { $total } Treffer
+edit_find_err_regex = Ungültiger regulärer Ausdruck
+edit_find_next = Nächster Treffer
+edit_find_no_results = Keine Treffer
+edit_find_position = { $current } von { $total }
+edit_find_previous = Vorheriger Treffer
+edit_find_regex = Regulären Ausdruck verwenden
+edit_find_toggle_replace = Ersetzen ein-/ausblenden
+edit_find_whole_word = Nur ganzes Wort suchen
+tab_close = Tab schließen
+tab_new = Neuer Tab
+dialog_discard_title = Ungespeicherte Änderungen
+dialog_discard = { $name } hat ungespeicherte Änderungen. Schließen und verwerfen?
+compare_current = Aktuell
+compare_diff = Unterschiede
+compare_frame_time = Durchschnittliche GPU-Zeit pro Frame
+compare_frame_time_estimate = Geschätzte GPU-Zeit pro Frame, da die GPU sie nicht meldet
+compare_no_changes = Keine Änderungen
+compare_saved = Gespeichert
+compare_wipe = Position der Überblendung
+snapshot_history = Schnappschüsse
+snapshot_label = Bezeichnung
+snapshot_take = Erstellen
+snapshot_untitled = Schnappschuss
+snapshot_diff = Vergleichen
+snapshot_diff_hint = Zeigt die Unterschiede zwischen den zwei ausgewählten Schnappschüssen
+snapshot_select = Zum Vergleichen auswählen
+snapshot_restore = Wiederherstellen
+snapshot_delete = Schnappschuss löschen
+outline_empty = Keine Funktionen, Strukturen oder Konstanten
+snapshot_none = Schnappschüsse werden bei jedem Kompilieren des Shaders oder von Hand erstellt.
+command_capture_image = Bild aufnehmen
+command_capture_thumbnail = Vorschaubild aufnehmen
+command_compare_saved = Mit gespeicherter Version vergleichen
+command_compile = Kompilieren und ausführen
+command_complete = Vervollständigung auslösen
+command_convert_project_to_wgs = Projektverzeichnis in .wgs umwandeln
+command_convert_wgs_to_project = .wgs in Projektverzeichnis umwandeln
+command_export_wgsl_fragment = WGSL-Fragmentquelltext exportieren
+command_export_wgsl_standalone = Eigenständigen WGSL-Shader exportieren
+command_find_references = Verweise suchen
+command_go_to_definition = Gehe zu Definition
+command_indent = Zeilen einrücken
+command_outdent = Zeilen ausrücken
+command_palette = Befehlspalette
+command_pause = Anhalten oder fortsetzen
+command_rename = Symbol umbenennen
+command_restart = Neu starten
+command_tab_next = Nächster Tab
+command_tab_previous = Vorheriger Tab
+command_texture_add = Textur hinzufügen
+shortcuts_title = Tastenkürzel
+shortcuts_filter = Befehle filtern
+shortcuts_reset_all = Alle zurücksetzen
+shortcuts_press = Tastenkürzel drücken…
+shortcuts_record = Klicken, dann das neue Tastenkürzel drücken, oder Escape zum Abbrechen
+shortcuts_clear = Tastenkürzel entfernen
+shortcuts_reset = Auf Standard zurücksetzen
+shortcuts_conflict = { $shortcut } ist bereits { $command } zugewiesen.
+shortcuts_reassign = Neu zuweisen
+shortcuts_cancel = Abbrechen
+color_schemes_title = Farbschemata
+color_schemes_dark = Dunkler Modus
+color_schemes_light = Heller Modus
+color_schemes_edit = Bearbeiten
+color_schemes_import = Importieren…
+color_schemes_import_hint = Ein Farbschema in TOML oder ein VS-Code- oder TextMate-Theme in JSON
+color_schemes_is_dark = Für einen dunklen Hintergrund
+color_schemes_preview = Vorschau
+color_schemes_save = Speichern
+color_schemes_reset = Zurücksetzen
+color_schemes_delete = Löschen
+color_schemes_new_name = Name der Kopie
+color_schemes_copy = Kopieren
+token_builtin_function = Integrierte Funktion
+token_comment = Kommentar
+token_field = Feld
+token_function = Funktionsaufruf
+token_function_definition = Funktionsdefinition
+token_global_variable = Globale Variable
+token_keyword_other = Schlüsselwort
+token_keyword_type = Typ
+token_literal = Text
+token_local_variable = Lokale Variable
+token_numeric = Zahl
+token_struct = Struktur
+palette_hint = Befehl eingeben
+edit_replace = Ersetzen
+edit_replace_all = Alle ersetzen
+edit_replace_one = Ersetzen
 
 # About
 about_homepage = Homepage
@@ -38,7 +201,40 @@ about_homepage = Homepage
 # Status
 status_compile_ok = Shader erfolgreich kompiliert!
 status_save_ok = Shader erfolgreich gespeichert!
+status_export_ok = Projekt erfolgreich exportiert!
+status_convert_ok = Shader erfolgreich konvertiert!
+status_color_scheme_imported = Farbschema { $name } importiert
+status_cursor = Z. { $line }, Sp. { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = Marke gesetzt
+keymap_vim_insert = EINFÜGEN
+keymap_vim_normal = NORMAL
+keymap_vim_visual = VISUELL
+keymap_vim_visual_line = VISUELL ZEILE
 status_err_open_example = Beispiel konnte nicht geöffnet werden
 status_err_open_file = Datei konnte nicht geöffnet werden
 status_err_open_texture = Textur konnte nicht geöffnet werden
+status_err_export = Projekt konnte nicht exportiert werden
+status_err_convert = Shader konnte nicht konvertiert werden
+status_err_import_color_scheme = Farbschema konnte nicht importiert werden
+status_err_save = Shader konnte nicht gespeichert werden
 status_err_valid = Shader-Validierungsfehler
+
+# WGSL
+wgsl_uniform = Werte, die die App in jedem Frame aktualisiert.
+wgsl_uniform_cursor = Position des Zeigers über der Vorschau, in Pixeln.
+wgsl_uniform_mouse_down = 1, solange eine Maustaste über der Vorschau gedrückt ist, sonst 0.
+wgsl_uniform_mouse_press = Wo zuletzt eine Maustaste über der Vorschau gedrückt wurde, in Pixeln.
+wgsl_uniform_mouse_release = Wo zuletzt eine Maustaste über der Vorschau losgelassen wurde, in Pixeln.
+wgsl_uniform_resolution = Größe der Vorschau, in Pixeln.
+wgsl_uniform_time = Sekunden seit dem Start des Shaders.
+wgsl_image = Tastet die Textur `t` mit dem Sampler `spl` bei `uv` ab, mit dem Ursprung unten links.
+wgsl_section_array = Integrierte Array-Funktionen
+wgsl_section_atomic = Integrierte atomare Funktionen
+wgsl_section_derivative = Integrierte Ableitungsfunktionen
+wgsl_section_logical = Integrierte logische Funktionen
+wgsl_section_numeric = Integrierte numerische Funktionen
+wgsl_section_packing = Integrierte Funktionen zum Packen von Daten
+wgsl_section_synchronization = Integrierte Synchronisationsfunktionen
+wgsl_section_texture = Integrierte Texturfunktionen
+wgsl_section_unpacking = Integrierte Funktionen zum Entpacken von Daten
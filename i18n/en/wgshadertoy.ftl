@@ -3,10 +3,46 @@
 menu_file = File
 menu_new = New
 menu_open = Open
+menu_open_project = Open Project Directory
 menu_open_examples = Open Examples
 menu_save = Save
 menu_save_as = Save As
+menu_save_project_as = Save As Project Directory
+menu_import_wgsl = Import WGSL
+menu_export_wgsl = Export WGSL
+menu_export_wgsl_fragment = Fragment Source Only
+menu_export_wgsl_standalone = Standalone Shader
+menu_export_rust_project = Export as Rust Project
+menu_convert = Convert
+menu_convert_wgs_to_project = .wgs to Project Directory
+menu_convert_project_to_wgs = Project Directory to .wgs
+menu_close_tab = Close Tab
 menu_quit = Quit
+## Menu Edit
+menu_edit = Edit
+menu_undo = Undo
+menu_redo = Redo
+menu_take_snapshot = Take Snapshot
+menu_snapshot_history = Snapshot History
+menu_find = Find
+menu_replace = Replace
+menu_format = Format Document
+menu_comment_line = Toggle Line Comment
+menu_comment_block = Toggle Block Comment
+menu_duplicate_line = Duplicate Line
+menu_move_line_up = Move Line Up
+menu_move_line_down = Move Line Down
+menu_delete_line = Delete Line
+menu_outline = Outline
+menu_fold_all = Fold All
+menu_unfold_all = Unfold All
+menu_compare = Compare
+menu_compare_saved = With Saved Version
+menu_compare_tab = With { $name }
+menu_compare_side_by_side = Side by Side
+menu_compare_wipe = Wipe
+menu_compare_show_diff = Show Differences
+menu_compare_stop = Stop Comparing
 ## Menu Peferences
 menu_preferences = Preferences
 menu_languages = Languages
@@ -14,7 +50,27 @@ menu_language_system = System Default
 menu_theme = Theme
 menu_theme_light = Light
 menu_theme_dark = Dark
+menu_ui_scale = Interface Scale
+menu_editor = Editor
+menu_auto_indent = Auto-Indent
+menu_auto_close = Auto-Close Brackets and Quotes
+menu_format_on_save = Format on Save
+menu_indent_width = Indent With { $width } Spaces
+menu_keymap_default = Default Keys
+menu_keymap_emacs = Emacs Keys
+menu_keymap_vim = Vim Keys
+menu_font_family = Font
+menu_font_family_default = Default
+menu_font_family_ligatures = With ligatures (drawn without them)
+menu_font_family_others = Other fonts
+menu_font_size = Font Size
+menu_line_height = Line Height
+menu_zoom_in = Zoom In
+menu_zoom_out = Zoom Out
+menu_zoom_reset = Reset Zoom
 menu_record_fps = Record FPS
+menu_color_schemes = Color Schemes
+menu_shortcuts = Keyboard Shortcuts
 ## Menu Help
 menu_help = Help
 menu_about = About
@@ -28,9 +84,116 @@ control_pause = Pause
 
 # Edit
 edit_name = Name
+edit_properties = Document properties
+edit_author = Author
+edit_description = Description
+edit_tags = Tags
+edit_license = License
+edit_created = Created
+edit_modified = Modified
+edit_thumbnail = Thumbnail
+edit_capture_thumbnail = Capture thumbnail from preview
 edit_change_texture = Change texture
 edit_remove_texture = Remove texture
 edit_add_texture = Add texture
+edit_references = References
+edit_rename = Rename symbol
+edit_rename_err_conflict = The new name conflicts with another name in scope
+edit_rename_err_invalid_name = Not a valid WGSL identifier
+edit_rename_err_predeclared = Only names declared and used in this shader can be renamed
+edit_find = Find
+edit_find_case_sensitive = Match case
+edit_find_close = Close
+edit_find_count = { $total } results
+edit_find_err_regex = Invalid regular expression
+edit_find_next = Next match
+edit_find_no_results = No results
+edit_find_position = { $current } of { $total }
+edit_find_previous = Previous match
+edit_find_regex = Use regular expression
+edit_find_toggle_replace = Toggle replace
+edit_find_whole_word = Match whole word
+tab_close = Close tab
+tab_new = New tab
+dialog_discard_title = Unsaved Changes
+dialog_discard = { $name } has unsaved changes. Close it and discard them?
+compare_current = Current
+compare_diff = Differences
+compare_frame_time = Average GPU time per frame
+compare_frame_time_estimate = Estimated GPU time per frame, as the GPU doesn't report it
+compare_no_changes = No changes
+compare_saved = Saved
+compare_wipe = Wipe position
+snapshot_history = Snapshots
+snapshot_label = Label
+snapshot_take = Take
+snapshot_untitled = Snapshot
+snapshot_diff = Diff
+snapshot_diff_hint = Shows the differences between the two snapshots selected
+snapshot_select = Select to diff
+snapshot_restore = Restore
+snapshot_delete = Delete snapshot
+outline_empty = No functions, structs or constants
+snapshot_none = Snapshots are taken each time the shader compiles, or by hand.
+command_capture_image = Capture Image
+command_capture_thumbnail = Capture Thumbnail
+command_compare_saved = Compare With Saved Version
+command_compile = Compile and Run
+command_complete = Trigger Completion
+command_convert_project_to_wgs = Convert Project Directory to .wgs
+command_convert_wgs_to_project = Convert .wgs to Project Directory
+command_export_wgsl_fragment = Export WGSL Fragment Source
+command_export_wgsl_standalone = Export Standalone WGSL Shader
+command_find_references = Find References
+command_go_to_definition = Go to Definition
+command_indent = Indent Lines
+command_outdent = Outdent Lines
+command_palette = Command Palette
+command_pause = Pause or Resume
+command_rename = Rename Symbol
+command_restart = Restart
+command_tab_next = Next Tab
+command_tab_previous = Previous Tab
+command_texture_add = Add Texture
+shortcuts_title = Keyboard Shortcuts
+shortcuts_filter = Filter commands
+shortcuts_reset_all = Reset All
+shortcuts_press = Press a shortcut…
+shortcuts_record = Click, then press the new shortcut, or Escape to cancel
+shortcuts_clear = Remove shortcut
+shortcuts_reset = Reset to default
+shortcuts_conflict = { $shortcut } is already bound to { $command }.
+shortcuts_reassign = Reassign
+shortcuts_cancel = Cancel
+color_schemes_title = Color Schemes
+color_schemes_dark = Dark mode
+color_schemes_light = Light mode
+color_schemes_edit = Edit
+color_schemes_import = Import…
+color_schemes_import_hint = A color scheme in TOML, or a VS Code or TextMate theme in JSON
+color_schemes_is_dark = For a dark background
+color_schemes_preview = Preview
+color_schemes_save = Save
+color_schemes_reset = Reset
+color_schemes_delete = Delete
+color_schemes_new_name = Name of a copy
+color_schemes_copy = Copy
+token_builtin_function = Built-in function
+token_comment = Comment
+token_field = Field
+token_function = Function call
+token_function_definition = Function definition
+token_global_variable = Global variable
+token_keyword_other = Keyword
+token_keyword_type = Type
+token_literal = Text
+token_local_variable = Local variable
+token_numeric = Number
+token_struct = Struct
+palette_hint = Type a command
+edit_replace = Replace
+edit_replace_all = Replace all
+edit_replace_one = Replace
 
 # About
 about_homepage = Homepage
@@ -38,7 +201,154 @@ about_homepage = Homepage
 # Status
 status_compile_ok = Shader compiled successfully!
 status_save_ok = Shader saved successfully!
+status_export_ok = Project exported successfully!
+status_convert_ok = Shader converted successfully!
+status_color_scheme_imported = Color scheme { $name } imported
+status_cursor = Ln { $line }, Col { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = mark set
+keymap_vim_insert = INSERT
+keymap_vim_normal = NORMAL
+keymap_vim_visual = VISUAL
+keymap_vim_visual_line = VISUAL LINE
 status_err_open_example = Failed to open example
 status_err_open_file = Failed to open file
 status_err_open_texture = Failed to open texture
+status_err_export = Failed to export project
+status_err_convert = Failed to convert shader
+status_err_import_color_scheme = Failed to import color scheme
+status_err_save = Failed to save shader
 status_err_valid = Shader validation error
+
+# WGSL
+wgsl_uniform = Values updated by the app every frame.
+wgsl_uniform_cursor = Position of the pointer over the preview, in pixels.
+wgsl_uniform_mouse_down = 1 while a mouse button is held over the preview, 0 otherwise.
+wgsl_uniform_mouse_press = Where a mouse button was last pressed over the preview, in pixels.
+wgsl_uniform_mouse_release = Where a mouse button was last released over the preview, in pixels.
+wgsl_uniform_resolution = Size of the preview, in pixels.
+wgsl_uniform_time = Seconds since the shader started running.
+wgsl_image = Samples texture `t` with sampler `spl` at `uv`, with the origin at the bottom left.
+wgsl_section_array = Array built-in functions
+wgsl_section_atomic = Atomic built-in functions
+wgsl_section_derivative = Derivative built-in functions
+wgsl_section_logical = Logical built-in functions
+wgsl_section_numeric = Numeric built-in functions
+wgsl_section_packing = Data packing built-in functions
+wgsl_section_synchronization = Synchronization built-in functions
+wgsl_section_texture = Texture built-in functions
+wgsl_section_unpacking = Data unpacking built-in functions
+
+## WGSL built-in functions
+## Translations may be left out: missing ones fall back to English.
+wgsl_fn_abs = The absolute value of `e`, component-wise for vectors.
+wgsl_fn_acos = The arc cosine of `e`, in radians.
+wgsl_fn_acosh = The inverse hyperbolic cosine of `e`.
+wgsl_fn_all = Whether every component of `e` is true.
+wgsl_fn_any = Whether any component of `e` is true.
+wgsl_fn_arrayLength = The number of elements in the runtime-sized array `p` points to.
+wgsl_fn_asin = The arc sine of `e`, in radians.
+wgsl_fn_asinh = The inverse hyperbolic sine of `e`.
+wgsl_fn_atan = The arc tangent of `e`, in radians.
+wgsl_fn_atan2 = The angle, in radians, whose tangent is `y / x`, in the quadrant given by the signs of `y` and `x`.
+wgsl_fn_atanh = The inverse hyperbolic tangent of `e`.
+wgsl_fn_atomicAdd = Atomically adds `v` to the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_atomicAnd = Atomically computes the bitwise AND of `v` and the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_atomicCompareExchangeWeak = Atomically stores `v` if the value `atomic_ptr` points to equals `cmp`, returning the original value and whether it was exchanged.
+wgsl_fn_atomicExchange = Atomically stores `v` where `atomic_ptr` points, returning the original value.
+wgsl_fn_atomicLoad = Atomically loads the value `atomic_ptr` points to.
+wgsl_fn_atomicMax = Atomically stores the maximum of `v` and the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_atomicMin = Atomically stores the minimum of `v` and the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_atomicOr = Atomically computes the bitwise OR of `v` and the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_atomicStore = Atomically stores `v` where `atomic_ptr` points.
+wgsl_fn_atomicSub = Atomically subtracts `v` from the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_atomicXor = Atomically computes the bitwise XOR of `v` and the value `atomic_ptr` points to, returning the original value.
+wgsl_fn_ceil = The smallest whole number not less than `e`.
+wgsl_fn_clamp = Restricts `e` to the range from `low` to `high`.
+wgsl_fn_cos = The cosine of `e`, in radians.
+wgsl_fn_cosh = The hyperbolic cosine of `e`.
+wgsl_fn_countLeadingZeros = The number of consecutive 0 bits starting from the most significant bit of `e`.
+wgsl_fn_countOneBits = The number of 1 bits in `e`.
+wgsl_fn_countTrailingZeros = The number of consecutive 0 bits starting from the least significant bit of `e`.
+wgsl_fn_cross = The cross product of `e1` and `e2`.
+wgsl_fn_degrees = Converts `e` from radians to degrees.
+wgsl_fn_determinant = The determinant of the square matrix `e`.
+wgsl_fn_distance = The distance between `e1` and `e2`.
+wgsl_fn_dot = The dot product of `e1` and `e2`.
+wgsl_fn_dpdx = The partial derivative of `e` with respect to the window x coordinate.
+wgsl_fn_dpdxCoarse = The partial derivative of `e` with respect to the window x coordinate, computed from fewer neighbouring invocations.
+wgsl_fn_dpdxFine = The partial derivative of `e` with respect to the window x coordinate, computed for each invocation.
+wgsl_fn_dpdy = The partial derivative of `e` with respect to the window y coordinate.
+wgsl_fn_dpdyCoarse = The partial derivative of `e` with respect to the window y coordinate, computed from fewer neighbouring invocations.
+wgsl_fn_dpdyFine = The partial derivative of `e` with respect to the window y coordinate, computed for each invocation.
+wgsl_fn_exp = The natural exponentiation of `e`, that is e to the power of `e`.
+wgsl_fn_exp2 = 2 raised to the power of `e`.
+wgsl_fn_extractBits = Reads `count` bits of `e` starting at bit `offset`.
+wgsl_fn_faceForward = Returns `e1` if the dot product of `e2` and `e3` is negative, and `-e1` otherwise.
+wgsl_fn_firstLeadingBit = The index of the most significant bit of `e` that differs from its sign bit, or -1.
+wgsl_fn_firstTrailingBit = The index of the least significant 1 bit of `e`, or -1 if there is none.
+wgsl_fn_floor = The largest whole number not greater than `e`.
+wgsl_fn_fma = Computes `e1 * e2 + e3`.
+wgsl_fn_fract = The fractional part of `e`, that is `e - floor(e)`.
+wgsl_fn_frexp = Splits `e` into a fraction and an exponent, such that `e = fract * 2^exp`.
+wgsl_fn_fwidth = The sum of the absolute derivatives of `e` in x and y.
+wgsl_fn_fwidthCoarse = The sum of the absolute coarse derivatives of `e` in x and y.
+wgsl_fn_fwidthFine = The sum of the absolute fine derivatives of `e` in x and y.
+wgsl_fn_insertBits = Replaces `count` bits of `e` starting at bit `offset` with the low bits of `newbits`.
+wgsl_fn_inverseSqrt = The reciprocal of the square root of `e`.
+wgsl_fn_ldexp = Computes `e1 * 2^e2`.
+wgsl_fn_length = The length of the vector `e`.
+wgsl_fn_log = The natural logarithm of `e`.
+wgsl_fn_log2 = The base-2 logarithm of `e`.
+wgsl_fn_max = The larger of `e1` and `e2`.
+wgsl_fn_min = The smaller of `e1` and `e2`.
+wgsl_fn_mix = Linearly blends `e1` and `e2` by `e3`, that is `e1 * (1 - e3) + e2 * e3`.
+wgsl_fn_modf = Splits `e` into its fractional and whole parts.
+wgsl_fn_normalize = The vector `e` scaled to a length of 1.
+wgsl_fn_pack2x16float = Converts the components of `e` to 16-bit floats and packs them into one u32.
+wgsl_fn_pack2x16snorm = Converts the components of `e`, from -1 to 1, to 16-bit signed normalized integers and packs them into one u32.
+wgsl_fn_pack2x16unorm = Converts the components of `e`, from 0 to 1, to 16-bit unsigned normalized integers and packs them into one u32.
+wgsl_fn_pack4x8snorm = Converts the components of `e`, from -1 to 1, to 8-bit signed normalized integers and packs them into one u32.
+wgsl_fn_pack4x8unorm = Converts the components of `e`, from 0 to 1, to 8-bit unsigned normalized integers and packs them into one u32.
+wgsl_fn_pow = `e1` raised to the power of `e2`.
+wgsl_fn_quantizeToF16 = Rounds `e` to the nearest value representable as a 16-bit float.
+wgsl_fn_radians = Converts `e` from degrees to radians.
+wgsl_fn_reflect = Reflects the incident vector `e1` about the surface normal `e2`.
+wgsl_fn_refract = Refracts the incident vector `e1` through the surface normal `e2` with the ratio of indices of refraction `e3`.
+wgsl_fn_reverseBits = Reverses the order of the bits of `e`.
+wgsl_fn_round = Rounds `e` to the nearest whole number, halfway cases to the nearest even number.
+wgsl_fn_saturate = Restricts `e` to the range from 0 to 1.
+wgsl_fn_select = Returns `t` when `cond` is true and `f` otherwise, component-wise for vector conditions.
+wgsl_fn_sign = -1, 0 or 1, depending on the sign of `e`.
+wgsl_fn_sin = The sine of `e`, in radians.
+wgsl_fn_sinh = The hyperbolic sine of `e`.
+wgsl_fn_smoothstep = Smooth Hermite interpolation from 0 to 1 as `x` goes from `low` to `high`.
+wgsl_fn_sqrt = The square root of `e`.
+wgsl_fn_step = 1 if `edge` is less than or equal to `x`, 0 otherwise.
+wgsl_fn_storageBarrier = Makes the storage writes of the workgroup visible before any invocation continues.
+wgsl_fn_tan = The tangent of `e`, in radians.
+wgsl_fn_tanh = The hyperbolic tangent of `e`.
+wgsl_fn_textureDimensions = The size of the texture `t`, or of its mip level `level`, in texels.
+wgsl_fn_textureGather = Reads one component from the four texels a bilinear sample of `t` at `coords` would use.
+wgsl_fn_textureGatherCompare = Compares `depth_ref` with the four texels a bilinear sample of the depth texture `t` at `coords` would use.
+wgsl_fn_textureLoad = Reads a single texel of `t` at integer `coords` without sampling.
+wgsl_fn_textureNumLayers = The number of layers of the array texture `t`.
+wgsl_fn_textureNumLevels = The number of mip levels of the texture `t`.
+wgsl_fn_textureNumSamples = The number of samples per texel of the multisampled texture `t`.
+wgsl_fn_textureSample = Samples the texture `t` with the sampler `s` at `coords`, choosing the mip level automatically.
+wgsl_fn_textureSampleBaseClampToEdge = Samples the base mip level of `t` at `coords`, clamped so that it never reads past the edge.
+wgsl_fn_textureSampleBias = Samples the texture `t` at `coords`, with `bias` added to the automatically chosen mip level.
+wgsl_fn_textureSampleCompare = Samples the depth texture `t` at `coords` and compares the result with `depth_ref`.
+wgsl_fn_textureSampleCompareLevel = Samples mip level 0 of the depth texture `t` at `coords` and compares the result with `depth_ref`.
+wgsl_fn_textureSampleGrad = Samples the texture `t` at `coords`, choosing the mip level from the explicit gradients `ddx` and `ddy`.
+wgsl_fn_textureSampleLevel = Samples the texture `t` with the sampler `s` at `coords`, from the explicit mip level `level`.
+wgsl_fn_textureStore = Writes `value` to the texel of the storage texture `t` at `coords`.
+wgsl_fn_transpose = The transpose of the matrix `e`.
+wgsl_fn_trunc = The whole number nearest to `e` whose absolute value is not greater.
+wgsl_fn_unpack2x16float = Unpacks two 16-bit floats from `e`.
+wgsl_fn_unpack2x16snorm = Unpacks two 16-bit signed normalized integers from `e` into floats from -1 to 1.
+wgsl_fn_unpack2x16unorm = Unpacks two 16-bit unsigned normalized integers from `e` into floats from 0 to 1.
+wgsl_fn_unpack4x8snorm = Unpacks four 8-bit signed normalized integers from `e` into floats from -1 to 1.
+wgsl_fn_unpack4x8unorm = Unpacks four 8-bit unsigned normalized integers from `e` into floats from 0 to 1.
+wgsl_fn_workgroupBarrier = Waits for every invocation of the workgroup and makes their workgroup memory writes visible.
+wgsl_fn_workgroupUniformLoad = Loads the value `p` points to and shares it with the whole workgroup, after a workgroup barrier.
@@ -3,10 +3,46 @@
 menu_file = Archivo
 menu_new = Nuevo
 menu_open = Abrir
+menu_open_project = Abrir directorio de proyecto
 menu_open_examples = Abrir Ejemplos
 menu_save = Guardar
 menu_save_as = Guardar Como
+menu_save_project_as = Guardar como directorio de proyecto
+menu_import_wgsl = Importar WGSL
+menu_export_wgsl = Exportar WGSL
+menu_export_wgsl_fragment = Solo código del fragmento
+menu_export_wgsl_standalone = Shader independiente
+menu_export_rust_project = Exportar como proyecto Rust
+menu_convert = Convertir
+menu_convert_wgs_to_project = .wgs a directorio de proyecto
+menu_convert_project_to_wgs = Directorio de proyecto a .wgs
+menu_close_tab = Cerrar pestaña
 menu_quit = Salir
+## Menu Edit
+menu_edit = Editar
+menu_undo = Deshacer
+menu_redo = Rehacer
+menu_take_snapshot = Tomar instantánea
+menu_snapshot_history = Historial de instantáneas
+menu_find = Buscar
+menu_replace = Reemplazar
+menu_format = Dar formato al documento
+menu_comment_line = Alternar comentario de línea
+menu_comment_block = Alternar comentario de bloque
+menu_duplicate_line = Duplicar línea
+menu_move_line_up = Mover línea arriba
+menu_move_line_down = Mover línea abajo
+menu_delete_line = Eliminar línea
+menu_outline = Esquema
+menu_fold_all = Plegar todo
+menu_unfold_all = Desplegar todo
+menu_compare = Comparar
+menu_compare_saved = Con la versión guardada
+menu_compare_tab = Con { $name }
+menu_compare_side_by_side = Lado a lado
+menu_compare_wipe = Cortinilla
+menu_compare_show_diff = Mostrar diferencias
+menu_compare_stop = Dejar de comparar
 ## Menu Peferences
 menu_preferences = Preferencias
 menu_languages = Idiomas
@@ -14,7 +50,27 @@ menu_language_system = Sistema Predeterminado
 menu_theme = Tema
 menu_theme_light = Claro
 menu_theme_dark = Oscuro
+menu_ui_scale = Escala de la interfaz
+menu_editor = Editor
+menu_auto_indent = Sangría automática
+menu_auto_close = Cerrar corchetes y comillas automáticamente
+menu_format_on_save = Dar formato al guardar
+menu_indent_width = Sangría de { $width } espacios
+menu_keymap_default = Teclas predeterminadas
+menu_keymap_emacs = Teclas de Emacs
+menu_keymap_vim = Teclas de Vim
+menu_font_family = Fuente
+menu_font_family_default = Predeterminada
+menu_font_family_ligatures = Con ligaduras (se muestran sin ellas)
+menu_font_family_others = Otras fuentes
+menu_font_size = Tamaño de fuente
+menu_line_height = Altura de línea
+menu_zoom_in = Acercar
+menu_zoom_out = Alejar
+menu_zoom_reset = Restablecer zoom
 menu_record_fps = Grabar FPS
+menu_color_schemes = Esquemas de color
+menu_shortcuts = Atajos de teclado
 ## Menu Help
 menu_help = Ayuda
 menu_about = Acerca de
@@ -28,9 +84,116 @@ control_pause = Pausa
 
 # Edit
 edit_name = Nombre
+edit_properties = Propiedades del documento
+edit_author = Autor
+edit_description = Descripción
+edit_tags = Etiquetas
+edit_license = Licencia
+edit_created = Creado
+edit_modified = Modificado
+edit_thumbnail = Miniatura
+edit_capture_thumbnail = Capturar miniatura de la vista previa
 edit_change_texture = Cambiar textura
 edit_remove_texture = Eliminar textura
 edit_add_texture = Añadir textura
+edit_references = Referencias
+edit_rename = Cambiar nombre del símbolo
+edit_rename_err_conflict = El nuevo nombre entra en conflicto con otro nombre del ámbito
+edit_rename_err_invalid_name = No es un identificador WGSL válido
+edit_rename_err_predeclared = Solo se pueden renombrar nombres declarados y usados en este shader
+edit_find = Buscar
+edit_find_case_sensitive = Coincidir mayúsculas y minúsculas
+edit_find_close = Cerrar
+edit_find_count = { $total } resultados
+edit_find_err_regex = Expresión regular no válida
+edit_find_next = Siguiente coincidencia
+edit_find_no_results = Sin resultados
+edit_find_position = { $current } de { $total }
+edit_find_previous = Coincidencia anterior
+edit_find_regex = Usar expresión regular
+edit_find_toggle_replace = Mostrar u ocultar reemplazar
+edit_find_whole_word = Solo palabras completas
+tab_close = Cerrar pestaña
+tab_new = Nueva pestaña
+dialog_discard_title = Cambios sin guardar
+dialog_discard = { $name } tiene cambios sin guardar. ¿Cerrarlo y descartarlos?
+compare_current = Actual
+compare_diff = Diferencias
+compare_frame_time = Tiempo medio de GPU por fotograma
+compare_frame_time_estimate = Tiempo de GPU estimado por fotograma, ya que la GPU no lo informa
+compare_no_changes = Sin cambios
+compare_saved = Guardada
+compare_wipe = Posición de la cortinilla
+snapshot_history = Instantáneas
+snapshot_label = Etiqueta
+snapshot_take = Tomar
+snapshot_untitled = Instantánea
+snapshot_diff = Comparar
+snapshot_diff_hint = Muestra las diferencias entre las dos instantáneas seleccionadas
+snapshot_select = Seleccionar para comparar
+snapshot_restore = Restaurar
+snapshot_delete = Eliminar instantánea
+outline_empty = No hay funciones, estructuras ni constantes
+snapshot_none = Las instantáneas se toman cada vez que el shader se compila, o a mano.
+command_capture_image = Capturar imagen
+command_capture_thumbnail = Capturar miniatura
+command_compare_saved = Comparar con la versión guardada
+command_compile = Compilar y ejecutar
+command_complete = Mostrar sugerencias
+command_convert_project_to_wgs = Convertir directorio de proyecto a .wgs
+command_convert_wgs_to_project = Convertir .wgs a directorio de proyecto
+command_export_wgsl_fragment = Exportar código de fragmento WGSL
+command_export_wgsl_standalone = Exportar shader WGSL independiente
+command_find_references = Buscar referencias
+command_go_to_definition = Ir a la definición
+command_indent = Aumentar sangría
+command_outdent = Reducir sangría
+command_palette = Paleta de comandos
+command_pause = Pausar o reanudar
+command_rename = Cambiar nombre del símbolo
+command_restart = Reiniciar
+command_tab_next = Pestaña siguiente
+command_tab_previous = Pestaña anterior
+command_texture_add = Añadir textura
+shortcuts_title = Atajos de teclado
+shortcuts_filter = Filtrar comandos
+shortcuts_reset_all = Restablecer todo
+shortcuts_press = Pulsa un atajo…
+shortcuts_record = Haz clic y pulsa el nuevo atajo, o Escape para cancelar
+shortcuts_clear = Quitar atajo
+shortcuts_reset = Restablecer valor predeterminado
+shortcuts_conflict = { $shortcut } ya está asignado a { $command }.
+shortcuts_reassign = Reasignar
+shortcuts_cancel = Cancelar
+color_schemes_title = Esquemas de color
+color_schemes_dark = Modo oscuro
+color_schemes_light = Modo claro
+color_schemes_edit = Editar
+color_schemes_import = Importar…
+color_schemes_import_hint = Un esquema de color en TOML, o un tema de VS Code o TextMate en JSON
+color_schemes_is_dark = Para fondo oscuro
+color_schemes_preview = Vista previa
+color_schemes_save = Guardar
+color_schemes_reset = Restablecer
+color_schemes_delete = Eliminar
+color_schemes_new_name = Nombre de la copia
+color_schemes_copy = Copiar
+token_builtin_function = Función integrada
+token_comment = Comentario
+token_field = Campo
+token_function = Llamada a función
+token_function_definition = Definición de función
+token_global_variable = Variable global
+token_keyword_other = Palabra clave
+token_keyword_type = Tipo
+token_literal = Texto
+token_local_variable = Variable local
+token_numeric = Número
+token_struct = Estructura
+palette_hint = Escribe un comando
+edit_replace = Reemplazar
+edit_replace_all = Reemplazar todo
+edit_replace_one = Reemplazar
 
 # About
 about_homepage = Página de inicio
@@ -38,7 +201,40 @@ about_homepage = Página de inicio
 # Status
 status_compile_ok = ¡Shader compilado con éxito!
 status_save_ok = ¡Shader guardado con éxito!
+status_export_ok = ¡Proyecto exportado correctamente!
+status_convert_ok = ¡Shader convertido correctamente!
+status_color_scheme_imported = Esquema de color { $name } importado
+status_cursor = Lín. { $line }, col. { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = marca establecida
+keymap_vim_insert = INSERTAR
+keymap_vim_normal = NORMAL
+keymap_vim_visual = VISUAL
+keymap_vim_visual_line = VISUAL LÍNEA
 status_err_open_example = Error al abrir el ejemplo
 status_err_open_file = Error al abrir el archivo
 status_err_open_texture = Error al abrir la textura
+status_err_export = Error al exportar el proyecto
+status_err_convert = Error al convertir el shader
+status_err_import_color_scheme = No se pudo importar el esquema de color
+status_err_save = Error al guardar el shader
 status_err_valid = Error de validación del Shader
+
+# WGSL
+wgsl_uniform = Valores que la aplicación actualiza en cada fotograma.
+wgsl_uniform_cursor = Posición del puntero sobre la vista previa, en píxeles.
+wgsl_uniform_mouse_down = 1 mientras se mantiene pulsado un botón del ratón sobre la vista previa, 0 en caso contrario.
+wgsl_uniform_mouse_press = Dónde se pulsó por última vez un botón del ratón sobre la vista previa, en píxeles.
+wgsl_uniform_mouse_release = Dónde se soltó por última vez un botón del ratón sobre la vista previa, en píxeles.
+wgsl_uniform_resolution = Tamaño de la vista previa, en píxeles.
+wgsl_uniform_time = Segundos desde que el shader empezó a ejecutarse.
+wgsl_image = Muestrea la textura `t` con el muestreador `spl` en `uv`, con el origen abajo a la izquierda.
+wgsl_section_array = Funciones integradas de arrays
+wgsl_section_atomic = Funciones integradas atómicas
+wgsl_section_derivative = Funciones integradas de derivadas
+wgsl_section_logical = Funciones integradas lógicas
+wgsl_section_numeric = Funciones integradas numéricas
+wgsl_section_packing = Funciones integradas de empaquetado de datos
+wgsl_section_synchronization = Funciones integradas de sincronización
+wgsl_section_texture = Funciones integradas de texturas
+wgsl_section_unpacking = Funciones integradas de desempaquetado de datos
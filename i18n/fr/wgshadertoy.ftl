@@ -3,10 +3,46 @@
 menu_file = Fichier
 menu_new = Nouveau
 menu_open = Ouvrir
+menu_open_project = Ouvrir un dossier de projet
 menu_open_examples = Ouvrir des exemples
 menu_save = Enregistrer
 menu_save_as = Enregistrer sous
+menu_save_project_as = Enregistrer comme dossier de projet
+menu_import_wgsl = Importer WGSL
+menu_export_wgsl = Exporter WGSL
+menu_export_wgsl_fragment = Source du fragment uniquement
+menu_export_wgsl_standalone = Shader autonome
+menu_export_rust_project = Exporter en projet Rust
+menu_convert = Convertir
+menu_convert_wgs_to_project = .wgs vers dossier de projet
+menu_convert_project_to_wgs = Dossier de projet vers .wgs
+menu_close_tab = Fermer l'onglet
 menu_quit = Quitter
+## Menu Edit
+menu_edit = Édition
+menu_undo = Annuler
+menu_redo = Rétablir
+menu_take_snapshot = Prendre un instantané
+menu_snapshot_history = Historique des instantanés
+menu_find = Rechercher
+menu_replace = Remplacer
+menu_format = Mettre en forme le document
+menu_comment_line = Basculer le commentaire de ligne
+menu_comment_block = Basculer le commentaire de bloc
+menu_duplicate_line = Dupliquer la ligne
+menu_move_line_up = Déplacer la ligne vers le haut
+menu_move_line_down = Déplacer la ligne vers le bas
+menu_delete_line = Supprimer la ligne
+menu_outline = Structure
+menu_fold_all = Tout replier
+menu_unfold_all = Tout déplier
+menu_compare = Comparer
+menu_compare_saved = Avec la version enregistrée
+menu_compare_tab = Avec { $name }
+menu_compare_side_by_side = Côte à côte
+menu_compare_wipe = Volet
+menu_compare_show_diff = Afficher les différences
+menu_compare_stop = Arrêter la comparaison
 ## Menu Peferences
 menu_preferences = Préférences
 menu_languages = Langues
@@ -14,7 +50,27 @@ menu_language_system = Par défaut du système
 menu_theme = Thème
 menu_theme_light = Clair
 menu_theme_dark = Sombre
+menu_ui_scale = Échelle de l’interface
+menu_editor = Éditeur
+menu_auto_indent = Indentation automatique
+menu_auto_close = Fermer automatiquement les crochets et les guillemets
+menu_format_on_save = Mettre en forme à l'enregistrement
+menu_indent_width = Indenter avec { $width } espaces
+menu_keymap_default = Touches par défaut
+menu_keymap_emacs = Touches Emacs
+menu_keymap_vim = Touches Vim
+menu_font_family = Police
+menu_font_family_default = Par défaut
+menu_font_family_ligatures = Avec ligatures (affichées sans elles)
+menu_font_family_others = Autres polices
+menu_font_size = Taille de police
+menu_line_height = Hauteur de ligne
+menu_zoom_in = Zoom avant
+menu_zoom_out = Zoom arrière
+menu_zoom_reset = Réinitialiser le zoom
 menu_record_fps = Enregistrer des FPS
+menu_color_schemes = Jeux de couleurs
+menu_shortcuts = Raccourcis clavier
 ## Menu Help
 menu_help = Aide
 menu_about = À propos
@@ -28,9 +84,116 @@ control_pause = Pause
 
 # Edit
 edit_name = Nom
+edit_properties = Propriétés du document
+edit_author = Auteur
+edit_description = Description
+edit_tags = Étiquettes
+edit_license = Licence
+edit_created = Créé
+edit_modified = Modifié
+edit_thumbnail = Miniature
+edit_capture_thumbnail = Capturer la miniature depuis l'aperçu
 edit_change_texture = Changer de texture
 edit_remove_texture = Supprimer la texture
 edit_add_texture = Ajouter une texture
+edit_references = Références
+edit_rename = Renommer le symbole
+edit_rename_err_conflict = Le nouveau nom entre en conflit avec un autre nom de la portée
+edit_rename_err_invalid_name = Identifiant WGSL invalide
+edit_rename_err_predeclared = Seuls les noms déclarés et utilisés dans ce shader peuvent être renommés
+edit_find = Rechercher
+edit_find_case_sensitive = Respecter la casse
+edit_find_close = Fermer
+edit_find_count = { $total } résultats
+edit_find_err_regex = Expression régulière invalide
+edit_find_next = Occurrence suivante
+edit_find_no_results = Aucun résultat
+edit_find_position = { $current } sur { $total }
+edit_find_previous = Occurrence précédente
+edit_find_regex = Utiliser une expression régulière
+edit_find_toggle_replace = Afficher ou masquer le remplacement
+edit_find_whole_word = Mot entier uniquement
+tab_close = Fermer l'onglet
+tab_new = Nouvel onglet
+dialog_discard_title = Modifications non enregistrées
+dialog_discard = { $name } a des modifications non enregistrées. Le fermer et les abandonner ?
+compare_current = Actuelle
+compare_diff = Différences
+compare_frame_time = Temps GPU moyen par image
+compare_frame_time_estimate = Temps GPU estimé par image, le GPU ne le fournissant pas
+compare_no_changes = Aucune modification
+compare_saved = Enregistrée
+compare_wipe = Position du volet
+snapshot_history = Instantanés
+snapshot_label = Libellé
+snapshot_take = Prendre
+snapshot_untitled = Instantané
+snapshot_diff = Comparer
+snapshot_diff_hint = Affiche les différences entre les deux instantanés sélectionnés
+snapshot_select = Sélectionner pour comparer
+snapshot_restore = Restaurer
+snapshot_delete = Supprimer l'instantané
+outline_empty = Aucune fonction, structure ni constante
+snapshot_none = Les instantanés sont pris à chaque compilation du shader, ou à la main.
+command_capture_image = Capturer une image
+command_capture_thumbnail = Capturer la miniature
+command_compare_saved = Comparer avec la version enregistrée
+command_compile = Compiler et exécuter
+command_complete = Déclencher la complétion
+command_convert_project_to_wgs = Convertir le répertoire de projet en .wgs
+command_convert_wgs_to_project = Convertir .wgs en répertoire de projet
+command_export_wgsl_fragment = Exporter la source de fragment WGSL
+command_export_wgsl_standalone = Exporter un shader WGSL autonome
+command_find_references = Rechercher les références
+command_go_to_definition = Atteindre la définition
+command_indent = Indenter les lignes
+command_outdent = Désindenter les lignes
+command_palette = Palette de commandes
+command_pause = Mettre en pause ou reprendre
+command_rename = Renommer le symbole
+command_restart = Redémarrer
+command_tab_next = Onglet suivant
+command_tab_previous = Onglet précédent
+command_texture_add = Ajouter une texture
+shortcuts_title = Raccourcis clavier
+shortcuts_filter = Filtrer les commandes
+shortcuts_reset_all = Tout réinitialiser
+shortcuts_press = Appuyez sur un raccourci…
+shortcuts_record = Cliquez, puis appuyez sur le nouveau raccourci, ou Échap pour annuler
+shortcuts_clear = Supprimer le raccourci
+shortcuts_reset = Rétablir la valeur par défaut
+shortcuts_conflict = { $shortcut } est déjà associé à { $command }.
+shortcuts_reassign = Réattribuer
+shortcuts_cancel = Annuler
+color_schemes_title = Jeux de couleurs
+color_schemes_dark = Mode sombre
+color_schemes_light = Mode clair
+color_schemes_edit = Modifier
+color_schemes_import = Importer…
+color_schemes_import_hint = Un jeu de couleurs en TOML, ou un thème VS Code ou TextMate en JSON
+color_schemes_is_dark = Pour un fond sombre
+color_schemes_preview = Aperçu
+color_schemes_save = Enregistrer
+color_schemes_reset = Réinitialiser
+color_schemes_delete = Supprimer
+color_schemes_new_name = Nom de la copie
+color_schemes_copy = Copier
+token_builtin_function = Fonction intégrée
+token_comment = Commentaire
+token_field = Champ
+token_function = Appel de fonction
+token_function_definition = Définition de fonction
+token_global_variable = Variable globale
+token_keyword_other = Mot-clé
+token_keyword_type = Type
+token_literal = Texte
+token_local_variable = Variable locale
+token_numeric = Nombre
+token_struct = Structure
+palette_hint = Tapez une commande
+edit_replace = Remplacer
+edit_replace_all = Tout remplacer
+edit_replace_one = Remplacer
 
 # About
 about_homepage = Page d'accueil
@@ -38,7 +201,40 @@ about_homepage = Page d'accueil
 # Status
 status_compile_ok = Shader compilé avec succès !
 status_save_ok = Shader enregistré avec succès !
+status_export_ok = Projet exporté avec succès !
+status_convert_ok = Shader converti avec succès !
+status_color_scheme_imported = Jeu de couleurs { $name } importé
+status_cursor = Ligne { $line }, col. { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = marque posée
+keymap_vim_insert = INSERTION
+keymap_vim_normal = NORMAL
+keymap_vim_visual = VISUEL
+keymap_vim_visual_line = VISUEL LIGNE
 status_err_open_example = Échec de l'ouverture de l'exemple
 status_err_open_file = Échec de l'ouverture du fichier
 status_err_open_texture = Échec de l'ouverture de la texture
+status_err_export = Échec de l'exportation du projet
+status_err_convert = Échec de la conversion du shader
+status_err_import_color_scheme = Échec de l’importation du jeu de couleurs
+status_err_save = Échec de l'enregistrement du shader
 status_err_valid = Erreur de validation du shader
+
+# WGSL
+wgsl_uniform = Valeurs mises à jour par l'application à chaque image.
+wgsl_uniform_cursor = Position du pointeur sur l'aperçu, en pixels.
+wgsl_uniform_mouse_down = 1 tant qu'un bouton de la souris est enfoncé sur l'aperçu, 0 sinon.
+wgsl_uniform_mouse_press = Position du dernier appui sur un bouton de la souris sur l'aperçu, en pixels.
+wgsl_uniform_mouse_release = Position du dernier relâchement d'un bouton de la souris sur l'aperçu, en pixels.
+wgsl_uniform_resolution = Taille de l'aperçu, en pixels.
+wgsl_uniform_time = Secondes écoulées depuis le lancement du shader.
+wgsl_image = Échantillonne la texture `t` avec l'échantillonneur `spl` en `uv`, avec l'origine en bas à gauche.
+wgsl_section_array = Fonctions intégrées de tableaux
+wgsl_section_atomic = Fonctions intégrées atomiques
+wgsl_section_derivative = Fonctions intégrées de dérivées
+wgsl_section_logical = Fonctions intégrées logiques
+wgsl_section_numeric = Fonctions intégrées numériques
+wgsl_section_packing = Fonctions intégrées d'empaquetage de données
+wgsl_section_synchronization = Fonctions intégrées de synchronisation
+wgsl_section_texture = Fonctions intégrées de textures
+wgsl_section_unpacking = Fonctions intégrées de dépaquetage de données
@@ -3,10 +3,46 @@
 menu_file = File
 menu_new = Nuovo
 menu_open = Apri
+menu_open_project = Apri cartella del progetto
 menu_open_examples = Apri Esempi
 menu_save = Salva
 menu_save_as = Salva come
+menu_save_project_as = Salva come cartella del progetto
+menu_import_wgsl = Importa WGSL
+menu_export_wgsl = Esporta WGSL
+menu_export_wgsl_fragment = Solo sorgente del frammento
+menu_export_wgsl_standalone = Shader autonomo
+menu_export_rust_project = Esporta come progetto Rust
+menu_convert = Converti
+menu_convert_wgs_to_project = .wgs in cartella del progetto
+menu_convert_project_to_wgs = Cartella del progetto in .wgs
+menu_close_tab = Chiudi scheda
 menu_quit = Esci
+## Menu Edit
+menu_edit = Modifica
+menu_undo = Annulla
+menu_redo = Ripeti
+menu_take_snapshot = Crea istantanea
+menu_snapshot_history = Cronologia istantanee
+menu_find = Trova
+menu_replace = Sostituisci
+menu_format = Formatta documento
+menu_comment_line = Attiva/disattiva commento di riga
+menu_comment_block = Attiva/disattiva commento di blocco
+menu_duplicate_line = Duplica riga
+menu_move_line_up = Sposta riga su
+menu_move_line_down = Sposta riga giù
+menu_delete_line = Elimina riga
+menu_outline = Struttura
+menu_fold_all = Comprimi tutto
+menu_unfold_all = Espandi tutto
+menu_compare = Confronta
+menu_compare_saved = Con la versione salvata
+menu_compare_tab = Con { $name }
+menu_compare_side_by_side = Affiancati
+menu_compare_wipe = Tendina
+menu_compare_show_diff = Mostra differenze
+menu_compare_stop = Termina confronto
 ## Menu Peferences
 menu_preferences = Preferenze
 menu_languages = Lingue
@@ -14,7 +50,27 @@ menu_language_system = Predefinito del sistema
 menu_theme = Tema
 menu_theme_light = Chiaro
 menu_theme_dark = Scuro
+menu_ui_scale = Scala dell’interfaccia
+menu_editor = Editor
+menu_auto_indent = Rientro automatico
+menu_auto_close = Chiudi automaticamente parentesi e virgolette
+menu_format_on_save = Formatta al salvataggio
+menu_indent_width = Rientro di { $width } spazi
+menu_keymap_default = Tasti predefiniti
+menu_keymap_emacs = Tasti Emacs
+menu_keymap_vim = Tasti Vim
+menu_font_family = Carattere
+menu_font_family_default = Predefinito
+menu_font_family_ligatures = Con legature (mostrate senza)
+menu_font_family_others = Altri caratteri
+menu_font_size = Dimensione del carattere
+menu_line_height = Altezza della riga
+menu_zoom_in = Ingrandisci
+menu_zoom_out = Riduci
+menu_zoom_reset = Ripristina zoom
 menu_record_fps = Registra FPS
+menu_color_schemes = Schemi di colori
+menu_shortcuts = Scorciatoie da tastiera
 ## Menu Help
 menu_help = Aiuto
 menu_about = Informazioni
@@ -28,9 +84,116 @@ control_pause = Pausa
 
 # Edit
 edit_name = Nome
+edit_properties = Proprietà del documento
+edit_author = Autore
+edit_description = Descrizione
+edit_tags = Tag
+edit_license = Licenza
+edit_created = Creato
+edit_modified = Modificato
+edit_thumbnail = Miniatura
+edit_capture_thumbnail = Cattura miniatura dall'anteprima
 edit_change_texture = Cambia texture
 edit_remove_texture = Rimuovi texture
 edit_add_texture = Aggiungi texture
+edit_references = Riferimenti
+edit_rename = Rinomina simbolo
+edit_rename_err_conflict = Il nuovo nome è in conflitto con un altro nome nell'ambito
+edit_rename_err_invalid_name = Non è un identificatore WGSL valido
+edit_rename_err_predeclared = Si possono rinominare solo i nomi dichiarati e usati in questo shader
+edit_find = Trova
+edit_find_case_sensitive = Maiuscole/minuscole
+edit_find_close = Chiudi
+edit_find_count = { $total } risultati
+edit_find_err_regex = Espressione regolare non valida
+edit_find_next = Corrispondenza successiva
+edit_find_no_results = Nessun risultato
+edit_find_position = { $current } di { $total }
+edit_find_previous = Corrispondenza precedente
+edit_find_regex = Usa espressione regolare
+edit_find_toggle_replace = Mostra/nascondi sostituzione
+edit_find_whole_word = Parola intera
+tab_close = Chiudi scheda
+tab_new = Nuova scheda
+dialog_discard_title = Modifiche non salvate
+dialog_discard = { $name } ha modifiche non salvate. Chiuderlo e scartarle?
+compare_current = Attuale
+compare_diff = Differenze
+compare_frame_time = Tempo GPU medio per fotogramma
+compare_frame_time_estimate = Tempo GPU stimato per fotogramma, poiché la GPU non lo riporta
+compare_no_changes = Nessuna modifica
+compare_saved = Salvata
+compare_wipe = Posizione della tendina
+snapshot_history = Istantanee
+snapshot_label = Etichetta
+snapshot_take = Crea
+snapshot_untitled = Istantanea
+snapshot_diff = Confronta
+snapshot_diff_hint = Mostra le differenze tra le due istantanee selezionate
+snapshot_select = Seleziona per confrontare
+snapshot_restore = Ripristina
+snapshot_delete = Elimina istantanea
+outline_empty = Nessuna funzione, struttura o costante
+snapshot_none = Le istantanee vengono create a ogni compilazione dello shader, o a mano.
+command_capture_image = Cattura immagine
+command_capture_thumbnail = Cattura miniatura
+command_compare_saved = Confronta con la versione salvata
+command_compile = Compila ed esegui
+command_complete = Mostra completamenti
+command_convert_project_to_wgs = Converti directory di progetto in .wgs
+command_convert_wgs_to_project = Converti .wgs in directory di progetto
+command_export_wgsl_fragment = Esporta sorgente frammento WGSL
+command_export_wgsl_standalone = Esporta shader WGSL autonomo
+command_find_references = Trova riferimenti
+command_go_to_definition = Vai alla definizione
+command_indent = Aumenta rientro
+command_outdent = Riduci rientro
+command_palette = Tavolozza dei comandi
+command_pause = Pausa o riprendi
+command_rename = Rinomina simbolo
+command_restart = Riavvia
+command_tab_next = Scheda successiva
+command_tab_previous = Scheda precedente
+command_texture_add = Aggiungi texture
+shortcuts_title = Scorciatoie da tastiera
+shortcuts_filter = Filtra comandi
+shortcuts_reset_all = Ripristina tutto
+shortcuts_press = Premi una scorciatoia…
+shortcuts_record = Fai clic, poi premi la nuova scorciatoia, o Esc per annullare
+shortcuts_clear = Rimuovi scorciatoia
+shortcuts_reset = Ripristina predefinita
+shortcuts_conflict = { $shortcut } è già assegnata a { $command }.
+shortcuts_reassign = Riassegna
+shortcuts_cancel = Annulla
+color_schemes_title = Schemi di colori
+color_schemes_dark = Modalità scura
+color_schemes_light = Modalità chiara
+color_schemes_edit = Modifica
+color_schemes_import = Importa…
+color_schemes_import_hint = Uno schema di colori in TOML, o un tema VS Code o TextMate in JSON
+color_schemes_is_dark = Per uno sfondo scuro
+color_schemes_preview = Anteprima
+color_schemes_save = Salva
+color_schemes_reset = Ripristina
+color_schemes_delete = Elimina
+color_schemes_new_name = Nome della copia
+color_schemes_copy = Copia
+token_builtin_function = Funzione integrata
+token_comment = Commento
+token_field = Campo
+token_function = Chiamata di funzione
+token_function_definition = Definizione di funzione
+token_global_variable = Variabile globale
+token_keyword_other = Parola chiave
+token_keyword_type = Tipo
+token_literal = Testo
+token_local_variable = Variabile locale
+token_numeric = Numero
+token_struct = Struttura
+palette_hint = Digita un comando
+edit_replace = Sostituisci
+edit_replace_all = Sostituisci tutto
+edit_replace_one = Sostituisci
 
 # About
 about_homepage = Homepage
@@ -38,7 +201,40 @@ about_homepage = Homepage
 # Status
 status_compile_ok = Shader compilato con successo!
 status_save_ok = Shader salvato con successo!
+status_export_ok = Progetto esportato con successo!
+status_convert_ok = Shader convertito con successo!
+status_color_scheme_imported = Schema di colori { $name } importato
+status_cursor = Riga { $line }, col. { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = segno impostato
+keymap_vim_insert = INSERIMENTO
+keymap_vim_normal = NORMALE
+keymap_vim_visual = VISUALE
+keymap_vim_visual_line = VISUALE RIGA
 status_err_open_example = Impossibile aprire l'esempio
 status_err_open_file = Impossibile aprire il file
 status_err_open_texture = Impossibile aprire la texture
+status_err_export = Impossibile esportare il progetto
+status_err_convert = Impossibile convertire lo shader
+status_err_import_color_scheme = Impossibile importare lo schema di colori
+status_err_save = Impossibile salvare lo shader
 status_err_valid = Errore di convalida dello shader
+
+# WGSL
+wgsl_uniform = Valori aggiornati dall'applicazione a ogni fotogramma.
+wgsl_uniform_cursor = Posizione del puntatore sull'anteprima, in pixel.
+wgsl_uniform_mouse_down = 1 mentre un pulsante del mouse è premuto sull'anteprima, 0 altrimenti.
+wgsl_uniform_mouse_press = Dove è stato premuto l'ultima volta un pulsante del mouse sull'anteprima, in pixel.
+wgsl_uniform_mouse_release = Dove è stato rilasciato l'ultima volta un pulsante del mouse sull'anteprima, in pixel.
+wgsl_uniform_resolution = Dimensione dell'anteprima, in pixel.
+wgsl_uniform_time = Secondi trascorsi dall'avvio dello shader.
+wgsl_image = Campiona la texture `t` con il campionatore `spl` in `uv`, con l'origine in basso a sinistra.
+wgsl_section_array = Funzioni integrate per array
+wgsl_section_atomic = Funzioni integrate atomiche
+wgsl_section_derivative = Funzioni integrate di derivata
+wgsl_section_logical = Funzioni integrate logiche
+wgsl_section_numeric = Funzioni integrate numeriche
+wgsl_section_packing = Funzioni integrate di impacchettamento dati
+wgsl_section_synchronization = Funzioni integrate di sincronizzazione
+wgsl_section_texture = Funzioni integrate per texture
+wgsl_section_unpacking = Funzioni integrate di spacchettamento dati
@@ -3,10 +3,46 @@
 menu_file = ファイル
 menu_new = 新規
 menu_open = 開く
+menu_open_project = プロジェクトフォルダーを開く
 menu_open_examples = 例を開く
 menu_save = 保存
 menu_save_as = 名前を付けて保存
+menu_save_project_as = プロジェクトフォルダーとして保存
+menu_import_wgsl = WGSL をインポート
+menu_export_wgsl = WGSL をエクスポート
+menu_export_wgsl_fragment = フラグメントのソースのみ
+menu_export_wgsl_standalone = スタンドアロンのシェーダー
+menu_export_rust_project = Rust プロジェクトとしてエクスポート
+menu_convert = 変換
+menu_convert_wgs_to_project = .wgs をプロジェクトフォルダーへ
+menu_convert_project_to_wgs = プロジェクトフォルダーを .wgs へ
+menu_close_tab = タブを閉じる
 menu_quit = 終了
+## Menu Edit
+menu_edit = 編集
+menu_undo = 元に戻す
+menu_redo = やり直し
+menu_take_snapshot = スナップショットを作成
+menu_snapshot_history = スナップショット履歴
+menu_find = 検索
+menu_replace = 置換
+menu_format = ドキュメントのフォーマット
+menu_comment_line = 行コメントの切り替え
+menu_comment_block = ブロックコメントの切り替え
+menu_duplicate_line = 行を複製
+menu_move_line_up = 行を上へ移動
+menu_move_line_down = 行を下へ移動
+menu_delete_line = 行を削除
+menu_outline = アウトライン
+menu_fold_all = すべて折りたたむ
+menu_unfold_all = すべて展開
+menu_compare = 比較
+menu_compare_saved = 保存済みのバージョンと
+menu_compare_tab = { $name } と
+menu_compare_side_by_side = 左右に並べる
+menu_compare_wipe = ワイプ
+menu_compare_show_diff = 差分を表示
+menu_compare_stop = 比較を終了
 ## Menu Peferences
 menu_preferences = 設定
 menu_languages = 言語
@@ -14,7 +50,27 @@ menu_language_system = システムデフォルト
 menu_theme = テーマ
 menu_theme_light = ライト
 menu_theme_dark = ダーク
+menu_ui_scale = インターフェースの拡大率
+menu_editor = エディター
+menu_auto_indent = 自動インデント
+menu_auto_close = 括弧と引用符を自動で閉じる
+menu_format_on_save = 保存時にフォーマット
+menu_indent_width = { $width } 個のスペースでインデント
+menu_keymap_default = 標準のキー操作
+menu_keymap_emacs = Emacs のキー操作
+menu_keymap_vim = Vim のキー操作
+menu_font_family = フォント
+menu_font_family_default = 既定
+menu_font_family_ligatures = 合字付き（合字なしで表示）
+menu_font_family_others = その他のフォント
+menu_font_size = フォントサイズ
+menu_line_height = 行の高さ
+menu_zoom_in = 拡大
+menu_zoom_out = 縮小
+menu_zoom_reset = ズームをリセット
 menu_record_fps = FPSを記録する
+menu_color_schemes = カラースキーム
+menu_shortcuts = キーボードショートカット
 ## Menu Help
 menu_help = ヘルプ
 menu_about = このプログラムについて
@@ -28,9 +84,116 @@ control_pause = 一時停止
 
 # Edit
 edit_name = 名前
+edit_properties = ドキュメントのプロパティ
+edit_author = 作成者
+edit_description = 説明
+edit_tags = タグ
+edit_license = ライセンス
+edit_created = 作成日時
+edit_modified = 更新日時
+edit_thumbnail = サムネイル
+edit_capture_thumbnail = プレビューからサムネイルを取得
 edit_change_texture = テクスチャを変更
 edit_remove_texture = テクスチャを削除
 edit_add_texture = テクスチャを追加
+edit_references = 参照
+edit_rename = シンボルの名前を変更
+edit_rename_err_conflict = 新しい名前がスコープ内の別の名前と競合しています
+edit_rename_err_invalid_name = 有効な WGSL 識別子ではありません
+edit_rename_err_predeclared = このシェーダーで宣言・使用されている名前のみ変更できます
+edit_find = 検索
+edit_find_case_sensitive = 大文字と小文字を区別
+edit_find_close = 閉じる
+edit_find_count = { $total } 件
+edit_find_err_regex = 無効な正規表現
+edit_find_next = 次の一致
+edit_find_no_results = 結果なし
+edit_find_position = { $total } 件中 { $current } 件目
+edit_find_previous = 前の一致
+edit_find_regex = 正規表現を使用
+edit_find_toggle_replace = 置換の切り替え
+edit_find_whole_word = 単語単位で検索
+tab_close = タブを閉じる
+tab_new = 新しいタブ
+dialog_discard_title = 未保存の変更
+dialog_discard = { $name } には未保存の変更があります。閉じて変更を破棄しますか？
+compare_current = 現在
+compare_diff = 差分
+compare_frame_time = フレームあたりの平均 GPU 時間
+compare_frame_time_estimate = フレームあたりの推定 GPU 時間（GPU が報告しないため）
+compare_no_changes = 変更はありません
+compare_saved = 保存済み
+compare_wipe = ワイプの位置
+snapshot_history = スナップショット
+snapshot_label = ラベル
+snapshot_take = 作成
+snapshot_untitled = スナップショット
+snapshot_diff = 差分
+snapshot_diff_hint = 選択した 2 つのスナップショットの差分を表示します
+snapshot_select = 差分用に選択
+snapshot_restore = 復元
+snapshot_delete = スナップショットを削除
+outline_empty = 関数、構造体、定数はありません
+snapshot_none = スナップショットはシェーダーのコンパイルごと、または手動で作成されます。
+command_capture_image = 画像をキャプチャ
+command_capture_thumbnail = サムネイルをキャプチャ
+command_compare_saved = 保存済みのバージョンと比較
+command_compile = コンパイルして実行
+command_complete = 補完を表示
+command_convert_project_to_wgs = プロジェクトディレクトリを .wgs に変換
+command_convert_wgs_to_project = .wgs をプロジェクトディレクトリに変換
+command_export_wgsl_fragment = WGSL フラグメントソースをエクスポート
+command_export_wgsl_standalone = スタンドアロン WGSL シェーダーをエクスポート
+command_find_references = 参照を検索
+command_go_to_definition = 定義へ移動
+command_indent = 行をインデント
+command_outdent = 行のインデントを解除
+command_palette = コマンドパレット
+command_pause = 一時停止または再開
+command_rename = シンボルの名前を変更
+command_restart = 再起動
+command_tab_next = 次のタブ
+command_tab_previous = 前のタブ
+command_texture_add = テクスチャを追加
+shortcuts_title = キーボードショートカット
+shortcuts_filter = コマンドを絞り込む
+shortcuts_reset_all = すべてリセット
+shortcuts_press = ショートカットを押してください…
+shortcuts_record = クリックして新しいショートカットを押します。Escape でキャンセル
+shortcuts_clear = ショートカットを削除
+shortcuts_reset = 既定に戻す
+shortcuts_conflict = { $shortcut } は既に { $command } に割り当てられています。
+shortcuts_reassign = 再割り当て
+shortcuts_cancel = キャンセル
+color_schemes_title = カラースキーム
+color_schemes_dark = ダークモード
+color_schemes_light = ライトモード
+color_schemes_edit = 編集
+color_schemes_import = インポート…
+color_schemes_import_hint = TOML のカラースキーム、または JSON の VS Code / TextMate テーマ
+color_schemes_is_dark = 暗い背景用
+color_schemes_preview = プレビュー
+color_schemes_save = 保存
+color_schemes_reset = リセット
+color_schemes_delete = 削除
+color_schemes_new_name = コピーの名前
+color_schemes_copy = コピー
+token_builtin_function = 組み込み関数
+token_comment = コメント
+token_field = フィールド
+token_function = 関数呼び出し
+token_function_definition = 関数定義
+token_global_variable = グローバル変数
+token_keyword_other = キーワード
+token_keyword_type = 型
+token_literal = テキスト
+token_local_variable = ローカル変数
+token_numeric = 数値
+token_struct = 構造体
+palette_hint = コマンドを入力
+edit_replace = 置換
+edit_replace_all = すべて置換
+edit_replace_one = 置換
 
 # About
 about_homepage = ホームページ
@@ -38,7 +201,40 @@ about_homepage = ホームページ
 # Status
 status_compile_ok = シェーダーが正常にコンパイルされました！
 status_save_ok = シェーダーが正常に保存されました！
+status_export_ok = プロジェクトのエクスポートに成功しました！
+status_convert_ok = シェーダーの変換に成功しました！
+status_color_scheme_imported = カラースキーム { $name } をインポートしました
+status_cursor = { $line } 行、{ $column } 列
+keymap_emacs = Emacs
+keymap_emacs_mark = マーク設定
+keymap_vim_insert = 挿入
+keymap_vim_normal = ノーマル
+keymap_vim_visual = ビジュアル
+keymap_vim_visual_line = ビジュアル行
 status_err_open_example = 例の開封に失敗しました
 status_err_open_file = ファイルの開封に失敗しました
 status_err_open_texture = テクスチャの開封に失敗しました
+status_err_export = プロジェクトのエクスポートに失敗しました
+status_err_convert = シェーダーの変換に失敗しました
+status_err_import_color_scheme = カラースキームのインポートに失敗しました
+status_err_save = シェーダーの保存に失敗しました
 status_err_valid = シェーダーの検証エラー
+
+# WGSL
+wgsl_uniform = アプリがフレームごとに更新する値。
+wgsl_uniform_cursor = プレビュー上のポインターの位置（ピクセル単位）。
+wgsl_uniform_mouse_down = プレビュー上でマウスボタンが押されている間は 1、それ以外は 0。
+wgsl_uniform_mouse_press = プレビュー上で最後にマウスボタンが押された位置（ピクセル単位）。
+wgsl_uniform_mouse_release = プレビュー上で最後にマウスボタンが離された位置（ピクセル単位）。
+wgsl_uniform_resolution = プレビューのサイズ（ピクセル単位）。
+wgsl_uniform_time = シェーダーの実行開始からの秒数。
+wgsl_image = サンプラー `spl` でテクスチャ `t` を `uv` の位置でサンプリングします。原点は左下です。
+wgsl_section_array = 配列組み込み関数
+wgsl_section_atomic = アトミック組み込み関数
+wgsl_section_derivative = 導関数組み込み関数
+wgsl_section_logical = 論理組み込み関数
+wgsl_section_numeric = 数値組み込み関数
+wgsl_section_packing = データパック組み込み関数
+wgsl_section_synchronization = 同期組み込み関数
+wgsl_section_texture = テクスチャ組み込み関数
+wgsl_section_unpacking = データアンパック組み込み関数
@@ -3,10 +3,46 @@
 menu_file = 파일
 menu_new = 새로 만들기
 menu_open = 열기
+menu_open_project = 프로젝트 폴더 열기
 menu_open_examples = 예제 열기
 menu_save = 저장
 menu_save_as = 다른 이름으로 저장
+menu_save_project_as = 프로젝트 폴더로 저장
+menu_import_wgsl = WGSL 가져오기
+menu_export_wgsl = WGSL 내보내기
+menu_export_wgsl_fragment = 프래그먼트 소스만
+menu_export_wgsl_standalone = 독립 실행형 셰이더
+menu_export_rust_project = Rust 프로젝트로 내보내기
+menu_convert = 변환
+menu_convert_wgs_to_project = .wgs를 프로젝트 폴더로
+menu_convert_project_to_wgs = 프로젝트 폴더를 .wgs로
+menu_close_tab = 탭 닫기
 menu_quit = 종료
+## Menu Edit
+menu_edit = 편집
+menu_undo = 실행 취소
+menu_redo = 다시 실행
+menu_take_snapshot = 스냅샷 만들기
+menu_snapshot_history = 스냅샷 기록
+menu_find = 찾기
+menu_replace = 바꾸기
+menu_format = 문서 서식 지정
+menu_comment_line = 줄 주석 전환
+menu_comment_block = 블록 주석 전환
+menu_duplicate_line = 줄 복제
+menu_move_line_up = 줄 위로 이동
+menu_move_line_down = 줄 아래로 이동
+menu_delete_line = 줄 삭제
+menu_outline = 개요
+menu_fold_all = 모두 접기
+menu_unfold_all = 모두 펼치기
+menu_compare = 비교
+menu_compare_saved = 저장된 버전과
+menu_compare_tab = { $name }와(과)
+menu_compare_side_by_side = 나란히
+menu_compare_wipe = 와이프
+menu_compare_show_diff = 차이점 표시
+menu_compare_stop = 비교 중지
 ## Menu Peferences
 menu_preferences = 환경 설정
 menu_languages = 언어
@@ -14,7 +50,27 @@ menu_language_system = 시스템 기본값
 menu_theme = 테마
 menu_theme_light = 밝은
 menu_theme_dark = 어두운
+menu_ui_scale = 인터페이스 배율
+menu_editor = 편집기
+menu_auto_indent = 자동 들여쓰기
+menu_auto_close = 괄호와 따옴표 자동 닫기
+menu_format_on_save = 저장 시 서식 지정
+menu_indent_width = 공백 { $width }칸으로 들여쓰기
+menu_keymap_default = 기본 키
+menu_keymap_emacs = Emacs 키
+menu_keymap_vim = Vim 키
+menu_font_family = 글꼴
+menu_font_family_default = 기본값
+menu_font_family_ligatures = 합자 포함 (합자 없이 표시됨)
+menu_font_family_others = 기타 글꼴
+menu_font_size = 글꼴 크기
+menu_line_height = 줄 높이
+menu_zoom_in = 확대
+menu_zoom_out = 축소
+menu_zoom_reset = 확대/축소 초기화
 menu_record_fps = FPS 기록
+menu_color_schemes = 색 구성표
+menu_shortcuts = 키보드 단축키
 ## Menu Help
 menu_help = 도움말
 menu_about = 소개
@@ -28,9 +84,116 @@ control_pause = 일시 중지
 
 # Edit
 edit_name = 이름
+edit_properties = 문서 속성
+edit_author = 작성자
+edit_description = 설명
+edit_tags = 태그
+edit_license = 라이선스
+edit_created = 생성일
+edit_modified = 수정일
+edit_thumbnail = 썸네일
+edit_capture_thumbnail = 미리보기에서 썸네일 캡처
 edit_change_texture = 텍스처 변경
 edit_remove_texture = 텍스처 제거
 edit_add_texture = 텍스처 추가
+edit_references = 참조
+edit_rename = 기호 이름 바꾸기
+edit_rename_err_conflict = 새 이름이 범위 안의 다른 이름과 충돌합니다
+edit_rename_err_invalid_name = 올바른 WGSL 식별자가 아닙니다
+edit_rename_err_predeclared = 이 셰이더에서 선언되고 사용된 이름만 바꿀 수 있습니다
+edit_find = 찾기
+edit_find_case_sensitive = 대/소문자 구분
+edit_find_close = 닫기
+edit_find_count = 결과 { $total }개
+edit_find_err_regex = 잘못된 정규식
+edit_find_next = 다음 일치 항목
+edit_find_no_results = 결과 없음
+edit_find_position = { $total }개 중 { $current }번째
+edit_find_previous = 이전 일치 항목
+edit_find_regex = 정규식 사용
+edit_find_toggle_replace = 바꾸기 전환
+edit_find_whole_word = 단어 단위로
+tab_close = 탭 닫기
+tab_new = 새 탭
+dialog_discard_title = 저장되지 않은 변경 사항
+dialog_discard = { $name }에 저장되지 않은 변경 사항이 있습니다. 닫고 변경 사항을 버리시겠습니까?
+compare_current = 현재
+compare_diff = 차이점
+compare_frame_time = 프레임당 평균 GPU 시간
+compare_frame_time_estimate = GPU가 보고하지 않아 추정한 프레임당 GPU 시간
+compare_no_changes = 변경 사항 없음
+compare_saved = 저장됨
+compare_wipe = 와이프 위치
+snapshot_history = 스냅샷
+snapshot_label = 레이블
+snapshot_take = 만들기
+snapshot_untitled = 스냅샷
+snapshot_diff = 비교
+snapshot_diff_hint = 선택한 두 스냅샷의 차이점을 표시합니다
+snapshot_select = 비교할 항목 선택
+snapshot_restore = 복원
+snapshot_delete = 스냅샷 삭제
+outline_empty = 함수, 구조체, 상수가 없습니다
+snapshot_none = 스냅샷은 셰이더를 컴파일할 때마다 또는 수동으로 만들어집니다.
+command_capture_image = 이미지 캡처
+command_capture_thumbnail = 썸네일 캡처
+command_compare_saved = 저장된 버전과 비교
+command_compile = 컴파일 및 실행
+command_complete = 자동 완성 표시
+command_convert_project_to_wgs = 프로젝트 디렉터리를 .wgs로 변환
+command_convert_wgs_to_project = .wgs를 프로젝트 디렉터리로 변환
+command_export_wgsl_fragment = WGSL 프래그먼트 소스 내보내기
+command_export_wgsl_standalone = 독립 실행형 WGSL 셰이더 내보내기
+command_find_references = 참조 찾기
+command_go_to_definition = 정의로 이동
+command_indent = 줄 들여쓰기
+command_outdent = 줄 내어쓰기
+command_palette = 명령 팔레트
+command_pause = 일시 정지 또는 재개
+command_rename = 기호 이름 바꾸기
+command_restart = 다시 시작
+command_tab_next = 다음 탭
+command_tab_previous = 이전 탭
+command_texture_add = 텍스처 추가
+shortcuts_title = 키보드 단축키
+shortcuts_filter = 명령 필터
+shortcuts_reset_all = 모두 초기화
+shortcuts_press = 단축키를 누르세요…
+shortcuts_record = 클릭한 다음 새 단축키를 누르세요. Escape로 취소
+shortcuts_clear = 단축키 제거
+shortcuts_reset = 기본값으로 초기화
+shortcuts_conflict = { $shortcut }은(는) 이미 { $command }에 할당되어 있습니다.
+shortcuts_reassign = 다시 할당
+shortcuts_cancel = 취소
+color_schemes_title = 색 구성표
+color_schemes_dark = 다크 모드
+color_schemes_light = 라이트 모드
+color_schemes_edit = 편집
+color_schemes_import = 가져오기…
+color_schemes_import_hint = TOML 색 구성표 또는 JSON 형식의 VS Code·TextMate 테마
+color_schemes_is_dark = 어두운 배경용
+color_schemes_preview = 미리 보기
+color_schemes_save = 저장
+color_schemes_reset = 초기화
+color_schemes_delete = 삭제
+color_schemes_new_name = 사본 이름
+color_schemes_copy = 복사
+token_builtin_function = 내장 함수
+token_comment = 주석
+token_field = 필드
+token_function = 함수 호출
+token_function_definition = 함수 정의
+token_global_variable = 전역 변수
+token_keyword_other = 키워드
+token_keyword_type = 타입
+token_literal = 텍스트
+token_local_variable = 지역 변수
+token_numeric = 숫자
+token_struct = 구조체
+palette_hint = 명령 입력
+edit_replace = 바꾸기
+edit_replace_all = 모두 바꾸기
+edit_replace_one = 바꾸기
 
 # About
 about_homepage = 홈페이지
@@ -38,7 +201,40 @@ about_homepage = 홈페이지
 # Status
 status_compile_ok = 셰이더가 성공적으로 컴파일되었습니다!
 status_save_ok = 셰이더가 성공적으로 저장되었습니다!
+status_export_ok = 프로젝트를 성공적으로 내보냈습니다!
+status_convert_ok = 셰이더를 성공적으로 변환했습니다!
+status_color_scheme_imported = 색 구성표 { $name }을(를) 가져왔습니다
+status_cursor = 줄 { $line }, 열 { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = 마크 설정됨
+keymap_vim_insert = 삽입
+keymap_vim_normal = 일반
+keymap_vim_visual = 비주얼
+keymap_vim_visual_line = 비주얼 줄
 status_err_open_example = 예제를 열지 못했습니다
 status_err_open_file = 파일을 열지 못했습니다
 status_err_open_texture = 텍스처를 열지 못했습니다
+status_err_export = 프로젝트 내보내기 실패
+status_err_convert = 셰이더 변환 실패
+status_err_import_color_scheme = 색 구성표를 가져오지 못했습니다
+status_err_save = 셰이더 저장 실패
 status_err_valid = 셰이더 유효성 검사 오류
+
+# WGSL
+wgsl_uniform = 앱이 매 프레임마다 갱신하는 값입니다.
+wgsl_uniform_cursor = 미리보기 위의 포인터 위치(픽셀 단위)입니다.
+wgsl_uniform_mouse_down = 미리보기 위에서 마우스 버튼을 누르고 있는 동안 1, 그 외에는 0입니다.
+wgsl_uniform_mouse_press = 미리보기 위에서 마지막으로 마우스 버튼을 누른 위치(픽셀 단위)입니다.
+wgsl_uniform_mouse_release = 미리보기 위에서 마지막으로 마우스 버튼을 뗀 위치(픽셀 단위)입니다.
+wgsl_uniform_resolution = 미리보기의 크기(픽셀 단위)입니다.
+wgsl_uniform_time = 셰이더가 실행된 후 경과한 초입니다.
+wgsl_image = 샘플러 `spl`로 텍스처 `t`를 `uv` 위치에서 샘플링합니다. 원점은 왼쪽 아래입니다.
+wgsl_section_array = 배열 내장 함수
+wgsl_section_atomic = 원자적 내장 함수
+wgsl_section_derivative = 미분 내장 함수
+wgsl_section_logical = 논리 내장 함수
+wgsl_section_numeric = 수치 내장 함수
+wgsl_section_packing = 데이터 패킹 내장 함수
+wgsl_section_synchronization = 동기화 내장 함수
+wgsl_section_texture = 텍스처 내장 함수
+wgsl_section_unpacking = 데이터 언패킹 내장 함수
@@ -3,10 +3,46 @@
 menu_file = Arquivo
 menu_new = Novo
 menu_open = Abrir
+menu_open_project = Abrir pasta do projeto
 menu_open_examples = Abrir Exemplos
 menu_save = Salvar
 menu_save_as = Salvar Como
+menu_save_project_as = Salvar como pasta do projeto
+menu_import_wgsl = Importar WGSL
+menu_export_wgsl = Exportar WGSL
+menu_export_wgsl_fragment = Somente código do fragmento
+menu_export_wgsl_standalone = Shader independente
+menu_export_rust_project = Exportar como projeto Rust
+menu_convert = Converter
+menu_convert_wgs_to_project = .wgs para pasta do projeto
+menu_convert_project_to_wgs = Pasta do projeto para .wgs
+menu_close_tab = Fechar aba
 menu_quit = Sair
+## Menu Edit
+menu_edit = Editar
+menu_undo = Desfazer
+menu_redo = Refazer
+menu_take_snapshot = Criar instantâneo
+menu_snapshot_history = Histórico de instantâneos
+menu_find = Localizar
+menu_replace = Substituir
+menu_format = Formatar documento
+menu_comment_line = Alternar comentário de linha
+menu_comment_block = Alternar comentário de bloco
+menu_duplicate_line = Duplicar linha
+menu_move_line_up = Mover linha para cima
+menu_move_line_down = Mover linha para baixo
+menu_delete_line = Excluir linha
+menu_outline = Estrutura
+menu_fold_all = Recolher tudo
+menu_unfold_all = Expandir tudo
+menu_compare = Comparar
+menu_compare_saved = Com a versão salva
+menu_compare_tab = Com { $name }
+menu_compare_side_by_side = Lado a lado
+menu_compare_wipe = Cortina
+menu_compare_show_diff = Mostrar diferenças
+menu_compare_stop = Parar de comparar
 ## Menu Peferences
 menu_preferences = Preferências
 menu_languages = Idiomas
@@ -14,7 +50,27 @@ menu_language_system = Sistema Padrão
 menu_theme = Tema
 menu_theme_light = Claro
 menu_theme_dark = Escuro
+menu_ui_scale = Escala da interface
+menu_editor = Editor
+menu_auto_indent = Recuo automático
+menu_auto_close = Fechar colchetes e aspas automaticamente
+menu_format_on_save = Formatar ao salvar
+menu_indent_width = Recuo de { $width } espaços
+menu_keymap_default = Teclas padrão
+menu_keymap_emacs = Teclas do Emacs
+menu_keymap_vim = Teclas do Vim
+menu_font_family = Fonte
+menu_font_family_default = Padrão
+menu_font_family_ligatures = Com ligaduras (exibidas sem elas)
+menu_font_family_others = Outras fontes
+menu_font_size = Tamanho da fonte
+menu_line_height = Altura da linha
+menu_zoom_in = Ampliar
+menu_zoom_out = Reduzir
+menu_zoom_reset = Redefinir zoom
 menu_record_fps = Gravar FPS
+menu_color_schemes = Esquemas de cores
+menu_shortcuts = Atalhos de teclado
 ## Menu Help
 menu_help = Ajuda
 menu_about = Sobre
@@ -28,9 +84,116 @@ control_pause = Pausar
 
 # Edit
 edit_name = Nome
+edit_properties = Propriedades do documento
+edit_author = Autor
+edit_description = Descrição
+edit_tags = Etiquetas
+edit_license = Licença
+edit_created = Criado
+edit_modified = Modificado
+edit_thumbnail = Miniatura
+edit_capture_thumbnail = Capturar miniatura da pré-visualização
 edit_change_texture = Alterar textura
 edit_remove_texture = Remover textura
 edit_add_texture = Adicionar textura
+edit_references = Referências
+edit_rename = Renomear símbolo
+edit_rename_err_conflict = O novo nome entra em conflito com outro nome no escopo
+edit_rename_err_invalid_name = Não é um identificador WGSL válido
+edit_rename_err_predeclared = Só é possível renomear nomes declarados e usados neste shader
+edit_find = Localizar
+edit_find_case_sensitive = Diferenciar maiúsculas e minúsculas
+edit_find_close = Fechar
+edit_find_count = { $total } resultados
+edit_find_err_regex = Expressão regular inválida
+edit_find_next = Próxima ocorrência
+edit_find_no_results = Nenhum resultado
+edit_find_position = { $current } de { $total }
+edit_find_previous = Ocorrência anterior
+edit_find_regex = Usar expressão regular
+edit_find_toggle_replace = Alternar substituição
+edit_find_whole_word = Palavra inteira
+tab_close = Fechar aba
+tab_new = Nova aba
+dialog_discard_title = Alterações não salvas
+dialog_discard = { $name } tem alterações não salvas. Fechar e descartá-las?
+compare_current = Atual
+compare_diff = Diferenças
+compare_frame_time = Tempo médio de GPU por quadro
+compare_frame_time_estimate = Tempo de GPU estimado por quadro, pois a GPU não o informa
+compare_no_changes = Sem alterações
+compare_saved = Salva
+compare_wipe = Posição da cortina
+snapshot_history = Instantâneos
+snapshot_label = Rótulo
+snapshot_take = Criar
+snapshot_untitled = Instantâneo
+snapshot_diff = Comparar
+snapshot_diff_hint = Mostra as diferenças entre os dois instantâneos selecionados
+snapshot_select = Selecionar para comparar
+snapshot_restore = Restaurar
+snapshot_delete = Excluir instantâneo
+outline_empty = Nenhuma função, estrutura ou constante
+snapshot_none = Os instantâneos são criados a cada compilação do shader, ou manualmente.
+command_capture_image = Capturar imagem
+command_capture_thumbnail = Capturar miniatura
+command_compare_saved = Comparar com a versão salva
+command_compile = Compilar e executar
+command_complete = Mostrar sugestões
+command_convert_project_to_wgs = Converter diretório de projeto para .wgs
+command_convert_wgs_to_project = Converter .wgs para diretório de projeto
+command_export_wgsl_fragment = Exportar código de fragmento WGSL
+command_export_wgsl_standalone = Exportar shader WGSL independente
+command_find_references = Localizar referências
+command_go_to_definition = Ir para definição
+command_indent = Aumentar recuo
+command_outdent = Diminuir recuo
+command_palette = Paleta de comandos
+command_pause = Pausar ou retomar
+command_rename = Renomear símbolo
+command_restart = Reiniciar
+command_tab_next = Próxima aba
+command_tab_previous = Aba anterior
+command_texture_add = Adicionar textura
+shortcuts_title = Atalhos de teclado
+shortcuts_filter = Filtrar comandos
+shortcuts_reset_all = Redefinir tudo
+shortcuts_press = Pressione um atalho…
+shortcuts_record = Clique e pressione o novo atalho, ou Escape para cancelar
+shortcuts_clear = Remover atalho
+shortcuts_reset = Redefinir para o padrão
+shortcuts_conflict = { $shortcut } já está atribuído a { $command }.
+shortcuts_reassign = Reatribuir
+shortcuts_cancel = Cancelar
+color_schemes_title = Esquemas de cores
+color_schemes_dark = Modo escuro
+color_schemes_light = Modo claro
+color_schemes_edit = Editar
+color_schemes_import = Importar…
+color_schemes_import_hint = Um esquema de cores em TOML, ou um tema do VS Code ou TextMate em JSON
+color_schemes_is_dark = Para fundo escuro
+color_schemes_preview = Pré-visualização
+color_schemes_save = Salvar
+color_schemes_reset = Redefinir
+color_schemes_delete = Excluir
+color_schemes_new_name = Nome da cópia
+color_schemes_copy = Copiar
+token_builtin_function = Função integrada
+token_comment = Comentário
+token_field = Campo
+token_function = Chamada de função
+token_function_definition = Definição de função
+token_global_variable = Variável global
+token_keyword_other = Palavra-chave
+token_keyword_type = Tipo
+token_literal = Texto
+token_local_variable = Variável local
+token_numeric = Número
+token_struct = Estrutura
+palette_hint = Digite um comando
+edit_replace = Substituir
+edit_replace_all = Substituir tudo
+edit_replace_one = Substituir
 
 # About
 about_homepage = Página inicial
@@ -38,7 +201,40 @@ about_homepage = Página inicial
 # Status
 status_compile_ok = Shader compilado com sucesso!
 status_save_ok = Shader salvo com sucesso!
+status_export_ok = Projeto exportado com sucesso!
+status_convert_ok = Shader convertido com sucesso!
+status_color_scheme_imported = Esquema de cores { $name } importado
+status_cursor = Ln { $line }, Col { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = marca definida
+keymap_vim_insert = INSERÇÃO
+keymap_vim_normal = NORMAL
+keymap_vim_visual = VISUAL
+keymap_vim_visual_line = VISUAL LINHA
 status_err_open_example = Falha ao abrir o exemplo
 status_err_open_file = Falha ao abrir o arquivo
 status_err_open_texture = Falha ao abrir a textura
+status_err_export = Falha ao exportar o projeto
+status_err_convert = Falha ao converter o shader
+status_err_import_color_scheme = Falha ao importar o esquema de cores
+status_err_save = Falha ao salvar o shader
 status_err_valid = Erro de validação do Shader
+
+# WGSL
+wgsl_uniform = Valores atualizados pelo aplicativo a cada quadro.
+wgsl_uniform_cursor = Posição do ponteiro sobre a pré-visualização, em pixels.
+wgsl_uniform_mouse_down = 1 enquanto um botão do mouse está pressionado sobre a pré-visualização, 0 caso contrário.
+wgsl_uniform_mouse_press = Onde um botão do mouse foi pressionado pela última vez sobre a pré-visualização, em pixels.
+wgsl_uniform_mouse_release = Onde um botão do mouse foi solto pela última vez sobre a pré-visualização, em pixels.
+wgsl_uniform_resolution = Tamanho da pré-visualização, em pixels.
+wgsl_uniform_time = Segundos desde que o shader começou a ser executado.
+wgsl_image = Amostra a textura `t` com o amostrador `spl` em `uv`, com a origem no canto inferior esquerdo.
+wgsl_section_array = Funções integradas de arrays
+wgsl_section_atomic = Funções integradas atômicas
+wgsl_section_derivative = Funções integradas de derivadas
+wgsl_section_logical = Funções integradas lógicas
+wgsl_section_numeric = Funções integradas numéricas
+wgsl_section_packing = Funções integradas de empacotamento de dados
+wgsl_section_synchronization = Funções integradas de sincronização
+wgsl_section_texture = Funções integradas de texturas
+wgsl_section_unpacking = Funções integradas de desempacotamento de dados
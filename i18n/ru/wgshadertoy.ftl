@@ -3,10 +3,46 @@
 menu_file = Файл
 menu_new = Новый
 menu_open = Открыть
+menu_open_project = Открыть папку проекта
 menu_open_examples = Открыть Примеры
 menu_save = Сохранить
 menu_save_as = Сохранить как
+menu_save_project_as = Сохранить как папку проекта
+menu_import_wgsl = Импортировать WGSL
+menu_export_wgsl = Экспортировать WGSL
+menu_export_wgsl_fragment = Только исходный код фрагмента
+menu_export_wgsl_standalone = Самостоятельный шейдер
+menu_export_rust_project = Экспортировать как проект Rust
+menu_convert = Преобразовать
+menu_convert_wgs_to_project = .wgs в папку проекта
+menu_convert_project_to_wgs = Папку проекта в .wgs
+menu_close_tab = Закрыть вкладку
 menu_quit = Выйти
+## Menu Edit
+menu_edit = Правка
+menu_undo = Отменить
+menu_redo = Повторить
+menu_take_snapshot = Сделать снимок
+menu_snapshot_history = История снимков
+menu_find = Найти
+menu_replace = Заменить
+menu_format = Форматировать документ
+menu_comment_line = Переключить строчный комментарий
+menu_comment_block = Переключить блочный комментарий
+menu_duplicate_line = Дублировать строку
+menu_move_line_up = Переместить строку вверх
+menu_move_line_down = Переместить строку вниз
+menu_delete_line = Удалить строку
+menu_outline = Структура
+menu_fold_all = Свернуть все
+menu_unfold_all = Развернуть все
+menu_compare = Сравнение
+menu_compare_saved = С сохранённой версией
+menu_compare_tab = С { $name }
+menu_compare_side_by_side = Рядом
+menu_compare_wipe = Шторка
+menu_compare_show_diff = Показать различия
+menu_compare_stop = Завершить сравнение
 ## Menu Peferences
 menu_preferences = Настройки
 menu_languages = Языки
@@ -14,7 +50,27 @@ menu_language_system = Системный
 menu_theme = Тема
 menu_theme_light = Светлая
 menu_theme_dark = Темная
+menu_ui_scale = Масштаб интерфейса
+menu_editor = Редактор
+menu_auto_indent = Автоотступ
+menu_auto_close = Автозакрытие скобок и кавычек
+menu_format_on_save = Форматировать при сохранении
+menu_indent_width = Отступ в { $width } пробела
+menu_keymap_default = Стандартные клавиши
+menu_keymap_emacs = Клавиши Emacs
+menu_keymap_vim = Клавиши Vim
+menu_font_family = Шрифт
+menu_font_family_default = По умолчанию
+menu_font_family_ligatures = С лигатурами (отображаются без них)
+menu_font_family_others = Другие шрифты
+menu_font_size = Размер шрифта
+menu_line_height = Высота строки
+menu_zoom_in = Увеличить
+menu_zoom_out = Уменьшить
+menu_zoom_reset = Сбросить масштаб
 menu_record_fps = Запись кадров в секунду
+menu_color_schemes = Цветовые схемы
+menu_shortcuts = Сочетания клавиш
 ## Menu Help
 menu_help = Помощь
 menu_about = О программе
@@ -28,9 +84,116 @@ control_pause = Пауза
 
 # Edit
 edit_name = Имя
+edit_properties = Свойства документа
+edit_author = Автор
+edit_description = Описание
+edit_tags = Теги
+edit_license = Лицензия
+edit_created = Создан
+edit_modified = Изменён
+edit_thumbnail = Миниатюра
+edit_capture_thumbnail = Снять миниатюру с предпросмотра
 edit_change_texture = Изменить текстуру
 edit_remove_texture = Удалить текстуру
 edit_add_texture = Добавить текстуру
+edit_references = Ссылки
+edit_rename = Переименовать символ
+edit_rename_err_conflict = Новое имя конфликтует с другим именем в области видимости
+edit_rename_err_invalid_name = Недопустимый идентификатор WGSL
+edit_rename_err_predeclared = Переименовать можно только имена, объявленные и используемые в этом шейдере
+edit_find = Найти
+edit_find_case_sensitive = С учётом регистра
+edit_find_close = Закрыть
+edit_find_count = Найдено: { $total }
+edit_find_err_regex = Неверное регулярное выражение
+edit_find_next = Следующее совпадение
+edit_find_no_results = Нет результатов
+edit_find_position = { $current } из { $total }
+edit_find_previous = Предыдущее совпадение
+edit_find_regex = Регулярное выражение
+edit_find_toggle_replace = Показать или скрыть замену
+edit_find_whole_word = Слово целиком
+tab_close = Закрыть вкладку
+tab_new = Новая вкладка
+dialog_discard_title = Несохранённые изменения
+dialog_discard = В { $name } есть несохранённые изменения. Закрыть и отменить их?
+compare_current = Текущая
+compare_diff = Различия
+compare_frame_time = Среднее время GPU на кадр
+compare_frame_time_estimate = Оценка времени GPU на кадр, так как GPU его не сообщает
+compare_no_changes = Нет изменений
+compare_saved = Сохранённая
+compare_wipe = Положение шторки
+snapshot_history = Снимки
+snapshot_label = Метка
+snapshot_take = Сделать
+snapshot_untitled = Снимок
+snapshot_diff = Сравнить
+snapshot_diff_hint = Показывает различия между двумя выбранными снимками
+snapshot_select = Выбрать для сравнения
+snapshot_restore = Восстановить
+snapshot_delete = Удалить снимок
+outline_empty = Нет функций, структур или констант
+snapshot_none = Снимки делаются при каждой компиляции шейдера или вручную.
+command_capture_image = Сохранить изображение
+command_capture_thumbnail = Сохранить миниатюру
+command_compare_saved = Сравнить с сохранённой версией
+command_compile = Скомпилировать и запустить
+command_complete = Показать автодополнение
+command_convert_project_to_wgs = Преобразовать каталог проекта в .wgs
+command_convert_wgs_to_project = Преобразовать .wgs в каталог проекта
+command_export_wgsl_fragment = Экспортировать исходник фрагмента WGSL
+command_export_wgsl_standalone = Экспортировать автономный шейдер WGSL
+command_find_references = Найти ссылки
+command_go_to_definition = Перейти к определению
+command_indent = Увеличить отступ
+command_outdent = Уменьшить отступ
+command_palette = Палитра команд
+command_pause = Пауза или продолжение
+command_rename = Переименовать символ
+command_restart = Перезапустить
+command_tab_next = Следующая вкладка
+command_tab_previous = Предыдущая вкладка
+command_texture_add = Добавить текстуру
+shortcuts_title = Сочетания клавиш
+shortcuts_filter = Фильтр команд
+shortcuts_reset_all = Сбросить все
+shortcuts_press = Нажмите сочетание…
+shortcuts_record = Нажмите, затем новое сочетание, или Escape для отмены
+shortcuts_clear = Удалить сочетание
+shortcuts_reset = Сбросить по умолчанию
+shortcuts_conflict = { $shortcut } уже назначено команде { $command }.
+shortcuts_reassign = Переназначить
+shortcuts_cancel = Отмена
+color_schemes_title = Цветовые схемы
+color_schemes_dark = Тёмный режим
+color_schemes_light = Светлый режим
+color_schemes_edit = Изменить
+color_schemes_import = Импорт…
+color_schemes_import_hint = Цветовая схема в TOML или тема VS Code или TextMate в JSON
+color_schemes_is_dark = Для тёмного фона
+color_schemes_preview = Предпросмотр
+color_schemes_save = Сохранить
+color_schemes_reset = Сбросить
+color_schemes_delete = Удалить
+color_schemes_new_name = Имя копии
+color_schemes_copy = Копировать
+token_builtin_function = Встроенная функция
+token_comment = Комментарий
+token_field = Поле
+token_function = Вызов функции
+token_function_definition = Определение функции
+token_global_variable = Глобальная переменная
+token_keyword_other = Ключевое слово
+token_keyword_type = Тип
+token_literal = Текст
+token_local_variable = Локальная переменная
+token_numeric = Число
+token_struct = Структура
+palette_hint = Введите команду
+edit_replace = Заменить
+edit_replace_all = Заменить все
+edit_replace_one = Заменить
 
 # About
 about_homepage = Домашняя страница
@@ -38,7 +201,40 @@ about_homepage = Домашняя страница
 # Status
 status_compile_ok = Шейдер успешно скомпилирован!
 status_save_ok = Шейдер успешно сохранен!
+status_export_ok = Проект успешно экспортирован!
+status_convert_ok = Шейдер успешно преобразован!
+status_color_scheme_imported = Цветовая схема { $name } импортирована
+status_cursor = Стр. { $line }, стлб. { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = метка установлена
+keymap_vim_insert = ВСТАВКА
+keymap_vim_normal = НОРМАЛЬНЫЙ
+keymap_vim_visual = ВИЗУАЛЬНЫЙ
+keymap_vim_visual_line = ВИЗУАЛЬНЫЙ СТРОКА
 status_err_open_example = Ошибка при открытии примера
 status_err_open_file = Ошибка при открытии файла
 status_err_open_texture = Ошибка при открытии текстуры
+status_err_export = Не удалось экспортировать проект
+status_err_convert = Не удалось преобразовать шейдер
+status_err_import_color_scheme = Не удалось импортировать цветовую схему
+status_err_save = Не удалось сохранить шейдер
 status_err_valid = Ошибка проверки шейдера
+
+# WGSL
+wgsl_uniform = Значения, которые приложение обновляет каждый кадр.
+wgsl_uniform_cursor = Положение указателя над предпросмотром, в пикселях.
+wgsl_uniform_mouse_down = 1, пока кнопка мыши зажата над предпросмотром, иначе 0.
+wgsl_uniform_mouse_press = Где последний раз была нажата кнопка мыши над предпросмотром, в пикселях.
+wgsl_uniform_mouse_release = Где последний раз была отпущена кнопка мыши над предпросмотром, в пикселях.
+wgsl_uniform_resolution = Размер предпросмотра, в пикселях.
+wgsl_uniform_time = Секунды с момента запуска шейдера.
+wgsl_image = Выбирает значение текстуры `t` сэмплером `spl` в точке `uv`; начало координат внизу слева.
+wgsl_section_array = Встроенные функции массивов
+wgsl_section_atomic = Встроенные атомарные функции
+wgsl_section_derivative = Встроенные функции производных
+wgsl_section_logical = Встроенные логические функции
+wgsl_section_numeric = Встроенные числовые функции
+wgsl_section_packing = Встроенные функции упаковки данных
+wgsl_section_synchronization = Встроенные функции синхронизации
+wgsl_section_texture = Встроенные функции текстур
+wgsl_section_unpacking = Встроенные функции распаковки данных
@@ -3,10 +3,46 @@
 menu_file = 文件
 menu_new = 新建
 menu_open = 打开
+menu_open_project = 打开项目目录
 menu_open_examples = 打开示例
 menu_save = 保存
 menu_save_as = 另存为
+menu_save_project_as = 另存为项目目录
+menu_import_wgsl = 导入 WGSL
+menu_export_wgsl = 导出 WGSL
+menu_export_wgsl_fragment = 仅片段源码
+menu_export_wgsl_standalone = 独立着色器
+menu_export_rust_project = 导出为 Rust 项目
+menu_convert = 转换
+menu_convert_wgs_to_project = .wgs 转为项目目录
+menu_convert_project_to_wgs = 项目目录转为 .wgs
+menu_close_tab = 关闭标签页
 menu_quit = 退出
+## Menu Edit
+menu_edit = 编辑
+menu_undo = 撤销
+menu_redo = 重做
+menu_take_snapshot = 创建快照
+menu_snapshot_history = 快照历史
+menu_find = 查找
+menu_replace = 替换
+menu_format = 格式化文档
+menu_comment_line = 切换行注释
+menu_comment_block = 切换块注释
+menu_duplicate_line = 复制行
+menu_move_line_up = 上移行
+menu_move_line_down = 下移行
+menu_delete_line = 删除行
+menu_outline = 大纲
+menu_fold_all = 全部折叠
+menu_unfold_all = 全部展开
+menu_compare = 比较
+menu_compare_saved = 与已保存的版本
+menu_compare_tab = 与 { $name }
+menu_compare_side_by_side = 并排
+menu_compare_wipe = 划像
+menu_compare_show_diff = 显示差异
+menu_compare_stop = 停止比较
 # Menu Peferences
 menu_preferences = 首选项
 menu_languages = 语言
@@ -14,7 +50,27 @@ menu_language_system = 系统语言
 menu_theme = 主题
 menu_theme_light = 浅色
 menu_theme_dark = 深色
+menu_ui_scale = 界面缩放
+menu_editor = 编辑器
+menu_auto_indent = 自动缩进
+menu_auto_close = 自动闭合括号和引号
+menu_format_on_save = 保存时格式化
+menu_indent_width = 以 { $width } 个空格缩进
+menu_keymap_default = 默认按键
+menu_keymap_emacs = Emacs 按键
+menu_keymap_vim = Vim 按键
+menu_font_family = 字体
+menu_font_family_default = 默认
+menu_font_family_ligatures = 带连字（显示时不含连字）
+menu_font_family_others = 其他字体
+menu_font_size = 字号
+menu_line_height = 行高
+menu_zoom_in = 放大
+menu_zoom_out = 缩小
+menu_zoom_reset = 重置缩放
 menu_record_fps = 记录 FPS
+menu_color_schemes = 配色方案
+menu_shortcuts = 键盘快捷键
 # Menu Help
 menu_help = 帮助
 menu_about = 关于
@@ -28,9 +84,116 @@ control_pause = 暂停播放
 
 # Edit
 edit_name = 名称
+edit_properties = 文档属性
+edit_author = 作者
+edit_description = 描述
+edit_tags = 标签
+edit_license = 许可证
+edit_created = 创建时间
+edit_modified = 修改时间
+edit_thumbnail = 缩略图
+edit_capture_thumbnail = 从预览截取缩略图
 edit_change_texture = 修改纹理
 edit_remove_texture = 移除纹理
 edit_add_texture = 添加纹理
+edit_references = 引用
+edit_rename = 重命名符号
+edit_rename_err_conflict = 新名称与作用域中的其他名称冲突
+edit_rename_err_invalid_name = 不是有效的 WGSL 标识符
+edit_rename_err_predeclared = 只能重命名在此着色器中声明和使用的名称
+edit_find = 查找
+edit_find_case_sensitive = 区分大小写
+edit_find_close = 关闭
+edit_find_count = { $total } 个结果
+edit_find_err_regex = 无效的正则表达式
+edit_find_next = 下一个匹配项
+edit_find_no_results = 无结果
+edit_find_position = 第 { $current } 个，共 { $total } 个
+edit_find_previous = 上一个匹配项
+edit_find_regex = 使用正则表达式
+edit_find_toggle_replace = 切换替换
+edit_find_whole_word = 全字匹配
+tab_close = 关闭标签页
+tab_new = 新建标签页
+dialog_discard_title = 未保存的更改
+dialog_discard = { $name } 有未保存的更改。关闭并放弃这些更改吗？
+compare_current = 当前
+compare_diff = 差异
+compare_frame_time = 每帧平均 GPU 时间
+compare_frame_time_estimate = 每帧的估计 GPU 时间（GPU 未提供该数据）
+compare_no_changes = 没有更改
+compare_saved = 已保存
+compare_wipe = 划像位置
+snapshot_history = 快照
+snapshot_label = 标签
+snapshot_take = 创建
+snapshot_untitled = 快照
+snapshot_diff = 对比
+snapshot_diff_hint = 显示所选两个快照之间的差异
+snapshot_select = 选择以对比
+snapshot_restore = 恢复
+snapshot_delete = 删除快照
+outline_empty = 没有函数、结构体或常量
+snapshot_none = 每次着色器编译时或手动创建快照。
+command_capture_image = 捕获图像
+command_capture_thumbnail = 捕获缩略图
+command_compare_saved = 与已保存版本对比
+command_compile = 编译并运行
+command_complete = 触发补全
+command_convert_project_to_wgs = 将项目目录转换为 .wgs
+command_convert_wgs_to_project = 将 .wgs 转换为项目目录
+command_export_wgsl_fragment = 导出 WGSL 片段源码
+command_export_wgsl_standalone = 导出独立 WGSL 着色器
+command_find_references = 查找引用
+command_go_to_definition = 转到定义
+command_indent = 缩进行
+command_outdent = 取消缩进行
+command_palette = 命令面板
+command_pause = 暂停或继续
+command_rename = 重命名符号
+command_restart = 重新开始
+command_tab_next = 下一个标签页
+command_tab_previous = 上一个标签页
+command_texture_add = 添加纹理
+shortcuts_title = 键盘快捷键
+shortcuts_filter = 筛选命令
+shortcuts_reset_all = 全部重置
+shortcuts_press = 请按下快捷键…
+shortcuts_record = 点击后按下新的快捷键，按 Escape 取消
+shortcuts_clear = 移除快捷键
+shortcuts_reset = 重置为默认值
+shortcuts_conflict = { $shortcut } 已绑定到 { $command }。
+shortcuts_reassign = 重新分配
+shortcuts_cancel = 取消
+color_schemes_title = 配色方案
+color_schemes_dark = 深色模式
+color_schemes_light = 浅色模式
+color_schemes_edit = 编辑
+color_schemes_import = 导入…
+color_schemes_import_hint = TOML 格式的配色方案，或 JSON 格式的 VS Code、TextMate 主题
+color_schemes_is_dark = 用于深色背景
+color_schemes_preview = 预览
+color_schemes_save = 保存
+color_schemes_reset = 重置
+color_schemes_delete = 删除
+color_schemes_new_name = 副本名称
+color_schemes_copy = 复制
+token_builtin_function = 内置函数
+token_comment = 注释
+token_field = 字段
+token_function = 函数调用
+token_function_definition = 函数定义
+token_global_variable = 全局变量
+token_keyword_other = 关键字
+token_keyword_type = 类型
+token_literal = 文本
+token_local_variable = 局部变量
+token_numeric = 数字
+token_struct = 结构体
+palette_hint = 输入命令
+edit_replace = 替换
+edit_replace_all = 全部替换
+edit_replace_one = 替换
 
 # About
 about_homepage = 主页
@@ -38,7 +201,40 @@ about_homepage = 主页
 # Status
 status_compile_ok = 着色器编译成功！
 status_save_ok = 着色器保存成功！
+status_export_ok = 项目导出成功！
+status_convert_ok = 着色器转换成功！
+status_color_scheme_imported = 已导入配色方案 { $name }
+status_cursor = 行 { $line }，列 { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = 已设置标记
+keymap_vim_insert = 插入
+keymap_vim_normal = 普通
+keymap_vim_visual = 可视
+keymap_vim_visual_line = 可视行
 status_err_open_example = 打开示例失败
 status_err_open_file = 打开文件失败
 status_err_open_texture = 打开纹理失败
+status_err_export = 导出项目失败
+status_err_convert = 转换着色器失败
+status_err_import_color_scheme = 导入配色方案失败
+status_err_save = 保存着色器失败
 status_err_valid = 着色器验证错误
+
+# WGSL
+wgsl_uniform = 应用每帧更新的值。
+wgsl_uniform_cursor = 指针在预览上的位置，以像素为单位。
+wgsl_uniform_mouse_down = 在预览上按住鼠标按键时为 1，否则为 0。
+wgsl_uniform_mouse_press = 上次在预览上按下鼠标按键的位置，以像素为单位。
+wgsl_uniform_mouse_release = 上次在预览上松开鼠标按键的位置，以像素为单位。
+wgsl_uniform_resolution = 预览的尺寸，以像素为单位。
+wgsl_uniform_time = 着色器开始运行以来的秒数。
+wgsl_image = 使用采样器 `spl` 在 `uv` 处对纹理 `t` 采样，原点位于左下角。
+wgsl_section_array = 数组内置函数
+wgsl_section_atomic = 原子内置函数
+wgsl_section_derivative = 导数内置函数
+wgsl_section_logical = 逻辑内置函数
+wgsl_section_numeric = 数值内置函数
+wgsl_section_packing = 数据打包内置函数
+wgsl_section_synchronization = 同步内置函数
+wgsl_section_texture = 纹理内置函数
+wgsl_section_unpacking = 数据解包内置函数
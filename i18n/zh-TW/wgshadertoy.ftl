@@ -3,10 +3,46 @@
 menu_file = 檔案
 menu_new = 新建
 menu_open = 開啟
+menu_open_project = 開啟專案目錄
 menu_open_examples = 開啟範例
 menu_save = 儲存
 menu_save_as = 另存為
+menu_save_project_as = 另存為專案目錄
+menu_import_wgsl = 匯入 WGSL
+menu_export_wgsl = 匯出 WGSL
+menu_export_wgsl_fragment = 僅片段原始碼
+menu_export_wgsl_standalone = 獨立著色器
+menu_export_rust_project = 匯出為 Rust 專案
+menu_convert = 轉換
+menu_convert_wgs_to_project = .wgs 轉為專案目錄
+menu_convert_project_to_wgs = 專案目錄轉為 .wgs
+menu_close_tab = 關閉分頁
 menu_quit = 退出
+## Menu Edit
+menu_edit = 編輯
+menu_undo = 復原
+menu_redo = 重做
+menu_take_snapshot = 建立快照
+menu_snapshot_history = 快照記錄
+menu_find = 尋找
+menu_replace = 取代
+menu_format = 格式化文件
+menu_comment_line = 切換行註解
+menu_comment_block = 切換區塊註解
+menu_duplicate_line = 複製行
+menu_move_line_up = 上移行
+menu_move_line_down = 下移行
+menu_delete_line = 刪除行
+menu_outline = 大綱
+menu_fold_all = 全部摺疊
+menu_unfold_all = 全部展開
+menu_compare = 比較
+menu_compare_saved = 與已儲存的版本
+menu_compare_tab = 與 { $name }
+menu_compare_side_by_side = 並排
+menu_compare_wipe = 劃像
+menu_compare_show_diff = 顯示差異
+menu_compare_stop = 停止比較
 ## Menu Peferences
 menu_preferences = 偏好設定
 menu_languages = 語言
@@ -14,7 +50,27 @@ menu_language_system = 系統預設
 menu_theme = 主題
 menu_theme_light = 淺色
 menu_theme_dark = 深色
+menu_ui_scale = 介面縮放
+menu_editor = 編輯器
+menu_auto_indent = 自動縮排
+menu_auto_close = 自動閉合括號和引號
+menu_format_on_save = 儲存時格式化
+menu_indent_width = 以 { $width } 個空格縮排
+menu_keymap_default = 預設按鍵
+menu_keymap_emacs = Emacs 按鍵
+menu_keymap_vim = Vim 按鍵
+menu_font_family = 字型
+menu_font_family_default = 預設
+menu_font_family_ligatures = 含連字（顯示時不含連字）
+menu_font_family_others = 其他字型
+menu_font_size = 字型大小
+menu_line_height = 行高
+menu_zoom_in = 放大
+menu_zoom_out = 縮小
+menu_zoom_reset = 重設縮放
 menu_record_fps = 記錄 FPS
+menu_color_schemes = 配色方案
+menu_shortcuts = 鍵盤快速鍵
 ## Menu Help
 menu_help = 幫助
 menu_about = 關於
@@ -28,9 +84,116 @@ control_pause = 暫停
 
 # Edit
 edit_name = 名稱
+edit_properties = 文件屬性
+edit_author = 作者
+edit_description = 描述
+edit_tags = 標籤
+edit_license = 授權條款
+edit_created = 建立時間
+edit_modified = 修改時間
+edit_thumbnail = 縮圖
+edit_capture_thumbnail = 從預覽擷取縮圖
 edit_change_texture = 更改紋理
 edit_remove_texture = 移除紋理
 edit_add_texture = 添加紋理
+edit_references = 參考
+edit_rename = 重新命名符號
+edit_rename_err_conflict = 新名稱與作用域中的其他名稱衝突
+edit_rename_err_invalid_name = 不是有效的 WGSL 識別字
+edit_rename_err_predeclared = 只能重新命名在此著色器中宣告和使用的名稱
+edit_find = 尋找
+edit_find_case_sensitive = 大小寫須相符
+edit_find_close = 關閉
+edit_find_count = { $total } 個結果
+edit_find_err_regex = 無效的規則運算式
+edit_find_next = 下一個相符項目
+edit_find_no_results = 沒有結果
+edit_find_position = 第 { $current } 個，共 { $total } 個
+edit_find_previous = 上一個相符項目
+edit_find_regex = 使用規則運算式
+edit_find_toggle_replace = 切換取代
+edit_find_whole_word = 全字拼寫須相符
+tab_close = 關閉分頁
+tab_new = 新增分頁
+dialog_discard_title = 未儲存的變更
+dialog_discard = { $name } 有未儲存的變更。關閉並捨棄這些變更嗎？
+compare_current = 目前
+compare_diff = 差異
+compare_frame_time = 每幀平均 GPU 時間
+compare_frame_time_estimate = 每幀的估計 GPU 時間（GPU 未提供此資料）
+compare_no_changes = 沒有變更
+compare_saved = 已儲存
+compare_wipe = 劃像位置
+snapshot_history = 快照
+snapshot_label = 標籤
+snapshot_take = 建立
+snapshot_untitled = 快照
+snapshot_diff = 對比
+snapshot_diff_hint = 顯示所選兩個快照之間的差異
+snapshot_select = 選取以對比
+snapshot_restore = 還原
+snapshot_delete = 刪除快照
+outline_empty = 沒有函式、結構或常數
+snapshot_none = 每次著色器編譯時或手動建立快照。
+command_capture_image = 擷取影像
+command_capture_thumbnail = 擷取縮圖
+command_compare_saved = 與已儲存版本對比
+command_compile = 編譯並執行
+command_complete = 觸發自動完成
+command_convert_project_to_wgs = 將專案目錄轉換為 .wgs
+command_convert_wgs_to_project = 將 .wgs 轉換為專案目錄
+command_export_wgsl_fragment = 匯出 WGSL 片段原始碼
+command_export_wgsl_standalone = 匯出獨立 WGSL 著色器
+command_find_references = 尋找參考
+command_go_to_definition = 移至定義
+command_indent = 縮排行
+command_outdent = 取消縮排行
+command_palette = 命令選擇區
+command_pause = 暫停或繼續
+command_rename = 重新命名符號
+command_restart = 重新開始
+command_tab_next = 下一個分頁
+command_tab_previous = 上一個分頁
+command_texture_add = 新增紋理
+shortcuts_title = 鍵盤快速鍵
+shortcuts_filter = 篩選命令
+shortcuts_reset_all = 全部重設
+shortcuts_press = 請按下快速鍵…
+shortcuts_record = 點擊後按下新的快速鍵，按 Escape 取消
+shortcuts_clear = 移除快速鍵
+shortcuts_reset = 重設為預設值
+shortcuts_conflict = { $shortcut } 已綁定到 { $command }。
+shortcuts_reassign = 重新指派
+shortcuts_cancel = 取消
+color_schemes_title = 配色方案
+color_schemes_dark = 深色模式
+color_schemes_light = 淺色模式
+color_schemes_edit = 編輯
+color_schemes_import = 匯入…
+color_schemes_import_hint = TOML 格式的配色方案，或 JSON 格式的 VS Code、TextMate 佈景主題
+color_schemes_is_dark = 用於深色背景
+color_schemes_preview = 預覽
+color_schemes_save = 儲存
+color_schemes_reset = 重設
+color_schemes_delete = 刪除
+color_schemes_new_name = 副本名稱
+color_schemes_copy = 複製
+token_builtin_function = 內建函式
+token_comment = 註解
+token_field = 欄位
+token_function = 函式呼叫
+token_function_definition = 函式定義
+token_global_variable = 全域變數
+token_keyword_other = 關鍵字
+token_keyword_type = 型別
+token_literal = 文字
+token_local_variable = 區域變數
+token_numeric = 數字
+token_struct = 結構
+palette_hint = 輸入命令
+edit_replace = 取代
+edit_replace_all = 全部取代
+edit_replace_one = 取代
 
 # About
 about_homepage = 主頁
@@ -38,7 +201,40 @@ about_homepage = 主頁
 # Status
 status_compile_ok = 著色器成功編譯！
 status_save_ok = 著色器成功保存！
+status_export_ok = 專案匯出成功！
+status_convert_ok = 著色器轉換成功！
+status_color_scheme_imported = 已匯入配色方案 { $name }
+status_cursor = 行 { $line }，欄 { $column }
+keymap_emacs = Emacs
+keymap_emacs_mark = 已設定標記
+keymap_vim_insert = 插入
+keymap_vim_normal = 一般
+keymap_vim_visual = 視覺
+keymap_vim_visual_line = 視覺行
 status_err_open_example = 無法打開範例
 status_err_open_file = 無法打開文件
 status_err_open_texture = 無法打開紋理
+status_err_export = 匯出專案失敗
+status_err_convert = 轉換著色器失敗
+status_err_import_color_scheme = 匯入配色方案失敗
+status_err_save = 儲存著色器失敗
 status_err_valid = 著色器驗證錯誤
+
+# WGSL
+wgsl_uniform = 應用程式每幀更新的值。
+wgsl_uniform_cursor = 指標在預覽上的位置，以像素為單位。
+wgsl_uniform_mouse_down = 在預覽上按住滑鼠按鍵時為 1，否則為 0。
+wgsl_uniform_mouse_press = 上次在預覽上按下滑鼠按鍵的位置，以像素為單位。
+wgsl_uniform_mouse_release = 上次在預覽上放開滑鼠按鍵的位置，以像素為單位。
+wgsl_uniform_resolution = 預覽的尺寸，以像素為單位。
+wgsl_uniform_time = 著色器開始執行以來的秒數。
+wgsl_image = 使用取樣器 `spl` 在 `uv` 處對紋理 `t` 取樣，原點位於左下角。
+wgsl_section_array = 陣列內建函式
+wgsl_section_atomic = 原子內建函式
+wgsl_section_derivative = 導數內建函式
+wgsl_section_logical = 邏輯內建函式
+wgsl_section_numeric = 數值內建函式
+wgsl_section_packing = 資料打包內建函式
+wgsl_section_synchronization = 同步內建函式
+wgsl_section_texture = 紋理內建函式
+wgsl_section_unpacking = 資料解包內建函式
@@ -3,7 +3,7 @@ use crate::{
     window::WindowExt, window_icon::window_icon,
 };
 use anyhow::Result;
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 use winit::{
     dpi::{LogicalSize, Size},
     event::{ElementState, Event, MouseButton, WindowEvent},
@@ -24,7 +24,8 @@ pub struct App {
 }
 
 impl App {
-    pub fn new() -> Result<Self> {
+    // Opens `path` on top of the last session, if any.
+    pub fn new(path: Option<PathBuf>) -> Result<Self> {
         let event_loop = EventLoopBuilder::<UserEvent>::with_user_event().build();
 
         let window = WindowBuilder::new()
@@ -37,7 +38,7 @@ impl App {
 
         let inner_size = window.inner_size();
 
-        let core = Core::new(
+        let mut core = Core::new(
             &event_loop,
             &window,
             inner_size.width as f32,
@@ -45,6 +46,10 @@ impl App {
             window.scale_factor() as f32,
         )?;
 
+        if let Some(path) = path {
+            core.open_path(&path);
+        }
+
         window.set_title(&core.window_title());
 
         select_system_locales()?;
@@ -62,6 +67,7 @@ impl App {
             *control_flow = ControlFlow::Poll;
 
             match event {
+                Event::LoopDestroyed => self.core.save_session(),
                 Event::MainEventsCleared => self.window.request_redraw(),
                 Event::RedrawRequested(window_id) => {
                     if window_id == self.window.id() {
@@ -89,6 +95,10 @@ impl App {
                     match event {
                         WindowEvent::CloseRequested => {
                             if window_id == self.window.id() {
+                                if !self.core.confirm_quit() {
+                                    return;
+                                }
+
                                 self.sub_window_map.clear();
 
                                 *control_flow = ControlFlow::Exit;
@@ -149,7 +159,7 @@ impl App {
                 Event::UserEvent(event) => {
                     let response = self.core.handle_user_event(event);
 
-                    if response.request_quit {
+                    if response.request_quit && self.core.confirm_quit() {
                         self.sub_window_map.clear();
 
                         *control_flow = ControlFlow::Exit;
@@ -0,0 +1,376 @@
+use crate::{
+    fs::{encode_wgs, load_wgs_from_file, open_image, WGSL_EXTENSION},
+    metadata::Metadata,
+    project::{load_project, save_project},
+    shader::validate,
+};
+use anyhow::{Context, Result};
+use clap::{CommandFactory, Parser, Subcommand};
+use serde_json::{json, Value};
+use std::{
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+};
+use wgs_core::WgsData;
+
+#[derive(Default, Parser)]
+#[command(
+    version,
+    about = "A WGSL playground inspired by Shadertoy.",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Print machine-readable JSON instead of text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// A .wgs file or a project directory to open in the app
+    pub path: Option<PathBuf>,
+}
+
+impl Cli {
+    // Only the subcommands fail on arguments they don't take. Otherwise the
+    // app opens anyway, e.g. with those a launcher or a file association adds.
+    pub fn parse_or_default() -> Self {
+        match Self::try_parse() {
+            Ok(cli) => cli,
+            Err(err) => {
+                let is_subcommand = std::env::args()
+                    .nth(1)
+                    .is_some_and(|arg| Self::command().find_subcommand(arg).is_some());
+
+                if is_subcommand || !err.use_stderr() {
+                    err.exit();
+                }
+
+                log::warn!("Ignoring the command line: {}", err);
+
+                Self::default()
+            }
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Print the name, textures, source size and metadata of a shader
+    Info {
+        /// A .wgs file or a project directory
+        path: PathBuf,
+    },
+    /// Dump the source and textures of a .wgs file into a project directory
+    Extract {
+        /// A .wgs file
+        path: PathBuf,
+
+        /// Output directory [default: the file name without extension]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Build a .wgs file from a WGSL source and images
+    Pack {
+        /// The WGSL fragment source
+        source: PathBuf,
+
+        /// Images to add as textures, in channel order
+        #[arg(short, long = "texture")]
+        textures: Vec<PathBuf>,
+
+        /// Shader name [default: the source file name]
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// Output .wgs file
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Parse and validate the WGSL of a shader, no GPU needed
+    Validate {
+        /// A .wgs file, a project directory or a .wgsl file
+        path: PathBuf,
+    },
+}
+
+// Runs a subcommand and returns the process exit code.
+pub fn run(command: Command, json: bool) -> i32 {
+    let result = match command {
+        Command::Info { path } => info(&path),
+        Command::Extract { path, output } => extract(&path, output),
+        Command::Pack {
+            source,
+            textures,
+            name,
+            output,
+        } => pack(&source, &textures, name, &output),
+        Command::Validate { path } => validate_file(&path),
+    };
+
+    match result {
+        Ok(report) => {
+            print_report(&report, json);
+
+            if report.success {
+                0
+            } else {
+                1
+            }
+        }
+        Err(err) => {
+            if json {
+                println!("{}", json!({ "error": format!("{:#}", err) }));
+            } else {
+                eprintln!("error: {:#}", err);
+            }
+
+            1
+        }
+    }
+}
+
+struct Report {
+    success: bool,
+    value: Value,
+}
+
+impl Report {
+    fn ok(value: Value) -> Self {
+        Self {
+            success: true,
+            value,
+        }
+    }
+}
+
+fn extract(path: &Path, output: Option<PathBuf>) -> Result<Report> {
+    let (wgs, metadata) =
+        load_wgs_from_file(path).with_context(|| format!("Failed to open {}", path.display()))?;
+
+    let output = output.unwrap_or_else(|| path.with_extension(""));
+
+    save_project(&output, &wgs, &metadata)?;
+
+    Ok(Report::ok(json!({
+        "output": output,
+        "textures": wgs.textures_ref().len(),
+    })))
+}
+
+fn info(path: &Path) -> Result<Report> {
+    let (wgs, metadata) = load_document(path)?;
+
+    let frag = wgs.frag();
+
+    let is_wgs = path.is_file() && path.extension().is_none_or(|ext| ext != WGSL_EXTENSION);
+    let format_version = is_wgs.then(|| wgs.version());
+
+    Ok(Report::ok(json!({
+        "name": wgs.name(),
+        "format_version": format_version,
+        "source_length": frag.len(),
+        "source_lines": frag.lines().count(),
+        "textures": wgs
+            .textures_ref()
+            .iter()
+            .map(|texture| json!({ "width": texture.width, "height": texture.height }))
+            .collect::<Vec<_>>(),
+        "author": metadata.author,
+        "description": metadata.description,
+        "license": metadata.license,
+        "tags": metadata.tags,
+        "created": metadata.created,
+        "modified": metadata.modified,
+        "thumbnail": metadata.thumbnail.is_some(),
+    })))
+}
+
+fn load_document(path: &Path) -> Result<(WgsData, Metadata)> {
+    if path.is_dir() {
+        return load_project(path);
+    }
+
+    if path.extension().is_some_and(|ext| ext == WGSL_EXTENSION) {
+        let mut wgs = WgsData::default();
+        wgs.set_name(&path.file_stem().unwrap_or_default().to_string_lossy());
+        wgs.set_frag(&read_to_string(path)?);
+
+        return Ok((wgs, Metadata::default()));
+    }
+
+    load_wgs_from_file(path).with_context(|| format!("Failed to open {}", path.display()))
+}
+
+fn pack(
+    source: &Path,
+    textures: &[PathBuf],
+    name: Option<String>,
+    output: &Path,
+) -> Result<Report> {
+    let frag =
+        read_to_string(source).with_context(|| format!("Failed to read {}", source.display()))?;
+
+    let name = name.unwrap_or_else(|| {
+        source
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned()
+    });
+
+    let mut wgs = WgsData::default();
+    wgs.set_name(&name);
+    wgs.set_frag(&frag);
+
+    for texture in textures {
+        let (width, height, data) =
+            open_image(texture).with_context(|| format!("Failed to open {}", texture.display()))?;
+
+        wgs.add_texture(width, height, data);
+    }
+
+    let mut metadata = Metadata::default();
+    metadata.touch();
+
+    write(output, encode_wgs(&wgs, &metadata))?;
+
+    Ok(Report::ok(json!({
+        "output": output,
+        "textures": textures.len(),
+    })))
+}
+
+fn print_report(report: &Report, json: bool) {
+    if json {
+        println!("{}", report.value);
+
+        return;
+    }
+
+    if let Value::Object(map) = &report.value {
+        for (key, value) in map {
+            match value {
+                Value::String(value) => println!("{}: {}", key, value),
+                Value::Array(values) => {
+                    println!("{}: {}", key, values.len());
+
+                    for value in values {
+                        println!("  - {}", value);
+                    }
+                }
+                value => println!("{}: {}", key, value),
+            }
+        }
+    }
+}
+
+fn validate_file(path: &Path) -> Result<Report> {
+    let (wgs, _) = load_document(path)?;
+
+    match validate(&wgs.frag(), wgs.textures_ref().len()) {
+        Ok(()) => Ok(Report::ok(json!({ "valid": true }))),
+        Err(diagnostic) => {
+            if !diagnostic.report.is_empty() {
+                eprintln!("{}", diagnostic.report);
+            }
+
+            Ok(Report {
+                success: false,
+                value: json!({
+                    "valid": false,
+                    "message": diagnostic.message,
+                    "line": diagnostic.line,
+                    "column": diagnostic.column,
+                }),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::ColorType;
+    use std::fs::{create_dir_all, remove_dir_all};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("wgshadertoy-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+
+        create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    fn example_frag() -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/default.wgs");
+
+        load_wgs_from_file(path).unwrap().0.frag()
+    }
+
+    #[test]
+    fn pack_extract_info() {
+        let dir = temp_dir("cli");
+        let frag = example_frag();
+
+        let source = dir.join("rings.wgsl");
+        write(&source, &frag).unwrap();
+
+        let texture = dir.join("channel.png");
+        image::save_buffer(&texture, &[255; 8], 2, 1, ColorType::Rgba8).unwrap();
+
+        let wgs_path = dir.join("rings.wgs");
+
+        let pack = Command::Pack {
+            source,
+            textures: vec![texture],
+            name: None,
+            output: wgs_path.clone(),
+        };
+
+        assert_eq!(run(pack, true), 0);
+
+        let report = info(&wgs_path).unwrap().value;
+
+        assert_eq!(report["name"], "rings");
+        assert_eq!(report["format_version"], wgs_core::VERSION);
+        assert_eq!(report["source_length"], frag.len());
+        assert_eq!(report["textures"], json!([{ "width": 2, "height": 1 }]));
+        assert!(report["created"].is_string());
+
+        let extract = Command::Extract {
+            path: wgs_path,
+            output: None,
+        };
+
+        assert_eq!(run(extract, true), 0);
+
+        let project = dir.join("rings");
+        let report = info(&project).unwrap().value;
+
+        assert_eq!(report["name"], "rings");
+        assert!(report["format_version"].is_null());
+        assert_eq!(report["textures"], json!([{ "width": 2, "height": 1 }]));
+        assert_eq!(load_project(&project).unwrap().0.frag(), frag);
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn validate_exit_codes() {
+        let dir = temp_dir("validate");
+
+        let valid = dir.join("valid.wgsl");
+        write(&valid, example_frag()).unwrap();
+
+        let invalid = dir.join("invalid.wgsl");
+        write(&invalid, "fn main_image(").unwrap();
+
+        for (path, code) in [(valid, 0), (invalid, 1), (dir.join("missing.wgs"), 1)] {
+            assert_eq!(run(Command::Validate { path }, true), code);
+        }
+
+        remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,637 @@
+use crate::fs::config_dir;
+use anyhow::{Context, Result};
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, write},
+    path::PathBuf,
+};
+
+const PICKED_FILENAME: &str = "color_schemes.toml";
+
+const SCHEMES_DIRNAME: &str = "color_schemes";
+
+// The TextMate scopes of each token, the first one a theme colours being
+// used. The literal colour is the theme's foreground.
+const SCOPES: [(&str, &[&str]); 11] = [
+    (
+        "builtin_function",
+        &["support.function", "entity.name.function"],
+    ),
+    ("comment", &["comment"]),
+    (
+        "field",
+        &[
+            "variable.other.property",
+            "variable.other.member",
+            "variable.other.object.property",
+            "variable",
+        ],
+    ),
+    ("function", &["entity.name.function", "support.function"]),
+    ("function_definition", &["entity.name.function"]),
+    (
+        "global_variable",
+        &[
+            "variable.other.constant",
+            "variable.other.global",
+            "constant.other",
+            "variable",
+        ],
+    ),
+    (
+        "keyword_other",
+        &["keyword.control", "keyword", "storage.modifier", "storage"],
+    ),
+    (
+        "keyword_type",
+        &[
+            "storage.type",
+            "support.type",
+            "keyword.type",
+            "entity.name.type",
+            "keyword",
+        ],
+    ),
+    (
+        "local_variable",
+        &["variable.other.readwrite", "variable.parameter", "variable"],
+    ),
+    ("numeric", &["constant.numeric", "constant"]),
+    (
+        "struct",
+        &[
+            "entity.name.type.struct",
+            "entity.name.type",
+            "entity.name.class",
+            "support.type",
+        ],
+    ),
+];
+
+// The kinds of tokens a scheme colours, as named in its file.
+pub const TOKENS: [&str; 12] = [
+    "builtin_function",
+    "comment",
+    "field",
+    "function",
+    "function_definition",
+    "global_variable",
+    "keyword_other",
+    "keyword_type",
+    "literal",
+    "local_variable",
+    "numeric",
+    "struct",
+];
+
+// The colours of the editor's syntax highlighting.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorScheme {
+    // By token, each of `TOKENS`.
+    pub colors: BTreeMap<&'static str, Color32>,
+    // Whether it's meant for a dark background.
+    pub dark: bool,
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SchemeFile {
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+    dark: bool,
+    name: String,
+}
+
+impl ColorScheme {
+    pub fn dark() -> Self {
+        Self::builtin(
+            "GitHub Dark",
+            true,
+            [
+                0x79c0ff, 0x8b949e, 0xa5d6ff, 0xd2a8ff, 0xd2a8ff, 0xe3b341, 0xff7b72, 0xff7b72,
+                0xc9d1d9, 0xffa657, 0x79c0ff, 0x7ee787,
+            ],
+        )
+    }
+
+    pub fn light() -> Self {
+        Self::builtin(
+            "GitHub Light",
+            false,
+            [
+                0x005cc5, 0x6a737d, 0x032f62, 0x6f42c1, 0x6f42c1, 0xb08800, 0xd73a49, 0xd73a49,
+                0x24292e, 0xe36209, 0x005cc5, 0x22863a,
+            ],
+        )
+    }
+
+    pub fn color(&self, token: &str) -> Color32 {
+        self.colors.get(token).copied().unwrap_or(Color32::GRAY)
+    }
+
+    // Takes the colours of the token kinds we have from a VS Code theme, or
+    // a TextMate theme in JSON, the others those of the built-in scheme.
+    pub fn import_json(text: &str, name: &str) -> Result<Self> {
+        let json: Value = serde_json::from_str(&strip_json_comments(text))?;
+
+        let rules = json
+            .get("tokenColors")
+            .or_else(|| json.get("settings"))
+            .and_then(Value::as_array)
+            .context("No token colors")?;
+
+        // TextMate themes have their colours in a rule without a scope.
+        let global = rules.iter().find(|rule| rule.get("scope").is_none());
+        let global_color = |key: &str| {
+            json.get("colors")
+                .and_then(|colors| colors.get(format!("editor.{}", key)))
+                .or_else(|| global?.get("settings")?.get(key))
+                .and_then(Value::as_str)
+                .and_then(parse_color)
+        };
+
+        let mut selectors: Vec<(&str, Color32)> = vec![];
+
+        for rule in rules {
+            let Some(color) = rule
+                .get("settings")
+                .and_then(|settings| settings.get("foreground"))
+                .and_then(Value::as_str)
+                .and_then(parse_color)
+            else {
+                continue;
+            };
+
+            let scopes: Vec<&str> = match rule.get("scope") {
+                Some(Value::String(scope)) => scope.split(',').collect(),
+                Some(Value::Array(scopes)) => scopes.iter().filter_map(Value::as_str).collect(),
+                _ => continue,
+            };
+
+            // Of a descendant selector, e.g. `source.wgsl keyword`, the last
+            // scope.
+            selectors.extend(
+                scopes
+                    .into_iter()
+                    .filter_map(|scope| Some((scope.split_whitespace().last()?, color))),
+            );
+        }
+
+        let dark = match json.get("type").and_then(Value::as_str) {
+            Some("light") => false,
+            Some(_) => true,
+            None => global_color("background").is_none_or(|background| {
+                let [r, g, b, _] = background.to_array();
+
+                u32::from(r) + u32::from(g) + u32::from(b) < 3 * 128
+            }),
+        };
+
+        let mut scheme = if dark { Self::dark() } else { Self::light() };
+
+        scheme.dark = dark;
+        scheme.name = json
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or(name)
+            .to_owned();
+
+        for (token, scopes) in SCOPES {
+            if let Some(color) = scopes
+                .iter()
+                .find_map(|scope| best_match(&selectors, scope))
+            {
+                scheme.colors.insert(token, color);
+            }
+        }
+
+        if let Some(foreground) = global_color("foreground") {
+            scheme.colors.insert("literal", foreground);
+        }
+
+        Ok(scheme)
+    }
+
+    // Tokens left out take their colours from the built-in scheme.
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: SchemeFile = toml::from_str(text)?;
+
+        let mut scheme = if file.dark {
+            Self::dark()
+        } else {
+            Self::light()
+        };
+
+        scheme.name = file.name;
+
+        for (name, text) in file.colors {
+            let Some(token) = TOKENS.into_iter().find(|token| *token == name) else {
+                log::warn!("Unknown token in color scheme: {}", name);
+
+                continue;
+            };
+
+            let color = parse_color(&text)
+                .with_context(|| format!("Invalid color for {}: {}", name, text))?;
+
+            scheme.colors.insert(token, color);
+        }
+
+        Ok(scheme)
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        let file = SchemeFile {
+            colors: self
+                .colors
+                .iter()
+                .map(|(token, color)| (token.to_string(), format_color(*color)))
+                .collect(),
+            dark: self.dark,
+            name: self.name.clone(),
+        };
+
+        Ok(toml::to_string_pretty(&file)?)
+    }
+
+    fn builtin(name: &str, dark: bool, colors: [u32; 12]) -> Self {
+        let colors = TOKENS
+            .into_iter()
+            .zip(colors)
+            .map(|(token, rgb)| {
+                let [_, r, g, b] = rgb.to_be_bytes();
+
+                (token, Color32::from_rgb(r, g, b))
+            })
+            .collect();
+
+        Self {
+            colors,
+            dark,
+            name: name.to_owned(),
+        }
+    }
+
+    // Where the scheme is saved, named after it.
+    fn path(&self) -> Result<PathBuf> {
+        let stem: String = self
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        Ok(config_dir()
+            .context("No config directory")?
+            .join(SCHEMES_DIRNAME)
+            .join(format!("{}.toml", stem)))
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct Picked {
+    dark: String,
+    light: String,
+}
+
+// The schemes there are, and those picked for dark and light mode.
+pub struct ColorSchemes {
+    // The name of the scheme used in dark mode.
+    pub dark: String,
+    // The name of the scheme used in light mode.
+    pub light: String,
+    // The built-in schemes first, then the user's.
+    pub schemes: Vec<ColorScheme>,
+}
+
+impl Default for ColorSchemes {
+    fn default() -> Self {
+        let schemes = vec![ColorScheme::dark(), ColorScheme::light()];
+
+        Self {
+            dark: schemes[0].name.clone(),
+            light: schemes[1].name.clone(),
+            schemes,
+        }
+    }
+}
+
+impl ColorSchemes {
+    // The built-in schemes, along with those the user saved, which replace
+    // the built-in ones of the same name.
+    pub fn load() -> Result<Self> {
+        let mut schemes = Self::default();
+
+        let dir = config_dir().context("No config directory")?;
+
+        if let Ok(entries) = read_dir(dir.join(SCHEMES_DIRNAME)) {
+            let mut paths: Vec<PathBuf> = entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                .collect();
+
+            paths.sort();
+
+            for path in paths {
+                match read_to_string(&path)
+                    .map_err(Into::into)
+                    .and_then(|text| ColorScheme::parse(&text))
+                {
+                    Ok(scheme) => {
+                        schemes.add(scheme);
+                    }
+                    Err(err) => {
+                        log::warn!("Failed to load {}: {}", path.display(), err);
+                    }
+                }
+            }
+        }
+
+        let path = dir.join(PICKED_FILENAME);
+
+        if path.is_file() {
+            let picked: Picked = toml::from_str(&read_to_string(path)?)?;
+
+            schemes.dark = picked.dark;
+            schemes.light = picked.light;
+        }
+
+        Ok(schemes)
+    }
+
+    // Adds a scheme, or replaces the one of the same name, and returns its
+    // index.
+    pub fn add(&mut self, scheme: ColorScheme) -> usize {
+        match self.index(&scheme.name) {
+            Some(index) => {
+                self.schemes[index] = scheme;
+
+                index
+            }
+            None => {
+                self.schemes.push(scheme);
+
+                self.schemes.len() - 1
+            }
+        }
+    }
+
+    // The scheme for dark or light mode, the built-in one when the scheme
+    // picked is gone.
+    pub fn current(&self, dark: bool) -> &ColorScheme {
+        let name = if dark { &self.dark } else { &self.light };
+
+        match self.index(name) {
+            Some(index) => &self.schemes[index],
+            None => &self.schemes[if dark { 0 } else { 1 }],
+        }
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.schemes.iter().position(|scheme| scheme.name == name)
+    }
+
+    pub fn is_builtin(name: &str) -> bool {
+        name == ColorScheme::dark().name || name == ColorScheme::light().name
+    }
+
+    // Deletes the file of a scheme. A built-in scheme is reset rather than
+    // removed.
+    pub fn remove(&mut self, index: usize) -> Result<()> {
+        let path = self.schemes[index].path()?;
+
+        if path.is_file() {
+            remove_file(path)?;
+        }
+
+        let name = &self.schemes[index].name;
+
+        if *name == ColorScheme::dark().name {
+            self.schemes[index] = ColorScheme::dark();
+        } else if *name == ColorScheme::light().name {
+            self.schemes[index] = ColorScheme::light();
+        } else {
+            self.schemes.remove(index);
+        }
+
+        Ok(())
+    }
+
+    // Saves which schemes are picked.
+    pub fn save(&self) -> Result<()> {
+        let picked = Picked {
+            dark: self.dark.clone(),
+            light: self.light.clone(),
+        };
+
+        let dir = config_dir().context("No config directory")?;
+
+        create_dir_all(&dir)?;
+        write(dir.join(PICKED_FILENAME), toml::to_string_pretty(&picked)?)?;
+
+        Ok(())
+    }
+
+    pub fn save_scheme(&self, index: usize) -> Result<()> {
+        let scheme = &self.schemes[index];
+        let path = scheme.path()?;
+
+        if let Some(dir) = path.parent() {
+            create_dir_all(dir)?;
+        }
+
+        write(path, scheme.to_toml()?)?;
+
+        Ok(())
+    }
+}
+
+// The colour of the longest selector matching a scope, `keyword` matching
+// `keyword.control` as well.
+fn best_match(selectors: &[(&str, Color32)], scope: &str) -> Option<Color32> {
+    selectors
+        .iter()
+        .filter(|(selector, _)| {
+            let selector = selector.trim();
+
+            scope == selector
+                || scope
+                    .strip_prefix(selector)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+        .max_by_key(|(selector, _)| selector.trim().len())
+        .map(|(_, color)| *color)
+}
+
+fn format_color(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+
+    if a == u8::MAX {
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    } else {
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
+
+// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`.
+fn parse_color(text: &str) -> Option<Color32> {
+    let digits: Vec<u8> = text
+        .trim()
+        .strip_prefix('#')?
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<_>>()?;
+
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] * 16 + pair[1])
+            .collect(),
+        _ => return None,
+    };
+
+    let alpha = channels.get(3).copied().unwrap_or(u8::MAX);
+
+    Some(Color32::from_rgba_unmultiplied(
+        channels[0],
+        channels[1],
+        channels[2],
+        alpha,
+    ))
+}
+
+// VS Code themes may have comments and trailing commas, which JSON doesn't.
+fn strip_json_comments(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            stripped.push(c);
+
+            match c {
+                '\\' => stripped.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+
+                stripped.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|c| *c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+
+                while let Some(c) = chars.next() {
+                    if c == '*' && chars.next_if_eq(&'/').is_some() {
+                        break;
+                    }
+                }
+            }
+            (',', _) => {
+                // Trailing, before a closing bracket.
+                let rest = chars.clone().find(|c| !c.is_whitespace());
+
+                if !matches!(rest, Some('}' | ']')) {
+                    stripped.push(c);
+                }
+            }
+            _ => stripped.push(c),
+        }
+    }
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_json() {
+        let theme = r##"{
+            // A comment.
+            "name": "Test",
+            "type": "light",
+            "colors": { "editor.foreground": "#112233" },
+            "tokenColors": [
+                { "scope": "comment", "settings": { "foreground": "#00ff00" } },
+                { "scope": ["keyword", "storage.type"], "settings": { "foreground": "#0000ff" } },
+                { "scope": "keyword.control", "settings": { "foreground": "#ff0000", }, },
+            ]
+        }"##;
+
+        let scheme = ColorScheme::import_json(theme, "Imported").unwrap();
+
+        assert_eq!(scheme.name, "Test");
+        assert!(!scheme.dark);
+        assert_eq!(scheme.color("comment"), Color32::from_rgb(0, 255, 0));
+        assert_eq!(scheme.color("keyword_other"), Color32::from_rgb(255, 0, 0));
+        assert_eq!(scheme.color("keyword_type"), Color32::from_rgb(0, 0, 255));
+        assert_eq!(scheme.color("literal"), Color32::from_rgb(0x11, 0x22, 0x33));
+
+        // Not in the theme.
+        assert_eq!(
+            scheme.color("numeric"),
+            ColorScheme::light().color("numeric")
+        );
+    }
+
+    #[test]
+    fn import_textmate() {
+        let theme = r##"{
+            "settings": [
+                { "settings": { "background": "#272822", "foreground": "#f8f8f2" } },
+                { "scope": "constant.numeric", "settings": { "foreground": "#ae81ff" } }
+            ]
+        }"##;
+
+        let scheme = ColorScheme::import_json(theme, "Monokai").unwrap();
+
+        assert_eq!(scheme.name, "Monokai");
+        assert!(scheme.dark);
+        assert_eq!(scheme.color("numeric"), Color32::from_rgb(0xae, 0x81, 0xff));
+        assert_eq!(scheme.color("literal"), Color32::from_rgb(0xf8, 0xf8, 0xf2));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut scheme = ColorScheme::dark();
+
+        scheme.name = "Mine".to_owned();
+        scheme.colors.insert("comment", Color32::from_rgb(1, 2, 3));
+
+        assert_eq!(
+            ColorScheme::parse(&scheme.to_toml().unwrap()).unwrap(),
+            scheme
+        );
+
+        // Left out, from the built-in scheme.
+        let scheme =
+            ColorScheme::parse("name = \"Short\"\ndark = false\n[colors]\ncomment = \"#abc\"")
+                .unwrap();
+
+        assert_eq!(scheme.color("comment"), Color32::from_rgb(0xaa, 0xbb, 0xcc));
+        assert_eq!(scheme.color("struct"), ColorScheme::light().color("struct"));
+
+        assert!(
+            ColorScheme::parse("name = \"Bad\"\ndark = true\n[colors]\ncomment = \"red\"").is_err()
+        );
+    }
+}
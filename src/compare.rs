@@ -0,0 +1,698 @@
+use crate::{
+    history::Texture,
+    preferences::{CompareLayout, ComparePreferences},
+    shader::{concat_shader, validate},
+};
+use std::{
+    collections::VecDeque,
+    mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+use wgs_runtime_wgpu::{
+    wgpu::{self, util::DeviceExt},
+    Viewport,
+};
+
+// How many frames the frame times are averaged over.
+const FRAME_TIME_SAMPLES: usize = 30;
+
+// A start and an end timestamp for each version.
+const QUERY_COUNT: u32 = 4;
+
+// The size of the `Uniform` struct of the prelude, padding included.
+const UNIFORM_SIZE: usize = 48;
+
+// What to compare the active tab with.
+#[derive(Debug)]
+pub enum CompareWith {
+    Saved,
+    Tab(usize),
+}
+
+// Renders an older version of a shader next to the active one, the two
+// sharing their time and mouse input.
+//
+// The runtime only holds one shader, so both versions are drawn here with
+// pipelines built from the standalone shader instead.
+pub struct Comparison {
+    // The cursor in the preview, from its top left corner.
+    cursor: [f32; 2],
+    // The format of the surface drawn to.
+    format: wgpu::TextureFormat,
+    gpu: Option<Gpu>,
+    last_frame: Option<Instant>,
+    name: String,
+    // Where each version is drawn the cursor is the same.
+    local_width: f32,
+    mouse_down: bool,
+    mouse_press: [f32; 2],
+    mouse_release: [f32; 2],
+    time: f32,
+    // The older version, then the active one.
+    versions: [Version; 2],
+}
+
+impl Comparison {
+    pub fn new(
+        format: wgpu::TextureFormat,
+        name: String,
+        old_frag: String,
+        old_textures: Vec<Texture>,
+        frag: String,
+        textures: Vec<Texture>,
+    ) -> Self {
+        Self {
+            cursor: [0.0, 0.0],
+            format,
+            gpu: None,
+            last_frame: None,
+            name,
+            local_width: 0.0,
+            mouse_down: false,
+            mouse_press: [0.0, 0.0],
+            mouse_release: [0.0, 0.0],
+            time: 0.0,
+            versions: [
+                Version::new(old_frag, old_textures),
+                Version::new(frag, textures),
+            ],
+        }
+    }
+
+    // The average time the GPU took to draw each version, in milliseconds,
+    // estimated unless `is_timed`.
+    pub fn frame_times(&self) -> [Option<f32>; 2] {
+        [self.versions[0].frame_time(), self.versions[1].frame_time()]
+    }
+
+    // Whether frame times are measured with timestamp queries, rather than
+    // estimated.
+    pub fn is_timed(&self) -> bool {
+        self.gpu.as_ref().is_some_and(|gpu| gpu.timer.is_some())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn old_frag(&self) -> &str {
+        &self.versions[0].frag
+    }
+
+    // Draws both versions into `viewport`, the preview, of `view`.
+    //
+    // With timestamp queries, each version's GPU time is read back a frame or
+    // more later. Without them, each version is submitted on its own and
+    // waited for, which stalls the frame while comparing, and only estimates
+    // its GPU time from the CPU's.
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        viewport: Viewport,
+        preferences: &ComparePreferences,
+        is_paused: bool,
+    ) {
+        let now = Instant::now();
+
+        if let Some(last_frame) = self.last_frame {
+            if !is_paused {
+                self.time += now.duration_since(last_frame).as_secs_f32();
+            }
+        }
+
+        self.last_frame = Some(now);
+
+        let (areas, local_width) = match preferences.layout {
+            CompareLayout::SideBySide => {
+                let width = viewport.width / 2.0;
+
+                (
+                    [
+                        (viewport.x, viewport.x + width),
+                        (viewport.x + width, viewport.x + viewport.width),
+                    ],
+                    width,
+                )
+            }
+            CompareLayout::Wipe => {
+                let split = viewport.x + viewport.width * preferences.wipe.clamp(0.0, 1.0);
+
+                (
+                    [(viewport.x, split), (split, viewport.x + viewport.width)],
+                    viewport.width,
+                )
+            }
+        };
+
+        self.local_width = local_width;
+
+        let uniform = self.uniform([local_width, viewport.height]);
+
+        let gpu = self.gpu.get_or_insert_with(|| Gpu::new(device, queue));
+
+        for version in &mut self.versions {
+            if version.pipeline.is_none() && !version.failed {
+                version.build(device, queue, self.format, gpu);
+            }
+        }
+
+        if let Some(times) = gpu.timer.as_mut().and_then(|timer| timer.read(device)) {
+            for (version, time) in self.versions.iter_mut().zip(times) {
+                if let Some(time) = time {
+                    version.push_frame_time(time);
+                }
+            }
+        }
+
+        queue.write_buffer(&gpu.uniform_buffer, 0, &uniform);
+
+        let encoder_descriptor = wgpu::CommandEncoderDescriptor {
+            label: Some("Compare Encoder"),
+        };
+
+        let mut encoder = device.create_command_encoder(&encoder_descriptor);
+
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Compare Clear Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        let is_timed = gpu.timer.is_some();
+
+        // Not while the last timestamps are still being read back.
+        let mut timer = gpu.timer.as_mut().filter(|timer| timer.drawn.is_none());
+        let mut drawn = [false; 2];
+
+        for (index, (start, end)) in areas.into_iter().enumerate() {
+            let (start, end) = (start.round(), end.round());
+
+            let version = &mut self.versions[index];
+
+            let x = match preferences.layout {
+                CompareLayout::SideBySide => start,
+                CompareLayout::Wipe => viewport.x,
+            };
+
+            let queries = 2 * index as u32;
+
+            if let Some(timer) = &timer {
+                encoder.write_timestamp(&timer.query_set, queries);
+            }
+
+            if let (Some(pipeline), true) = (&version.pipeline, end > start) {
+                let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Compare Render Pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: true,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                });
+
+                render_pass.set_viewport(
+                    x,
+                    viewport.y,
+                    local_width,
+                    viewport.height,
+                    viewport.min_depth,
+                    viewport.max_depth,
+                );
+                render_pass.set_scissor_rect(
+                    start as u32,
+                    viewport.y as u32,
+                    (end - start) as u32,
+                    viewport.height as u32,
+                );
+
+                render_pass.set_pipeline(&pipeline.pipeline);
+                render_pass.set_bind_group(0, &gpu.uniform_bind_group, &[]);
+                for (index, bind_group) in pipeline.texture_bind_groups.iter().enumerate() {
+                    render_pass.set_bind_group(index as u32 + 1, bind_group, &[]);
+                }
+                render_pass.draw(0..3, 0..1);
+
+                drawn[index] = true;
+            }
+
+            if let Some(timer) = &timer {
+                encoder.write_timestamp(&timer.query_set, queries + 1);
+            } else if drawn[index] && !is_timed {
+                // The wait also counts whatever was submitted before.
+                let submitted = Instant::now();
+
+                let finished = mem::replace(
+                    &mut encoder,
+                    device.create_command_encoder(&encoder_descriptor),
+                );
+
+                queue.submit(Some(finished.finish()));
+                device.poll(wgpu::Maintain::Wait);
+
+                version.push_frame_time(submitted.elapsed().as_secs_f32() * 1000.0);
+            }
+        }
+
+        if let Some(timer) = &mut timer {
+            timer.resolve(&mut encoder, drawn);
+        }
+
+        queue.submit(Some(encoder.finish()));
+
+        if let Some(timer) = &timer {
+            timer.map();
+        }
+    }
+
+    pub fn restart(&mut self) {
+        self.time = 0.0;
+        self.last_frame = None;
+    }
+
+    // Follows the active tab's source, rebuilding its pipeline once changed.
+    pub fn set_frag(&mut self, frag: &str) {
+        let version = &mut self.versions[1];
+
+        if version.frag != frag {
+            version.frag = frag.to_owned();
+            version.invalidate();
+        }
+    }
+
+    pub fn set_textures(&mut self, textures: Vec<Texture>) {
+        let version = &mut self.versions[1];
+
+        if version.textures != textures {
+            version.textures = textures;
+            version.invalidate();
+        }
+    }
+
+    // `cursor` is from the top left corner of the preview.
+    pub fn update_cursor(&mut self, cursor: [f32; 2]) {
+        self.cursor = cursor;
+    }
+
+    pub fn update_mouse(&mut self, press: bool) {
+        self.mouse_down = press;
+
+        if press {
+            self.mouse_press = self.local_cursor();
+        } else {
+            self.mouse_release = self.local_cursor();
+        }
+    }
+
+    // The cursor within the version it's over, from the bottom left corner.
+    fn local_cursor(&self) -> [f32; 2] {
+        let x = if self.local_width > 0.0 {
+            self.cursor[0] % self.local_width
+        } else {
+            self.cursor[0]
+        };
+
+        [x, self.cursor[1]]
+    }
+
+    fn uniform(&self, resolution: [f32; 2]) -> [u8; UNIFORM_SIZE] {
+        let cursor = self.local_cursor();
+
+        let mut bytes = [0; UNIFORM_SIZE];
+
+        let mut write = |offset: usize, value: [u8; 4]| {
+            bytes[offset..offset + 4].copy_from_slice(&value);
+        };
+
+        write(0, cursor[0].to_ne_bytes());
+        write(4, (resolution[1] - cursor[1]).to_ne_bytes());
+        write(8, (self.mouse_down as u32).to_ne_bytes());
+        write(16, self.mouse_press[0].to_ne_bytes());
+        write(20, (resolution[1] - self.mouse_press[1]).to_ne_bytes());
+        write(24, self.mouse_release[0].to_ne_bytes());
+        write(28, (resolution[1] - self.mouse_release[1]).to_ne_bytes());
+        write(32, resolution[0].to_ne_bytes());
+        write(36, resolution[1].to_ne_bytes());
+        write(40, self.time.to_ne_bytes());
+
+        bytes
+    }
+}
+
+// What both versions' pipelines are built with.
+struct Gpu {
+    sampler: wgpu::Sampler,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    // Only where the device has timestamp queries.
+    timer: Option<Timer>,
+    uniform_bind_group: wgpu::BindGroup,
+    uniform_bind_group_layout: wgpu::BindGroupLayout,
+    uniform_buffer: wgpu::Buffer,
+}
+
+impl Gpu {
+    fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Compare Uniform Buffer"),
+            contents: &[0; UNIFORM_SIZE],
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let uniform_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Compare Uniform Bind Group Layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+
+        let uniform_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Compare Uniform Bind Group"),
+            layout: &uniform_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let texture_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Compare Texture Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let timer = device
+            .features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY)
+            .then(|| Timer::new(device, queue));
+
+        Self {
+            sampler,
+            texture_bind_group_layout,
+            timer,
+            uniform_bind_group,
+            uniform_bind_group_layout,
+            uniform_buffer,
+        }
+    }
+}
+
+// Reads back the time between the timestamps written around each version,
+// without waiting for it.
+struct Timer {
+    // Which versions were drawn between the timestamps being read back.
+    drawn: Option<[bool; 2]>,
+    // Set once `read_buffer` is mapped.
+    mapped: Arc<AtomicBool>,
+    // Nanoseconds per timestamp increment.
+    period: f32,
+    query_set: wgpu::QuerySet,
+    read_buffer: wgpu::Buffer,
+    resolve_buffer: wgpu::Buffer,
+}
+
+impl Timer {
+    fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let size = (QUERY_COUNT * wgpu::QUERY_SIZE) as wgpu::BufferAddress;
+
+        Self {
+            drawn: None,
+            mapped: Arc::new(AtomicBool::new(false)),
+            period: queue.get_timestamp_period(),
+            query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("Compare Query Set"),
+                ty: wgpu::QueryType::Timestamp,
+                count: QUERY_COUNT,
+            }),
+            read_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Compare Query Read Buffer"),
+                size,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            }),
+            resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Compare Query Resolve Buffer"),
+                size,
+                usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::QUERY_RESOLVE,
+                mapped_at_creation: false,
+            }),
+        }
+    }
+
+    // Copies the timestamps written in `encoder` to be read back.
+    fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder, drawn: [bool; 2]) {
+        encoder.resolve_query_set(&self.query_set, 0..QUERY_COUNT, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.read_buffer,
+            0,
+            self.read_buffer.size(),
+        );
+
+        self.drawn = Some(drawn);
+    }
+
+    // Once the copy of `resolve` is submitted.
+    fn map(&self) {
+        let mapped = self.mapped.clone();
+
+        self.read_buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                mapped.store(result.is_ok(), Ordering::Release);
+            });
+    }
+
+    // The time each drawn version took, in milliseconds, once read back.
+    fn read(&mut self, device: &wgpu::Device) -> Option<[Option<f32>; 2]> {
+        let drawn = self.drawn?;
+
+        device.poll(wgpu::Maintain::Poll);
+
+        if !self.mapped.swap(false, Ordering::Acquire) {
+            return None;
+        }
+
+        let timestamps: Vec<u64> = self
+            .read_buffer
+            .slice(..)
+            .get_mapped_range()
+            .chunks_exact(wgpu::QUERY_SIZE as usize)
+            .map(|bytes| u64::from_ne_bytes(bytes.try_into().unwrap()))
+            .collect();
+
+        self.read_buffer.unmap();
+        self.drawn = None;
+
+        Some(std::array::from_fn(|index| {
+            drawn[index].then(|| {
+                let ticks = timestamps[2 * index + 1].saturating_sub(timestamps[2 * index]);
+
+                ticks as f32 * self.period / 1_000_000.0
+            })
+        }))
+    }
+}
+
+struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    texture_bind_groups: Vec<wgpu::BindGroup>,
+}
+
+struct Version {
+    // Whether the pipeline couldn't be built, it isn't tried again until the
+    // version changes.
+    failed: bool,
+    frag: String,
+    frame_times: VecDeque<f32>,
+    pipeline: Option<Pipeline>,
+    textures: Vec<Texture>,
+}
+
+impl Version {
+    fn new(frag: String, textures: Vec<Texture>) -> Self {
+        Self {
+            failed: false,
+            frag,
+            frame_times: VecDeque::with_capacity(FRAME_TIME_SAMPLES),
+            pipeline: None,
+            textures,
+        }
+    }
+
+    fn build(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        gpu: &Gpu,
+    ) {
+        if let Err(diagnostic) = validate(&self.frag, self.textures.len()) {
+            log::warn!("Failed to build compared version: {}", diagnostic.message);
+
+            self.failed = true;
+
+            return;
+        }
+
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+        let texture_bind_groups = self
+            .textures
+            .iter()
+            .map(|texture| {
+                let texture = device.create_texture_with_data(
+                    queue,
+                    &wgpu::TextureDescriptor {
+                        label: Some("Compare Texture"),
+                        size: wgpu::Extent3d {
+                            width: texture.width,
+                            height: texture.height,
+                            depth_or_array_layers: 1,
+                        },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                        view_formats: &[],
+                    },
+                    &texture.data,
+                );
+
+                let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("Compare Texture Bind Group"),
+                    layout: &gpu.texture_bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&gpu.sampler),
+                        },
+                    ],
+                })
+            })
+            .collect();
+
+        let mut bind_group_layouts = vec![&gpu.uniform_bind_group_layout];
+        bind_group_layouts.extend(self.textures.iter().map(|_| &gpu.texture_bind_group_layout));
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Compare Pipeline Layout"),
+            bind_group_layouts: &bind_group_layouts,
+            push_constant_ranges: &[],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Compare Shader"),
+            source: wgpu::ShaderSource::Wgsl(concat_shader(&self.frag, self.textures.len()).into()),
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Compare Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(format.into())],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        match futures::executor::block_on(device.pop_error_scope()) {
+            Some(err) => {
+                log::warn!("Failed to build compared version: {}", err);
+
+                self.failed = true;
+            }
+            None => {
+                self.pipeline = Some(Pipeline {
+                    pipeline,
+                    texture_bind_groups,
+                })
+            }
+        }
+    }
+
+    fn frame_time(&self) -> Option<f32> {
+        if self.frame_times.is_empty() {
+            return None;
+        }
+
+        Some(self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32)
+    }
+
+    fn push_frame_time(&mut self, time: f32) {
+        if self.frame_times.len() == FRAME_TIME_SAMPLES {
+            self.frame_times.pop_front();
+        }
+
+        self.frame_times.push_back(time);
+    }
+
+    fn invalidate(&mut self) {
+        self.failed = false;
+        self.frame_times.clear();
+        self.pipeline = None;
+    }
+}
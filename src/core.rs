@@ -1,18 +1,33 @@
 use crate::{
-    event::{AppResponse, AppStatus, EventProxyWinit, UserEvent},
+    color_scheme::{ColorScheme, ColorSchemes},
+    compare::{CompareWith, Comparison},
+    event::{AppResponse, AppStatus, EventProxy, EventProxyWinit, UserEvent},
+    export::{export_rust_project, package_name},
     fps_counter::FpsCounter,
-    fs::{create_file, select_file, select_texture, write_file},
+    fs::{
+        confirm_discard, create_file, create_wgsl_file, load_wgs_from_buffer, load_wgs_from_file,
+        open_image, save_wgs, select_color_scheme_file, select_file, select_folder, select_texture,
+        select_wgsl_file, write_file, WGSL_EXTENSION,
+    },
+    history::{Change, Document, History, SourceEdit, Texture},
+    metadata::{Metadata, Thumbnail},
     preferences::Preferences,
-    ui::{EditContext, Ui, UiState},
+    project::{load_project, save_project},
+    shader::concat_shader,
+    shortcut::Shortcuts,
+    snapshots::Snapshots,
+    tabs::{Session, Stash, Tab},
+    ui::{CompareState, EditContext, TabState, Ui, UiState},
+    wgsl::{format_document, Selection, Tokenizer},
 };
-use anyhow::{bail, Result};
+use anyhow::Result;
 use egui::ClippedPrimitive;
 use egui_wgpu::{renderer::ScreenDescriptor, Renderer};
 use egui_winit::State;
 use image::ColorType;
 use std::{
-    fs::read,
-    io::{self, Cursor},
+    fs::{create_dir_all, read_to_string},
+    mem,
     path::{Path, PathBuf},
     time::Instant,
 };
@@ -21,17 +36,25 @@ use wgs_runtime_wgpu::{wgpu, Runtime, RuntimeExt, Viewport};
 use winit::{event::WindowEvent, event_loop::EventLoop, window::Window};
 
 pub struct Core {
+    active_tab: usize,
+    comparison: Option<Comparison>,
     cursor: [f32; 2],
     event_proxy: EventProxyWinit<UserEvent>,
     fps: Option<usize>,
     fps_counter: FpsCounter,
     has_validation_error: bool,
+    history: History,
+    next_tab_id: u64,
     preferences: Preferences,
     runtime: Runtime,
+    // The OS's, the UI scale applying on top of it.
+    scale_factor: f32,
     size: (f32, f32),
+    snapshots: Snapshots,
     state: State,
     status: AppStatus,
     status_clock: Instant,
+    tabs: Vec<Tab>,
     ui: Ui,
     ui_edit_context: EditContext,
     ui_renderer: Renderer,
@@ -69,7 +92,10 @@ impl Core {
 
         let ui_edit_context = EditContext {
             frag: wgs.frag(),
+            metadata: Metadata::default(),
             name: wgs.name(),
+            separate_step: false,
+            tokenizer: Tokenizer::default(),
         };
 
         let mut runtime = futures::executor::block_on(Runtime::new(w, wgs, Some(viewport)))?;
@@ -82,27 +108,64 @@ impl Core {
 
         let initial_status = AppStatus::Info("Shader compiled successfully!".to_owned());
 
-        Ok(Self {
+        let shortcuts = Shortcuts::load().unwrap_or_else(|err| {
+            log::warn!("Failed to load shortcuts: {}", err);
+
+            Shortcuts::default()
+        });
+
+        let color_schemes = ColorSchemes::load().unwrap_or_else(|err| {
+            log::warn!("Failed to load color schemes: {}", err);
+
+            ColorSchemes::default()
+        });
+
+        let preferences = Preferences::load().unwrap_or_else(|err| {
+            log::warn!("Failed to load preferences: {}", err);
+
+            Preferences::default()
+        });
+
+        let mut core = Self {
+            active_tab: 0,
+            comparison: None,
             cursor: [0.0, 0.0],
             event_proxy,
             fps: None,
             fps_counter: FpsCounter::new(),
             has_validation_error: false,
+            history: History::new(&ui_edit_context.frag),
+            next_tab_id: 1,
             runtime,
-            preferences: Preferences::default(),
+            preferences: Preferences {
+                color_schemes,
+                shortcuts,
+                ..preferences
+            },
+            scale_factor,
             size: (width, height),
+            snapshots: Snapshots::default(),
             state,
             status: initial_status,
             status_clock: Instant::now(),
+            tabs: vec![Tab { id: 0, stash: None }],
             ui,
             ui_edit_context,
             ui_renderer,
             wgs_path: None,
-        })
+        };
+
+        core.restore_session();
+
+        Ok(core)
     }
 
     pub fn handle_mouse_input(&mut self, press: bool) {
         if self.cursor[0] > self.size.0 / 2.0 {
+            if let Some(comparison) = &mut self.comparison {
+                comparison.update_mouse(press);
+            }
+
             if press {
                 self.runtime.update_mouse_press();
             } else {
@@ -116,16 +179,13 @@ impl Core {
 
         let mut update_result = None;
 
+        // Typing since the last frame comes before whatever the event does.
+        self.history
+            .record_source(&self.ui_edit_context.frag, Instant::now());
+
         match event {
             UserEvent::CaptureImage => {
-                let half_width = self.size.0 / 2.0;
-
-                let viewport = Viewport {
-                    x: half_width,
-                    width: half_width,
-                    height: self.size.1,
-                    ..Default::default()
-                };
+                let viewport = self.capture_viewport();
 
                 let filename = format!(
                     "Capture_{}.{}",
@@ -147,12 +207,34 @@ impl Core {
                     },
                 );
             }
+            UserEvent::CaptureThumbnail => {
+                let viewport = self.capture_viewport();
+
+                let event_proxy = self.event_proxy.clone();
+
+                self.runtime.request_capture_image(
+                    &viewport,
+                    move |_runtime, width, height, buffer| {
+                        if let Some(thumbnail) = Thumbnail::from_capture(width, height, buffer) {
+                            event_proxy.send_event(UserEvent::ThumbnailCaptured(thumbnail));
+                        }
+                    },
+                );
+            }
             UserEvent::ChangeTexture(index) => {
                 if let Some(path) = select_texture() {
                     match open_image(path) {
                         Ok((width, height, data)) => {
+                            let before = self.texture(index);
+
                             self.ui.change_texture(index, width, height, &data);
                             self.runtime.change_texture(index, width, height, data);
+
+                            self.history.record(Change::Texture {
+                                index,
+                                before,
+                                after: self.texture(index),
+                            });
                         }
                         Err(err) => {
                             log::error!("{}", format!("Failed to open texture: {}", err));
@@ -166,12 +248,209 @@ impl Core {
                     }
                 }
             }
+            UserEvent::CloseTab(index) => {
+                update_result = self.close_tab(index);
+
+                response.set_title = Some(self.format_title());
+            }
+            UserEvent::Compare(with) => {
+                self.compare(with);
+            }
+            UserEvent::ConvertProjectToWgs => {
+                if let Some(dir) = select_folder() {
+                    match load_project(&dir) {
+                        Ok((wgs, metadata)) => {
+                            if let Some(path) = create_file(&format!(
+                                "{}.{}",
+                                wgs.name().to_ascii_lowercase().replace(" ", "_"),
+                                wgs_core::EXTENSION
+                            )) {
+                                match save_wgs(&path, &wgs, &metadata) {
+                                    Ok(()) => self
+                                        .change_status(AppStatus::Info(fl!("status_convert_ok"))),
+                                    Err(err) => {
+                                        self.change_status(AppStatus::Error(format!(
+                                            "{}: {}",
+                                            fl!("status_err_convert"),
+                                            err
+                                        )));
+                                    }
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            log::error!("{}", format!("Failed to convert project: {}", err));
+
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_convert"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
+            UserEvent::ConvertWgsToProject => {
+                if let Some(path) = select_file() {
+                    match load_wgs_from_file(&path) {
+                        Ok((wgs, metadata)) => {
+                            if let Some(dir) = select_folder() {
+                                let dir = dir.join(
+                                    path.file_stem()
+                                        .map(|stem| stem.to_string_lossy().into_owned())
+                                        .unwrap_or_else(|| {
+                                            wgs.name().to_ascii_lowercase().replace(" ", "_")
+                                        }),
+                                );
+
+                                match save_project(&dir, &wgs, &metadata) {
+                                    Ok(()) => {
+                                        self.change_status(AppStatus::Info(fl!(
+                                            "status_convert_ok"
+                                        )));
+                                    }
+                                    Err(err) => {
+                                        log::error!(
+                                            "{}",
+                                            format!("Failed to convert file: {}", err)
+                                        );
+
+                                        self.change_status(AppStatus::Error(format!(
+                                            "{}: {}",
+                                            fl!("status_err_convert"),
+                                            err
+                                        )));
+                                    }
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            log::error!("{}", format!("Failed to open file: {}", err));
+
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_open_file"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
+            UserEvent::ExportRustProject => {
+                if let Some(dir) = select_folder() {
+                    self.runtime.set_wgs_frag(&self.ui_edit_context.frag);
+                    self.runtime.set_wgs_name(&self.ui_edit_context.name);
+
+                    let wgs = self.runtime.wgs();
+                    let dir = dir.join(package_name(&wgs.name()));
+
+                    match export_rust_project(&dir, wgs) {
+                        Ok(()) => {
+                            self.change_status(AppStatus::Info(fl!("status_export_ok")));
+                        }
+                        Err(err) => {
+                            log::error!("{}", format!("Failed to export project: {}", err));
+
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_export"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
+            UserEvent::ExportWgsl { with_prelude } => {
+                if let Some(path) = create_wgsl_file(&format!(
+                    "{}.{}",
+                    self.ui_edit_context
+                        .name
+                        .to_ascii_lowercase()
+                        .replace(" ", "_"),
+                    WGSL_EXTENSION
+                )) {
+                    let source = if with_prelude {
+                        concat_shader(
+                            &self.ui_edit_context.frag,
+                            self.runtime.wgs().textures_ref().len(),
+                        )
+                    } else {
+                        self.ui_edit_context.frag.clone()
+                    };
+
+                    log::info!("Saving wgsl file: {:?}", path);
+
+                    match write_file(&path, source) {
+                        Ok(()) => self.change_status(AppStatus::Info(fl!("status_save_ok"))),
+                        Err(err) => {
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_save"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
+            UserEvent::ImportColorScheme => {
+                if let Some(path) = select_color_scheme_file() {
+                    match self.import_color_scheme(&path) {
+                        Ok(name) => {
+                            self.change_status(AppStatus::Info(fl!(
+                                "status_color_scheme_imported",
+                                name = name
+                            )));
+                        }
+                        Err(err) => {
+                            log::error!("{}", format!("Failed to import color scheme: {}", err));
+
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_import_color_scheme"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
+            UserEvent::ImportWgsl => {
+                if let Some(path) = select_wgsl_file() {
+                    match read_to_string(&path) {
+                        Ok(frag) => {
+                            // A step of its own, so one undo takes it back.
+                            let removed = mem::replace(&mut self.ui_edit_context.frag, frag);
+
+                            self.history.record(Change::Source(SourceEdit {
+                                start: 0,
+                                removed,
+                                inserted: self.ui_edit_context.frag.clone(),
+                            }));
+
+                            self.runtime.set_wgs_frag(&self.ui_edit_context.frag);
+
+                            update_result = Some(self.runtime.compile());
+                        }
+                        Err(err) => {
+                            log::error!("{}", format!("Failed to import wgsl: {}", err));
+
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_open_file"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
             UserEvent::NewFile => {
                 let wgs = WgsData::default();
-                self.wgs_path = None;
+
+                self.new_tab(&wgs.frag());
 
                 self.ui.reset_textures();
+                self.ui.set_thumbnail(None);
                 self.ui_edit_context.frag = wgs.frag();
+                self.ui_edit_context.metadata = Metadata::default();
                 self.ui_edit_context.name = wgs.name();
 
                 update_result = Some(self.runtime.load(wgs));
@@ -185,12 +464,8 @@ impl Core {
                 let bytes = example.data();
 
                 match load_wgs_from_buffer(&bytes) {
-                    Ok(wgs) => {
-                        self.wgs_path = None;
-
-                        self.load_wgs(&wgs);
-
-                        update_result = Some(self.runtime.load(wgs));
+                    Ok((wgs, metadata)) => {
+                        update_result = Some(self.open_document(wgs, metadata, None));
 
                         response.set_title = Some(self.format_title());
                     }
@@ -208,17 +483,33 @@ impl Core {
             UserEvent::OpenFile => {
                 if let Some(path) = select_file() {
                     match load_wgs_from_file(&path) {
-                        Ok(wgs) => {
-                            self.wgs_path = Some(path);
+                        Ok((wgs, metadata)) => {
+                            update_result = Some(self.open_document(wgs, metadata, Some(path)));
 
-                            self.load_wgs(&wgs);
+                            response.set_title = Some(self.format_title());
+                        }
+                        Err(err) => {
+                            log::error!("{}", format!("Failed to open file: {}", err));
 
-                            update_result = Some(self.runtime.load(wgs));
+                            self.change_status(AppStatus::Error(format!(
+                                "{}: {}",
+                                fl!("status_err_open_file"),
+                                err
+                            )));
+                        }
+                    }
+                }
+            }
+            UserEvent::OpenProject => {
+                if let Some(dir) = select_folder() {
+                    match load_project(&dir) {
+                        Ok((wgs, metadata)) => {
+                            update_result = Some(self.open_document(wgs, metadata, Some(dir)));
 
                             response.set_title = Some(self.format_title());
                         }
                         Err(err) => {
-                            log::error!("{}", format!("Failed to open file: {}", err));
+                            log::error!("{}", format!("Failed to open project: {}", err));
 
                             self.change_status(AppStatus::Error(format!(
                                 "{}: {}",
@@ -233,8 +524,16 @@ impl Core {
                 if let Some(path) = select_texture() {
                     match open_image(path) {
                         Ok((width, height, data)) => {
+                            let index = self.runtime.wgs().textures_ref().len();
+
                             self.ui.add_texture(width, height, &data);
                             self.runtime.add_texture(width, height, data);
+
+                            self.history.record(Change::Texture {
+                                index,
+                                before: None,
+                                after: self.texture(index),
+                            });
                         }
                         Err(err) => {
                             log::error!("{}", format!("Failed to open texture: {}", err));
@@ -254,17 +553,56 @@ impl Core {
             UserEvent::Quit => {
                 response.request_quit = true;
             }
+            UserEvent::Redo => {
+                if let Some(change) = self.history.redo() {
+                    update_result = self.apply_change(change, &mut response);
+                }
+            }
             UserEvent::RemoveTexture(index) => {
+                self.history.record(Change::Texture {
+                    index,
+                    before: self.texture(index),
+                    after: None,
+                });
+
                 self.runtime.remove_texture(index);
                 self.ui.remove_texture(index);
             }
+            UserEvent::RemoveSnapshot(id) => {
+                self.snapshots.remove(id);
+            }
             UserEvent::RequestRedraw => {
                 self.runtime.set_wgs_frag(&self.ui_edit_context.frag);
 
-                update_result = Some(self.runtime.compile());
+                let result = self.runtime.compile();
+
+                if result.is_ok() {
+                    self.take_snapshot(None);
+                }
+
+                update_result = Some(result);
             }
             UserEvent::Restart => {
                 self.runtime.restart();
+
+                if let Some(comparison) = &mut self.comparison {
+                    comparison.restart();
+                }
+            }
+            UserEvent::RestoreSnapshot(id) => {
+                if let Some(snapshot) = self.snapshots.get(id) {
+                    let before = self.document();
+
+                    let after = Document {
+                        frag: snapshot.frag.clone(),
+                        textures: snapshot.textures.clone(),
+                        ..before.clone()
+                    };
+
+                    update_result = Some(self.load_document(after));
+
+                    self.record_document(before);
+                }
             }
             UserEvent::Resume => {
                 self.runtime.resume();
@@ -279,6 +617,40 @@ impl Core {
                     response.set_title = Some(title);
                 }
             }
+            UserEvent::SaveProjectAs => {
+                if let Some(title) = self.save_project_as() {
+                    response.set_title = Some(title);
+                }
+            }
+            UserEvent::SelectTab(index) => {
+                update_result = self.select_tab(index);
+
+                response.set_title = Some(self.format_title());
+            }
+            UserEvent::SnapshotThumbnailCaptured(id, thumbnail) => {
+                self.snapshots.set_thumbnail(id, thumbnail);
+            }
+            UserEvent::StopComparing => {
+                self.comparison = None;
+            }
+            UserEvent::TakeSnapshot(label) => {
+                let label = label.trim();
+
+                self.take_snapshot(Some(if label.is_empty() {
+                    fl!("snapshot_untitled")
+                } else {
+                    label.to_owned()
+                }));
+            }
+            UserEvent::ThumbnailCaptured(thumbnail) => {
+                self.ui.set_thumbnail(Some(&thumbnail));
+                self.ui_edit_context.metadata.thumbnail = Some(thumbnail);
+            }
+            UserEvent::Undo => {
+                if let Some(change) = self.history.undo() {
+                    update_result = self.apply_change(change, &mut response);
+                }
+            }
         }
 
         if let Some(result) = update_result {
@@ -296,6 +668,15 @@ impl Core {
             }
         }
 
+        // The event may have changed the textures compared.
+        if self.comparison.is_some() {
+            let textures = self.textures();
+
+            if let Some(comparison) = &mut self.comparison {
+                comparison.set_textures(textures);
+            }
+        }
+
         response
     }
 
@@ -348,7 +729,66 @@ impl Core {
         self.size = (width, height);
 
         self.runtime.resize(width, height);
-        self.state.set_pixels_per_point(scale_factor);
+
+        self.scale_factor = scale_factor;
+    }
+
+    // Opens a `wgs` file or a project directory in a tab of its own, unless
+    // there is only an untouched one.
+    pub fn open_path(&mut self, path: &Path) {
+        let result = load_path(path)
+            .and_then(|(wgs, metadata)| self.open_document(wgs, metadata, Some(path.to_owned())));
+
+        if let Err(err) = result {
+            log::error!("Failed to open {:?}: {}", path, err);
+
+            self.change_status(AppStatus::Error(format!(
+                "{}: {}",
+                fl!("status_err_open_file"),
+                err
+            )));
+        }
+    }
+
+    // Asks before quitting with unsaved changes in any tab, returns whether
+    // to quit.
+    pub fn confirm_quit(&self) -> bool {
+        self.tabs.iter().all(|tab| {
+            let (name, is_modified) = match &tab.stash {
+                Some(stash) => (&stash.document.name, stash.history.is_modified()),
+                None => (&self.ui_edit_context.name, self.history.is_modified()),
+            };
+
+            !is_modified
+                || confirm_discard(
+                    &fl!("dialog_discard_title"),
+                    &fl!("dialog_discard", name = name.as_str()),
+                )
+        })
+    }
+
+    // Remembers the tabs saved to a file, to open them again on next launch.
+    pub fn save_session(&self) {
+        let mut session = Session::default();
+
+        for (index, tab) in self.tabs.iter().enumerate() {
+            let path = match &tab.stash {
+                Some(stash) => stash.document.path.as_ref(),
+                None => self.wgs_path.as_ref(),
+            };
+
+            if let Some(path) = path {
+                if index == self.active_tab {
+                    session.active = session.paths.len();
+                }
+
+                session.paths.push(path.clone());
+            }
+        }
+
+        if let Err(err) = session.save() {
+            log::warn!("Failed to save session: {}", err);
+        }
     }
 
     pub fn update_cursor(&mut self, x: f32, y: f32) {
@@ -357,6 +797,10 @@ impl Core {
         let half_width = self.size.0 / 2.0;
         if x > half_width {
             self.runtime.update_cursor([x - half_width, y]);
+
+            if let Some(comparison) = &mut self.comparison {
+                comparison.update_cursor([x - half_width, y]);
+            }
         }
     }
 
@@ -364,17 +808,281 @@ impl Core {
         self.format_title()
     }
 
+    // Applies a change undone or redone, returns the result of loading
+    // the document when it changed.
+    fn apply_change(&mut self, change: Change, response: &mut AppResponse) -> Option<Result<()>> {
+        match change {
+            Change::Document { after, .. } => {
+                let result = self.load_document(*after);
+
+                if self.snapshots.document() != self.wgs_path.as_deref() {
+                    self.snapshots = Snapshots::load(self.wgs_path.as_deref());
+                }
+
+                response.set_title = Some(self.format_title());
+
+                Some(result)
+            }
+            Change::Source(edit) => {
+                self.ui_edit_context.frag = self.history.source().to_owned();
+
+                self.ui.set_editor_cursor(
+                    &self.ui_edit_context.frag,
+                    edit.start + edit.inserted.len(),
+                );
+
+                None
+            }
+            Change::Texture {
+                index,
+                before,
+                after,
+            } => {
+                match (before, after) {
+                    (_, None) => {
+                        self.runtime.remove_texture(index);
+                        self.ui.remove_texture(index);
+                    }
+                    (None, Some(texture)) => self.insert_texture(index, texture),
+                    (Some(_), Some(texture)) => {
+                        self.ui
+                            .change_texture(index, texture.width, texture.height, &texture.data);
+                        self.runtime.change_texture(
+                            index,
+                            texture.width,
+                            texture.height,
+                            texture.data,
+                        );
+                    }
+                }
+
+                None
+            }
+        }
+    }
+
+    fn capture_viewport(&self) -> Viewport {
+        let half_width = self.size.0 / 2.0;
+
+        Viewport {
+            x: half_width,
+            width: half_width,
+            height: self.size.1,
+            ..Default::default()
+        }
+    }
+
     fn change_status(&mut self, status: AppStatus) {
         self.status = status;
 
         self.status_clock = Instant::now();
     }
 
+    // Closes a tab, asking first when it has unsaved changes. The last tab is
+    // left with a new document.
+    fn close_tab(&mut self, index: usize) -> Option<Result<()>> {
+        let (name, is_modified) = match &self.tabs.get(index)?.stash {
+            Some(stash) => (stash.document.name.clone(), stash.history.is_modified()),
+            None => (
+                self.ui_edit_context.name.clone(),
+                self.history.is_modified(),
+            ),
+        };
+
+        if is_modified
+            && !confirm_discard(
+                &fl!("dialog_discard_title"),
+                &fl!("dialog_discard", name = name),
+            )
+        {
+            return None;
+        }
+
+        if self.tabs.len() == 1 {
+            let wgs = WgsData::default();
+
+            self.tabs[0].id = self.next_tab_id;
+            self.next_tab_id += 1;
+
+            self.ui.set_tab(self.tabs[0].id);
+            self.history = History::new(&wgs.frag());
+            self.snapshots = Snapshots::default();
+            self.wgs_path = None;
+            self.load_wgs(&wgs, Metadata::default());
+
+            return Some(self.runtime.load(wgs));
+        }
+
+        self.tabs.remove(index);
+
+        if index < self.active_tab {
+            self.active_tab -= 1;
+        } else if index == self.active_tab {
+            self.active_tab = index.min(self.tabs.len() - 1);
+
+            let stash = self.tabs[self.active_tab].stash.take()?;
+
+            return Some(self.unstash(stash));
+        }
+
+        None
+    }
+
+    // Starts comparing the active tab with an older version of it, which is
+    // drawn on the left of the preview.
+    fn compare(&mut self, with: CompareWith) {
+        let (name, frag, textures) = match with {
+            CompareWith::Saved => {
+                let Some(path) = &self.wgs_path else {
+                    return;
+                };
+
+                match load_path(path) {
+                    Ok((wgs, _)) => (
+                        fl!("compare_saved"),
+                        wgs.frag(),
+                        wgs.textures_ref()
+                            .iter()
+                            .map(|texture| Texture {
+                                width: texture.width,
+                                height: texture.height,
+                                data: texture.data.clone(),
+                            })
+                            .collect(),
+                    ),
+                    Err(err) => {
+                        self.change_status(AppStatus::Error(format!(
+                            "{}: {}",
+                            fl!("status_err_open_file"),
+                            err
+                        )));
+
+                        return;
+                    }
+                }
+            }
+            CompareWith::Tab(index) => {
+                let Some(stash) = self.tabs.get(index).and_then(|tab| tab.stash.as_ref()) else {
+                    return;
+                };
+
+                let document = &stash.document;
+
+                (
+                    document.name.clone(),
+                    document.frag.clone(),
+                    document.textures.clone(),
+                )
+            }
+        };
+
+        self.comparison = Some(Comparison::new(
+            self.runtime.format(),
+            name,
+            frag,
+            textures,
+            self.ui_edit_context.frag.clone(),
+            self.textures(),
+        ));
+    }
+
+    fn document(&self) -> Document {
+        Document {
+            frag: self.ui_edit_context.frag.clone(),
+            metadata: self.ui_edit_context.metadata.clone(),
+            name: self.ui_edit_context.name.clone(),
+            path: self.wgs_path.clone(),
+            textures: self.textures(),
+        }
+    }
+
+    fn format_on_save(&mut self) {
+        let editor = &self.preferences.editor;
+
+        if !editor.format_on_save {
+            return;
+        }
+
+        let frag = &self.ui_edit_context.frag;
+        let cursor = self.ui.editor_cursor(frag).unwrap_or(0);
+
+        // Left as is when it doesn't format, e.g. with unbalanced brackets.
+        if let Some(edit) = format_document(frag, Selection::caret(cursor), &editor.indent()) {
+            self.ui.set_editor_cursor(&edit.text, edit.selection.cursor);
+            self.ui_edit_context.frag = edit.text;
+        }
+    }
+
     fn format_title(&self) -> String {
         format!("[{}] - WgShadertoy", self.runtime.wgs().name())
     }
 
-    fn load_wgs(&mut self, wgs: &WgsData) {
+    // Saves the scheme among the user's and picks it for its mode. Returns
+    // its name.
+    fn import_color_scheme(&mut self, path: &Path) -> Result<String> {
+        let text = read_to_string(path)?;
+
+        let scheme = if path.extension().is_some_and(|ext| ext == "json") {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+            ColorScheme::import_json(&text, &stem)?
+        } else {
+            ColorScheme::parse(&text)?
+        };
+
+        let name = scheme.name.clone();
+        let color_schemes = &mut self.preferences.color_schemes;
+
+        if scheme.dark {
+            color_schemes.dark = name.clone();
+        } else {
+            color_schemes.light = name.clone();
+        }
+
+        let index = color_schemes.add(scheme);
+
+        color_schemes.save_scheme(index)?;
+        color_schemes.save()?;
+
+        Ok(name)
+    }
+
+    // Textures can only be added at the end, the ones after `index` are
+    // added again after it.
+    fn insert_texture(&mut self, index: usize, texture: Texture) {
+        let count = self.runtime.wgs().textures_ref().len();
+        let following: Vec<Texture> = (index..count)
+            .filter_map(|index| self.texture(index))
+            .collect();
+
+        for _ in index..count {
+            self.runtime.remove_texture(index);
+            self.ui.remove_texture(index);
+        }
+
+        for texture in std::iter::once(texture).chain(following) {
+            self.ui
+                .add_texture(texture.width, texture.height, &texture.data);
+            self.runtime
+                .add_texture(texture.width, texture.height, texture.data);
+        }
+    }
+
+    fn load_document(&mut self, document: Document) -> Result<()> {
+        let mut wgs = WgsData::new(&document.name, &document.frag);
+
+        for texture in document.textures {
+            wgs.add_texture(texture.width, texture.height, texture.data);
+        }
+
+        self.wgs_path = document.path;
+
+        self.load_wgs(&wgs, document.metadata);
+
+        self.runtime.load(wgs)
+    }
+
+    fn load_wgs(&mut self, wgs: &WgsData, metadata: Metadata) {
         self.ui.reset_textures();
 
         for texture in wgs.textures_ref() {
@@ -382,38 +1090,172 @@ impl Core {
                 .add_texture(texture.width, texture.height, &texture.data);
         }
 
+        self.ui.set_thumbnail(metadata.thumbnail.as_ref());
+
         self.ui_edit_context.frag = wgs.frag();
+        self.ui_edit_context.metadata = metadata;
         self.ui_edit_context.name = wgs.name();
     }
 
+    // Stashes the active tab and opens a new one with an empty history,
+    // leaving it to the caller to load the new document.
+    fn new_tab(&mut self, frag: &str) {
+        let stash = self.stash();
+        self.tabs[self.active_tab].stash = Some(stash);
+
+        let id = self.next_tab_id;
+        self.next_tab_id += 1;
+
+        self.tabs.push(Tab { id, stash: None });
+        self.active_tab = self.tabs.len() - 1;
+
+        self.ui.set_tab(id);
+        self.history = History::new(frag);
+
+        self.runtime.resume();
+    }
+
+    // Opens a document in a new tab, or in place of the active one while it's
+    // a new document left untouched.
+    fn open_document(
+        &mut self,
+        wgs: WgsData,
+        metadata: Metadata,
+        path: Option<PathBuf>,
+    ) -> Result<()> {
+        let untouched =
+            self.wgs_path.is_none() && !self.history.can_undo() && !self.history.can_redo();
+
+        let before = if untouched {
+            Some(self.document())
+        } else {
+            self.new_tab(&wgs.frag());
+
+            None
+        };
+
+        self.snapshots = Snapshots::load(path.as_deref());
+        self.wgs_path = path;
+
+        self.load_wgs(&wgs, metadata);
+
+        let result = self.runtime.load(wgs);
+
+        if let Some(before) = before {
+            self.record_document(before);
+        }
+
+        self.history.mark_saved();
+
+        result
+    }
+
+    // Records that the document was replaced, `before` is what it was.
+    fn record_document(&mut self, before: Document) {
+        self.history.record(Change::Document {
+            before: Box::new(before),
+            after: Box::new(self.document()),
+        });
+    }
+
+    // Opens the tabs of the last session, those that still open.
+    fn restore_session(&mut self) {
+        let session = match Session::load() {
+            Ok(session) => session,
+            Err(err) => {
+                log::warn!("Failed to load session: {}", err);
+
+                return;
+            }
+        };
+
+        let mut active = 0;
+
+        for (index, path) in session.paths.iter().enumerate() {
+            match load_path(path) {
+                Ok((wgs, metadata)) => {
+                    if let Err(err) = self.open_document(wgs, metadata, Some(path.clone())) {
+                        self.change_status(AppStatus::Error(err.to_string()));
+                    }
+
+                    if index == session.active {
+                        active = self.active_tab;
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Failed to restore {:?}: {}", path, err);
+                }
+            }
+        }
+
+        if let Some(Err(err)) = self.select_tab(active) {
+            self.change_status(AppStatus::Error(err.to_string()));
+        }
+    }
+
     fn render(&mut self, window: &Window) -> Result<()> {
         self.runtime.frame_start()?;
 
         let half_width = self.size.0 / 2.0;
 
-        if !self.has_validation_error {
-            let viewport = Viewport {
-                x: half_width,
-                width: half_width,
-                height: self.size.1,
-                ..Default::default()
-            };
-            self.runtime.set_viewport(Some(viewport));
+        let preview = Viewport {
+            x: half_width,
+            width: half_width,
+            height: self.size.1,
+            ..Default::default()
+        };
+
+        // While comparing, both versions are drawn with the UI instead.
+        if let Some(comparison) = &mut self.comparison {
+            comparison.set_frag(&self.ui_edit_context.frag);
+        } else if !self.has_validation_error {
+            self.runtime.set_viewport(Some(preview.clone()));
 
             self.runtime.render()?;
         }
 
         {
+            let tabs = self
+                .tabs
+                .iter()
+                .map(|tab| match &tab.stash {
+                    Some(stash) => TabState {
+                        is_modified: stash.history.is_modified(),
+                        name: stash.document.name.clone(),
+                    },
+                    None => TabState {
+                        is_modified: self.history.is_modified(),
+                        name: self.ui_edit_context.name.clone(),
+                    },
+                })
+                .collect();
+
             let ui_state = UiState {
+                active_tab: self.active_tab,
                 can_capture: self.runtime.is_capture_supported(),
+                can_redo: self.history.can_redo(),
+                can_undo: self.history.can_undo(),
+                compare: self.comparison.as_ref().map(|comparison| CompareState {
+                    frag: comparison.old_frag().to_owned(),
+                    frame_times: comparison.frame_times(),
+                    is_timed: comparison.is_timed(),
+                    name: comparison.name().to_owned(),
+                }),
                 file_saved: self.wgs_path.is_some(),
                 fps: self.fps,
                 is_paused: self.runtime.is_paused(),
+                snapshots: self.snapshots.as_slice(),
                 status: self.status.clone(),
+                tabs,
                 texture_addable: self.runtime.wgs().textures_ref().len() + 1
                     < self.runtime.max_texture_count() as usize,
             };
 
+            // The UI scale on top of the OS's.
+            let pixels_per_point = self.scale_factor * self.preferences.ui_scale;
+
+            self.state.set_pixels_per_point(pixels_per_point);
+
             let raw_input = self.state.take_egui_input(window);
 
             let full_output = self.ui.prepare(
@@ -424,6 +1266,13 @@ impl Core {
                 ui_state,
             );
 
+            if mem::take(&mut self.ui_edit_context.separate_step) {
+                self.history.record_step(&self.ui_edit_context.frag);
+            } else {
+                self.history
+                    .record_source(&self.ui_edit_context.frag, Instant::now());
+            }
+
             self.state.handle_platform_output(
                 window,
                 self.ui.context(),
@@ -441,10 +1290,23 @@ impl Core {
 
             let screen_descriptor = ScreenDescriptor {
                 size_in_pixels: [viewport.width as u32, viewport.height as u32],
-                pixels_per_point: window.scale_factor() as f32,
+                pixels_per_point,
             };
 
+            let is_paused = self.runtime.is_paused();
+
             self.runtime.render_with(|device, queue, view| {
+                if let Some(comparison) = &mut self.comparison {
+                    comparison.render(
+                        device,
+                        queue,
+                        view,
+                        preview,
+                        &self.preferences.compare,
+                        is_paused,
+                    );
+                }
+
                 for (id, delta) in &full_output.textures_delta.set {
                     self.ui_renderer.update_texture(device, queue, *id, &delta);
                 }
@@ -515,26 +1377,12 @@ impl Core {
     }
 
     fn save_file_impl(&mut self, save_as: bool) -> Option<String> {
-        self.runtime.set_wgs_frag(&self.ui_edit_context.frag);
         self.runtime.set_wgs_name(&self.ui_edit_context.name);
 
-        let wgs = self.runtime.wgs();
-
-        if save_as {
-            // Save as.
-            if let Some(path) = create_file(&format!(
-                "{}.{}",
-                wgs.name().to_ascii_lowercase().replace(" ", "_"),
-                wgs_core::EXTENSION
-            )) {
-                self.wgs_path = Some(path);
-            // Early return when cancelled.
-            } else {
-                return None;
-            }
-        // Never been saved before.
-        } else if self.wgs_path.is_none() {
-            self.wgs_path = create_file(&format!(
+        let path = match &self.wgs_path {
+            Some(path) if !save_as => path.clone(),
+            // Save as, or never been saved before. Early return when cancelled.
+            _ => create_file(&format!(
                 "{}.{}",
                 self.runtime
                     .wgs()
@@ -542,76 +1390,191 @@ impl Core {
                     .to_ascii_lowercase()
                     .replace(" ", "_"),
                 wgs_core::EXTENSION
-            ));
-        }
+            ))?,
+        };
 
-        if self.wgs_path.is_some() {
-            save_wgs(&self.wgs_path.as_ref().unwrap(), &wgs);
+        self.write_document(path)
+    }
 
-            self.change_status(AppStatus::Info(fl!("status_save_ok")));
+    fn save_project_as(&mut self) -> Option<String> {
+        self.runtime.set_wgs_name(&self.ui_edit_context.name);
 
-            Some(self.format_title())
-        } else {
-            None
+        // Early return when cancelled.
+        let dir = select_folder()?.join(
+            self.runtime
+                .wgs()
+                .name()
+                .to_ascii_lowercase()
+                .replace(" ", "_"),
+        );
+
+        if let Err(err) = create_dir_all(&dir) {
+            log::error!("{}", format!("Failed to create directory: {}", err));
+
+            self.change_status(AppStatus::Error(format!(
+                "{}: {}",
+                fl!("status_err_save"),
+                err
+            )));
+
+            return None;
         }
+
+        self.write_document(dir)
     }
-}
 
-fn load_wgs_from_buffer(buffer: &[u8]) -> io::Result<WgsData> {
-    let mut reader = Cursor::new(&buffer);
+    fn select_tab(&mut self, index: usize) -> Option<Result<()>> {
+        if index == self.active_tab || index >= self.tabs.len() {
+            return None;
+        }
 
-    Ok(WgsData::load(&mut reader).unwrap())
-}
+        let stash = self.stash();
+        self.tabs[self.active_tab].stash = Some(stash);
 
-fn load_wgs_from_file<P>(path: P) -> io::Result<WgsData>
-where
-    P: AsRef<Path>,
-{
-    let buffer = read(&path)?;
+        self.active_tab = index;
 
-    load_wgs_from_buffer(&buffer)
-}
+        let stash = self.tabs[index].stash.take()?;
 
-fn on_image_captured(width: u32, height: u32, buffer: Vec<u8>, filename: &str) {
-    if let Some(path) = create_file(filename) {
-        match image::save_buffer(&path, &buffer, width, height, ColorType::Rgba8) {
-            Ok(()) => log::info!("Saving image file: {:?}", path),
-            Err(err) => log::error!("Failed to save image: {}", err),
+        Some(self.unstash(stash))
+    }
+
+    // Takes the active tab's document out of the editor and the runtime.
+    fn stash(&mut self) -> Stash {
+        Stash {
+            document: self.document(),
+            history: mem::replace(&mut self.history, History::new("")),
+            is_paused: self.runtime.is_paused(),
+            snapshots: mem::take(&mut self.snapshots),
         }
     }
-}
 
-fn open_image<P>(path: P) -> Result<(u32, u32, Vec<u8>)>
-where
-    P: AsRef<Path>,
-{
-    let image = image::open(path)?;
+    // Records a snapshot of the document, with a thumbnail of the preview
+    // once captured.
+    fn take_snapshot(&mut self, label: Option<String>) {
+        let textures = self.textures();
 
-    let image = image.into_rgba8();
+        let Some(id) = self
+            .snapshots
+            .record(label, &self.ui_edit_context.frag, textures)
+        else {
+            return;
+        };
 
-    let width = image.width();
-    if width > 2048 {
-        bail!("Width larger than 2048");
+        // The runtime isn't drawing while comparing.
+        if self.comparison.is_some() {
+            return;
+        }
+
+        let viewport = self.capture_viewport();
+
+        let event_proxy = self.event_proxy.clone();
+
+        self.runtime
+            .request_capture_image(&viewport, move |_runtime, width, height, buffer| {
+                if let Some(thumbnail) = Thumbnail::from_capture(width, height, buffer) {
+                    event_proxy.send_event(UserEvent::SnapshotThumbnailCaptured(id, thumbnail));
+                }
+            });
     }
 
-    let height = image.height();
-    if height > 2048 {
-        bail!("Height larger than 2048");
+    fn texture(&self, index: usize) -> Option<Texture> {
+        self.runtime
+            .wgs()
+            .textures_ref()
+            .get(index)
+            .map(|texture| Texture {
+                width: texture.width,
+                height: texture.height,
+                data: texture.data.clone(),
+            })
     }
 
-    let data = image.into_vec();
+    fn textures(&self) -> Vec<Texture> {
+        (0..self.runtime.wgs().textures_ref().len())
+            .filter_map(|index| self.texture(index))
+            .collect()
+    }
 
-    Ok((width, height, data))
-}
+    // Puts a tab's document back into the editor and the runtime, the tab
+    // being the active one.
+    fn unstash(&mut self, stash: Stash) -> Result<()> {
+        self.ui.set_tab(self.tabs[self.active_tab].id);
+        self.history = stash.history;
+
+        let result = self.load_document(stash.document);
+
+        self.snapshots = stash.snapshots;
+
+        // Loading restarts the runtime's clock, which it has no way to set,
+        // so the shader's time starts over while playing or paused alike.
+        if stash.is_paused {
+            self.runtime.pause();
+        } else {
+            self.runtime.resume();
+        }
+
+        result
+    }
+
+    // Saves the document to `path`, which it is then saved to from now on.
+    fn write_document(&mut self, path: PathBuf) -> Option<String> {
+        // Only once saving wasn't cancelled.
+        self.format_on_save();
+
+        self.runtime.set_wgs_frag(&self.ui_edit_context.frag);
+
+        let wgs = self.runtime.wgs();
+
+        let metadata = &mut self.ui_edit_context.metadata;
+        metadata.touch();
 
-fn save_wgs<P>(path: P, wgs: &WgsData)
-where
-    P: AsRef<Path>,
-{
-    let mut writer = Cursor::new(vec![]);
-    wgs.save(&mut writer).unwrap();
+        // A document opened from, or saved as, a project directory stays one.
+        let result = if path.is_dir() {
+            save_project(&path, wgs, metadata)
+        } else {
+            save_wgs(&path, wgs, metadata).map_err(Into::into)
+        };
+
+        match result {
+            Ok(()) => {
+                self.history.mark_saved();
+                self.snapshots.set_document(&path);
+
+                self.wgs_path = Some(path);
 
-    write_file(&path, writer.into_inner());
+                self.change_status(AppStatus::Info(fl!("status_save_ok")));
 
-    log::info!("Saving wgs file: {:?}", path.as_ref());
+                Some(self.format_title())
+            }
+            Err(err) => {
+                log::error!("{}", format!("Failed to save project: {}", err));
+
+                self.change_status(AppStatus::Error(format!(
+                    "{}: {}",
+                    fl!("status_err_save"),
+                    err
+                )));
+
+                None
+            }
+        }
+    }
+}
+
+// Loads a `wgs` file, or a project directory.
+fn load_path(path: &Path) -> Result<(WgsData, Metadata)> {
+    if path.is_dir() {
+        load_project(path)
+    } else {
+        Ok(load_wgs_from_file(path)?)
+    }
+}
+
+fn on_image_captured(width: u32, height: u32, buffer: Vec<u8>, filename: &str) {
+    if let Some(path) = create_file(filename) {
+        match image::save_buffer(&path, &buffer, width, height, ColorType::Rgba8) {
+            Ok(()) => log::info!("Saving image file: {:?}", path),
+            Err(err) => log::error!("Failed to save image: {}", err),
+        }
+    }
 }
@@ -0,0 +1,112 @@
+// A line of a diff between an old and a new text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiffLine<'a> {
+    Added(&'a str),
+    Removed(&'a str),
+    Unchanged(&'a str),
+}
+
+// Diffs two texts line by line, keeping the longest run of common lines.
+// Removed lines come before the lines added in their place.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Only the part between the common head and tail needs the table.
+    let head = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let tail = old[head..]
+        .iter()
+        .rev()
+        .zip(new[head..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let old_middle = &old[head..old.len() - tail];
+    let new_middle = &new[head..new.len() - tail];
+
+    // `common[i][j]` is how many lines `old_middle[i..]` and `new_middle[j..]`
+    // have in common.
+    let width = new_middle.len() + 1;
+    let mut common = vec![0u32; (old_middle.len() + 1) * width];
+
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            common[i * width + j] = if old_middle[i] == new_middle[j] {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut lines: Vec<DiffLine> = old[..head]
+        .iter()
+        .map(|line| DiffLine::Unchanged(line))
+        .collect();
+
+    let (mut i, mut j) = (0, 0);
+
+    while i < old_middle.len() || j < new_middle.len() {
+        if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+            lines.push(DiffLine::Unchanged(old_middle[i]));
+
+            i += 1;
+            j += 1;
+        } else if j == new_middle.len()
+            || (i < old_middle.len() && common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            lines.push(DiffLine::Removed(old_middle[i]));
+
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new_middle[j]));
+
+            j += 1;
+        }
+    }
+
+    lines.extend(
+        old[old.len() - tail..]
+            .iter()
+            .map(|line| DiffLine::Unchanged(line)),
+    );
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_lines, DiffLine::*};
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            diff_lines("a\nb", "a\nb"),
+            vec![Unchanged("a"), Unchanged("b")]
+        );
+
+        assert_eq!(
+            diff_lines("a\nb\nc\nd", "a\nx\nc\nd\ne"),
+            vec![
+                Unchanged("a"),
+                Removed("b"),
+                Added("x"),
+                Unchanged("c"),
+                Unchanged("d"),
+                Added("e"),
+            ]
+        );
+
+        assert_eq!(
+            diff_lines("a\nb\nc", "c\na"),
+            vec![Removed("a"), Removed("b"), Unchanged("c"), Added("a")]
+        );
+
+        assert_eq!(diff_lines("", "a"), vec![Added("a")]);
+        assert_eq!(diff_lines("a", ""), vec![Removed("a")]);
+    }
+}
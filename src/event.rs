@@ -1,4 +1,4 @@
-use crate::example::Example;
+use crate::{compare::CompareWith, example::Example, metadata::Thumbnail};
 use winit::event_loop::EventLoopProxy;
 
 #[derive(Clone, Debug)]
@@ -12,20 +12,40 @@ pub enum AppStatus {
 #[derive(Debug)]
 pub enum UserEvent {
     CaptureImage,
+    CaptureThumbnail,
     ChangeTexture(usize),
+    CloseTab(usize),
+    Compare(CompareWith),
+    ConvertProjectToWgs,
+    ConvertWgsToProject,
+    ExportRustProject,
+    ExportWgsl { with_prelude: bool },
+    ImportColorScheme,
+    ImportWgsl,
     NewFile,
     OpenAbout,
     OpenExample(Example),
     OpenFile,
+    OpenProject,
     OpenTexture,
     Pause,
     Quit,
+    Redo,
+    RemoveSnapshot(i64),
     RemoveTexture(usize),
     RequestRedraw,
     Restart,
+    RestoreSnapshot(i64),
     Resume,
     SaveFile,
     SaveFileAs,
+    SaveProjectAs,
+    SelectTab(usize),
+    SnapshotThumbnailCaptured(i64, Thumbnail),
+    StopComparing,
+    TakeSnapshot(String),
+    ThumbnailCaptured(Thumbnail),
+    Undo,
 }
 
 pub trait EventProxy<T> {
@@ -44,6 +64,14 @@ pub struct EventProxyWinit<T: 'static> {
     inner: EventLoopProxy<T>,
 }
 
+impl<T> Clone for EventProxyWinit<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<T> EventProxy<T> for EventProxyWinit<T> {
     fn send_event(&self, event: T) {
         self.inner.send_event(event);
@@ -0,0 +1,151 @@
+use crate::shader::concat_shader;
+use anyhow::{bail, Result};
+use image::ColorType;
+use std::{
+    fs::{create_dir_all, read_dir, write},
+    path::Path,
+};
+use wgs_core::WgsData;
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("export/Cargo.toml.in");
+const MAIN_RS_TEMPLATE: &str = include_str!("export/main.rs.in");
+
+// Writes a Cargo project which renders the shader in its own window.
+//
+// Layout of the exported directory:
+//
+// - Cargo.toml
+// - shader.wgsl: the complete shader module, prelude included.
+// - src/main.rs: a winit + wgpu host replicating the runtime's bindings.
+// - textures/channel{n}.png: one image per texture, embedded at build time.
+//
+// Refuses a directory that isn't empty, rather than overwrite what's in it.
+pub fn export_rust_project<P>(dir: P, wgs: &WgsData) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    let dir = dir.as_ref();
+
+    if read_dir(dir).is_ok_and(|mut entries| entries.next().is_some()) {
+        bail!("{} is not empty", dir.display());
+    }
+
+    create_dir_all(dir.join("src"))?;
+
+    let name = wgs.name();
+    let textures = wgs.textures_ref();
+
+    let cargo_toml = CARGO_TOML_TEMPLATE.replace("{{name}}", &package_name(&name));
+    write(dir.join("Cargo.toml"), cargo_toml)?;
+
+    write(
+        dir.join("shader.wgsl"),
+        concat_shader(&wgs.frag(), textures.len()),
+    )?;
+
+    if !textures.is_empty() {
+        create_dir_all(dir.join("textures"))?;
+    }
+
+    for (index, texture) in textures.iter().enumerate() {
+        image::save_buffer(
+            dir.join("textures").join(texture_filename(index)),
+            &texture.data,
+            texture.width,
+            texture.height,
+            ColorType::Rgba8,
+        )?;
+    }
+
+    write(
+        dir.join("src").join("main.rs"),
+        main_rs(&name, textures.len()),
+    )?;
+
+    log::info!("Exporting Rust project: {:?}", dir);
+
+    Ok(())
+}
+
+// The host's source, embedding `texture_count` textures.
+fn main_rs(name: &str, texture_count: usize) -> String {
+    let texture_includes: Vec<String> = (0..texture_count)
+        .map(|index| {
+            format!(
+                "include_bytes!(\"../textures/{}\")",
+                texture_filename(index)
+            )
+        })
+        .collect();
+
+    MAIN_RS_TEMPLATE
+        .replace("{{title}}", &format!("{:?}", name))
+        .replace("{{textures}}", &texture_includes.join(", "))
+}
+
+fn texture_filename(index: usize) -> String {
+    format!("channel{}.png", index)
+}
+
+// Cargo package names may only contain alphanumerics, `-` and `_`,
+// and must not start with a digit. Also names the exported directory.
+pub fn package_name(name: &str) -> String {
+    let mut package_name: String = name
+        .trim()
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if package_name.is_empty() || package_name.starts_with(|c: char| c.is_ascii_digit()) {
+        package_name.insert_str(0, "shader_");
+    }
+
+    package_name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_to_string, remove_dir_all};
+
+    #[test]
+    fn exported_project() {
+        let dir = std::env::temp_dir().join(format!("wgshadertoy-export-{}", std::process::id()));
+        let _ = remove_dir_all(&dir);
+
+        let mut wgs = WgsData::new("My Shader", "fn main_image() {}");
+        wgs.add_texture(1, 1, vec![255, 0, 0, 255]);
+        wgs.add_texture(1, 1, vec![0, 255, 0, 255]);
+
+        export_rust_project(&dir, &wgs).unwrap();
+
+        let cargo_toml = read_to_string(dir.join("Cargo.toml")).unwrap();
+        let main_rs = read_to_string(dir.join("src").join("main.rs")).unwrap();
+        let shader = read_to_string(dir.join("shader.wgsl")).unwrap();
+
+        assert!(cargo_toml.contains("name = \"my_shader\""));
+        assert!(main_rs.contains("const TITLE: &str = \"My Shader\";"));
+        assert!(main_rs.contains(
+            "&[include_bytes!(\"../textures/channel0.png\"), \
+             include_bytes!(\"../textures/channel1.png\")]"
+        ));
+        assert!(main_rs.contains("let bind_groups = 1 + TEXTURES.len() as u32;"));
+        assert!(!main_rs.contains("{{") && !cargo_toml.contains("{{"));
+        assert_eq!(shader, concat_shader("fn main_image() {}", 2));
+        assert!(shader.contains("@group(2)") && !shader.contains("@group(3)"));
+        assert!(dir.join("textures").join("channel1.png").is_file());
+
+        // Not over the project just exported.
+        assert!(export_rust_project(&dir, &wgs).is_err());
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn package_names() {
+        assert_eq!(package_name("My Shader"), "my_shader");
+        assert_eq!(package_name(""), "shader_");
+        assert_eq!(package_name("../2d"), "___2d");
+    }
+}
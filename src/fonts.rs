@@ -4,6 +4,9 @@ use font_kit::{
 };
 use std::fs::read;
 
+// The family of the editor's font, falling back to egui's monospace fonts.
+pub const EDITOR_FAMILY: &str = "editor";
+
 // Try to load suitable fonts for different scripts.
 //
 // We have pre-listed some popular fonts, instead of
@@ -128,6 +131,17 @@ const FONTS_MAP: [(&str, &[&str]); 4] = [
     ),
 ];
 
+// Fonts made for code with programming ligatures, which egui draws without
+// them. Variants such as "Iosevka Term" share the prefix.
+const LIGATURE_FONTS: [&str; 6] = [
+    "Cascadia Code",
+    "Fira Code",
+    "Hasklig",
+    "Iosevka",
+    "JetBrains Mono",
+    "Victor Mono",
+];
+
 pub fn load_font(fonts: &mut FontDefinitions, font_name: &str, font_data: FontData) {
     let font_name = font_name.to_owned();
 
@@ -165,22 +179,12 @@ pub fn load_system_font(font_def: &mut FontDefinitions) {
                 break;
             }
 
-            if let Ok(handle) = source.select_best_match(
-                &[FamilyName::Title(font_name.to_string())],
-                &Properties::new(),
-            ) {
-                let buf = match handle {
-                    Handle::Memory { bytes, .. } => Some(bytes.to_vec()),
-                    Handle::Path { path, .. } => read(path).ok(),
-                };
+            if let Some(buf) = read_family(&source, font_name) {
+                load_font(font_def, font_name, FontData::from_owned(buf));
 
-                if let Some(buf) = buf {
-                    load_font(font_def, font_name, FontData::from_owned(buf.to_vec()));
-
-                    script_font_loaded = true;
+                script_font_loaded = true;
 
-                    break;
-                }
+                break;
             }
         }
 
@@ -189,3 +193,62 @@ pub fn load_system_font(font_def: &mut FontDefinitions) {
         }
     }
 }
+
+// Puts a system font first in the editor's family, egui's monospace fonts
+// following for the characters it lacks.
+pub fn set_editor_font(font_def: &mut FontDefinitions, family: Option<&str>) {
+    let mut names = font_def
+        .families
+        .get(&FontFamily::Monospace)
+        .cloned()
+        .unwrap_or_default();
+
+    if let Some(family) = family {
+        let loaded = font_def.font_data.contains_key(family)
+            || match read_family(&SystemSource::new(), family) {
+                Some(buf) => {
+                    font_def
+                        .font_data
+                        .insert(family.to_owned(), FontData::from_owned(buf));
+
+                    true
+                }
+                None => false,
+            };
+
+        if loaded {
+            names.insert(0, family.to_owned());
+        } else {
+            log::warn!("Failed to load font: {}", family);
+        }
+    }
+
+    font_def
+        .families
+        .insert(FontFamily::Name(EDITOR_FAMILY.into()), names);
+}
+
+pub fn has_ligatures(family: &str) -> bool {
+    LIGATURE_FONTS.iter().any(|name| family.starts_with(name))
+}
+
+// The installed families, sorted.
+pub fn system_families() -> Vec<String> {
+    let mut families = SystemSource::new().all_families().unwrap_or_default();
+
+    families.sort();
+    families.dedup();
+
+    families
+}
+
+fn read_family(source: &SystemSource, family: &str) -> Option<Vec<u8>> {
+    let handle = source
+        .select_best_match(&[FamilyName::Title(family.to_owned())], &Properties::new())
+        .ok()?;
+
+    match handle {
+        Handle::Memory { bytes, .. } => Some(bytes.to_vec()),
+        Handle::Path { path, .. } => read(path).ok(),
+    }
+}
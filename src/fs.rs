@@ -1,8 +1,34 @@
-use rfd::FileDialog;
+use crate::metadata::Metadata;
+use anyhow::{bail, Result};
+use rfd::{FileDialog, MessageButtons, MessageDialog, MessageLevel};
 use std::{
-    fs::write,
+    fs::{read, write},
+    io::{self, Cursor},
     path::{Path, PathBuf},
 };
+use wgs_core::WgsData;
+
+pub const WGSL_EXTENSION: &str = "wgsl";
+
+// Where the app keeps its settings.
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("wgshadertoy"))
+}
+
+// Where the app keeps what it records, e.g. snapshots.
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join("wgshadertoy"))
+}
+
+// Asks before discarding unsaved changes, returns whether to go on.
+pub fn confirm_discard(title: &str, description: &str) -> bool {
+    MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(title)
+        .set_description(description)
+        .set_buttons(MessageButtons::YesNo)
+        .show()
+}
 
 pub fn create_file(filename: &str) -> Option<PathBuf> {
     FileDialog::new()
@@ -12,6 +38,90 @@ pub fn create_file(filename: &str) -> Option<PathBuf> {
         .save_file()
 }
 
+pub fn create_wgsl_file(filename: &str) -> Option<PathBuf> {
+    FileDialog::new()
+        .set_directory("~")
+        .add_filter("WGSL", &[WGSL_EXTENSION])
+        .set_file_name(filename)
+        .save_file()
+}
+
+pub fn encode_wgs(wgs: &WgsData, metadata: &Metadata) -> Vec<u8> {
+    let mut writer = Cursor::new(vec![]);
+    wgs.save(&mut writer).unwrap();
+
+    if let Err(err) = metadata.write(&mut writer) {
+        log::warn!("Failed to write metadata: {}", err);
+    }
+
+    writer.into_inner()
+}
+
+pub fn load_wgs_from_buffer(buffer: &[u8]) -> io::Result<(WgsData, Metadata)> {
+    let mut reader = Cursor::new(&buffer);
+
+    let wgs = WgsData::load(&mut reader)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    // Right after the `wgs` data, which is read up to its end only.
+    let metadata = Metadata::read(&mut reader).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Corrupt metadata: {}", err),
+        )
+    })?;
+
+    Ok((wgs, metadata))
+}
+
+pub fn load_wgs_from_file<P>(path: P) -> io::Result<(WgsData, Metadata)>
+where
+    P: AsRef<Path>,
+{
+    let buffer = read(&path)?;
+
+    load_wgs_from_buffer(&buffer)
+}
+
+pub fn open_image<P>(path: P) -> Result<(u32, u32, Vec<u8>)>
+where
+    P: AsRef<Path>,
+{
+    let image = image::open(path)?;
+
+    let image = image.into_rgba8();
+
+    let width = image.width();
+    if width > 2048 {
+        bail!("Width larger than 2048");
+    }
+
+    let height = image.height();
+    if height > 2048 {
+        bail!("Height larger than 2048");
+    }
+
+    let data = image.into_vec();
+
+    Ok((width, height, data))
+}
+
+pub fn save_wgs<P>(path: P, wgs: &WgsData, metadata: &Metadata) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    log::info!("Saving wgs file: {:?}", path.as_ref());
+
+    write_file(&path, encode_wgs(wgs, metadata))
+}
+
+// Our own schemes, or VS Code and TextMate themes.
+pub fn select_color_scheme_file() -> Option<PathBuf> {
+    FileDialog::new()
+        .set_directory("~")
+        .add_filter("Color Schemes", &["json", "toml"])
+        .pick_file()
+}
+
 pub fn select_file() -> Option<PathBuf> {
     FileDialog::new()
         .set_directory("~")
@@ -19,6 +129,10 @@ pub fn select_file() -> Option<PathBuf> {
         .pick_file()
 }
 
+pub fn select_folder() -> Option<PathBuf> {
+    FileDialog::new().set_directory("~").pick_folder()
+}
+
 pub fn select_texture() -> Option<PathBuf> {
     FileDialog::new()
         .set_directory("~")
@@ -26,15 +140,97 @@ pub fn select_texture() -> Option<PathBuf> {
         .pick_file()
 }
 
-pub fn write_file<P, C>(path: P, contents: C)
+pub fn select_wgsl_file() -> Option<PathBuf> {
+    FileDialog::new()
+        .set_directory("~")
+        .add_filter("WGSL", &[WGSL_EXTENSION])
+        .pick_file()
+}
+
+pub fn write_file<P, C>(path: P, contents: C) -> io::Result<()>
 where
     P: AsRef<Path>,
     C: AsRef<[u8]>,
 {
-    match write(path, contents) {
-        Ok(_) => {}
-        Err(err) => {
-            log::warn!("{}", format!("Failed to write file: {}", err));
+    write(path, contents).map_err(|err| {
+        log::warn!("Failed to write file: {}", err);
+
+        err
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wgs_with_metadata() {
+        let mut wgs = WgsData::new("Rings", "fn main_image() {}");
+        wgs.add_texture(1, 1, vec![255, 0, 0, 255]);
+
+        let metadata = Metadata {
+            author: "Someone".to_owned(),
+            tags: vec!["rings".to_owned()],
+            ..Default::default()
+        };
+
+        let (loaded, loaded_metadata) = load_wgs_from_buffer(&encode_wgs(&wgs, &metadata)).unwrap();
+
+        assert_eq!(loaded.name(), "Rings");
+        assert_eq!(loaded.frag(), "fn main_image() {}");
+        assert_eq!(loaded.textures_ref()[0].data, [255, 0, 0, 255]);
+        assert_eq!(loaded_metadata.author, "Someone");
+        assert_eq!(loaded_metadata.tags, ["rings"]);
+    }
+
+    #[test]
+    fn truncated_wgs() {
+        let buffer = encode_wgs(
+            &WgsData::new("Rings", "fn main_image() {}"),
+            &Metadata::default(),
+        );
+
+        assert!(load_wgs_from_buffer(&buffer[..8]).is_err());
+    }
+
+    #[test]
+    fn wgs_without_metadata() {
+        let wgs = WgsData::new("Rings", "fn main_image() {}");
+
+        let mut writer = Cursor::new(vec![]);
+        wgs.save(&mut writer).unwrap();
+
+        let (_, metadata) = load_wgs_from_buffer(&writer.into_inner()).unwrap();
+
+        assert!(metadata.author.is_empty() && metadata.created.is_none());
+    }
+
+    #[test]
+    fn corrupt_metadata() {
+        let wgs = WgsData::new("Rings", "fn main_image() {}");
+        let metadata = Metadata {
+            author: "Someone".to_owned(),
+            ..Default::default()
+        };
+
+        let buffer = encode_wgs(&wgs, &metadata);
+
+        // The trailer cut short, its JSON broken and its magic wrong.
+        let mut broken_json = buffer.clone();
+        let brace = broken_json.iter().rposition(|&byte| byte == b'}').unwrap();
+        broken_json[brace] = b']';
+
+        let mut wrong_magic = buffer.clone();
+        let magic = wrong_magic
+            .windows(8)
+            .position(|bytes| bytes == b"WGSMETA\0")
+            .unwrap();
+        wrong_magic[magic] = b'X';
+
+        for buffer in [&buffer[..buffer.len() - 2], &broken_json, &wrong_magic] {
+            let err = load_wgs_from_buffer(buffer).err().unwrap();
+
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
         }
     }
 }
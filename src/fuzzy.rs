@@ -0,0 +1,95 @@
+// Scores how well the characters of `pattern` appear in order in `text`,
+// ignoring case and the blanks of `pattern`. Matches at the start, at word
+// boundaries (after a character that isn't alphanumeric, or in camelCase) and
+// right after the previous match score higher, skipped characters lower, and
+// those of the same case a little higher. `None` if not all characters appear.
+//
+// Shared by the command palette and code completion, so both rank alike.
+pub fn fuzzy_score(pattern: &str, text: &str) -> Option<i32> {
+    let pattern: Vec<char> = pattern.chars().filter(|c| !c.is_whitespace()).collect();
+    let text: Vec<char> = text.chars().collect();
+
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    let bonus = |p: char, index: usize| {
+        let c = text[index];
+
+        if !c.to_lowercase().eq(p.to_lowercase()) {
+            return None;
+        }
+
+        let mut bonus = if c == p { 1 } else { 0 };
+
+        if index == 0 {
+            bonus += 8;
+        } else {
+            let previous = text[index - 1];
+
+            if !previous.is_alphanumeric() || (previous.is_lowercase() && c.is_uppercase()) {
+                bonus += 6;
+            }
+        }
+
+        Some(bonus)
+    };
+
+    // The best score of the pattern so far, with its last character matched
+    // at each index of the text.
+    let mut scores: Vec<Option<i32>> = (0..text.len())
+        .map(|index| bonus(pattern[0], index).map(|bonus| bonus - index as i32))
+        .collect();
+
+    for &p in &pattern[1..] {
+        scores = (0..text.len())
+            .map(|index| {
+                let bonus = bonus(p, index)?;
+
+                let best = (0..index)
+                    .filter_map(|last| {
+                        let gap = (index - last - 1) as i32;
+                        let adjacency = if gap == 0 { 4 } else { -gap };
+
+                        scores[last].map(|score| score + adjacency)
+                    })
+                    .max()?;
+
+                Some(best + bonus)
+            })
+            .collect();
+    }
+
+    scores.into_iter().flatten().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_score;
+
+    #[test]
+    fn score() {
+        assert_eq!(fuzzy_score("", "Save"), Some(0));
+        assert_eq!(fuzzy_score("sva", "Save"), None);
+        assert!(fuzzy_score("SAVE", "Save As").is_some());
+
+        // At word starts.
+        assert!(fuzzy_score("sa", "Save As") > fuzzy_score("sa", "Close Tab"));
+
+        // One after another.
+        assert!(fuzzy_score("sav", "Save") > fuzzy_score("sav", "Restart Preview"));
+
+        // After `_` and in camelCase.
+        assert!(fuzzy_score("tc", "texture_count") > fuzzy_score("tc", "extract"));
+        assert!(fuzzy_score("fC", "fragColor") > fuzzy_score("fC", "fract"));
+
+        // Skipping fewer characters.
+        assert!(fuzzy_score("sn", "sin") > fuzzy_score("sn", "saturation"));
+
+        // Spaces in the pattern are ignored.
+        assert_eq!(
+            fuzzy_score("save as", "Save As"),
+            fuzzy_score("saveas", "Save As")
+        );
+    }
+}
@@ -0,0 +1,468 @@
+use crate::metadata::Metadata;
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+// Typing pauses longer than this start a new undo step.
+const GROUP_TIMEOUT: Duration = Duration::from_secs(1);
+const MAX_UNDOS: usize = 100;
+
+#[derive(Clone, PartialEq)]
+pub struct Texture {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+// Everything a new or opened document replaces.
+#[derive(Clone)]
+pub struct Document {
+    pub frag: String,
+    pub metadata: Metadata,
+    pub name: String,
+    pub path: Option<PathBuf>,
+    pub textures: Vec<Texture>,
+}
+
+// Replaces `removed`, starting at the byte offset `start`, with `inserted`.
+#[derive(Clone)]
+pub struct SourceEdit {
+    pub start: usize,
+    pub removed: String,
+    pub inserted: String,
+}
+
+impl SourceEdit {
+    fn apply(&self, source: &mut String) {
+        source.replace_range(self.start..self.start + self.removed.len(), &self.inserted);
+    }
+
+    fn inverse(&self) -> Self {
+        Self {
+            start: self.start,
+            removed: self.inserted.clone(),
+            inserted: self.removed.clone(),
+        }
+    }
+
+    // Appends `next` to the edit when both are part of the same typing,
+    // returns whether it did.
+    fn merge(&mut self, next: &SourceEdit) -> bool {
+        let is_insertion = self.removed.is_empty() && next.removed.is_empty();
+        let is_deletion = self.inserted.is_empty() && next.inserted.is_empty();
+
+        if is_insertion && next.start == self.start + self.inserted.len() {
+            // Each line, and each word after a blank, is a step of its own.
+            let ends_blank = self.inserted.ends_with(char::is_whitespace);
+            let starts_blank = next.inserted.starts_with(char::is_whitespace);
+
+            if self.inserted.ends_with('\n') || (ends_blank && !starts_blank) {
+                return false;
+            }
+
+            self.inserted.push_str(&next.inserted);
+
+            return true;
+        }
+
+        if is_deletion && next.start + next.removed.len() == self.start {
+            // Backspace.
+            self.start = next.start;
+            self.removed.insert_str(0, &next.removed);
+
+            return true;
+        }
+
+        if is_deletion && next.start == self.start {
+            // Delete.
+            self.removed.push_str(&next.removed);
+
+            return true;
+        }
+
+        false
+    }
+}
+
+#[derive(Clone)]
+pub enum Change {
+    Document {
+        before: Box<Document>,
+        after: Box<Document>,
+    },
+    Source(SourceEdit),
+    // A texture added when there is nothing `before`, removed when there is
+    // nothing `after`.
+    Texture {
+        index: usize,
+        before: Option<Texture>,
+        after: Option<Texture>,
+    },
+}
+
+impl Change {
+    fn inverse(&self) -> Self {
+        match self {
+            Change::Document { before, after } => Change::Document {
+                before: after.clone(),
+                after: before.clone(),
+            },
+            Change::Source(edit) => Change::Source(edit.inverse()),
+            Change::Texture {
+                index,
+                before,
+                after,
+            } => Change::Texture {
+                index: *index,
+                before: after.clone(),
+                after: before.clone(),
+            },
+        }
+    }
+}
+
+// The undo history of the document, kept by the app rather than the editor
+// so that it survives loading and saving, and covers textures.
+//
+// Edits of the source are found by comparing it with the last source
+// recorded, see `record_source`.
+pub struct History {
+    // When the last edit of the source was recorded, to group typing.
+    last_edit: Option<Instant>,
+    redos: Vec<Change>,
+    // How many undos there were when the document was last saved, nothing
+    // when undoing or redoing can't get back there.
+    saved: Option<usize>,
+    source: String,
+    undos: Vec<Change>,
+}
+
+impl History {
+    pub fn new(source: &str) -> Self {
+        Self {
+            last_edit: None,
+            redos: vec![],
+            saved: Some(0),
+            source: source.to_owned(),
+            undos: vec![],
+        }
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    // Whether the document differs from when it was last saved.
+    pub fn is_modified(&self) -> bool {
+        self.saved != Some(self.undos.len())
+    }
+
+    pub fn mark_saved(&mut self) {
+        self.saved = Some(self.undos.len());
+    }
+
+    // Records a change of the document other than typing, a step of its own.
+    // A source edit is of the last source recorded.
+    pub fn record(&mut self, change: Change) {
+        match &change {
+            Change::Document { after, .. } => self.source = after.frag.clone(),
+            Change::Source(edit) => edit.apply(&mut self.source),
+            Change::Texture { .. } => {}
+        }
+
+        self.last_edit = None;
+
+        self.push(change);
+    }
+
+    // Records how `source` differs from the last source recorded, if it does,
+    // as a step of its own.
+    pub fn record_step(&mut self, source: &str) {
+        if source != self.source {
+            self.record(Change::Source(diff(&self.source, source)));
+        }
+    }
+
+    // Records how `source` differs from the last source recorded, if it does,
+    // as part of the last step while typing goes on.
+    pub fn record_source(&mut self, source: &str, now: Instant) {
+        if source == self.source {
+            return;
+        }
+
+        let edit = diff(&self.source, source);
+
+        self.source = source.to_owned();
+
+        let typing = self
+            .last_edit
+            .is_some_and(|last_edit| now.duration_since(last_edit) < GROUP_TIMEOUT);
+
+        self.last_edit = Some(now);
+
+        if let (true, Some(Change::Source(last))) = (typing, self.undos.last_mut()) {
+            if last.merge(&edit) {
+                self.redos.clear();
+
+                // The last step no longer ends where it was saved.
+                if self.saved == Some(self.undos.len()) {
+                    self.saved = None;
+                }
+
+                return;
+            }
+        }
+
+        self.push(Change::Source(edit));
+    }
+
+    // Takes back the last change, returns what to apply to the document.
+    pub fn undo(&mut self) -> Option<Change> {
+        let change = self.undos.pop()?.inverse();
+
+        Some(self.step(change, true))
+    }
+
+    // Applies again the last change undone, returns what to apply to the document.
+    pub fn redo(&mut self) -> Option<Change> {
+        let change = self.redos.pop()?;
+
+        Some(self.step(change, false))
+    }
+
+    // The source as of the last change recorded, undone or redone.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn push(&mut self, change: Change) {
+        // Changing the document after undoing past where it was saved.
+        if self.saved.is_some_and(|saved| saved > self.undos.len()) {
+            self.saved = None;
+        }
+
+        self.redos.clear();
+        self.undos.push(change);
+
+        if self.undos.len() > MAX_UNDOS {
+            self.undos.remove(0);
+
+            self.saved = self.saved.and_then(|saved| saved.checked_sub(1));
+        }
+    }
+
+    fn step(&mut self, change: Change, undo: bool) -> Change {
+        match &change {
+            Change::Document { after, .. } => self.source = after.frag.clone(),
+            Change::Source(edit) => edit.apply(&mut self.source),
+            Change::Texture { .. } => {}
+        }
+
+        // Typing after an undo or a redo starts a new step.
+        self.last_edit = None;
+
+        if undo {
+            self.redos.push(change.inverse());
+        } else {
+            self.undos.push(change.clone());
+        }
+
+        change
+    }
+}
+
+// The single edit turning `before` into `after`.
+fn diff(before: &str, after: &str) -> SourceEdit {
+    let prefix = before
+        .char_indices()
+        .zip(after.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(before.len().min(after.len()), |((index, _), _)| index);
+
+    let suffix = before[prefix..]
+        .chars()
+        .rev()
+        .zip(after[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+
+    SourceEdit {
+        start: prefix,
+        removed: before[prefix..before.len() - suffix].to_owned(),
+        inserted: after[prefix..after.len() - suffix].to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, History, SourceEdit};
+    use std::time::{Duration, Instant};
+
+    fn type_text(history: &mut History, source: &mut String, text: &str, now: &mut Instant) {
+        for c in text.chars() {
+            source.push(c);
+
+            history.record_source(source, *now);
+
+            *now += Duration::from_millis(100);
+        }
+    }
+
+    fn undo(history: &mut History) -> String {
+        history.undo();
+
+        history.source().to_owned()
+    }
+
+    #[test]
+    fn typing_groups() {
+        let mut now = Instant::now();
+        let mut source = String::new();
+        let mut history = History::new(&source);
+
+        type_text(&mut history, &mut source, "let a = 1;\nlet b", &mut now);
+
+        // A pause.
+        now += Duration::from_secs(2);
+        type_text(&mut history, &mut source, " = 2;", &mut now);
+
+        // Backspaces.
+        for _ in 0..3 {
+            source.pop();
+
+            history.record_source(&source, now);
+        }
+
+        // Each word and its blanks, each line, the words typed after the pause
+        // and the deletion are steps of their own.
+        for expected in [
+            "let a = 1;\nlet b = 2;",
+            "let a = 1;\nlet b = ",
+            "let a = 1;\nlet b ",
+            "let a = 1;\nlet b",
+            "let a = 1;\nlet ",
+            "let a = 1;\n",
+            "let a = ",
+            "let a ",
+            "let ",
+            "",
+        ] {
+            assert_eq!(undo(&mut history), expected);
+        }
+
+        assert!(!history.can_undo());
+
+        history.redo();
+        history.redo();
+
+        assert_eq!(history.source(), "let a ");
+    }
+
+    #[test]
+    fn document_changes() {
+        let mut history = History::new("a");
+
+        history.record_source("ab", Instant::now());
+        history.record(Change::Texture {
+            index: 0,
+            before: None,
+            after: None,
+        });
+
+        assert!(matches!(history.undo(), Some(Change::Texture { .. })));
+        assert_eq!(undo(&mut history), "a");
+
+        history.record_source("ac", Instant::now());
+
+        // Editing drops what was undone.
+        assert!(!history.can_redo());
+        assert_eq!(undo(&mut history), "a");
+    }
+
+    #[test]
+    fn recorded_source_edits() {
+        let mut now = Instant::now();
+        let mut source = String::new();
+        let mut history = History::new(&source);
+
+        type_text(&mut history, &mut source, "ab", &mut now);
+
+        // E.g. an import, right after typing.
+        history.record(Change::Source(SourceEdit {
+            start: 0,
+            removed: source.clone(),
+            inserted: "xyz".to_owned(),
+        }));
+        source = "xyz".to_owned();
+
+        assert_eq!(history.source(), "xyz");
+
+        type_text(&mut history, &mut source, "w", &mut now);
+
+        assert_eq!(undo(&mut history), "xyz");
+        assert_eq!(undo(&mut history), "ab");
+    }
+
+    #[test]
+    fn separate_steps() {
+        let mut now = Instant::now();
+        let mut source = String::new();
+        let mut history = History::new(&source);
+
+        type_text(&mut history, &mut source, "a a", &mut now);
+
+        // E.g. replacing every match, between keystrokes.
+        source = "b b".to_owned();
+        history.record_step(&source);
+
+        type_text(&mut history, &mut source, "c", &mut now);
+
+        assert_eq!(undo(&mut history), "b b");
+        assert_eq!(undo(&mut history), "a a");
+    }
+
+    #[test]
+    fn saved_state() {
+        let mut now = Instant::now();
+        let mut source = String::new();
+        let mut history = History::new(&source);
+
+        assert!(!history.is_modified());
+
+        type_text(&mut history, &mut source, "a b", &mut now);
+        history.mark_saved();
+
+        assert!(!history.is_modified());
+
+        // Typing on merges into the saved step.
+        type_text(&mut history, &mut source, "c", &mut now);
+
+        assert!(history.is_modified());
+
+        history.mark_saved();
+        history.undo();
+
+        assert!(history.is_modified());
+
+        history.redo();
+
+        assert!(!history.is_modified());
+
+        // Undoing past the save, then changing the document.
+        history.undo();
+        history.record_source("x", now + Duration::from_secs(2));
+        history.record(Change::Texture {
+            index: 0,
+            before: None,
+            after: None,
+        });
+
+        assert!(history.is_modified());
+    }
+}
@@ -0,0 +1,379 @@
+mod emacs;
+mod vim;
+
+pub use emacs::Emacs;
+pub use vim::{Vim, VimMode};
+
+use crate::{preferences::Keymap, wgsl::Selection};
+
+// A key pressed in the editor, as keymaps see it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyPress {
+    // A letter, a digit or space with Alt, letters in lowercase.
+    Alt(char),
+    Backspace,
+    // Typed text, one character at a time.
+    Char(char),
+    // A letter, a digit or space with Ctrl, letters in lowercase.
+    Ctrl(char),
+    Delete,
+    Down,
+    End,
+    Enter,
+    Escape,
+    Home,
+    Left,
+    Right,
+    Up,
+}
+
+// What a keymap asks the app to do.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeymapAction {
+    CloseTab,
+    Redo,
+    Save,
+    Undo,
+}
+
+// What a keymap did with a key.
+#[derive(Debug, Default, PartialEq)]
+pub struct Outcome {
+    // In the order they are to run.
+    pub actions: Vec<KeymapAction>,
+    // Whether the key was handled, or is left to the editor.
+    pub handled: bool,
+}
+
+impl Outcome {
+    fn handled() -> Self {
+        Self {
+            actions: vec![],
+            handled: true,
+        }
+    }
+
+    fn unhandled() -> Self {
+        Self::default()
+    }
+
+    fn with(actions: Vec<KeymapAction>) -> Self {
+        Self {
+            actions,
+            handled: true,
+        }
+    }
+}
+
+// The state of the keymap the editor uses.
+#[derive(Clone)]
+pub enum KeymapState {
+    Emacs(Emacs),
+    Vim(Vim),
+}
+
+impl KeymapState {
+    // Nothing for the default keymap, which the editor handles itself.
+    pub fn new(keymap: Keymap) -> Option<Self> {
+        match keymap {
+            Keymap::Default => None,
+            Keymap::Emacs => Some(Self::Emacs(Emacs::default())),
+            Keymap::Vim => Some(Self::Vim(Vim::default())),
+        }
+    }
+
+    pub fn handle(
+        &mut self,
+        key: KeyPress,
+        text: &mut String,
+        selection: &mut Selection,
+        indent: &str,
+    ) -> Outcome {
+        match self {
+            Self::Emacs(emacs) => emacs.handle(key, text, selection),
+            Self::Vim(vim) => vim.handle(key, text, selection, indent),
+        }
+    }
+
+    pub fn keymap(&self) -> Keymap {
+        match self {
+            Self::Emacs(_) => Keymap::Emacs,
+            Self::Vim(_) => Keymap::Vim,
+        }
+    }
+
+    // Takes a selection the editor made, e.g. with the mouse or by typing.
+    pub fn sync(&mut self, text: &str, selection: &mut Selection) {
+        match self {
+            Self::Emacs(emacs) => emacs.sync(selection),
+            Self::Vim(vim) => vim.sync(text, selection),
+        }
+    }
+
+    // The selection the editor shows, which differs from the keymap's own in
+    // Vim's visual modes.
+    pub fn visible_selection(&self, text: &str, selection: Selection) -> Selection {
+        match self {
+            Self::Emacs(_) => selection,
+            Self::Vim(vim) => vim.visible_selection(text, selection),
+        }
+    }
+}
+
+// The character class of a word, Vim's words being runs of one class.
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if is_word(c) {
+        1
+    } else {
+        2
+    }
+}
+
+// The column, in characters, of a byte offset.
+fn column(text: &str, offset: usize) -> usize {
+    text[line_start(text, offset)..offset].chars().count()
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// The offset of the line break after `offset`, or the end of the text.
+fn line_end(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .find('\n')
+        .map_or(text.len(), |index| offset + index)
+}
+
+// The offset after the line break before `offset`.
+fn line_start(text: &str, offset: usize) -> usize {
+    text[..offset].rfind('\n').map_or(0, |index| index + 1)
+}
+
+// The offset `lines` lines below, or above when negative, at the same column
+// where the line is long enough. Nothing when there's no such line.
+fn move_lines(text: &str, offset: usize, lines: isize, column: usize) -> Option<usize> {
+    let mut start = line_start(text, offset);
+
+    for _ in 0..lines.unsigned_abs() {
+        start = if lines > 0 {
+            let end = line_end(text, start);
+
+            (end < text.len()).then_some(end + 1)?
+        } else {
+            (start > 0).then(|| line_start(text, start - 1))?
+        };
+    }
+
+    Some(offset_at_column(text, start, column))
+}
+
+fn next_char(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .chars()
+        .next()
+        .map_or(offset, |c| offset + c.len_utf8())
+}
+
+// The offset of a column of the line starting at `start`, or the end of the
+// line when shorter.
+fn offset_at_column(text: &str, start: usize, column: usize) -> usize {
+    text[start..line_end(text, start)]
+        .char_indices()
+        .nth(column)
+        .map_or(line_end(text, start), |(index, _)| start + index)
+}
+
+fn prev_char(text: &str, offset: usize) -> usize {
+    text[..offset]
+        .chars()
+        .next_back()
+        .map_or(offset, |c| offset - c.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INDENT: &str = "    ";
+
+    // `|` marks the cursor.
+    fn run(keymap: Keymap, marked: &str, keys: &[KeyPress]) -> (String, Vec<KeymapAction>) {
+        let mut text = marked.replace('|', "");
+        let mut selection = Selection::caret(marked.find('|').unwrap());
+
+        let mut state = KeymapState::new(keymap).unwrap();
+        let mut actions = vec![];
+
+        for key in keys {
+            actions.extend(
+                state
+                    .handle(*key, &mut text, &mut selection, INDENT)
+                    .actions,
+            );
+        }
+
+        text.insert(selection.cursor, '|');
+
+        (text, actions)
+    }
+
+    fn vim(marked: &str, keys: &str) -> String {
+        let keys: Vec<KeyPress> = keys
+            .chars()
+            .map(|c| match c {
+                '\n' => KeyPress::Enter,
+                '\x1b' => KeyPress::Escape,
+                c => KeyPress::Char(c),
+            })
+            .collect();
+
+        run(Keymap::Vim, marked, &keys).0
+    }
+
+    #[test]
+    fn emacs() {
+        use KeyPress::*;
+
+        assert_eq!(
+            run(Keymap::Emacs, "|let a;\nlet b;", &[Ctrl('n'), Ctrl('e')]).0,
+            "let a;\nlet b;|"
+        );
+        assert_eq!(
+            run(
+                Keymap::Emacs,
+                "|let a = 1;",
+                &[Alt('f'), Alt('f'), Alt('b')]
+            )
+            .0,
+            "let |a = 1;"
+        );
+
+        // Kills in a row add up.
+        assert_eq!(
+            run(
+                Keymap::Emacs,
+                "|a\nb\nc",
+                &[Ctrl('k'), Ctrl('k'), Ctrl('k'), Ctrl('e'), Ctrl('y')]
+            )
+            .0,
+            "a\nb|\nc"
+        );
+
+        // The region.
+        assert_eq!(
+            run(
+                Keymap::Emacs,
+                "|one two",
+                &[Ctrl(' '), Alt('f'), Ctrl('w'), Ctrl('e'), Ctrl('y')]
+            )
+            .0,
+            " twoone|"
+        );
+
+        assert_eq!(
+            run(Keymap::Emacs, "|", &[Ctrl('x'), Ctrl('s')]).1,
+            [KeymapAction::Save]
+        );
+    }
+
+    #[test]
+    fn vim_commands() {
+        assert_eq!(
+            run(
+                Keymap::Vim,
+                "|",
+                &[KeyPress::Char(':'), KeyPress::Char('w'), KeyPress::Enter]
+            )
+            .1,
+            [KeymapAction::Save]
+        );
+        assert_eq!(vim("|a\nb\nc", ":3\n"), "a\nb\n|c");
+        assert_eq!(vim("|a", "2u").len(), 2);
+    }
+
+    #[test]
+    fn vim_insert() {
+        assert_eq!(vim("a|bc", "i"), "a|bc");
+        assert_eq!(vim("a|bc", "a"), "ab|c");
+        assert_eq!(vim("  a|bc", "I"), "  |abc");
+        assert_eq!(vim("a|bc", "A"), "abc|");
+        assert_eq!(vim("  a|b\nc", "o"), "  ab\n  |\nc");
+        assert_eq!(vim("  a|b", "O"), "  |\n  ab");
+        assert_eq!(vim("ab|", "i\x1b"), "a|b");
+    }
+
+    #[test]
+    fn vim_motions() {
+        assert_eq!(vim("|let a = b;", "w"), "let |a = b;");
+        assert_eq!(vim("|let a = b;", "3w"), "let a = |b;");
+        assert_eq!(vim("|let a = b;", "e"), "le|t a = b;");
+        assert_eq!(vim("let a = |b;", "2b"), "let |a = b;");
+        assert_eq!(vim("|let a = b;", "$"), "let a = b|;");
+        assert_eq!(vim("  let |a", "^"), "  |let a");
+        assert_eq!(vim("  let |a", "0"), "|  let a");
+        assert_eq!(vim("|let a = b;", "f="), "let a |= b;");
+        assert_eq!(vim("|let a = b;", "t="), "let a| = b;");
+        assert_eq!(vim("let a = b|;", "Fa"), "let |a = b;");
+
+        // Keeps the column through shorter lines.
+        assert_eq!(vim("ab|c\n\nabc", "jj"), "abc\n\nab|c");
+        assert_eq!(vim("a\nb\n|c", "gg"), "|a\nb\nc");
+        assert_eq!(vim("|a\nb\nc", "G"), "a\nb\n|c");
+        assert_eq!(vim("|a\nb\nc", "2G"), "a\n|b\nc");
+
+        // Huge counts go as far as they can.
+        assert_eq!(vim("|let a = b;", "99999999999999999999w"), "let a = b|;");
+        assert_eq!(vim("let a = |b;", "99999999999999999999h"), "|let a = b;");
+        assert_eq!(vim("|a\nb", "99999999999999999999j"), "a\n|b");
+    }
+
+    #[test]
+    fn vim_operators() {
+        assert_eq!(vim("|let a = b;", "dw"), "|a = b;");
+        assert_eq!(vim("|let a = b;", "d2w"), "|= b;");
+        assert_eq!(vim("|let a = b;", "2dw"), "|= b;");
+        assert_eq!(vim("let a = |b;", "d$"), "let a =| ");
+        assert_eq!(vim("let a = |b;", "dt;"), "let a = |;");
+        assert_eq!(vim("|let a\nb", "cw"), "| a\nb");
+        assert_eq!(vim("let |a = b;", "x"), "let | = b;");
+        assert_eq!(vim("a\n|b\nc", "dd"), "a\n|c");
+        assert_eq!(vim("a\nb\n|c", "dd"), "a\n|b");
+        assert_eq!(vim("|a\nb\nc", "2dd"), "|c");
+        assert_eq!(vim("a\n|b\nc", "dk"), "|c");
+        assert_eq!(vim("  a|b\nc", "cc"), "  |\nc");
+        assert_eq!(vim("|a\nb", ">>"), "    |a\nb");
+        assert_eq!(vim("    |a\n    b", "<j"), "|a\nb");
+        assert_eq!(vim("ab|c", "rx"), "ab|x");
+        assert_eq!(vim("|a\n  b", "J"), "a| b");
+    }
+
+    #[test]
+    fn vim_registers() {
+        assert_eq!(vim("|a\nb", "yyp"), "a\n|a\nb");
+        assert_eq!(vim("|a\nb", "yyjp"), "a\nb\n|a");
+        assert_eq!(vim("|ab", "ylp"), "a|ab");
+        assert_eq!(vim("|ab", "x$p"), "b|a");
+        assert_eq!(vim("|a b", "\"qylwx\"qp"), "a |a");
+
+        // Uppercase appends.
+        assert_eq!(vim("|ab", "\"qyl\"Qyl\"qP"), "a|aab");
+
+        // The black hole register keeps the unnamed register.
+        assert_eq!(vim("|ab", "yl\"_xP"), "|ab");
+
+        // Up to the largest count.
+        assert_eq!(vim("|a", "yl99999999999999999999p").len(), 10_002);
+    }
+
+    #[test]
+    fn vim_visual() {
+        assert_eq!(vim("|let a = b;", "vwd"), "| = b;");
+        assert_eq!(vim("let a = |b;", "vbby$p"), "let a = b;a = |b");
+        assert_eq!(vim("a\n|b\nc", "Vjd"), "|a");
+        assert_eq!(vim("|abc", "vlU"), "|ABc");
+        assert_eq!(vim("|abc", "vl\x1bx"), "a|c");
+    }
+}
@@ -0,0 +1,198 @@
+use super::{
+    is_word, line_end, line_start, move_lines, next_char, prev_char, KeyPress, KeymapAction,
+    Outcome,
+};
+use crate::wgsl::Selection;
+use std::{mem, ops::Range};
+
+// Emacs' keys: moving by characters, words and lines, killing and yanking,
+// and the region between the mark and the cursor.
+#[derive(Clone, Default)]
+pub struct Emacs {
+    // The column kept moving up and down through shorter lines.
+    column: Option<usize>,
+    // The text killed last, yanked back with C-y.
+    kill: String,
+    // Whether the last key killed, for kills in a row to add up.
+    killing: bool,
+    // Whether the mark is set, the region running from the selection's
+    // anchor to the cursor.
+    mark: bool,
+    // After C-x, waiting for the rest of the key sequence.
+    prefix: bool,
+}
+
+impl Emacs {
+    pub fn handle(
+        &mut self,
+        key: KeyPress,
+        text: &mut String,
+        selection: &mut Selection,
+    ) -> Outcome {
+        let killing = mem::take(&mut self.killing);
+        let column = self.column.take();
+
+        if mem::take(&mut self.prefix) {
+            let actions = match key {
+                KeyPress::Char('h') => {
+                    self.mark = true;
+
+                    *selection = Selection {
+                        anchor: 0,
+                        cursor: text.len(),
+                    };
+
+                    vec![]
+                }
+                KeyPress::Char('k') => vec![KeymapAction::CloseTab],
+                KeyPress::Char('u') => vec![KeymapAction::Undo],
+                KeyPress::Ctrl('s') => vec![KeymapAction::Save],
+                // Anything else cancels C-x.
+                _ => vec![],
+            };
+
+            return Outcome::with(actions);
+        }
+
+        let cursor = selection.cursor;
+
+        let moved = match key {
+            KeyPress::Alt('b') => Some(word_backward(text, cursor)),
+            KeyPress::Alt('f') => Some(word_forward(text, cursor)),
+            KeyPress::Ctrl('a') | KeyPress::Home => Some(line_start(text, cursor)),
+            KeyPress::Ctrl('b') | KeyPress::Left => Some(prev_char(text, cursor)),
+            KeyPress::Ctrl('e') | KeyPress::End => Some(line_end(text, cursor)),
+            KeyPress::Ctrl('f') | KeyPress::Right => Some(next_char(text, cursor)),
+            KeyPress::Ctrl('n') | KeyPress::Down | KeyPress::Ctrl('p') | KeyPress::Up => {
+                let column = column.unwrap_or_else(|| super::column(text, cursor));
+                let lines = if matches!(key, KeyPress::Ctrl('n') | KeyPress::Down) {
+                    1
+                } else {
+                    -1
+                };
+
+                self.column = Some(column);
+
+                Some(move_lines(text, cursor, lines, column).unwrap_or(cursor))
+            }
+            _ => None,
+        };
+
+        if let Some(offset) = moved {
+            selection.cursor = offset;
+
+            if !self.mark {
+                selection.anchor = offset;
+            }
+
+            return Outcome::handled();
+        }
+
+        match key {
+            KeyPress::Alt('d') => {
+                self.kill(text, selection, cursor..word_forward(text, cursor), killing);
+            }
+            KeyPress::Alt('w') => {
+                self.kill = text[selection.range()].to_owned();
+                self.mark = false;
+
+                *selection = Selection::caret(cursor);
+            }
+            KeyPress::Ctrl(' ') => {
+                self.mark = true;
+
+                *selection = Selection::caret(cursor);
+            }
+            KeyPress::Ctrl('d') => {
+                text.replace_range(cursor..next_char(text, cursor), "");
+
+                *selection = Selection::caret(cursor);
+            }
+            KeyPress::Ctrl('g') => {
+                self.mark = false;
+
+                *selection = Selection::caret(cursor);
+            }
+            KeyPress::Ctrl('k') => {
+                let end = line_end(text, cursor);
+
+                // At the end of a line, the line break.
+                let end = if end == cursor {
+                    next_char(text, cursor)
+                } else {
+                    end
+                };
+
+                self.kill(text, selection, cursor..end, killing);
+            }
+            KeyPress::Ctrl('o') => {
+                text.insert(cursor, '\n');
+
+                *selection = Selection::caret(cursor);
+            }
+            KeyPress::Ctrl('w') => {
+                if self.mark {
+                    self.kill(text, selection, selection.range(), false);
+                }
+            }
+            KeyPress::Ctrl('x') => self.prefix = true,
+            KeyPress::Ctrl('y') => {
+                let start = selection.range().start;
+
+                text.replace_range(selection.range(), &self.kill);
+
+                self.mark = false;
+
+                *selection = Selection::caret(start + self.kill.len());
+            }
+            _ => return Outcome::unhandled(),
+        }
+
+        Outcome::handled()
+    }
+
+    // The mark, or C-x, waiting for the rest of its key sequence.
+    pub fn status(&self) -> (bool, bool) {
+        (self.mark, self.prefix)
+    }
+
+    // A selection made by the editor sets the mark at its anchor.
+    pub fn sync(&mut self, selection: &Selection) {
+        self.mark = !selection.is_empty();
+    }
+
+    fn kill(
+        &mut self,
+        text: &mut String,
+        selection: &mut Selection,
+        range: Range<usize>,
+        append: bool,
+    ) {
+        if !append {
+            self.kill.clear();
+        }
+
+        self.kill.push_str(&text[range.clone()]);
+        self.killing = true;
+        self.mark = false;
+
+        text.replace_range(range.clone(), "");
+
+        *selection = Selection::caret(range.start);
+    }
+}
+
+// To the start of the word before `offset`.
+fn word_backward(text: &str, offset: usize) -> usize {
+    let before = text[..offset].trim_end_matches(|c| !is_word(c));
+
+    before.trim_end_matches(is_word).len()
+}
+
+// To the end of the word after `offset`.
+fn word_forward(text: &str, offset: usize) -> usize {
+    let after = text[offset..].trim_start_matches(|c| !is_word(c));
+    let end = after.trim_start_matches(is_word);
+
+    text.len() - end.len()
+}
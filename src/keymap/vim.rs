@@ -0,0 +1,1250 @@
+use super::{
+    char_class, column, line_end, line_start, move_lines, next_char, prev_char, KeyPress,
+    KeymapAction, Outcome,
+};
+use crate::wgsl::Selection;
+use std::{collections::HashMap, iter::Peekable, mem, ops::Range, str::Chars};
+
+// Counts past this only make a command slow, e.g. pasting.
+const MAX_COUNT: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum VimMode {
+    // Typing a command after `:`.
+    Command,
+    Insert,
+    #[default]
+    Normal,
+    Visual,
+    VisualLine,
+}
+
+enum Action {
+    // Keys which are neither motions nor operators, e.g. `i` or `p`.
+    Key(char),
+    Motion(Motion),
+    // On whole lines from the cursor without a motion, as with `dd`.
+    Operator(Operator, Option<Motion>),
+    Replace(char),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Motion {
+    Down,
+    FileEnd,
+    FileStart,
+    FindBackward(char),
+    FindForward(char),
+    FirstNonBlank,
+    Left,
+    LineEnd,
+    LineStart,
+    Right,
+    TillBackward(char),
+    TillForward(char),
+    Up,
+    WordBackward,
+    WordEnd,
+    WordForward,
+}
+
+impl Motion {
+    // Whether an operator takes the character the motion ends on.
+    fn is_inclusive(self) -> bool {
+        matches!(
+            self,
+            Self::FindForward(_) | Self::LineEnd | Self::TillForward(_) | Self::WordEnd
+        )
+    }
+
+    // Whether an operator takes whole lines.
+    fn is_linewise(self) -> bool {
+        matches!(
+            self,
+            Self::Down | Self::FileEnd | Self::FileStart | Self::Up
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Operator {
+    Change,
+    Delete,
+    Indent,
+    Outdent,
+    Yank,
+}
+
+enum Parse {
+    Complete(Parsed),
+    Incomplete,
+    Invalid,
+}
+
+// A command typed in normal or visual mode, e.g. `"a2dw`.
+struct Parsed {
+    action: Action,
+    count: Option<usize>,
+    register: Option<char>,
+}
+
+#[derive(Clone, Default)]
+struct Register {
+    // Whether it holds whole lines, pasted above or below the cursor's.
+    linewise: bool,
+    text: String,
+}
+
+// Vim's modes, motions, operators, counts and registers.
+//
+// In normal and visual modes the cursor is on a character rather than
+// between two.
+#[derive(Clone, Default)]
+pub struct Vim {
+    // The column kept moving up and down through shorter lines.
+    column: Option<usize>,
+    // The line typed after `:`.
+    command: String,
+    mode: VimMode,
+    // The keys typed of a command not complete yet, e.g. `"a2d`.
+    pending: String,
+    registers: HashMap<char, Register>,
+}
+
+impl Vim {
+    pub fn handle(
+        &mut self,
+        key: KeyPress,
+        text: &mut String,
+        selection: &mut Selection,
+        indent: &str,
+    ) -> Outcome {
+        match self.mode {
+            VimMode::Command => self.handle_command(key, text, selection),
+            VimMode::Insert => {
+                if key != KeyPress::Escape {
+                    return Outcome::unhandled();
+                }
+
+                self.mode = VimMode::Normal;
+
+                // Back onto the last character typed.
+                let cursor = selection.cursor;
+                let cursor = if cursor > line_start(text, cursor) {
+                    prev_char(text, cursor)
+                } else {
+                    cursor
+                };
+
+                *selection = Selection::caret(cursor);
+
+                Outcome::handled()
+            }
+            VimMode::Normal | VimMode::Visual | VimMode::VisualLine => {
+                self.handle_normal(key, text, selection, indent)
+            }
+        }
+    }
+
+    pub fn mode(&self) -> VimMode {
+        self.mode
+    }
+
+    // The keys of a command not complete yet, or the line typed after `:`.
+    pub fn pending(&self) -> String {
+        if self.mode == VimMode::Command {
+            format!(":{}", self.command)
+        } else {
+            self.pending.clone()
+        }
+    }
+
+    // A click leaves visual mode, dragging enters it.
+    pub fn sync(&mut self, text: &str, selection: &mut Selection) {
+        self.pending.clear();
+
+        if self.mode == VimMode::Insert {
+            return;
+        }
+
+        if selection.is_empty() {
+            self.mode = VimMode::Normal;
+
+            *selection = Selection::caret(clamp(text, selection.cursor));
+        } else {
+            self.mode = VimMode::Visual;
+
+            // Onto the last character selected.
+            if selection.cursor > selection.anchor {
+                selection.cursor = prev_char(text, selection.cursor);
+            }
+        }
+    }
+
+    // Visual modes show the characters or lines selected, including the
+    // ones the cursor and the anchor are on, and normal mode the character
+    // under the cursor.
+    pub fn visible_selection(&self, text: &str, selection: Selection) -> Selection {
+        let Selection { anchor, cursor } = selection;
+
+        match self.mode {
+            VimMode::Command | VimMode::Normal => Selection {
+                anchor: inclusive_end(text, cursor),
+                cursor,
+            },
+            VimMode::Insert => Selection::caret(cursor),
+            VimMode::Visual if cursor >= anchor => Selection {
+                anchor,
+                cursor: inclusive_end(text, cursor),
+            },
+            VimMode::Visual => Selection {
+                anchor: inclusive_end(text, anchor),
+                cursor,
+            },
+            VimMode::VisualLine if cursor >= anchor => Selection {
+                anchor: line_start(text, anchor),
+                cursor: line_end(text, cursor),
+            },
+            VimMode::VisualLine => Selection {
+                anchor: line_end(text, anchor),
+                cursor: line_start(text, cursor),
+            },
+        }
+    }
+
+    fn handle_command(&mut self, key: KeyPress, text: &str, selection: &mut Selection) -> Outcome {
+        match key {
+            KeyPress::Alt(_) | KeyPress::Ctrl(_) => return Outcome::unhandled(),
+            KeyPress::Backspace => {
+                // Past the `:`, out of the command line.
+                self.mode = match self.command.pop() {
+                    Some(_) => VimMode::Command,
+                    None => VimMode::Normal,
+                };
+            }
+            KeyPress::Char(c) => self.command.push(c),
+            KeyPress::Enter => {
+                self.mode = VimMode::Normal;
+
+                let command = mem::take(&mut self.command);
+
+                return Outcome::with(run_command(&command, text, selection));
+            }
+            KeyPress::Escape => {
+                self.mode = VimMode::Normal;
+                self.command.clear();
+            }
+            _ => {}
+        }
+
+        Outcome::handled()
+    }
+
+    fn handle_normal(
+        &mut self,
+        key: KeyPress,
+        text: &mut String,
+        selection: &mut Selection,
+        indent: &str,
+    ) -> Outcome {
+        let c = match key {
+            KeyPress::Ctrl('r') => {
+                self.pending.clear();
+
+                return Outcome::with(vec![KeymapAction::Redo]);
+            }
+            KeyPress::Alt(_) | KeyPress::Ctrl(_) => return Outcome::unhandled(),
+            KeyPress::Backspace | KeyPress::Left => 'h',
+            KeyPress::Char(c) => c,
+            KeyPress::Delete => 'x',
+            KeyPress::Down | KeyPress::Enter => 'j',
+            KeyPress::End => '$',
+            KeyPress::Escape => {
+                self.pending.clear();
+
+                if self.mode != VimMode::Normal {
+                    self.mode = VimMode::Normal;
+
+                    *selection = Selection::caret(selection.cursor);
+                }
+
+                return Outcome::handled();
+            }
+            KeyPress::Home => '0',
+            KeyPress::Right => 'l',
+            KeyPress::Up => 'k',
+        };
+
+        self.pending.push(c);
+
+        let is_visual = self.mode != VimMode::Normal;
+
+        match parse(&self.pending, is_visual) {
+            Parse::Complete(parsed) => {
+                self.pending.clear();
+
+                let actions = if is_visual {
+                    self.run_visual(parsed, text, selection, indent)
+                } else {
+                    self.run_normal(parsed, text, selection, indent)
+                };
+
+                Outcome::with(actions)
+            }
+            Parse::Incomplete => Outcome::handled(),
+            Parse::Invalid => {
+                self.pending.clear();
+
+                Outcome::handled()
+            }
+        }
+    }
+
+    fn operate(
+        &mut self,
+        operator: Operator,
+        // Whole lines when `true`, without their last line break.
+        (range, linewise): (Range<usize>, bool),
+        register: Option<char>,
+        text: &mut String,
+        selection: &mut Selection,
+        indent: &str,
+    ) {
+        let cursor = selection.cursor;
+
+        let taken = if linewise {
+            format!("{}\n", &text[range.clone()])
+        } else {
+            text[range.clone()].to_owned()
+        };
+
+        let cursor = match operator {
+            Operator::Change => {
+                self.store(register, taken, linewise);
+                self.mode = VimMode::Insert;
+
+                if linewise {
+                    // Keeps the indentation of the first line.
+                    let indentation = indentation(text, range.start).to_owned();
+
+                    text.replace_range(range.clone(), &indentation);
+
+                    range.start + indentation.len()
+                } else {
+                    text.replace_range(range.clone(), "");
+
+                    range.start
+                }
+            }
+            Operator::Delete => {
+                self.store(register, taken, linewise);
+
+                if linewise {
+                    let range = with_line_break(text, range);
+
+                    text.replace_range(range.clone(), "");
+
+                    first_non_blank(text, line_start(text, range.start.min(text.len())))
+                } else {
+                    text.replace_range(range.clone(), "");
+
+                    clamp(text, range.start)
+                }
+            }
+            Operator::Indent | Operator::Outdent => {
+                let start = line_start(text, range.start);
+                let lines = start..line_end(text, range.end);
+
+                let shifted: Vec<String> = text[lines.clone()]
+                    .split('\n')
+                    .map(|line| shift(line, indent, operator == Operator::Indent))
+                    .collect();
+
+                text.replace_range(lines, &shifted.join("\n"));
+
+                first_non_blank(text, start)
+            }
+            Operator::Yank => {
+                self.store(register, taken, linewise);
+
+                if linewise && line_start(text, cursor) == range.start {
+                    cursor
+                } else {
+                    range.start
+                }
+            }
+        };
+
+        *selection = Selection::caret(cursor);
+    }
+
+    fn paste(
+        &mut self,
+        after: bool,
+        count: usize,
+        register: Option<char>,
+        text: &mut String,
+        selection: &mut Selection,
+    ) {
+        let name = register.map_or('"', |name| name.to_ascii_lowercase());
+
+        let Some(Register {
+            linewise,
+            text: pasted,
+        }) = self.registers.get(&name)
+        else {
+            return;
+        };
+
+        if pasted.is_empty() {
+            return;
+        }
+
+        let pasted = pasted.repeat(count);
+        let cursor = selection.cursor;
+
+        let cursor = if *linewise {
+            let end = line_end(text, cursor);
+
+            let offset = if !after {
+                line_start(text, cursor)
+            } else if end < text.len() {
+                end + 1
+            } else {
+                // Below the last line, which has no line break to paste after.
+                text.push('\n');
+                text.len()
+            };
+
+            let pasted = if offset == text.len() {
+                pasted.strip_suffix('\n').unwrap_or(&pasted)
+            } else {
+                &pasted
+            };
+
+            text.insert_str(offset, pasted);
+
+            first_non_blank(text, offset)
+        } else {
+            let offset = if after && cursor < line_end(text, cursor) {
+                next_char(text, cursor)
+            } else {
+                cursor
+            };
+
+            text.insert_str(offset, &pasted);
+
+            // On the last character pasted.
+            prev_char(text, offset + pasted.len())
+        };
+
+        *selection = Selection::caret(cursor);
+    }
+
+    fn run_key(
+        &mut self,
+        key: char,
+        count: usize,
+        register: Option<char>,
+        text: &mut String,
+        selection: &mut Selection,
+    ) -> Vec<KeymapAction> {
+        let cursor = selection.cursor;
+
+        let insert_at = match key {
+            'A' => Some(line_end(text, cursor)),
+            'I' => Some(first_non_blank(text, cursor)),
+            'J' => {
+                let cursor = join_lines(text, cursor, count.max(2) - 1);
+
+                *selection = Selection::caret(clamp(text, cursor));
+
+                None
+            }
+            'O' => {
+                let start = line_start(text, cursor);
+                let indentation = indentation(text, cursor).to_owned();
+
+                text.insert_str(start, &format!("{}\n", indentation));
+
+                Some(start + indentation.len())
+            }
+            'P' | 'p' => {
+                self.paste(key == 'p', count, register, text, selection);
+
+                None
+            }
+            'V' => {
+                self.mode = VimMode::VisualLine;
+
+                None
+            }
+            'a' => Some(if cursor < line_end(text, cursor) {
+                next_char(text, cursor)
+            } else {
+                cursor
+            }),
+            'i' => Some(cursor),
+            'o' => {
+                let end = line_end(text, cursor);
+                let indentation = indentation(text, cursor).to_owned();
+
+                text.insert_str(end, &format!("\n{}", indentation));
+
+                Some(end + 1 + indentation.len())
+            }
+            'u' => return vec![KeymapAction::Undo; count],
+            'v' => {
+                self.mode = VimMode::Visual;
+
+                None
+            }
+            '~' => {
+                let end = text[cursor..line_end(text, cursor)]
+                    .char_indices()
+                    .nth(count)
+                    .map_or(line_end(text, cursor), |(index, _)| cursor + index);
+
+                map_case(text, cursor..end, key);
+
+                *selection = Selection::caret(clamp(text, end));
+
+                None
+            }
+            ':' => {
+                self.mode = VimMode::Command;
+
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(offset) = insert_at {
+            self.mode = VimMode::Insert;
+
+            *selection = Selection::caret(offset);
+        }
+
+        vec![]
+    }
+
+    fn run_normal(
+        &mut self,
+        parsed: Parsed,
+        text: &mut String,
+        selection: &mut Selection,
+        indent: &str,
+    ) -> Vec<KeymapAction> {
+        let Parsed {
+            action,
+            count,
+            register,
+        } = parsed;
+
+        let cursor = selection.cursor;
+
+        match action {
+            Action::Key(key) => {
+                return self.run_key(key, count.unwrap_or(1), register, text, selection);
+            }
+            Action::Motion(motion) => {
+                if let Some(target) = self.target(text, cursor, motion, count) {
+                    *selection = Selection::caret(clamp(text, target));
+                }
+            }
+            Action::Operator(operator, None) => {
+                // The lines from the cursor's, as many as the count.
+                let last = (1..count.unwrap_or(1))
+                    .try_fold(cursor, |offset, _| move_lines(text, offset, 1, 0))
+                    .unwrap_or_else(|| line_start(text, text.len()));
+
+                let range = line_start(text, cursor)..line_end(text, last);
+
+                self.operate(operator, (range, true), register, text, selection, indent);
+            }
+            Action::Operator(operator, Some(motion)) => {
+                // `cw` changes up to the end of the word, as `ce` does, but
+                // from its last character only that one.
+                let (target, motion) = if operator == Operator::Change
+                    && motion == Motion::WordForward
+                    && char_class(text[cursor..].chars().next().unwrap_or(' ')) != 0
+                {
+                    let target = repeat(count.unwrap_or(1) - 1, run_end(text, cursor), |offset| {
+                        word_end(text, offset)
+                    });
+
+                    (target, Motion::WordEnd)
+                } else {
+                    let Some(target) = self.target(text, cursor, motion, count) else {
+                        return vec![];
+                    };
+
+                    (target, motion)
+                };
+
+                let taken = motion_range(text, cursor, target, motion);
+
+                self.operate(operator, taken, register, text, selection, indent);
+            }
+            Action::Replace(c) => {
+                let count = count.unwrap_or(1);
+                let end = line_end(text, cursor);
+
+                let chars: Vec<(usize, char)> =
+                    text[cursor..end].char_indices().take(count).collect();
+
+                if let (Some((index, last)), true) = (chars.last(), chars.len() == count) {
+                    let replaced = cursor..cursor + index + last.len_utf8();
+
+                    text.replace_range(replaced, &c.to_string().repeat(count));
+
+                    *selection = Selection::caret(cursor + (count - 1) * c.len_utf8());
+                }
+            }
+        }
+
+        vec![]
+    }
+
+    fn run_visual(
+        &mut self,
+        parsed: Parsed,
+        text: &mut String,
+        selection: &mut Selection,
+        indent: &str,
+    ) -> Vec<KeymapAction> {
+        let Parsed {
+            action,
+            count,
+            register,
+        } = parsed;
+
+        let Selection { anchor, cursor } = *selection;
+        let linewise = self.mode == VimMode::VisualLine;
+
+        let start = anchor.min(cursor);
+        let end = anchor.max(cursor);
+
+        let range = if linewise {
+            line_start(text, start)..line_end(text, end)
+        } else {
+            start..inclusive_end(text, end)
+        };
+
+        match action {
+            Action::Key('J') => {
+                self.mode = VimMode::Normal;
+
+                let lines = text[range].matches('\n').count().max(1);
+                let cursor = join_lines(text, start, lines);
+
+                *selection = Selection::caret(clamp(text, cursor));
+            }
+            Action::Key(key @ ('U' | 'u' | '~')) => {
+                self.mode = VimMode::Normal;
+
+                map_case(text, range.clone(), key);
+
+                *selection = Selection::caret(range.start);
+            }
+            Action::Key(key @ ('V' | 'v')) => {
+                let mode = if key == 'V' {
+                    VimMode::VisualLine
+                } else {
+                    VimMode::Visual
+                };
+
+                // The same key again leaves visual mode.
+                if self.mode == mode {
+                    self.mode = VimMode::Normal;
+
+                    *selection = Selection::caret(cursor);
+                } else {
+                    self.mode = mode;
+                }
+            }
+            Action::Key('o') => {
+                *selection = Selection {
+                    anchor: cursor,
+                    cursor: anchor,
+                };
+            }
+            Action::Key(':') => {
+                self.mode = VimMode::Command;
+
+                *selection = Selection::caret(cursor);
+            }
+            Action::Key(_) => {}
+            Action::Motion(motion) => {
+                if let Some(target) = self.target(text, cursor, motion, count) {
+                    selection.cursor = clamp(text, target);
+                }
+            }
+            Action::Operator(operator, _) => {
+                self.mode = VimMode::Normal;
+
+                self.operate(
+                    operator,
+                    (range, linewise),
+                    register,
+                    text,
+                    selection,
+                    indent,
+                );
+            }
+            Action::Replace(c) => {
+                self.mode = VimMode::Normal;
+
+                let replaced: String = text[range.clone()]
+                    .chars()
+                    .map(|other| if other == '\n' { other } else { c })
+                    .collect();
+
+                text.replace_range(range.clone(), &replaced);
+
+                *selection = Selection::caret(range.start);
+            }
+        }
+
+        vec![]
+    }
+
+    fn store(&mut self, register: Option<char>, text: String, linewise: bool) {
+        let register = match register {
+            // The black hole register.
+            Some('_') => return,
+            // Uppercase appends to the lowercase register.
+            Some(name) if name.is_ascii_uppercase() => {
+                let register = self.registers.entry(name.to_ascii_lowercase()).or_default();
+
+                register.linewise |= linewise;
+                register.text.push_str(&text);
+
+                register.clone()
+            }
+            Some(name) => {
+                let register = Register { linewise, text };
+
+                self.registers.insert(name, register.clone());
+
+                register
+            }
+            None => Register { linewise, text },
+        };
+
+        // The unnamed register has the last text taken.
+        self.registers.insert('"', register);
+    }
+
+    // Where a motion goes from `offset`, nothing when it can't go anywhere.
+    fn target(
+        &mut self,
+        text: &str,
+        offset: usize,
+        motion: Motion,
+        count: Option<usize>,
+    ) -> Option<usize> {
+        let times = count.unwrap_or(1);
+
+        if !matches!(motion, Motion::Down | Motion::Up) {
+            self.column = None;
+        }
+
+        let target = match motion {
+            Motion::Down | Motion::Up => {
+                let column = *self.column.get_or_insert_with(|| column(text, offset));
+                let lines = if motion == Motion::Down { 1 } else { -1 };
+
+                // As far as there are lines.
+                let mut target = None;
+
+                for _ in 0..times {
+                    match move_lines(text, target.unwrap_or(offset), lines, column) {
+                        Some(offset) => target = Some(offset),
+                        None => break,
+                    }
+                }
+
+                target?
+            }
+            Motion::FileEnd | Motion::FileStart => {
+                let lines = text.split('\n').count();
+
+                let line = match (motion, count) {
+                    (_, Some(line)) => line.clamp(1, lines),
+                    (Motion::FileStart, None) => 1,
+                    _ => lines,
+                };
+
+                let start = text
+                    .split('\n')
+                    .take(line - 1)
+                    .map(|line| line.len() + 1)
+                    .sum();
+
+                first_non_blank(text, start)
+            }
+            Motion::FindBackward(c) | Motion::TillBackward(c) => {
+                let start = line_start(text, offset);
+
+                let found = text[start..offset]
+                    .char_indices()
+                    .rev()
+                    .filter(|(_, other)| *other == c)
+                    .nth(times - 1)
+                    .map(|(index, _)| start + index)?;
+
+                if matches!(motion, Motion::TillBackward(_)) {
+                    next_char(text, found)
+                } else {
+                    found
+                }
+            }
+            Motion::FindForward(c) | Motion::TillForward(c) => {
+                let end = line_end(text, offset);
+                let start = next_char(text, offset).min(end);
+
+                let found = text[start..end]
+                    .char_indices()
+                    .filter(|(_, other)| *other == c)
+                    .nth(times - 1)
+                    .map(|(index, _)| start + index)?;
+
+                if matches!(motion, Motion::TillForward(_)) {
+                    prev_char(text, found)
+                } else {
+                    found
+                }
+            }
+            Motion::FirstNonBlank => first_non_blank(text, offset),
+            Motion::Left => {
+                let start = line_start(text, offset);
+
+                repeat(times, offset, |offset| {
+                    if offset > start {
+                        prev_char(text, offset)
+                    } else {
+                        offset
+                    }
+                })
+            }
+            Motion::LineEnd => {
+                // With a count, on the lines below.
+                let line = (1..times)
+                    .try_fold(offset, |offset, _| move_lines(text, offset, 1, 0))
+                    .unwrap_or_else(|| line_start(text, text.len()));
+
+                let end = line_end(text, line);
+
+                if end > line_start(text, end) {
+                    prev_char(text, end)
+                } else {
+                    end
+                }
+            }
+            Motion::LineStart => line_start(text, offset),
+            Motion::Right => {
+                let end = line_end(text, offset);
+
+                repeat(times, offset, |offset| {
+                    if offset < end {
+                        next_char(text, offset)
+                    } else {
+                        offset
+                    }
+                })
+            }
+            Motion::WordBackward => repeat(times, offset, |offset| word_backward(text, offset)),
+            Motion::WordEnd => repeat(times, offset, |offset| word_end(text, offset)),
+            Motion::WordForward => repeat(times, offset, |offset| word_forward(text, offset)),
+        };
+
+        Some(target)
+    }
+}
+
+// Moves `times` by `step` from `offset`, stopping early once a step no
+// longer moves.
+fn repeat(times: usize, offset: usize, step: impl Fn(usize) -> usize) -> usize {
+    let mut offset = offset;
+
+    for _ in 0..times {
+        let next = step(offset);
+
+        if next == offset {
+            break;
+        }
+
+        offset = next;
+    }
+
+    offset
+}
+
+// Keeps the cursor on a character, off the line break unless the line is
+// empty.
+fn clamp(text: &str, offset: usize) -> usize {
+    let offset = offset.min(text.len());
+    let end = line_end(text, offset);
+
+    if offset == end && end > line_start(text, offset) {
+        prev_char(text, end)
+    } else {
+        offset
+    }
+}
+
+fn first_non_blank(text: &str, offset: usize) -> usize {
+    let start = line_start(text, offset);
+    let end = line_end(text, offset);
+
+    text[start..end]
+        .find(|c| c != ' ' && c != '\t')
+        .map_or(end, |index| start + index)
+}
+
+// After the character at `offset`, unless it's a line break.
+fn inclusive_end(text: &str, offset: usize) -> usize {
+    if offset == line_end(text, offset) {
+        offset
+    } else {
+        next_char(text, offset)
+    }
+}
+
+fn indentation(text: &str, offset: usize) -> &str {
+    &text[line_start(text, offset)..first_non_blank(text, offset)]
+}
+
+// Joins the line of `offset` with those below it, separated by a space, and
+// returns where the last join is.
+fn join_lines(text: &mut String, offset: usize, lines: usize) -> usize {
+    let mut joined = offset;
+
+    for _ in 0..lines {
+        let end = line_end(text, offset);
+
+        if end == text.len() {
+            break;
+        }
+
+        let next = first_non_blank(text, end + 1);
+
+        let separator = if next == line_end(text, next) || text[next..].starts_with(')') {
+            ""
+        } else {
+            " "
+        };
+
+        text.replace_range(end..next, separator);
+
+        joined = end;
+    }
+
+    joined
+}
+
+// Uppercases with `U`, lowercases with `u` and toggles the case otherwise.
+fn map_case(text: &mut String, range: Range<usize>, key: char) {
+    let mapped: String = text[range.clone()]
+        .chars()
+        .flat_map(|c| {
+            let upper = match key {
+                'U' => true,
+                'u' => false,
+                _ => !c.is_uppercase(),
+            };
+
+            if upper {
+                c.to_uppercase().collect::<Vec<_>>()
+            } else {
+                c.to_lowercase().collect()
+            }
+        })
+        .collect();
+
+    text.replace_range(range, &mapped);
+}
+
+// The range an operator takes going from `offset` to `target` with a motion,
+// and whether it's whole lines, in which case it leaves out the last line
+// break.
+fn motion_range(text: &str, offset: usize, target: usize, motion: Motion) -> (Range<usize>, bool) {
+    let start = offset.min(target);
+    let end = offset.max(target);
+
+    if motion.is_linewise() {
+        return (line_start(text, start)..line_end(text, end), true);
+    }
+
+    let end = if motion.is_inclusive() {
+        inclusive_end(text, end)
+    } else if motion == Motion::WordForward && text[start..end].contains('\n') {
+        // `dw` on the last word of a line stops at its end.
+        line_end(text, start)
+    } else {
+        end
+    };
+
+    (start..end, false)
+}
+
+fn parse(keys: &str, is_visual: bool) -> Parse {
+    let mut keys = keys.chars().peekable();
+
+    let mut register = None;
+
+    if keys.next_if_eq(&'"').is_some() {
+        match keys.next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '"' || c == '_' => register = Some(c),
+            Some(_) => return Parse::Invalid,
+            None => return Parse::Incomplete,
+        }
+    }
+
+    let count = parse_count(&mut keys);
+
+    let Some(key) = keys.next() else {
+        return Parse::Incomplete;
+    };
+
+    let operator = match key {
+        '<' => Some(Operator::Outdent),
+        '>' => Some(Operator::Indent),
+        'c' => Some(Operator::Change),
+        'd' => Some(Operator::Delete),
+        'y' => Some(Operator::Yank),
+        _ => None,
+    };
+
+    let (action, count) = match operator {
+        // Visual modes have the selection to operate on.
+        Some(operator) if is_visual => (Action::Operator(operator, None), count),
+        Some(operator) => {
+            let motion_count = parse_count(&mut keys);
+
+            let count = match (count, motion_count) {
+                (None, None) => None,
+                _ => Some((count.unwrap_or(1) * motion_count.unwrap_or(1)).min(MAX_COUNT)),
+            };
+
+            let Some(next) = keys.next() else {
+                return Parse::Incomplete;
+            };
+
+            // Doubled, as with `dd`, on whole lines.
+            if next == key {
+                (Action::Operator(operator, None), count)
+            } else {
+                match parse_motion(next, &mut keys) {
+                    Ok(motion) => (Action::Operator(operator, Some(motion)), count),
+                    Err(parse) => return parse,
+                }
+            }
+        }
+        None => match key {
+            'A' | 'I' | 'J' | 'O' | 'P' | 'U' | 'V' | 'a' | 'i' | 'o' | 'p' | 'u' | 'v' | '~'
+            | ':' => (Action::Key(key), count),
+            // Shorthands for operators with a motion.
+            'C' => (
+                Action::Operator(Operator::Change, Some(Motion::LineEnd)),
+                count,
+            ),
+            'D' => (
+                Action::Operator(Operator::Delete, Some(Motion::LineEnd)),
+                count,
+            ),
+            'S' => (Action::Operator(Operator::Change, None), count),
+            'X' => (
+                Action::Operator(Operator::Delete, Some(Motion::Left)),
+                count,
+            ),
+            'Y' => (Action::Operator(Operator::Yank, None), count),
+            'r' => match keys.next() {
+                Some(c) => (Action::Replace(c), count),
+                None => return Parse::Incomplete,
+            },
+            's' => (
+                Action::Operator(Operator::Change, Some(Motion::Right)),
+                count,
+            ),
+            'x' => (
+                Action::Operator(Operator::Delete, Some(Motion::Right)),
+                count,
+            ),
+            _ => match parse_motion(key, &mut keys) {
+                Ok(motion) => (Action::Motion(motion), count),
+                Err(parse) => return parse,
+            },
+        },
+    };
+
+    Parse::Complete(Parsed {
+        action,
+        count,
+        register,
+    })
+}
+
+fn parse_count(keys: &mut Peekable<Chars>) -> Option<usize> {
+    let mut count: Option<usize> = None;
+
+    // A leading 0 is the motion to the start of the line.
+    while let Some(digit) = keys
+        .next_if(|c| c.is_ascii_digit() && (count.is_some() || *c != '0'))
+        .and_then(|c| c.to_digit(10))
+    {
+        count = Some(
+            count
+                .unwrap_or(0)
+                .saturating_mul(10)
+                .saturating_add(digit as usize)
+                .min(MAX_COUNT),
+        );
+    }
+
+    count
+}
+
+fn parse_motion(key: char, keys: &mut Peekable<Chars>) -> Result<Motion, Parse> {
+    let motion = match key {
+        ' ' | 'l' => Motion::Right,
+        '$' => Motion::LineEnd,
+        '0' => Motion::LineStart,
+        'G' => Motion::FileEnd,
+        '^' => Motion::FirstNonBlank,
+        'b' => Motion::WordBackward,
+        'e' => Motion::WordEnd,
+        'F' | 'T' | 'f' | 't' => {
+            let Some(c) = keys.next() else {
+                return Err(Parse::Incomplete);
+            };
+
+            match key {
+                'F' => Motion::FindBackward(c),
+                'T' => Motion::TillBackward(c),
+                'f' => Motion::FindForward(c),
+                _ => Motion::TillForward(c),
+            }
+        }
+        'g' => match keys.next() {
+            Some('g') => Motion::FileStart,
+            Some(_) => return Err(Parse::Invalid),
+            None => return Err(Parse::Incomplete),
+        },
+        'h' => Motion::Left,
+        'j' => Motion::Down,
+        'k' => Motion::Up,
+        'w' => Motion::WordForward,
+        _ => return Err(Parse::Invalid),
+    };
+
+    Ok(motion)
+}
+
+// Runs a command typed after `:`, saving with `w`, closing with `q` or going
+// to a line with its number.
+fn run_command(command: &str, text: &str, selection: &mut Selection) -> Vec<KeymapAction> {
+    match command.trim() {
+        "q" | "q!" => vec![KeymapAction::CloseTab],
+        "w" => vec![KeymapAction::Save],
+        "wq" | "x" => vec![KeymapAction::Save, KeymapAction::CloseTab],
+        command => {
+            if let Ok(line) = command.parse::<usize>() {
+                let start = text
+                    .split('\n')
+                    .take(line.saturating_sub(1))
+                    .map(|line| line.len() + 1)
+                    .sum::<usize>()
+                    .min(text.len());
+
+                *selection = Selection::caret(first_non_blank(text, start));
+            }
+
+            vec![]
+        }
+    }
+}
+
+// The last character of the run of one class of characters at `offset`.
+fn run_end(text: &str, offset: usize) -> usize {
+    let class = text[offset..].chars().next().map_or(0, char_class);
+
+    text[offset..]
+        .char_indices()
+        .take_while(|(_, c)| char_class(*c) == class)
+        .last()
+        .map_or(offset, |(index, _)| offset + index)
+}
+
+// Indents a line, or outdents it by one indent or one tab.
+fn shift(line: &str, indent: &str, is_indent: bool) -> String {
+    if is_indent {
+        return if line.trim().is_empty() {
+            line.to_owned()
+        } else {
+            format!("{}{}", indent, line)
+        };
+    }
+
+    if let Some(outdented) = line.strip_prefix('\t') {
+        return outdented.to_owned();
+    }
+
+    let spaces = line.len() - line.trim_start_matches(' ').len();
+
+    line[spaces.min(indent.len())..].to_owned()
+}
+
+// Whole lines with a line break, the one after them or, for the last line,
+// the one before.
+fn with_line_break(text: &str, lines: Range<usize>) -> Range<usize> {
+    if lines.end < text.len() {
+        lines.start..lines.end + 1
+    } else if lines.start > 0 {
+        lines.start - 1..lines.end
+    } else {
+        lines
+    }
+}
+
+// To the start of the word before `offset`.
+fn word_backward(text: &str, offset: usize) -> usize {
+    let before = text[..offset].trim_end();
+
+    let Some(last) = before.chars().next_back() else {
+        return 0;
+    };
+
+    let class = char_class(last);
+
+    before
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| char_class(*c) == class)
+        .last()
+        .map_or(offset, |(index, _)| index)
+}
+
+// To the last character of the word after `offset`.
+fn word_end(text: &str, offset: usize) -> usize {
+    let start = next_char(text, offset);
+
+    match text[start..].find(|c: char| !c.is_whitespace()) {
+        Some(index) => run_end(text, start + index),
+        None => offset,
+    }
+}
+
+// To the start of the word after `offset`.
+fn word_forward(text: &str, offset: usize) -> usize {
+    let class = text[offset..].chars().next().map_or(0, char_class);
+
+    let after = text[offset..]
+        .find(|c| char_class(c) != class)
+        .map_or(text.len(), |index| offset + index);
+
+    text[after..]
+        .find(|c: char| !c.is_whitespace())
+        .map_or(text.len(), |index| after + index)
+}
@@ -5,27 +5,67 @@ mod macros;
 
 mod about;
 mod app;
+mod cli;
+mod color_scheme;
+mod compare;
 mod core;
+mod diff;
 mod egui_winit_wgpu_context;
 mod event;
 mod example;
+mod export;
 mod fonts;
 mod fps_counter;
 mod fs;
+mod fuzzy;
+mod history;
 mod i18n;
+mod keymap;
+mod metadata;
 mod preferences;
+mod project;
+mod shader;
 mod shortcut;
+mod snapshots;
+mod tabs;
 mod ui;
+mod wgsl;
 mod window;
 mod window_icon;
 
 fn main() {
+    #[cfg(windows)]
+    attach_console();
+
     env_logger::init();
 
-    match app::App::new() {
+    let cli = cli::Cli::parse_or_default();
+
+    if let Some(command) = cli.command {
+        std::process::exit(cli::run(command, cli.json));
+    }
+
+    match app::App::new(cli.path) {
         Ok(app) => app.run(),
         Err(err) => {
             log::error!("Failed to initialize WgShadertoy: {}", err);
         }
     }
 }
+
+// The app has no console of its own on Windows, so the output of the CLI, and
+// the logs, would otherwise be lost when started from a terminal. Nothing
+// happens when started otherwise.
+#[cfg(windows)]
+fn attach_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use image::{imageops::FilterType, ColorType, ImageOutputFormat, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor, Read, Write};
+
+// Document metadata is stored as a trailer after the `wgs` data, which readers
+// of the plain format simply ignore. Files without the trailer load with
+// empty metadata, those with a trailer that doesn't read fail to load rather
+// than have it saved over.
+//
+// Layout of the trailer, little-endian:
+//
+// - magic: b"WGSMETA\0"
+// - version: u32
+// - metadata length: u32, followed by the metadata as JSON.
+// - thumbnail length: u32, followed by the thumbnail as PNG. Zero if none.
+const MAGIC: &[u8; 8] = b"WGSMETA\0";
+const VERSION: u32 = 1;
+
+pub const THUMBNAIL_SIZE: u32 = 256;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Metadata {
+    pub author: String,
+    pub description: String,
+    pub license: String,
+    pub tags: Vec<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub modified: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    pub thumbnail: Option<Thumbnail>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Thumbnail {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl Metadata {
+    // Reads what follows the `wgs` data, up to the end.
+    pub fn read<R>(reader: &mut R) -> Result<Self>
+    where
+        R: Read,
+    {
+        Ok(Self::read_trailer(reader)?.unwrap_or_default())
+    }
+
+    pub fn write<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: Write,
+    {
+        let json = serde_json::to_vec(&self.normalized())?;
+
+        let thumbnail = match &self.thumbnail {
+            Some(thumbnail) => thumbnail.encode_png()?,
+            None => vec![],
+        };
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(json.len() as u32).to_le_bytes())?;
+        writer.write_all(&json)?;
+        writer.write_all(&(thumbnail.len() as u32).to_le_bytes())?;
+        writer.write_all(&thumbnail)?;
+
+        Ok(())
+    }
+
+    // Tags are edited as a single comma separated line, so empty entries
+    // and surrounding spaces are only dropped when the document is written.
+    pub fn normalized(&self) -> Self {
+        let mut metadata = self.clone();
+
+        metadata.tags = self
+            .tags
+            .iter()
+            .map(|tag| tag.trim().to_owned())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+
+        metadata
+    }
+
+    pub fn touch(&mut self) {
+        let now = Utc::now();
+
+        if self.created.is_none() {
+            self.created = Some(now);
+        }
+
+        self.modified = Some(now);
+    }
+
+    fn read_trailer<R>(reader: &mut R) -> Result<Option<Self>>
+    where
+        R: Read,
+    {
+        let mut magic = [0; 8];
+        match reader.read_exact(&mut magic) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+
+        if &magic != MAGIC {
+            bail!("Unknown data after the shader");
+        }
+
+        let _version = read_u32(reader)?;
+
+        let mut json = vec![0; read_u32(reader)? as usize];
+        reader.read_exact(&mut json)?;
+
+        let mut metadata: Self = serde_json::from_slice(&json)?;
+
+        let mut png = vec![0; read_u32(reader)? as usize];
+        reader.read_exact(&mut png)?;
+
+        if !png.is_empty() {
+            metadata.thumbnail = Some(Thumbnail::decode_png(&png)?);
+        }
+
+        Ok(Some(metadata))
+    }
+}
+
+impl Thumbnail {
+    // Scales a captured frame down so it fits into `THUMBNAIL_SIZE`.
+    pub fn from_capture(width: u32, height: u32, data: Vec<u8>) -> Option<Self> {
+        let image = RgbaImage::from_raw(width, height, data)?;
+
+        let scale = (THUMBNAIL_SIZE as f32 / width.max(height) as f32).min(1.0);
+        let image = image::imageops::resize(
+            &image,
+            ((width as f32 * scale) as u32).max(1),
+            ((height as f32 * scale) as u32).max(1),
+            FilterType::Triangle,
+        );
+
+        Some(Self {
+            width: image.width(),
+            height: image.height(),
+            data: image.into_raw(),
+        })
+    }
+
+    pub fn decode_png(bytes: &[u8]) -> Result<Self> {
+        let image = image::load_from_memory(bytes)?.into_rgba8();
+
+        Ok(Self {
+            width: image.width(),
+            height: image.height(),
+            data: image.into_raw(),
+        })
+    }
+
+    pub fn encode_png(&self) -> Result<Vec<u8>> {
+        let mut writer = Cursor::new(vec![]);
+
+        image::write_buffer_with_format(
+            &mut writer,
+            &self.data,
+            self.width,
+            self.height,
+            ColorType::Rgba8,
+            ImageOutputFormat::Png,
+        )?;
+
+        Ok(writer.into_inner())
+    }
+}
+
+fn read_u32<R>(reader: &mut R) -> io::Result<u32>
+where
+    R: Read,
+{
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u32::from_le_bytes(bytes))
+}
@@ -1,3 +1,14 @@
+use crate::{color_scheme::ColorSchemes, fs::config_dir, shortcut::Shortcuts};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{create_dir_all, read_to_string, write};
+
+const PREFERENCES_FILENAME: &str = "preferences.toml";
+
+const ZOOM_RANGE: (f32, f32) = (0.5, 3.0);
+
+const ZOOM_STEP: f32 = 1.1;
+
 #[derive(Clone, Copy, Default, PartialEq)]
 pub enum Theme {
     Light,
@@ -5,8 +16,196 @@ pub enum Theme {
     Dark,
 }
 
-#[derive(Default)]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum CompareLayout {
+    #[default]
+    SideBySide,
+    // Both versions fill the preview, split where the wipe is.
+    Wipe,
+}
+
+// The keys of the editor, on top of its own.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Keymap {
+    #[default]
+    Default,
+    Emacs,
+    Vim,
+}
+
+pub struct ComparePreferences {
+    pub layout: CompareLayout,
+    pub show_diff: bool,
+    // Where the wipe is, from 0 at the left of the preview to 1 at its right.
+    pub wipe: f32,
+}
+
+impl Default for ComparePreferences {
+    fn default() -> Self {
+        Self {
+            layout: CompareLayout::default(),
+            show_diff: true,
+            wipe: 0.5,
+        }
+    }
+}
+
+pub struct EditorPreferences {
+    pub auto_close: bool,
+    pub auto_indent: bool,
+    // A system font, egui's monospace font when none.
+    pub font_family: Option<String>,
+    // In points, before zooming.
+    pub font_size: f32,
+    pub format_on_save: bool,
+    pub indent_width: usize,
+    pub keymap: Keymap,
+    // A factor on the font's own line height.
+    pub line_height: f32,
+    // A factor on the font size, changed with Ctrl+= and Ctrl+-.
+    pub zoom: f32,
+}
+
+impl Default for EditorPreferences {
+    fn default() -> Self {
+        Self {
+            auto_close: true,
+            auto_indent: true,
+            font_family: None,
+            font_size: 16.0,
+            format_on_save: false,
+            indent_width: 4,
+            keymap: Keymap::default(),
+            line_height: 1.0,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl EditorPreferences {
+    // The font size, zoomed.
+    pub fn font_size(&self) -> f32 {
+        self.font_size * self.zoom
+    }
+
+    pub fn indent(&self) -> String {
+        " ".repeat(self.indent_width)
+    }
+
+    pub fn zoom_in(&mut self) {
+        self.zoom = (self.zoom * ZOOM_STEP).min(ZOOM_RANGE.1);
+    }
+
+    pub fn zoom_out(&mut self) {
+        self.zoom = (self.zoom / ZOOM_STEP).max(ZOOM_RANGE.0);
+    }
+}
+
 pub struct Preferences {
+    pub color_schemes: ColorSchemes,
+    pub compare: ComparePreferences,
+    pub editor: EditorPreferences,
     pub record_fps: bool,
+    pub shortcuts: Shortcuts,
     pub theme: Theme,
+    // A factor on the OS scale factor, for the whole window.
+    pub ui_scale: f32,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            color_schemes: ColorSchemes::default(),
+            compare: ComparePreferences::default(),
+            editor: EditorPreferences::default(),
+            record_fps: false,
+            shortcuts: Shortcuts::default(),
+            theme: Theme::default(),
+            ui_scale: 1.0,
+        }
+    }
+}
+
+impl Preferences {
+    // The defaults, with those saved by the user in place. Shortcuts and
+    // color schemes are loaded on their own.
+    pub fn load() -> Result<Self> {
+        let mut preferences = Self::default();
+
+        let path = config_dir()
+            .context("No config directory")?
+            .join(PREFERENCES_FILENAME);
+
+        if !path.is_file() {
+            return Ok(preferences);
+        }
+
+        let saved: SavedPreferences = toml::from_str(&read_to_string(path)?)?;
+
+        let editor = &mut preferences.editor;
+        editor.auto_close = saved.auto_close;
+        editor.auto_indent = saved.auto_indent;
+        editor.font_family = saved.font_family;
+        editor.font_size = saved.font_size;
+        editor.format_on_save = saved.format_on_save;
+        editor.indent_width = saved.indent_width;
+        editor.line_height = saved.line_height;
+        editor.zoom = saved.zoom.clamp(ZOOM_RANGE.0, ZOOM_RANGE.1);
+
+        preferences.ui_scale = saved.ui_scale;
+
+        Ok(preferences)
+    }
+
+    // Those kept across launches, to save when they change.
+    pub fn saved(&self) -> SavedPreferences {
+        let editor = &self.editor;
+
+        SavedPreferences {
+            auto_close: editor.auto_close,
+            auto_indent: editor.auto_indent,
+            font_family: editor.font_family.clone(),
+            font_size: editor.font_size,
+            format_on_save: editor.format_on_save,
+            indent_width: editor.indent_width,
+            line_height: editor.line_height,
+            ui_scale: self.ui_scale,
+            zoom: editor.zoom,
+        }
+    }
+}
+
+// Missing ones, e.g. from an older version, are left as the defaults.
+#[derive(Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct SavedPreferences {
+    auto_close: bool,
+    auto_indent: bool,
+    font_family: Option<String>,
+    font_size: f32,
+    format_on_save: bool,
+    indent_width: usize,
+    line_height: f32,
+    ui_scale: f32,
+    zoom: f32,
+}
+
+impl Default for SavedPreferences {
+    fn default() -> Self {
+        Preferences::default().saved()
+    }
+}
+
+impl SavedPreferences {
+    pub fn save(&self) -> Result<()> {
+        let dir = config_dir().context("No config directory")?;
+
+        create_dir_all(&dir)?;
+        write(
+            dir.join(PREFERENCES_FILENAME),
+            toml::to_string_pretty(self)?,
+        )?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,247 @@
+use crate::metadata::{Metadata, Thumbnail};
+use anyhow::{bail, Context, Result};
+use image::ColorType;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, create_dir_all, read, read_to_string, remove_file, write},
+    path::{Component, Path, PathBuf},
+};
+use wgs_core::WgsData;
+
+// An unpacked alternative to the binary `wgs` format, friendly to version control:
+//
+// - wgshadertoy.toml: the manifest.
+// - main.wgsl: the fragment source.
+// - textures/channel{n}.png: one lossless image per texture.
+// - thumbnail.png: the preview image, if any.
+//
+// The manifest leaves out when the document was modified, which would change
+// on every save, the source file's own time standing in for it.
+const FORMAT_VERSION: u32 = 1;
+const MANIFEST_FILENAME: &str = "wgshadertoy.toml";
+const SOURCE_FILENAME: &str = "main.wgsl";
+const TEXTURES_DIRNAME: &str = "textures";
+const THUMBNAIL_FILENAME: &str = "thumbnail.png";
+
+#[derive(Deserialize, Serialize)]
+struct Manifest {
+    format_version: u32,
+    name: String,
+    source: String,
+    #[serde(default)]
+    textures: Vec<String>,
+    #[serde(default)]
+    metadata: Metadata,
+}
+
+pub fn load_project<P>(dir: P) -> Result<(WgsData, Metadata)>
+where
+    P: AsRef<Path>,
+{
+    let dir = dir.as_ref();
+
+    let manifest = read_to_string(dir.join(MANIFEST_FILENAME))
+        .with_context(|| format!("Missing {}", MANIFEST_FILENAME))?;
+    let manifest: Manifest = toml::from_str(&manifest)?;
+
+    if manifest.format_version > FORMAT_VERSION {
+        bail!("Unsupported format version {}", manifest.format_version);
+    }
+
+    let source_path = project_path(dir, &manifest.source)?;
+
+    let frag =
+        read_to_string(&source_path).with_context(|| format!("Missing {}", manifest.source))?;
+
+    let mut wgs = WgsData::default();
+    wgs.set_name(&manifest.name);
+    wgs.set_frag(&frag);
+
+    for texture in &manifest.textures {
+        let image = image::open(project_path(dir, texture)?)
+            .with_context(|| format!("Failed to open {}", texture))?
+            .into_rgba8();
+
+        wgs.add_texture(image.width(), image.height(), image.into_vec());
+    }
+
+    let mut metadata = manifest.metadata;
+    metadata.modified = fs::metadata(&source_path)?.modified().ok().map(Into::into);
+
+    let thumbnail_path = dir.join(THUMBNAIL_FILENAME);
+    if thumbnail_path.is_file() {
+        metadata.thumbnail = Some(Thumbnail::decode_png(&read(thumbnail_path)?)?);
+    }
+
+    Ok((wgs, metadata))
+}
+
+pub fn save_project<P>(dir: P, wgs: &WgsData, metadata: &Metadata) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    let dir = dir.as_ref();
+
+    create_dir_all(dir)?;
+
+    let textures = wgs.textures_ref();
+
+    let mut manifest = Manifest {
+        format_version: FORMAT_VERSION,
+        name: wgs.name(),
+        source: SOURCE_FILENAME.to_owned(),
+        textures: vec![],
+        metadata: Metadata {
+            modified: None,
+            ..metadata.normalized()
+        },
+    };
+
+    if !textures.is_empty() {
+        create_dir_all(dir.join(TEXTURES_DIRNAME))?;
+    }
+
+    for (index, texture) in textures.iter().enumerate() {
+        let path = texture_path(index);
+
+        image::save_buffer(
+            dir.join(&path),
+            &texture.data,
+            texture.width,
+            texture.height,
+            ColorType::Rgba8,
+        )?;
+
+        manifest.textures.push(path);
+    }
+
+    // Drop images left behind by textures removed since the last save.
+    let mut index = textures.len();
+    while dir.join(texture_path(index)).is_file() {
+        remove_file(dir.join(texture_path(index)))?;
+
+        index += 1;
+    }
+
+    match &metadata.thumbnail {
+        Some(thumbnail) => write(dir.join(THUMBNAIL_FILENAME), thumbnail.encode_png()?)?,
+        None => {
+            if dir.join(THUMBNAIL_FILENAME).is_file() {
+                remove_file(dir.join(THUMBNAIL_FILENAME))?;
+            }
+        }
+    }
+
+    write(dir.join(SOURCE_FILENAME), wgs.frag())?;
+    write(
+        dir.join(MANIFEST_FILENAME),
+        toml::to_string_pretty(&manifest)?,
+    )?;
+
+    log::info!("Saving project directory: {:?}", dir);
+
+    Ok(())
+}
+
+// A path of the manifest within the project, which a shared project can't
+// lead out of.
+fn project_path(dir: &Path, path: &str) -> Result<PathBuf> {
+    let is_within = Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::CurDir | Component::Normal(_)));
+
+    if !is_within {
+        bail!("{} is outside the project", path);
+    }
+
+    Ok(dir.join(path))
+}
+
+fn texture_path(index: usize) -> String {
+    format!("{}/channel{}.png", TEXTURES_DIRNAME, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("wgshadertoy-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = temp_dir("project");
+
+        let mut wgs = WgsData::new("Rings", "fn main_image() {}\n");
+        wgs.add_texture(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 128]);
+
+        let mut metadata = Metadata {
+            author: "Someone".to_owned(),
+            tags: vec!["rings".to_owned(), " ".to_owned()],
+            thumbnail: Some(Thumbnail {
+                width: 1,
+                height: 1,
+                data: vec![1, 2, 3, 255],
+            }),
+            ..Default::default()
+        };
+        metadata.touch();
+
+        save_project(&dir, &wgs, &metadata).unwrap();
+
+        let manifest = read_to_string(dir.join(MANIFEST_FILENAME)).unwrap();
+
+        assert!(!manifest.contains("modified"));
+
+        let (loaded, loaded_metadata) = load_project(&dir).unwrap();
+
+        assert_eq!(loaded.name(), "Rings");
+        assert_eq!(loaded.frag(), "fn main_image() {}\n");
+        assert_eq!(loaded.textures_ref().len(), 1);
+        assert_eq!(loaded.textures_ref()[0].width, 2);
+        assert_eq!(loaded.textures_ref()[0].data, wgs.textures_ref()[0].data);
+        assert_eq!(loaded_metadata.author, "Someone");
+        assert_eq!(loaded_metadata.tags, ["rings"]);
+        assert_eq!(loaded_metadata.created, metadata.created);
+        assert!(loaded_metadata.modified.is_some());
+        assert_eq!(
+            loaded_metadata.thumbnail.map(|thumbnail| thumbnail.data),
+            Some(vec![1, 2, 3, 255])
+        );
+
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn paths_outside() {
+        let dir = temp_dir("outside");
+
+        for (source, textures) in [
+            ("../main.wgsl", ""),
+            ("/etc/passwd", ""),
+            ("main.wgsl", "\"textures/../../secret.png\""),
+        ] {
+            create_dir_all(&dir).unwrap();
+            write(dir.join(SOURCE_FILENAME), "").unwrap();
+            write(
+                dir.join(MANIFEST_FILENAME),
+                format!(
+                    "format_version = 1\nname = \"\"\nsource = \"{}\"\ntextures = [{}]\n",
+                    source, textures
+                ),
+            )
+            .unwrap();
+
+            let err = load_project(&dir).err().unwrap();
+
+            assert!(err.to_string().contains("outside the project"), "{}", err);
+        }
+
+        remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+use naga::{
+    valid::{Capabilities, ValidationFlags, Validator},
+    SourceLocation,
+};
+use std::error::Error;
+
+// The WGSL that surrounds the user's `main_image` function.
+//
+// This mirrors the layout used by the runtime, so that a fragment source
+// written in the editor can be turned into a standalone shader module.
+pub const UNIFORM_STRUCT: &str = "struct Uniform {
+    cursor: vec2<f32>,
+    mouse_down: u32,
+    mouse_press: vec2<f32>,
+    mouse_release: vec2<f32>,
+    resolution: vec2<f32>,
+    time: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> u: Uniform;
+";
+
+// The fields of `UNIFORM_STRUCT` and their types.
+pub const UNIFORM_FIELDS: [(&str, &str); 6] = [
+    ("cursor", "vec2<f32>"),
+    ("mouse_down", "u32"),
+    ("mouse_press", "vec2<f32>"),
+    ("mouse_release", "vec2<f32>"),
+    ("resolution", "vec2<f32>"),
+    ("time", "f32"),
+];
+
+pub const IMAGE_FN_SIGNATURE: &str =
+    "image(t: texture_2d<f32>, spl: sampler, uv: vec2<f32>) -> vec4<f32>";
+
+const IMAGE_FN: &str = "fn image(t: texture_2d<f32>, spl: sampler, uv: vec2<f32>) -> vec4<f32> {
+    return textureSample(t, spl, vec2(uv.x, 1.0 - uv.y));
+}
+";
+
+const VERTEX_STAGE: &str = "@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    var positions = array<vec2<f32>, 3>(
+        vec2(-1.0, -1.0),
+        vec2(3.0, -1.0),
+        vec2(-1.0, 3.0),
+    );
+
+    return vec4(positions[vertex_index], 0.0, 1.0);
+}
+";
+
+const FRAGMENT_STAGE: &str = "@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let frag_coord = vec2(position.x, u.resolution.y - position.y);
+
+    return main_image(vec4(0.0, 0.0, 0.0, 1.0), frag_coord);
+}
+";
+
+pub fn concat_shader(frag: &str, texture_count: usize) -> String {
+    format!(
+        "{}\n{}\n{}\n{}",
+        prelude(texture_count),
+        frag.trim_end(),
+        VERTEX_STAGE,
+        FRAGMENT_STAGE
+    )
+}
+
+pub fn prelude(texture_count: usize) -> String {
+    format!(
+        "{}\n{}{}",
+        UNIFORM_STRUCT,
+        texture_bindings(texture_count),
+        IMAGE_FN
+    )
+}
+
+pub fn texture_bindings(texture_count: usize) -> String {
+    let mut bindings = String::new();
+
+    // Every texture lives in its own bind group, right after the uniform one.
+    for index in 0..texture_count {
+        bindings.push_str(&format!(
+            "@group({group}) @binding(0)\nvar texture{index}: texture_2d<f32>;\n\n\
+             @group({group}) @binding(1)\nvar sampler{index}: sampler;\n\n",
+            group = index + 1,
+            index = index
+        ));
+    }
+
+    bindings
+}
+
+// Line of the concatenated shader where the user's fragment source starts, 1-based.
+pub fn frag_line_offset(texture_count: usize) -> usize {
+    prelude(texture_count).lines().count() + 2
+}
+
+pub fn validate(frag: &str, texture_count: usize) -> Result<(), Diagnostic> {
+    let source = concat_shader(frag, texture_count);
+
+    let module = naga::front::wgsl::parse_str(&source).map_err(|err| {
+        Diagnostic::new(
+            err.message(),
+            err.location(&source),
+            err.emit_to_string(&source),
+            texture_count,
+        )
+    })?;
+
+    Validator::new(ValidationFlags::all(), Capabilities::all())
+        .validate(&module)
+        .map_err(|err| {
+            Diagnostic::new(
+                &error_chain(err.as_inner()),
+                err.location(&source),
+                err.emit_to_string(&source),
+                texture_count,
+            )
+        })?;
+
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub message: String,
+    // Position in the user's fragment source, 1-based.
+    // `None` when the error lies in the generated prelude.
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub report: String,
+}
+
+impl Diagnostic {
+    fn new(
+        message: &str,
+        location: Option<SourceLocation>,
+        report: String,
+        texture_count: usize,
+    ) -> Self {
+        let offset = frag_line_offset(texture_count);
+
+        let (line, column) = match location {
+            Some(location) if location.line_number as usize >= offset => (
+                Some(location.line_number as usize - offset + 1),
+                Some(location.line_position as usize),
+            ),
+            _ => (None, None),
+        };
+
+        Self {
+            message: message.to_owned(),
+            line,
+            column,
+            report,
+        }
+    }
+}
+
+fn error_chain(err: &dyn Error) -> String {
+    let mut message = err.to_string();
+
+    let mut source = err.source();
+    while let Some(err) = source {
+        message.push_str(": ");
+        message.push_str(&err.to_string());
+
+        source = err.source();
+    }
+
+    message
+}
@@ -1,21 +1,584 @@
-use egui::{Key, KeyboardShortcut, Modifiers};
+use crate::fs::config_dir;
+use anyhow::{Context as _, Result};
+use egui::{Context, InputState, Key, KeyboardShortcut, Modifiers};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{create_dir_all, read_to_string, write},
+};
 
-pub struct Shortcut {
-    pub app_quit: KeyboardShortcut,
-    pub file_new: KeyboardShortcut,
-    pub file_open: KeyboardShortcut,
-    pub file_save: KeyboardShortcut,
-    pub file_save_as: KeyboardShortcut,
+const SHORTCUTS_FILENAME: &str = "shortcuts.toml";
+
+// Every key egui reports, to read a key back from its name.
+const KEYS: &[Key] = &[
+    Key::ArrowDown,
+    Key::ArrowLeft,
+    Key::ArrowRight,
+    Key::ArrowUp,
+    Key::Escape,
+    Key::Tab,
+    Key::Backspace,
+    Key::Enter,
+    Key::Space,
+    Key::Insert,
+    Key::Delete,
+    Key::Home,
+    Key::End,
+    Key::PageUp,
+    Key::PageDown,
+    Key::Minus,
+    Key::PlusEquals,
+    Key::Num0,
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+];
+
+// A command that can be bound to a shortcut.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Command {
+    AppAbout,
+    AppCommandPalette,
+    AppQuit,
+    CompareSaved,
+    CompareStop,
+    EditCommentBlock,
+    EditCommentLine,
+    EditComplete,
+    EditDeleteLine,
+    EditDuplicateLine,
+    EditFind,
+    EditFindReferences,
+    EditFormat,
+    EditGoToDefinition,
+    EditIndent,
+    EditMoveLineDown,
+    EditMoveLineUp,
+    EditOutdent,
+    EditRedo,
+    EditRename,
+    EditReplace,
+    EditUndo,
+    FileConvertProjectToWgs,
+    FileConvertWgsToProject,
+    FileExportRustProject,
+    FileExportWgslFragment,
+    FileExportWgslStandalone,
+    FileImportWgsl,
+    FileNew,
+    FileOpen,
+    FileOpenProject,
+    FileSave,
+    FileSaveAs,
+    FileSaveProjectAs,
+    ShaderCaptureImage,
+    ShaderCaptureThumbnail,
+    ShaderCompile,
+    ShaderPause,
+    ShaderRestart,
+    SnapshotHistory,
+    SnapshotTake,
+    TabClose,
+    TabNext,
+    TabPrevious,
+    TextureAdd,
+    ViewFoldAll,
+    ViewOutline,
+    ViewUnfoldAll,
+    ViewZoomIn,
+    ViewZoomOut,
+    ViewZoomReset,
+}
+
+impl Command {
+    pub const ALL: [Command; 51] = [
+        Command::AppAbout,
+        Command::AppCommandPalette,
+        Command::AppQuit,
+        Command::CompareSaved,
+        Command::CompareStop,
+        Command::EditCommentBlock,
+        Command::EditCommentLine,
+        Command::EditComplete,
+        Command::EditDeleteLine,
+        Command::EditDuplicateLine,
+        Command::EditFind,
+        Command::EditFindReferences,
+        Command::EditFormat,
+        Command::EditGoToDefinition,
+        Command::EditIndent,
+        Command::EditMoveLineDown,
+        Command::EditMoveLineUp,
+        Command::EditOutdent,
+        Command::EditRedo,
+        Command::EditRename,
+        Command::EditReplace,
+        Command::EditUndo,
+        Command::FileConvertProjectToWgs,
+        Command::FileConvertWgsToProject,
+        Command::FileExportRustProject,
+        Command::FileExportWgslFragment,
+        Command::FileExportWgslStandalone,
+        Command::FileImportWgsl,
+        Command::FileNew,
+        Command::FileOpen,
+        Command::FileOpenProject,
+        Command::FileSave,
+        Command::FileSaveAs,
+        Command::FileSaveProjectAs,
+        Command::ShaderCaptureImage,
+        Command::ShaderCaptureThumbnail,
+        Command::ShaderCompile,
+        Command::ShaderPause,
+        Command::ShaderRestart,
+        Command::SnapshotHistory,
+        Command::SnapshotTake,
+        Command::TabClose,
+        Command::TabNext,
+        Command::TabPrevious,
+        Command::TextureAdd,
+        Command::ViewFoldAll,
+        Command::ViewOutline,
+        Command::ViewUnfoldAll,
+        Command::ViewZoomIn,
+        Command::ViewZoomOut,
+        Command::ViewZoomReset,
+    ];
+
+    pub fn default_shortcut(self) -> Option<KeyboardShortcut> {
+        let (modifiers, key) = match self {
+            Command::AppAbout => (Modifiers::NONE, Key::F1),
+            Command::AppCommandPalette => (Modifiers::CTRL | Modifiers::SHIFT, Key::P),
+            Command::AppQuit => (Modifiers::CTRL, Key::Q),
+            Command::EditCommentBlock => (Modifiers::SHIFT | Modifiers::ALT, Key::A),
+            // egui has no key for `/`.
+            Command::EditCommentLine => (Modifiers::CTRL, Key::K),
+            Command::EditComplete => (Modifiers::CTRL, Key::Space),
+            Command::EditDeleteLine => (Modifiers::CTRL | Modifiers::SHIFT, Key::K),
+            Command::EditDuplicateLine => (Modifiers::SHIFT | Modifiers::ALT, Key::ArrowDown),
+            Command::EditFind => (Modifiers::CTRL, Key::F),
+            Command::EditFindReferences => (Modifiers::SHIFT, Key::F12),
+            Command::EditFormat => (Modifiers::SHIFT | Modifiers::ALT, Key::F),
+            Command::EditGoToDefinition => (Modifiers::NONE, Key::F12),
+            Command::EditIndent => (Modifiers::NONE, Key::Tab),
+            Command::EditMoveLineDown => (Modifiers::ALT, Key::ArrowDown),
+            Command::EditMoveLineUp => (Modifiers::ALT, Key::ArrowUp),
+            Command::EditOutdent => (Modifiers::SHIFT, Key::Tab),
+            Command::EditRedo => (Modifiers::CTRL | Modifiers::SHIFT, Key::Z),
+            Command::EditRename => (Modifiers::NONE, Key::F2),
+            Command::EditReplace => (Modifiers::CTRL, Key::H),
+            Command::EditUndo => (Modifiers::CTRL, Key::Z),
+            Command::FileNew => (Modifiers::CTRL, Key::N),
+            Command::FileOpen => (Modifiers::CTRL, Key::O),
+            Command::FileOpenProject => (Modifiers::CTRL | Modifiers::SHIFT, Key::O),
+            Command::FileSave => (Modifiers::CTRL, Key::S),
+            Command::FileSaveAs => (Modifiers::CTRL | Modifiers::SHIFT, Key::S),
+            Command::ShaderCaptureImage => (Modifiers::CTRL | Modifiers::SHIFT, Key::C),
+            Command::ShaderCompile => (Modifiers::NONE, Key::F5),
+            Command::ShaderPause => (Modifiers::NONE, Key::F6),
+            Command::ShaderRestart => (Modifiers::CTRL, Key::F5),
+            Command::SnapshotTake => (Modifiers::CTRL | Modifiers::ALT, Key::S),
+            Command::TabClose => (Modifiers::CTRL, Key::W),
+            Command::TabNext => (Modifiers::CTRL, Key::PageDown),
+            Command::TabPrevious => (Modifiers::CTRL, Key::PageUp),
+            Command::ViewZoomIn => (Modifiers::CTRL, Key::PlusEquals),
+            Command::ViewZoomOut => (Modifiers::CTRL, Key::Minus),
+            Command::ViewZoomReset => (Modifiers::CTRL, Key::Num0),
+            _ => return None,
+        };
+
+        Some(KeyboardShortcut::new(modifiers, key))
+    }
+
+    // Those the editor runs itself while it has the focus.
+    pub fn is_editor(self) -> bool {
+        matches!(
+            self,
+            Command::EditCommentBlock
+                | Command::EditCommentLine
+                | Command::EditComplete
+                | Command::EditDeleteLine
+                | Command::EditDuplicateLine
+                | Command::EditFind
+                | Command::EditFindReferences
+                | Command::EditFormat
+                | Command::EditGoToDefinition
+                | Command::EditIndent
+                | Command::EditMoveLineDown
+                | Command::EditMoveLineUp
+                | Command::EditOutdent
+                | Command::EditRename
+                | Command::EditReplace
+        )
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            Command::AppAbout => fl!("menu_about"),
+            Command::AppCommandPalette => fl!("command_palette"),
+            Command::AppQuit => fl!("menu_quit"),
+            Command::CompareSaved => fl!("command_compare_saved"),
+            Command::CompareStop => fl!("menu_compare_stop"),
+            Command::EditCommentBlock => fl!("menu_comment_block"),
+            Command::EditCommentLine => fl!("menu_comment_line"),
+            Command::EditComplete => fl!("command_complete"),
+            Command::EditDeleteLine => fl!("menu_delete_line"),
+            Command::EditDuplicateLine => fl!("menu_duplicate_line"),
+            Command::EditFind => fl!("menu_find"),
+            Command::EditFindReferences => fl!("command_find_references"),
+            Command::EditFormat => fl!("menu_format"),
+            Command::EditGoToDefinition => fl!("command_go_to_definition"),
+            Command::EditIndent => fl!("command_indent"),
+            Command::EditMoveLineDown => fl!("menu_move_line_down"),
+            Command::EditMoveLineUp => fl!("menu_move_line_up"),
+            Command::EditOutdent => fl!("command_outdent"),
+            Command::EditRedo => fl!("menu_redo"),
+            Command::EditRename => fl!("command_rename"),
+            Command::EditReplace => fl!("menu_replace"),
+            Command::EditUndo => fl!("menu_undo"),
+            Command::FileConvertProjectToWgs => fl!("command_convert_project_to_wgs"),
+            Command::FileConvertWgsToProject => fl!("command_convert_wgs_to_project"),
+            Command::FileExportRustProject => fl!("menu_export_rust_project"),
+            Command::FileExportWgslFragment => fl!("command_export_wgsl_fragment"),
+            Command::FileExportWgslStandalone => fl!("command_export_wgsl_standalone"),
+            Command::FileImportWgsl => fl!("menu_import_wgsl"),
+            Command::FileNew => fl!("menu_new"),
+            Command::FileOpen => fl!("menu_open"),
+            Command::FileOpenProject => fl!("menu_open_project"),
+            Command::FileSave => fl!("menu_save"),
+            Command::FileSaveAs => fl!("menu_save_as"),
+            Command::FileSaveProjectAs => fl!("menu_save_project_as"),
+            Command::ShaderCaptureImage => fl!("command_capture_image"),
+            Command::ShaderCaptureThumbnail => fl!("command_capture_thumbnail"),
+            Command::ShaderCompile => fl!("command_compile"),
+            Command::ShaderPause => fl!("command_pause"),
+            Command::ShaderRestart => fl!("command_restart"),
+            Command::SnapshotHistory => fl!("menu_snapshot_history"),
+            Command::SnapshotTake => fl!("menu_take_snapshot"),
+            Command::TabClose => fl!("menu_close_tab"),
+            Command::TabNext => fl!("command_tab_next"),
+            Command::TabPrevious => fl!("command_tab_previous"),
+            Command::TextureAdd => fl!("command_texture_add"),
+            Command::ViewFoldAll => fl!("menu_fold_all"),
+            Command::ViewOutline => fl!("menu_outline"),
+            Command::ViewUnfoldAll => fl!("menu_unfold_all"),
+            Command::ViewZoomIn => fl!("menu_zoom_in"),
+            Command::ViewZoomOut => fl!("menu_zoom_out"),
+            Command::ViewZoomReset => fl!("menu_zoom_reset"),
+        }
+    }
+
+    // Names the command in the shortcuts file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Command::AppAbout => "app_about",
+            Command::AppCommandPalette => "app_command_palette",
+            Command::AppQuit => "app_quit",
+            Command::CompareSaved => "compare_saved",
+            Command::CompareStop => "compare_stop",
+            Command::EditCommentBlock => "edit_comment_block",
+            Command::EditCommentLine => "edit_comment_line",
+            Command::EditComplete => "edit_complete",
+            Command::EditDeleteLine => "edit_delete_line",
+            Command::EditDuplicateLine => "edit_duplicate_line",
+            Command::EditFind => "edit_find",
+            Command::EditFindReferences => "edit_find_references",
+            Command::EditFormat => "edit_format",
+            Command::EditGoToDefinition => "edit_go_to_definition",
+            Command::EditIndent => "edit_indent",
+            Command::EditMoveLineDown => "edit_move_line_down",
+            Command::EditMoveLineUp => "edit_move_line_up",
+            Command::EditOutdent => "edit_outdent",
+            Command::EditRedo => "edit_redo",
+            Command::EditRename => "edit_rename",
+            Command::EditReplace => "edit_replace",
+            Command::EditUndo => "edit_undo",
+            Command::FileConvertProjectToWgs => "file_convert_project_to_wgs",
+            Command::FileConvertWgsToProject => "file_convert_wgs_to_project",
+            Command::FileExportRustProject => "file_export_rust_project",
+            Command::FileExportWgslFragment => "file_export_wgsl_fragment",
+            Command::FileExportWgslStandalone => "file_export_wgsl_standalone",
+            Command::FileImportWgsl => "file_import_wgsl",
+            Command::FileNew => "file_new",
+            Command::FileOpen => "file_open",
+            Command::FileOpenProject => "file_open_project",
+            Command::FileSave => "file_save",
+            Command::FileSaveAs => "file_save_as",
+            Command::FileSaveProjectAs => "file_save_project_as",
+            Command::ShaderCaptureImage => "shader_capture_image",
+            Command::ShaderCaptureThumbnail => "shader_capture_thumbnail",
+            Command::ShaderCompile => "shader_compile",
+            Command::ShaderPause => "shader_pause",
+            Command::ShaderRestart => "shader_restart",
+            Command::SnapshotHistory => "snapshot_history",
+            Command::SnapshotTake => "snapshot_take",
+            Command::TabClose => "tab_close",
+            Command::TabNext => "tab_next",
+            Command::TabPrevious => "tab_previous",
+            Command::TextureAdd => "texture_add",
+            Command::ViewFoldAll => "view_fold_all",
+            Command::ViewOutline => "view_outline",
+            Command::ViewUnfoldAll => "view_unfold_all",
+            Command::ViewZoomIn => "view_zoom_in",
+            Command::ViewZoomOut => "view_zoom_out",
+            Command::ViewZoomReset => "view_zoom_reset",
+        }
+    }
+}
+
+// The shortcut bound to each command, those left out having none.
+#[derive(Clone)]
+pub struct Shortcuts {
+    bindings: HashMap<Command, KeyboardShortcut>,
 }
 
-impl Shortcut {
-    pub fn new() -> Self {
+impl Default for Shortcuts {
+    fn default() -> Self {
         Self {
-            app_quit: KeyboardShortcut::new(Modifiers::CTRL, Key::Q),
-            file_new: KeyboardShortcut::new(Modifiers::CTRL, Key::N),
-            file_open: KeyboardShortcut::new(Modifiers::CTRL, Key::O),
-            file_save: KeyboardShortcut::new(Modifiers::CTRL, Key::S),
-            file_save_as: KeyboardShortcut::new(Modifiers::CTRL | Modifiers::SHIFT, Key::S),
+            bindings: Command::ALL
+                .into_iter()
+                .filter_map(|command| Some((command, command.default_shortcut()?)))
+                .collect(),
+        }
+    }
+}
+
+impl Shortcuts {
+    // The defaults, with the shortcuts changed by the user in place.
+    pub fn load() -> Result<Self> {
+        let mut shortcuts = Self::default();
+
+        let path = config_dir()
+            .context("No config directory")?
+            .join(SHORTCUTS_FILENAME);
+
+        if !path.is_file() {
+            return Ok(shortcuts);
+        }
+
+        let changed: BTreeMap<String, String> = toml::from_str(&read_to_string(path)?)?;
+
+        for (name, text) in changed {
+            let Some(command) = Command::ALL
+                .into_iter()
+                .find(|command| command.name() == name)
+            else {
+                log::warn!("Unknown command in shortcuts: {}", name);
+
+                continue;
+            };
+
+            if text.is_empty() {
+                shortcuts.set(command, None);
+            } else if let Some(shortcut) = parse(&text) {
+                shortcuts.set(command, Some(shortcut));
+            } else {
+                log::warn!("Invalid shortcut for {}: {}", name, text);
+            }
+        }
+
+        Ok(shortcuts)
+    }
+
+    // Only the shortcuts that differ from the defaults are written, an empty
+    // one for a command left without.
+    pub fn save(&self) -> Result<()> {
+        let changed: BTreeMap<&str, String> = Command::ALL
+            .into_iter()
+            .filter(|command| !self.is_default(*command))
+            .map(|command| {
+                (
+                    command.name(),
+                    self.get(command).map(to_string).unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        let dir = config_dir().context("No config directory")?;
+
+        create_dir_all(&dir)?;
+        write(
+            dir.join(SHORTCUTS_FILENAME),
+            toml::to_string_pretty(&changed)?,
+        )?;
+
+        Ok(())
+    }
+
+    // The command bound to a shortcut, other than `command`.
+    pub fn conflict(&self, command: Command, shortcut: KeyboardShortcut) -> Option<Command> {
+        Command::ALL
+            .into_iter()
+            .find(|other| *other != command && self.get(*other) == Some(shortcut))
+    }
+
+    pub fn consume(&self, input: &mut InputState, command: Command) -> bool {
+        self.get(command)
+            .is_some_and(|shortcut| input.consume_shortcut(&shortcut))
+    }
+
+    // Empty for a command without a shortcut, to show next to it in menus.
+    pub fn format(&self, ctx: &Context, command: Command) -> String {
+        self.get(command)
+            .map(|shortcut| ctx.format_shortcut(&shortcut))
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, command: Command) -> Option<KeyboardShortcut> {
+        self.bindings.get(&command).copied()
+    }
+
+    pub fn is_default(&self, command: Command) -> bool {
+        self.get(command) == command.default_shortcut()
+    }
+
+    pub fn set(&mut self, command: Command, shortcut: Option<KeyboardShortcut>) {
+        match shortcut {
+            Some(shortcut) => self.bindings.insert(command, shortcut),
+            None => self.bindings.remove(&command),
+        };
+    }
+}
+
+// The shortcut of a key pressed. egui sets `command` along with Ctrl, or Cmd
+// on macOS, which shortcuts leave out to compare equal to the defaults.
+pub fn pressed(modifiers: Modifiers, key: Key) -> KeyboardShortcut {
+    KeyboardShortcut::new(
+        Modifiers {
+            command: false,
+            ..modifiers
+        },
+        key,
+    )
+}
+
+// E.g. `Ctrl+Shift+S`, the same on every platform, unlike the text egui
+// formats them to.
+fn to_string(shortcut: KeyboardShortcut) -> String {
+    let modifiers = shortcut.modifiers;
+
+    let mut names: Vec<&str> = [
+        (modifiers.ctrl, "Ctrl"),
+        (modifiers.alt, "Alt"),
+        (modifiers.shift, "Shift"),
+        (modifiers.mac_cmd, "Cmd"),
+    ]
+    .into_iter()
+    .filter_map(|(is_down, name)| is_down.then_some(name))
+    .collect();
+
+    names.push(shortcut.key.name());
+
+    names.join("+")
+}
+
+fn parse(text: &str) -> Option<KeyboardShortcut> {
+    let mut names: Vec<&str> = text.split('+').map(str::trim).collect();
+
+    let key = names.pop()?;
+    let key = KEYS
+        .iter()
+        .copied()
+        .find(|k| k.name().eq_ignore_ascii_case(key))?;
+
+    let mut modifiers = Modifiers::NONE;
+
+    for name in names {
+        match name.to_ascii_lowercase().as_str() {
+            "alt" => modifiers.alt = true,
+            "cmd" => modifiers.mac_cmd = true,
+            "ctrl" => modifiers.ctrl = true,
+            "shift" => modifiers.shift = true,
+            _ => return None,
+        }
+    }
+
+    Some(KeyboardShortcut::new(modifiers, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, to_string, Command, Shortcuts};
+    use egui::{Key, KeyboardShortcut, Modifiers};
+
+    #[test]
+    fn round_trip() {
+        for command in Command::ALL {
+            if let Some(shortcut) = Shortcuts::default().get(command) {
+                assert_eq!(parse(&to_string(shortcut)), Some(shortcut));
+            }
+        }
+
+        assert_eq!(
+            parse("ctrl + shift + plus"),
+            Some(KeyboardShortcut::new(
+                Modifiers::CTRL | Modifiers::SHIFT,
+                Key::PlusEquals
+            ))
+        );
+        assert_eq!(parse("Hyper+A"), None);
+        assert_eq!(parse("Ctrl+"), None);
+    }
+
+    #[test]
+    fn defaults_conflict_free() {
+        let shortcuts = Shortcuts::default();
+
+        for command in Command::ALL {
+            if let Some(shortcut) = shortcuts.get(command) {
+                assert_eq!(shortcuts.conflict(command, shortcut), None, "{:?}", command);
+            }
         }
     }
 }
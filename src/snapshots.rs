@@ -0,0 +1,289 @@
+use crate::{
+    fs::{data_dir, encode_wgs, load_wgs_from_file},
+    history::Texture,
+    metadata::{Metadata, Thumbnail},
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::{
+    fs::{create_dir_all, read_dir, remove_file, write},
+    path::{Path, PathBuf},
+};
+use wgs_core::WgsData;
+
+// Automatic snapshots beyond this many are dropped, oldest first.
+const MAX_AUTOMATIC: usize = 50;
+
+pub struct Snapshot {
+    pub created: DateTime<Utc>,
+    pub frag: String,
+    pub id: i64,
+    // Given to those taken by hand. Automatic ones have none and are the only
+    // ones dropped.
+    pub label: Option<String>,
+    pub textures: Vec<Texture>,
+    pub thumbnail: Option<Thumbnail>,
+}
+
+// The snapshots of a document. Those of a saved document are stored with
+// the app's data, as `wgs` files named after their id, the others are only
+// kept in memory until it's saved.
+#[derive(Default)]
+pub struct Snapshots {
+    // Where the snapshots are stored, nothing when the document isn't saved.
+    dir: Option<PathBuf>,
+    document: Option<PathBuf>,
+    snapshots: Vec<Snapshot>,
+}
+
+impl Snapshots {
+    pub fn load(document: Option<&Path>) -> Self {
+        let dir = document.and_then(store_dir);
+
+        let mut snapshots = vec![];
+
+        if let Some(entries) = dir.as_ref().and_then(|dir| read_dir(dir).ok()) {
+            for entry in entries.flatten() {
+                match Snapshot::read(&entry.path()) {
+                    Ok(snapshot) => snapshots.push(snapshot),
+                    Err(err) => log::warn!("Failed to read snapshot {:?}: {}", entry.path(), err),
+                }
+            }
+        }
+
+        snapshots.sort_by_key(|snapshot| snapshot.id);
+
+        Self {
+            dir,
+            document: document.map(Path::to_path_buf),
+            snapshots,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[Snapshot] {
+        &self.snapshots
+    }
+
+    pub fn document(&self) -> Option<&Path> {
+        self.document.as_deref()
+    }
+
+    pub fn get(&self, id: i64) -> Option<&Snapshot> {
+        self.snapshots.iter().find(|snapshot| snapshot.id == id)
+    }
+
+    // Records a snapshot, unless it's automatic and nothing changed since the
+    // last one. Returns its id.
+    pub fn record(
+        &mut self,
+        label: Option<String>,
+        frag: &str,
+        textures: Vec<Texture>,
+    ) -> Option<i64> {
+        let unchanged = self
+            .snapshots
+            .last()
+            .is_some_and(|last| last.frag == frag && last.textures == textures);
+
+        if label.is_none() && unchanged {
+            return None;
+        }
+
+        let created = Utc::now();
+
+        // Ids are the time taken, kept unique within a millisecond.
+        let id = created
+            .timestamp_millis()
+            .max(self.snapshots.last().map_or(0, |last| last.id + 1));
+
+        let snapshot = Snapshot {
+            created,
+            frag: frag.to_owned(),
+            id,
+            label,
+            textures,
+            thumbnail: None,
+        };
+
+        self.write(&snapshot);
+        self.snapshots.push(snapshot);
+
+        let automatic = self
+            .snapshots
+            .iter()
+            .filter(|snapshot| snapshot.label.is_none())
+            .count();
+
+        if automatic > MAX_AUTOMATIC {
+            if let Some(index) = self
+                .snapshots
+                .iter()
+                .position(|snapshot| snapshot.label.is_none())
+            {
+                let snapshot = self.snapshots.remove(index);
+                self.delete(&snapshot);
+            }
+        }
+
+        Some(id)
+    }
+
+    pub fn remove(&mut self, id: i64) {
+        if let Some(index) = self.snapshots.iter().position(|snapshot| snapshot.id == id) {
+            let snapshot = self.snapshots.remove(index);
+            self.delete(&snapshot);
+        }
+    }
+
+    // Stores the snapshots with the document once it's saved, or saved
+    // somewhere else, along with those already there.
+    pub fn set_document(&mut self, document: &Path) {
+        if self.document() == Some(document) {
+            return;
+        }
+
+        self.document = Some(document.to_path_buf());
+        self.dir = store_dir(document);
+
+        if self.dir.is_none() {
+            return;
+        }
+
+        for snapshot in &self.snapshots {
+            self.write(snapshot);
+        }
+
+        *self = Self::load(Some(document));
+    }
+
+    pub fn set_thumbnail(&mut self, id: i64, thumbnail: Thumbnail) {
+        if let Some(index) = self.snapshots.iter().position(|snapshot| snapshot.id == id) {
+            self.snapshots[index].thumbnail = Some(thumbnail);
+
+            self.write(&self.snapshots[index]);
+        }
+    }
+
+    fn delete(&self, snapshot: &Snapshot) {
+        if let Some(dir) = &self.dir {
+            if let Err(err) = remove_file(dir.join(snapshot.file_name())) {
+                log::warn!("Failed to delete snapshot: {}", err);
+            }
+        }
+    }
+
+    fn write(&self, snapshot: &Snapshot) {
+        if let Some(dir) = &self.dir {
+            if let Err(err) = snapshot.write(dir) {
+                log::warn!("Failed to write snapshot: {}", err);
+            }
+        }
+    }
+}
+
+impl Snapshot {
+    fn file_name(&self) -> String {
+        format!("{}.{}", self.id, wgs_core::EXTENSION)
+    }
+
+    // The label is stored as the name, the time taken and the thumbnail in
+    // the metadata.
+    fn read(path: &Path) -> Result<Self> {
+        let id = path
+            .file_stem()
+            .and_then(|stem| stem.to_str()?.parse().ok())
+            .context("Not a snapshot")?;
+
+        let (wgs, metadata) = load_wgs_from_file(path)?;
+
+        let name = wgs.name();
+
+        Ok(Self {
+            created: metadata.created.context("No creation time")?,
+            frag: wgs.frag(),
+            id,
+            label: (!name.is_empty()).then_some(name),
+            textures: wgs
+                .textures_ref()
+                .iter()
+                .map(|texture| Texture {
+                    width: texture.width,
+                    height: texture.height,
+                    data: texture.data.clone(),
+                })
+                .collect(),
+            thumbnail: metadata.thumbnail,
+        })
+    }
+
+    fn write(&self, dir: &Path) -> Result<()> {
+        let mut wgs = WgsData::new(self.label.as_deref().unwrap_or_default(), &self.frag);
+
+        for texture in &self.textures {
+            wgs.add_texture(texture.width, texture.height, texture.data.clone());
+        }
+
+        let metadata = Metadata {
+            created: Some(self.created),
+            thumbnail: self.thumbnail.clone(),
+            ..Default::default()
+        };
+
+        create_dir_all(dir)?;
+        write(dir.join(self.file_name()), encode_wgs(&wgs, &metadata))?;
+
+        Ok(())
+    }
+}
+
+// A directory per document, named after a hash of its path. FNV-1a, unlike
+// the standard hasher, hashes the same from one build to the next.
+fn store_dir(document: &Path) -> Option<PathBuf> {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for byte in document.to_string_lossy().bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    Some(data_dir()?.join("snapshots").join(format!("{:016x}", hash)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Snapshots, MAX_AUTOMATIC};
+
+    #[test]
+    fn record() {
+        let mut snapshots = Snapshots::default();
+
+        let first = snapshots.record(None, "a", vec![]);
+        assert!(first.is_some());
+
+        // Nothing changed.
+        assert_eq!(snapshots.record(None, "a", vec![]), None);
+
+        // Taken by hand.
+        let labelled = snapshots
+            .record(Some("kept".to_owned()), "a", vec![])
+            .unwrap();
+        assert!(labelled > first.unwrap());
+
+        for index in 0..MAX_AUTOMATIC {
+            snapshots.record(None, &index.to_string(), vec![]);
+        }
+
+        // The oldest automatic one went, the labelled one stays.
+        assert_eq!(snapshots.as_slice().len(), MAX_AUTOMATIC + 1);
+        assert!(snapshots.get(first.unwrap()).is_none());
+        assert_eq!(
+            snapshots
+                .get(labelled)
+                .and_then(|snapshot| snapshot.label.as_deref()),
+            Some("kept")
+        );
+
+        snapshots.remove(labelled);
+        assert!(snapshots.get(labelled).is_none());
+    }
+}
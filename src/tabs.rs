@@ -0,0 +1,59 @@
+use crate::{
+    fs::config_dir,
+    history::{Document, History},
+    snapshots::Snapshots,
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+const SESSION_FILENAME: &str = "session.json";
+
+// What a tab keeps while another one is active, the active tab's document
+// living in the editor and the runtime instead.
+pub struct Stash {
+    pub document: Document,
+    pub history: History,
+    pub is_paused: bool,
+    pub snapshots: Snapshots,
+}
+
+pub struct Tab {
+    // Keys the editor's state in egui's memory, e.g. its cursor and scroll
+    // position, so that each tab keeps its own.
+    pub id: u64,
+    pub stash: Option<Stash>,
+}
+
+// The tabs open when the app was last closed, those saved to a file.
+#[derive(Default, Deserialize, Serialize)]
+pub struct Session {
+    pub active: usize,
+    pub paths: Vec<PathBuf>,
+}
+
+impl Session {
+    pub fn load() -> Result<Self> {
+        let path = config_dir()
+            .context("No config directory")?
+            .join(SESSION_FILENAME);
+
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        Ok(serde_json::from_str(&read_to_string(path)?)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let dir = config_dir().context("No config directory")?;
+
+        create_dir_all(&dir)?;
+        write(dir.join(SESSION_FILENAME), serde_json::to_vec_pretty(self)?)?;
+
+        Ok(())
+    }
+}
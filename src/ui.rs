@@ -1,48 +1,110 @@
+mod color_schemes_editor;
+mod command_palette;
+mod completion;
+mod decorations;
+mod diff_view;
+mod docs;
+mod editing;
+mod find;
+mod folding;
+mod gutter;
 mod highlight;
 mod image_upload;
+mod keymap;
+mod navigation;
+mod outline_panel;
+mod shortcuts_editor;
+mod snapshots_panel;
 mod utils;
 
 use crate::{
+    compare::CompareWith,
     event::{AppStatus, EventProxy, UserEvent},
     example::Example,
-    fonts::{load_font, load_system_font},
+    fonts::{
+        has_ligatures, load_font, load_system_font, set_editor_font, system_families, EDITOR_FAMILY,
+    },
     i18n::{select_locales, select_system_locales, LANGUAGES},
-    preferences::{Preferences, Theme},
-    shortcut::Shortcut,
+    keymap::KeymapAction,
+    metadata::{Metadata, Thumbnail},
+    preferences::{CompareLayout, Keymap, Preferences, Theme},
+    shortcut::Command,
+    snapshots::Snapshot,
+    wgsl::{Analyzer, Tokenizer},
 };
+use chrono::{DateTime, Local, Utc};
+use color_schemes_editor::ColorSchemesEditor;
+use command_palette::{CommandPalette, PaletteItem};
+use completion::Completion;
+use decorations::{paint_current_line, paint_matching_brackets};
+use diff_view::show_diff;
+use docs::Docs;
+use editing::{EditCommand, Editing};
 use egui::{
-    menu, style::FontSelection, Align, Button, CentralPanel, Color32, ColorImage, Context,
-    FontData, FontDefinitions, FullOutput, Layout, RawInput, ScrollArea, TextEdit, TextureHandle,
-    TextureOptions, TopBottomPanel, Visuals,
+    menu,
+    text::{CCursor, CCursorRange},
+    text_edit::TextEditState,
+    Align, Button, CentralPanel, CollapsingHeader, Color32, ColorImage, Context, FontData,
+    FontDefinitions, FontFamily, FontId, FullOutput, Grid, Id, Layout, RawInput, RichText,
+    ScrollArea, Slider, TextEdit, TextureHandle, TextureOptions, TopBottomPanel, Visuals,
 };
+use find::Find;
+use folding::{hidden_ranges, hide, Folding};
+use gutter::{FoldToggle, Gutter, Marker, MarkerKind};
 use highlight::{CodeTheme, Highlighter};
 use image_upload::ImageUpload;
+use keymap::EditorKeymap;
 use material_icons::{icon_to_char, Icon};
+use navigation::Navigation;
+use outline_panel::OutlinePanel;
+use shortcuts_editor::ShortcutsEditor;
+use snapshots_panel::SnapshotsPanel;
 use std::sync::Arc;
+use utils::{byte_index, line_column, select_range};
+
+const FONT_LIST_HEIGHT: f32 = 320.0;
+
+const UI_SCALES: [f32; 6] = [0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
 
 pub struct EditContext {
     pub frag: String,
+    pub metadata: Metadata,
     pub name: String,
+    // Whether `frag` was last edited by more than typing, such as replacing
+    // every match, which is undone on its own.
+    pub separate_step: bool,
+    // Lexes `frag` incrementally, for every part of the editor.
+    pub tokenizer: Tokenizer,
 }
 
 pub struct Ui {
+    analyzer: Analyzer,
     context: Context,
-    highlighter: Highlighter,
-    shortcut: Shortcut,
+    // The family of the editor's font in `context`.
+    editor_font: Option<String>,
+    // The fonts loaded at startup, before the editor's own.
+    fonts: FontDefinitions,
+    // The id of the active tab.
+    tab: u64,
     textures: Vec<TextureHandle>,
+    thumbnail: Option<TextureHandle>,
 }
 
 impl Ui {
     pub fn new() -> Self {
-        let mut context = Context::default();
+        let context = Context::default();
+        let fonts = setup_fonts();
 
-        setup_fonts(&mut context);
+        context.set_fonts(fonts.clone());
 
         Self {
+            analyzer: Analyzer::new(),
             context,
-            highlighter: Highlighter::default(),
-            shortcut: Shortcut::new(),
+            editor_font: None,
+            fonts,
+            tab: 0,
             textures: vec![],
+            thumbnail: None,
         }
     }
 
@@ -72,14 +134,35 @@ impl Ui {
         &self.context
     }
 
+    // The byte offset of the editor's cursor in `frag`.
+    pub fn editor_cursor(&self, frag: &str) -> Option<usize> {
+        let range = TextEditState::load(&self.context, self.editor_id())?.ccursor_range()?;
+
+        Some(byte_index(frag, range.primary.index))
+    }
+
     pub fn prepare(
         &mut self,
         raw_input: RawInput,
         preferences: &mut Preferences,
         edit_context: &mut EditContext,
         event_proxy: &impl EventProxy<UserEvent>,
-        state: UiState,
+        state: UiState<'_>,
     ) -> FullOutput {
+        self.analyzer
+            .update(&edit_context.frag, self.textures.len());
+
+        let editor_font = &preferences.editor.font_family;
+
+        if *editor_font != self.editor_font {
+            let mut fonts = self.fonts.clone();
+
+            set_editor_font(&mut fonts, editor_font.as_deref());
+
+            self.context.set_fonts(fonts);
+            self.editor_font = editor_font.clone();
+        }
+
         self.context.run(raw_input, |ctx| {
             self.ui(ctx, preferences, edit_context, event_proxy, state);
         })
@@ -93,44 +176,165 @@ impl Ui {
         self.textures.clear();
     }
 
-    fn ui(
+    // Moves the cursor of the editor to a byte offset of `frag`.
+    pub fn set_editor_cursor(&self, frag: &str, offset: usize) {
+        let mut state = TextEditState::load(&self.context, self.editor_id()).unwrap_or_default();
+
+        let ccursor = CCursor::new(frag[..offset].chars().count());
+        state.set_ccursor_range(Some(CCursorRange::one(ccursor)));
+
+        state.store(&self.context, self.editor_id());
+    }
+
+    pub fn set_thumbnail(&mut self, thumbnail: Option<&Thumbnail>) {
+        self.thumbnail = thumbnail.map(|thumbnail| {
+            self.context.load_texture(
+                "thumbnail",
+                Arc::new(ColorImage::from_rgba_unmultiplied(
+                    [thumbnail.width as usize, thumbnail.height as usize],
+                    &thumbnail.data,
+                )),
+                TextureOptions::LINEAR,
+            )
+        });
+    }
+
+    // Switches the editor's state, e.g. its cursor and scroll position, to
+    // that of another tab.
+    pub fn set_tab(&mut self, tab: u64) {
+        self.tab = tab;
+    }
+
+    fn editor_id(&self) -> Id {
+        Id::new("editor").with(self.tab)
+    }
+
+    fn run_palette_item(
         &self,
         ctx: &Context,
+        item: PaletteItem,
         preferences: &mut Preferences,
         edit_context: &mut EditContext,
         event_proxy: &impl EventProxy<UserEvent>,
-        state: UiState,
+        state: &UiState<'_>,
     ) {
-        if ctx.input_mut(|i| i.consume_shortcut(&self.shortcut.app_quit)) {
-            event_proxy.send_event(UserEvent::Quit);
+        let editor_id = self.editor_id();
+
+        match item {
+            PaletteItem::Command(command @ (Command::EditFind | Command::EditReplace)) => {
+                let replace = command == Command::EditReplace;
+
+                Find::open(ctx, editor_id, &edit_context.frag, replace);
+            }
+            PaletteItem::Command(command) => match EditCommand::from_command(command) {
+                Some(edit_command) => {
+                    Editing::new(editor_id, &preferences.editor).run(
+                        ctx,
+                        edit_command,
+                        &mut edit_context.frag,
+                    );
+
+                    // Back to where it was run from.
+                    ctx.memory_mut(|mem| mem.request_focus(editor_id));
+                }
+                None => run_command(ctx, command, preferences, event_proxy, state),
+            },
+            PaletteItem::CompareLayout(layout) => preferences.compare.layout = layout,
+            PaletteItem::CompareTab(index) => {
+                event_proxy.send_event(UserEvent::Compare(CompareWith::Tab(index)));
+            }
+            PaletteItem::Example(example) => {
+                event_proxy.send_event(UserEvent::OpenExample(example));
+            }
+            PaletteItem::Language(language) => {
+                let result = match language {
+                    Some(id) => select_locales(&[id]),
+                    None => select_system_locales(),
+                };
+
+                if let Err(err) = result {
+                    log::warn!("Failed to select language: {}", err);
+                }
+            }
+            PaletteItem::Theme(theme) => preferences.theme = theme,
         }
+    }
 
-        if ctx.input_mut(|i| i.consume_shortcut(&self.shortcut.file_new)) {
-            event_proxy.send_event(UserEvent::NewFile);
-        }
+    fn ui(
+        &self,
+        ctx: &Context,
+        preferences: &mut Preferences,
+        edit_context: &mut EditContext,
+        event_proxy: &impl EventProxy<UserEvent>,
+        state: UiState<'_>,
+    ) {
+        let editor_id = self.editor_id();
 
-        if ctx.input_mut(|i| i.consume_shortcut(&self.shortcut.file_open)) {
-            event_proxy.send_event(UserEvent::OpenFile);
-        }
+        // To save those changed this frame.
+        let saved_preferences = preferences.saved();
 
-        if ctx.input_mut(|i| i.consume_shortcut(&self.shortcut.file_save)) {
-            event_proxy.send_event(UserEvent::SaveFile);
-        }
+        let mut color_schemes_editor = ColorSchemesEditor::new(ctx);
+        let mut shortcuts_editor = ShortcutsEditor::new(ctx);
+
+        // Before anything else takes the key being bound.
+        shortcuts_editor.consume_keys(ctx);
+
+        // Before the app's shortcuts, the keymap's keys taking over theirs in
+        // the editor.
+        let actions = EditorKeymap::new(editor_id, &preferences.editor)
+            .consume_keys(ctx, &mut edit_context.frag);
 
-        if ctx.input_mut(|i| i.consume_shortcut(&self.shortcut.file_save_as)) {
-            event_proxy.send_event(UserEvent::SaveFileAs);
+        for action in actions {
+            event_proxy.send_event(match action {
+                KeymapAction::CloseTab => UserEvent::CloseTab(state.active_tab),
+                KeymapAction::Redo => UserEvent::Redo,
+                KeymapAction::Save => UserEvent::SaveFile,
+                KeymapAction::Undo => UserEvent::Undo,
+            });
         }
 
-        let theme = CodeTheme::from_memory(ctx);
+        // Other text fields keep their own undo.
+        let editor_or_nothing_focused =
+            ctx.memory(|mem| mem.focus().is_none_or(|id| id == editor_id));
+
+        for command in Command::ALL {
+            let is_undo = matches!(command, Command::EditRedo | Command::EditUndo);
 
-        let mut layouter = |ui: &egui::Ui, string: &str, wrap_width: f32| {
-            let mut layout_job = self.highlighter.highlight(&theme, string);
-            layout_job.wrap.max_width = wrap_width;
+            if command.is_editor() || (is_undo && !editor_or_nothing_focused) {
+                continue;
+            }
 
-            ui.fonts(|f| f.layout_job(layout_job))
-        };
+            if ctx.input_mut(|i| preferences.shortcuts.consume(i, command)) {
+                run_command(ctx, command, preferences, event_proxy, &state);
+            }
+        }
+
+        let shortcuts = &preferences.shortcuts;
 
         let is_dark = preferences.theme == Theme::Dark;
+
+        let font_id = FontId::new(
+            preferences.editor.font_size(),
+            FontFamily::Name(EDITOR_FAMILY.into()),
+        );
+        let row_height =
+            ctx.fonts(|fonts| fonts.row_height(&font_id)) * preferences.editor.line_height;
+
+        let theme = CodeTheme::new(
+            preferences.color_schemes.current(is_dark),
+            font_id,
+            Some(row_height),
+        );
+
+        let tokens = edit_context.tokenizer.update(&edit_context.frag);
+        let outline = folding::outline(ctx, &edit_context.frag, tokens);
+
+        let mut folding = Folding::new(ctx, editor_id);
+        folding.update(ctx, &edit_context.frag, &outline);
+
+        // The text laid out may have been edited since, so is folded again.
+        let folded = folding.folded().clone();
+
         if ctx.style().visuals.dark_mode != is_dark {
             ctx.set_visuals(if is_dark {
                 Visuals::dark()
@@ -145,7 +349,7 @@ impl Ui {
                     if ui
                         .add(
                             Button::new(fl!("menu_new"))
-                                .shortcut_text(ui.ctx().format_shortcut(&self.shortcut.file_new)),
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::FileNew)),
                         )
                         .clicked()
                     {
@@ -157,7 +361,7 @@ impl Ui {
                     if ui
                         .add(
                             Button::new(fl!("menu_open"))
-                                .shortcut_text(ui.ctx().format_shortcut(&self.shortcut.file_open)),
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::FileOpen)),
                         )
                         .clicked()
                     {
@@ -166,6 +370,19 @@ impl Ui {
                         ui.close_menu();
                     }
 
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_open_project")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::FileOpenProject),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::OpenProject);
+
+                        ui.close_menu();
+                    }
+
                     ui.menu_button(fl!("menu_open_examples"), |ui| {
                         if ui.button(Example::Default.description()).clicked() {
                             event_proxy.send_event(UserEvent::OpenExample(Example::Default));
@@ -185,50 +402,448 @@ impl Ui {
                             ui.close_menu();
                         }
 
-                        if ui.button(Example::TwoTexture.description()).clicked() {
-                            event_proxy.send_event(UserEvent::OpenExample(Example::TwoTexture));
+                        if ui.button(Example::TwoTexture.description()).clicked() {
+                            event_proxy.send_event(UserEvent::OpenExample(Example::TwoTexture));
+
+                            ui.close_menu();
+                        }
+                    });
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_save"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::FileSave)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::SaveFile);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_save_as"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::FileSaveAs)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::SaveFileAs);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_save_project_as")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::FileSaveProjectAs),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::SaveProjectAs);
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_import_wgsl"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::FileImportWgsl)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::ImportWgsl);
+
+                        ui.close_menu();
+                    }
+
+                    ui.menu_button(fl!("menu_export_wgsl"), |ui| {
+                        if ui
+                            .add(Button::new(fl!("menu_export_wgsl_fragment")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::FileExportWgslFragment),
+                            ))
+                            .clicked()
+                        {
+                            event_proxy.send_event(UserEvent::ExportWgsl {
+                                with_prelude: false,
+                            });
+
+                            ui.close_menu();
+                        }
+
+                        if ui
+                            .add(
+                                Button::new(fl!("menu_export_wgsl_standalone")).shortcut_text(
+                                    shortcuts.format(ui.ctx(), Command::FileExportWgslStandalone),
+                                ),
+                            )
+                            .clicked()
+                        {
+                            event_proxy.send_event(UserEvent::ExportWgsl { with_prelude: true });
+
+                            ui.close_menu();
+                        }
+                    });
+
+                    if ui
+                        .add(Button::new(fl!("menu_export_rust_project")).shortcut_text(
+                            shortcuts.format(ui.ctx(), Command::FileExportRustProject),
+                        ))
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::ExportRustProject);
+
+                        ui.close_menu();
+                    }
+
+                    ui.menu_button(fl!("menu_convert"), |ui| {
+                        if ui
+                            .add(
+                                Button::new(fl!("menu_convert_wgs_to_project")).shortcut_text(
+                                    shortcuts.format(ui.ctx(), Command::FileConvertWgsToProject),
+                                ),
+                            )
+                            .clicked()
+                        {
+                            event_proxy.send_event(UserEvent::ConvertWgsToProject);
+
+                            ui.close_menu();
+                        }
+
+                        if ui
+                            .add(
+                                Button::new(fl!("menu_convert_project_to_wgs")).shortcut_text(
+                                    shortcuts.format(ui.ctx(), Command::FileConvertProjectToWgs),
+                                ),
+                            )
+                            .clicked()
+                        {
+                            event_proxy.send_event(UserEvent::ConvertProjectToWgs);
+
+                            ui.close_menu();
+                        }
+                    });
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_close_tab"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::TabClose)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::CloseTab(state.active_tab));
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_quit"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::AppQuit)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::Quit);
+
+                        ui.close_menu();
+                    }
+                });
+
+                ui.menu_button(fl!("menu_edit"), |ui| {
+                    let editing = Editing::new(editor_id, &preferences.editor);
+
+                    if ui
+                        .add_enabled(
+                            state.can_undo,
+                            Button::new(fl!("menu_undo"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditUndo)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::Undo);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add_enabled(
+                            state.can_redo,
+                            Button::new(fl!("menu_redo"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditRedo)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::Redo);
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_take_snapshot"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::SnapshotTake)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::TakeSnapshot(String::new()));
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_snapshot_history")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::SnapshotHistory),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        SnapshotsPanel::open(ui.ctx());
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_find"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditFind)),
+                        )
+                        .clicked()
+                    {
+                        Find::open(ui.ctx(), editor_id, &edit_context.frag, false);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_replace"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditReplace)),
+                        )
+                        .clicked()
+                    {
+                        Find::open(ui.ctx(), editor_id, &edit_context.frag, true);
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_format"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditFormat)),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::Format, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_comment_line")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::EditCommentLine),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::CommentLine, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_comment_block")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::EditCommentBlock),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::CommentBlock, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_duplicate_line")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::EditDuplicateLine),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::DuplicateLine, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_move_line_up"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditMoveLineUp)),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::MoveLineUp, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_move_line_down")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::EditMoveLineDown),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::MoveLineDown, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_delete_line"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::EditDeleteLine)),
+                        )
+                        .clicked()
+                    {
+                        editing.run(ui.ctx(), EditCommand::DeleteLine, &mut edit_context.frag);
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_outline"))
+                                .selected(OutlinePanel::is_open(ui.ctx()))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::ViewOutline)),
+                        )
+                        .clicked()
+                    {
+                        OutlinePanel::toggle(ui.ctx());
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_fold_all"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::ViewFoldAll)),
+                        )
+                        .clicked()
+                    {
+                        Folding::fold_all(ui.ctx(), true);
+
+                        ui.close_menu();
+                    }
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_unfold_all"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::ViewUnfoldAll)),
+                        )
+                        .clicked()
+                    {
+                        Folding::fold_all(ui.ctx(), false);
+
+                        ui.close_menu();
+                    }
+                });
+
+                ui.menu_button(fl!("menu_compare"), |ui| {
+                    if ui
+                        .add_enabled(
+                            state.file_saved,
+                            Button::new(fl!("menu_compare_saved"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::CompareSaved)),
+                        )
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::Compare(CompareWith::Saved));
+
+                        ui.close_menu();
+                    }
+
+                    for (index, tab) in state.tabs.iter().enumerate() {
+                        if index != state.active_tab
+                            && ui
+                                .button(fl!("menu_compare_tab", name = tab.name.as_str()))
+                                .clicked()
+                        {
+                            event_proxy.send_event(UserEvent::Compare(CompareWith::Tab(index)));
 
                             ui.close_menu();
                         }
-                    });
+                    }
 
                     ui.separator();
 
+                    let compare = &mut preferences.compare;
+
                     if ui
-                        .add(
-                            Button::new(fl!("menu_save"))
-                                .shortcut_text(ui.ctx().format_shortcut(&self.shortcut.file_save)),
+                        .radio_value(
+                            &mut compare.layout,
+                            CompareLayout::SideBySide,
+                            fl!("menu_compare_side_by_side"),
                         )
                         .clicked()
                     {
-                        event_proxy.send_event(UserEvent::SaveFile);
-
                         ui.close_menu();
                     }
 
                     if ui
-                        .add(
-                            Button::new(fl!("menu_save_as")).shortcut_text(
-                                ui.ctx().format_shortcut(&self.shortcut.file_save_as),
-                            ),
+                        .radio_value(
+                            &mut compare.layout,
+                            CompareLayout::Wipe,
+                            fl!("menu_compare_wipe"),
                         )
                         .clicked()
                     {
-                        event_proxy.send_event(UserEvent::SaveFileAs);
+                        ui.close_menu();
+                    }
 
+                    if ui
+                        .checkbox(&mut compare.show_diff, fl!("menu_compare_show_diff"))
+                        .clicked()
+                    {
                         ui.close_menu();
                     }
 
                     ui.separator();
 
                     if ui
-                        .add(
-                            Button::new(fl!("menu_quit"))
-                                .shortcut_text(ui.ctx().format_shortcut(&self.shortcut.app_quit)),
+                        .add_enabled(
+                            state.compare.is_some(),
+                            Button::new(fl!("menu_compare_stop"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::CompareStop)),
                         )
                         .clicked()
                     {
-                        event_proxy.send_event(UserEvent::Quit);
+                        event_proxy.send_event(UserEvent::StopComparing);
 
                         ui.close_menu();
                     }
@@ -284,16 +899,218 @@ impl Ui {
                         }
                     });
 
+                    ui.menu_button(fl!("menu_ui_scale"), |ui| {
+                        for scale in UI_SCALES {
+                            if ui
+                                .radio_value(
+                                    &mut preferences.ui_scale,
+                                    scale,
+                                    format!("{}%", (scale * 100.0).round()),
+                                )
+                                .clicked()
+                            {
+                                ui.close_menu();
+                            }
+                        }
+                    });
+
+                    ui.menu_button(fl!("menu_editor"), |ui| {
+                        let editor = &mut preferences.editor;
+
+                        if ui
+                            .checkbox(&mut editor.auto_indent, fl!("menu_auto_indent"))
+                            .clicked()
+                        {
+                            ui.close_menu();
+                        }
+
+                        if ui
+                            .checkbox(&mut editor.auto_close, fl!("menu_auto_close"))
+                            .clicked()
+                        {
+                            ui.close_menu();
+                        }
+
+                        if ui
+                            .checkbox(&mut editor.format_on_save, fl!("menu_format_on_save"))
+                            .clicked()
+                        {
+                            ui.close_menu();
+                        }
+
+                        ui.separator();
+
+                        for width in [2, 4, 8] {
+                            if ui
+                                .radio_value(
+                                    &mut editor.indent_width,
+                                    width,
+                                    fl!("menu_indent_width", width = width),
+                                )
+                                .clicked()
+                            {
+                                ui.close_menu();
+                            }
+                        }
+
+                        ui.separator();
+
+                        for (keymap, label) in [
+                            (Keymap::Default, fl!("menu_keymap_default")),
+                            (Keymap::Emacs, fl!("menu_keymap_emacs")),
+                            (Keymap::Vim, fl!("menu_keymap_vim")),
+                        ] {
+                            if ui.radio_value(&mut editor.keymap, keymap, label).clicked() {
+                                ui.close_menu();
+                            }
+                        }
+
+                        ui.separator();
+
+                        ui.menu_button(fl!("menu_font_family"), |ui| {
+                            ScrollArea::vertical()
+                                .max_height(FONT_LIST_HEIGHT)
+                                .show(ui, |ui| {
+                                    if ui
+                                        .radio_value(
+                                            &mut editor.font_family,
+                                            None,
+                                            fl!("menu_font_family_default"),
+                                        )
+                                        .clicked()
+                                    {
+                                        ui.close_menu();
+                                    }
+
+                                    // Fonts made for code with ligatures first.
+                                    let (ligature_families, other_families): (Vec<_>, Vec<_>) =
+                                        font_families(ui.ctx())
+                                            .iter()
+                                            .cloned()
+                                            .partition(|family| has_ligatures(family));
+
+                                    for (families, heading) in [
+                                        (ligature_families, fl!("menu_font_family_ligatures")),
+                                        (other_families, fl!("menu_font_family_others")),
+                                    ] {
+                                        if families.is_empty() {
+                                            continue;
+                                        }
+
+                                        ui.separator();
+                                        ui.label(RichText::new(heading).weak());
+
+                                        for family in families {
+                                            if ui
+                                                .radio_value(
+                                                    &mut editor.font_family,
+                                                    Some(family.clone()),
+                                                    family,
+                                                )
+                                                .clicked()
+                                            {
+                                                ui.close_menu();
+                                            }
+                                        }
+                                    }
+                                });
+                        });
+
+                        ui.add(
+                            Slider::new(&mut editor.font_size, 8.0..=32.0)
+                                .step_by(1.0)
+                                .text(fl!("menu_font_size")),
+                        );
+                        ui.add(
+                            Slider::new(&mut editor.line_height, 1.0..=2.0)
+                                .step_by(0.05)
+                                .text(fl!("menu_line_height")),
+                        );
+
+                        ui.separator();
+
+                        // Left open to zoom again.
+                        if ui
+                            .add(
+                                Button::new(fl!("menu_zoom_in"))
+                                    .shortcut_text(shortcuts.format(ui.ctx(), Command::ViewZoomIn)),
+                            )
+                            .clicked()
+                        {
+                            editor.zoom_in();
+                        }
+
+                        if ui
+                            .add(
+                                Button::new(fl!("menu_zoom_out")).shortcut_text(
+                                    shortcuts.format(ui.ctx(), Command::ViewZoomOut),
+                                ),
+                            )
+                            .clicked()
+                        {
+                            editor.zoom_out();
+                        }
+
+                        if ui
+                            .add(
+                                Button::new(format!(
+                                    "{} ({}%)",
+                                    fl!("menu_zoom_reset"),
+                                    (editor.zoom * 100.0).round()
+                                ))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::ViewZoomReset)),
+                            )
+                            .clicked()
+                        {
+                            editor.zoom = 1.0;
+                        }
+                    });
+
                     if ui
                         .checkbox(&mut preferences.record_fps, fl!("menu_record_fps"))
                         .clicked()
                     {
                         ui.close_menu();
                     }
+
+                    ui.separator();
+
+                    if ui.button(fl!("menu_color_schemes")).clicked() {
+                        color_schemes_editor.open();
+
+                        ui.close_menu();
+                    }
+
+                    if ui.button(fl!("menu_shortcuts")).clicked() {
+                        shortcuts_editor.open();
+
+                        ui.close_menu();
+                    }
                 });
 
                 ui.menu_button(fl!("menu_help"), |ui| {
-                    if ui.button(fl!("menu_about")).clicked() {
+                    if ui
+                        .add(
+                            Button::new(fl!("command_palette")).shortcut_text(
+                                shortcuts.format(ui.ctx(), Command::AppCommandPalette),
+                            ),
+                        )
+                        .clicked()
+                    {
+                        CommandPalette::open(ui.ctx());
+
+                        ui.close_menu();
+                    }
+
+                    ui.separator();
+
+                    if ui
+                        .add(
+                            Button::new(fl!("menu_about"))
+                                .shortcut_text(shortcuts.format(ui.ctx(), Command::AppAbout)),
+                        )
+                        .clicked()
+                    {
                         event_proxy.send_event(UserEvent::OpenAbout);
 
                         ui.close_menu();
@@ -302,6 +1119,48 @@ impl Ui {
             });
         });
 
+        TopBottomPanel::top("tabs").show(ctx, |ui| {
+            ScrollArea::horizontal().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for (index, tab) in state.tabs.iter().enumerate() {
+                        let label = if tab.is_modified {
+                            format!("{} •", tab.name)
+                        } else {
+                            tab.name.clone()
+                        };
+
+                        let resp = ui.selectable_label(index == state.active_tab, label);
+
+                        if resp.clicked() {
+                            event_proxy.send_event(UserEvent::SelectTab(index));
+                        }
+
+                        if resp.middle_clicked() {
+                            event_proxy.send_event(UserEvent::CloseTab(index));
+                        }
+
+                        if ui
+                            .small_button(icon_to_char(Icon::Close).to_string())
+                            .on_hover_text(fl!("tab_close"))
+                            .clicked()
+                        {
+                            event_proxy.send_event(UserEvent::CloseTab(index));
+                        }
+
+                        ui.separator();
+                    }
+
+                    if ui
+                        .small_button(icon_to_char(Icon::Add).to_string())
+                        .on_hover_text(fl!("tab_new"))
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::NewFile);
+                    }
+                });
+            });
+        });
+
         TopBottomPanel::bottom("status").show(ctx, |ui| {
             ui.horizontal(|ui| {
                 if let Some(fps) = state.fps {
@@ -310,7 +1169,14 @@ impl Ui {
                     ui.separator();
                 }
 
-                match state.status {
+                if let Some(status) = EditorKeymap::new(editor_id, &preferences.editor).status(ctx)
+                {
+                    ui.monospace(status);
+
+                    ui.separator();
+                }
+
+                match &state.status {
                     AppStatus::Info(message) => {
                         ui.label(message);
                     }
@@ -336,9 +1202,31 @@ impl Ui {
                     }
                     _ => {}
                 }
+
+                let cursor = TextEditState::load(ctx, editor_id)
+                    .and_then(|state| state.ccursor_range())
+                    .map(|range| byte_index(&edit_context.frag, range.primary.index));
+
+                if let Some(cursor) = cursor {
+                    let (line, column) = line_column(&edit_context.frag, cursor);
+
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        ui.label(fl!("status_cursor", line = line, column = column));
+                    });
+                }
             });
         });
 
+        let cursor = TextEditState::load(ctx, editor_id)
+            .and_then(|state| state.ccursor_range())
+            .map(|range| byte_index(&edit_context.frag, range.primary.index));
+
+        let jump = OutlinePanel::new(ctx).show(
+            ctx,
+            &outline,
+            cursor.and_then(|cursor| outline.item_at(cursor)),
+        );
+
         CentralPanel::default().show(ctx, |ui| {
             ui.horizontal_wrapped(|ui| {
                 ui.set_max_width(ui.available_width() / 2.0);
@@ -391,6 +1279,38 @@ impl Ui {
                         UserEvent::Pause
                     });
                 }
+
+                if let Some(compare) = &state.compare {
+                    ui.separator();
+
+                    let (prefix, hint) = if compare.is_timed {
+                        ("", fl!("compare_frame_time"))
+                    } else {
+                        ("~", fl!("compare_frame_time_estimate"))
+                    };
+
+                    ui.label(format!(
+                        "{}: {}{}",
+                        compare.name,
+                        prefix,
+                        format_frame_time(compare.frame_times[0])
+                    ))
+                    .on_hover_text(&hint);
+                    ui.label(format!(
+                        "{}: {}{}",
+                        fl!("compare_current"),
+                        prefix,
+                        format_frame_time(compare.frame_times[1])
+                    ))
+                    .on_hover_text(hint);
+
+                    if preferences.compare.layout == CompareLayout::Wipe {
+                        ui.add(
+                            Slider::new(&mut preferences.compare.wipe, 0.0..=1.0).show_value(false),
+                        )
+                        .on_hover_text(fl!("compare_wipe"));
+                    }
+                }
             });
 
             ui.horizontal_wrapped(|ui| {
@@ -400,6 +1320,69 @@ impl Ui {
                 ui.text_edit_singleline(&mut edit_context.name);
             });
 
+            ui.vertical(|ui| {
+                ui.set_max_width(ui.available_width() / 2.0);
+
+                CollapsingHeader::new(fl!("edit_properties")).show(ui, |ui| {
+                    let metadata = &mut edit_context.metadata;
+
+                    Grid::new("properties")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label(fl!("edit_author"));
+                            ui.text_edit_singleline(&mut metadata.author);
+                            ui.end_row();
+
+                            ui.label(fl!("edit_description"));
+                            ui.add(TextEdit::multiline(&mut metadata.description).desired_rows(2));
+                            ui.end_row();
+
+                            ui.label(fl!("edit_tags"));
+                            let mut tags = metadata.tags.join(",");
+                            if ui
+                                .add(TextEdit::singleline(&mut tags).hint_text("tag1, tag2"))
+                                .changed()
+                            {
+                                metadata.tags = tags.split(',').map(str::to_owned).collect();
+                            }
+                            ui.end_row();
+
+                            ui.label(fl!("edit_license"));
+                            ui.add(TextEdit::singleline(&mut metadata.license).hint_text("MIT"));
+                            ui.end_row();
+
+                            ui.label(fl!("edit_created"));
+                            ui.label(format_time(metadata.created));
+                            ui.end_row();
+
+                            ui.label(fl!("edit_modified"));
+                            ui.label(format_time(metadata.modified));
+                            ui.end_row();
+
+                            ui.label(fl!("edit_thumbnail"));
+                            ui.horizontal(|ui| {
+                                if let Some(thumbnail) = &self.thumbnail {
+                                    let size = thumbnail.size_vec2();
+                                    let scale = (96.0 / size.x.max(size.y)).min(1.0);
+
+                                    ui.image((thumbnail.id(), size * scale));
+                                }
+
+                                if state.can_capture
+                                    && ui
+                                        .button(icon_to_char(Icon::PhotoCamera).to_string())
+                                        .on_hover_text(fl!("edit_capture_thumbnail"))
+                                        .clicked()
+                                {
+                                    event_proxy.send_event(UserEvent::CaptureThumbnail);
+                                }
+                            });
+                            ui.end_row();
+                        });
+                });
+            });
+
             ui.with_layout(Layout::bottom_up(Align::LEFT), |ui| {
                 ui.with_layout(Layout::left_to_right(Align::BOTTOM), |ui| {
                     for (index, texture) in self.textures.iter().enumerate() {
@@ -427,36 +1410,338 @@ impl Ui {
                     }
                 });
 
-                ScrollArea::vertical().show(ui, |ui| {
-                    ui.with_layout(Layout::top_down(Align::LEFT), |ui| {
-                        let editor = TextEdit::multiline(&mut edit_context.frag)
-                            .code_editor()
-                            .desired_width(ui.available_width() / 2.0 - 16.0);
+                ScrollArea::vertical().id_source(editor_id).show(ui, |ui| {
+                    ui.horizontal_top(|ui| {
+                        ui.spacing_mut().item_spacing.x = 0.0;
+
+                        let markers = self
+                            .analyzer
+                            .diagnostic()
+                            .and_then(|diagnostic| {
+                                Some(Marker {
+                                    kind: MarkerKind::Error,
+                                    line: diagnostic.line?,
+                                    message: diagnostic.message.clone(),
+                                })
+                            })
+                            .into_iter()
+                            .collect();
+
+                        let folds = outline
+                            .folds
+                            .iter()
+                            .map(|fold| FoldToggle {
+                                folded: folding.folded().contains(&fold.key),
+                                key: fold.key.clone(),
+                                line: fold.lines.start() + 1,
+                            })
+                            .collect();
+
+                        let gutter = Gutter::new(theme.font_id(), markers, folds);
+                        let gutter_rect = gutter.allocate(ui, &edit_context.frag);
+
+                        let mut completion = Completion::new(
+                            ctx,
+                            editor_id,
+                            self.analyzer.symbols(),
+                            self.textures.len(),
+                        );
+                        completion.consume_keys(ctx, shortcuts);
+
+                        let mut navigation = Navigation::new(ctx, editor_id, self.textures.len());
+                        navigation.consume_keys(ctx, shortcuts);
+
+                        let mut find = Find::new(ctx, editor_id);
+                        find.consume_keys(ctx, shortcuts, &edit_context.frag);
+
+                        Editing::new(editor_id, &preferences.editor).consume_keys(
+                            ctx,
+                            shortcuts,
+                            &mut edit_context.frag,
+                        );
+
+                        let tokenizer = &mut edit_context.tokenizer;
+                        let symbols = self.analyzer.symbols();
+
+                        let mut layouter = |ui: &egui::Ui, string: &str, wrap_width: f32| {
+                            let tokens = tokenizer.update(string);
+
+                            let mut layout_job =
+                                Highlighter::layout(ui.ctx(), &theme, string, tokens, symbols);
+                            layout_job.wrap.max_width = wrap_width;
+
+                            hide(
+                                &mut layout_job,
+                                &hidden_ranges(
+                                    string,
+                                    &folding::outline(ui.ctx(), string, tokens),
+                                    &folded,
+                                ),
+                            );
 
-                        let font_id = FontSelection::default().resolve(ui.style());
-                        let row_height = self.context.fonts(|fonts| fonts.row_height(&font_id));
+                            ui.fonts(|f| f.layout_job(layout_job))
+                        };
 
-                        let editor = editor
+                        let editor = TextEdit::multiline(&mut edit_context.frag)
+                            .code_editor()
+                            // Up to the middle of the window, where the preview starts.
+                            .desired_width(ctx.screen_rect().center().x - ui.cursor().left() - 16.0)
+                            .id(editor_id)
                             .desired_rows((ui.available_height() / row_height) as usize)
                             .layouter(&mut layouter);
-                        editor.show(ui);
+                        let mut output = editor.show(ui);
+
+                        EditorKeymap::new(editor_id, &preferences.editor).show(ui, &output);
+
+                        let cursor = output
+                            .cursor_range
+                            .map(|range| range.primary.ccursor)
+                            .or_else(|| output.state.ccursor_range().map(|range| range.primary));
+                        let current_line = cursor.map(|cursor| {
+                            line_column(
+                                &edit_context.frag,
+                                byte_index(&edit_context.frag, cursor.index),
+                            )
+                            .0
+                        });
+
+                        if let Some(key) = gutter.paint(ui, gutter_rect, &output, current_line) {
+                            folding.toggle(ctx, &key);
+                        }
+
+                        folding.paint(ui, &output, &edit_context.frag, &outline);
+
+                        if let Some(range) = jump.clone() {
+                            select_range(ui, &mut output, editor_id, &edit_context.frag, range);
+
+                            ctx.memory_mut(|mem| mem.request_focus(editor_id));
+                        }
+
+                        let tokens = edit_context.tokenizer.update(&edit_context.frag);
+
+                        if let Some(cursor_range) = output.cursor_range {
+                            paint_current_line(ui, &output, cursor_range.primary.ccursor);
+                            paint_matching_brackets(
+                                ui,
+                                &output,
+                                &edit_context.frag,
+                                tokens,
+                                cursor_range.primary.ccursor,
+                            );
+                        }
+
+                        Docs::new(editor_id, self.analyzer.symbols(), tokens).show(
+                            ui,
+                            &output,
+                            &edit_context.frag,
+                        );
+
+                        completion.show(
+                            ui,
+                            &mut output,
+                            &mut edit_context.frag,
+                            &mut edit_context.tokenizer,
+                        );
+                        navigation.show(
+                            ui,
+                            &mut output,
+                            &mut edit_context.frag,
+                            &mut edit_context.tokenizer,
+                        );
+                        find.show(ui, &mut output, edit_context);
                     });
                 });
             });
         });
+
+        folding.store(ctx);
+
+        if let Some(compare) = &state.compare {
+            if preferences.compare.show_diff {
+                show_diff(
+                    ctx,
+                    Id::new("compare_diff"),
+                    format!("{}: {}", fl!("compare_diff"), compare.name),
+                    &mut preferences.compare.show_diff,
+                    &compare.frag,
+                    &edit_context.frag,
+                );
+            }
+        }
+
+        if let Some(item) = CommandPalette::new(ctx).show(ctx, &preferences.shortcuts, &state) {
+            self.run_palette_item(ctx, item, preferences, edit_context, event_proxy, &state);
+        }
+
+        SnapshotsPanel::new(ctx).show(ctx, state.snapshots, event_proxy);
+
+        color_schemes_editor.show(ctx, &mut preferences.color_schemes, event_proxy);
+
+        if shortcuts_editor.show(ctx, &mut preferences.shortcuts) {
+            if let Err(err) = preferences.shortcuts.save() {
+                log::warn!("Failed to save shortcuts: {}", err);
+            }
+        }
+
+        let saved = preferences.saved();
+
+        if saved != saved_preferences {
+            if let Err(err) = saved.save() {
+                log::warn!("Failed to save preferences: {}", err);
+            }
+        }
     }
 }
 
-pub struct UiState {
+pub struct UiState<'a> {
+    pub active_tab: usize,
     pub can_capture: bool,
+    pub can_redo: bool,
+    pub can_undo: bool,
+    pub compare: Option<CompareState>,
     pub file_saved: bool,
     pub fps: Option<usize>,
     pub is_paused: bool,
+    pub snapshots: &'a [Snapshot],
     pub status: AppStatus,
+    pub tabs: Vec<TabState>,
     pub texture_addable: bool,
 }
 
-fn setup_fonts(ctx: &mut Context) {
+pub struct CompareState {
+    // The source of the older version.
+    pub frag: String,
+    pub frame_times: [Option<f32>; 2],
+    // Whether the frame times are measured on the GPU, rather than estimated.
+    pub is_timed: bool,
+    pub name: String,
+}
+
+pub struct TabState {
+    pub is_modified: bool,
+    pub name: String,
+}
+
+// Listed once, when first shown.
+fn font_families(ctx: &Context) -> Arc<Vec<String>> {
+    let id = Id::new("font_families");
+
+    if let Some(families) = ctx.data_mut(|d| d.get_temp(id)) {
+        return families;
+    }
+
+    let families = Arc::new(system_families());
+
+    ctx.data_mut(|d| d.insert_temp(id, families.clone()));
+
+    families
+}
+
+fn format_frame_time(frame_time: Option<f32>) -> String {
+    frame_time.map_or_else(|| "-".to_owned(), |time| format!("{:.2} ms", time))
+}
+
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map_or_else(
+        || "-".to_owned(),
+        |time| {
+            time.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        },
+    )
+}
+
+// Runs a command from its shortcut, those of the editor being run by the
+// editor itself.
+fn run_command(
+    ctx: &Context,
+    command: Command,
+    preferences: &mut Preferences,
+    event_proxy: &impl EventProxy<UserEvent>,
+    state: &UiState<'_>,
+) {
+    let event = match command {
+        Command::AppAbout => UserEvent::OpenAbout,
+        Command::AppCommandPalette => {
+            CommandPalette::open(ctx);
+
+            return;
+        }
+        Command::AppQuit => UserEvent::Quit,
+        Command::CompareSaved if state.file_saved => UserEvent::Compare(CompareWith::Saved),
+        Command::CompareStop if state.compare.is_some() => UserEvent::StopComparing,
+        Command::EditRedo => UserEvent::Redo,
+        Command::EditUndo => UserEvent::Undo,
+        Command::FileConvertProjectToWgs => UserEvent::ConvertProjectToWgs,
+        Command::FileConvertWgsToProject => UserEvent::ConvertWgsToProject,
+        Command::FileExportRustProject => UserEvent::ExportRustProject,
+        Command::FileExportWgslFragment => UserEvent::ExportWgsl {
+            with_prelude: false,
+        },
+        Command::FileExportWgslStandalone => UserEvent::ExportWgsl { with_prelude: true },
+        Command::FileImportWgsl => UserEvent::ImportWgsl,
+        Command::FileNew => UserEvent::NewFile,
+        Command::FileOpen => UserEvent::OpenFile,
+        Command::FileOpenProject => UserEvent::OpenProject,
+        Command::FileSave => UserEvent::SaveFile,
+        Command::FileSaveAs => UserEvent::SaveFileAs,
+        Command::FileSaveProjectAs => UserEvent::SaveProjectAs,
+        Command::ShaderCaptureImage if state.can_capture => UserEvent::CaptureImage,
+        Command::ShaderCaptureThumbnail if state.can_capture => UserEvent::CaptureThumbnail,
+        Command::ShaderCompile => UserEvent::RequestRedraw,
+        Command::ShaderPause if state.is_paused => UserEvent::Resume,
+        Command::ShaderPause => UserEvent::Pause,
+        Command::ShaderRestart => UserEvent::Restart,
+        Command::SnapshotHistory => {
+            SnapshotsPanel::open(ctx);
+
+            return;
+        }
+        Command::SnapshotTake => UserEvent::TakeSnapshot(String::new()),
+        Command::TabClose => UserEvent::CloseTab(state.active_tab),
+        Command::TabNext => UserEvent::SelectTab((state.active_tab + 1) % state.tabs.len()),
+        Command::TabPrevious => {
+            UserEvent::SelectTab((state.active_tab + state.tabs.len() - 1) % state.tabs.len())
+        }
+        Command::TextureAdd if state.texture_addable => UserEvent::OpenTexture,
+        Command::ViewFoldAll => {
+            Folding::fold_all(ctx, true);
+
+            return;
+        }
+        Command::ViewOutline => {
+            OutlinePanel::toggle(ctx);
+
+            return;
+        }
+        Command::ViewUnfoldAll => {
+            Folding::fold_all(ctx, false);
+
+            return;
+        }
+        Command::ViewZoomIn => {
+            preferences.editor.zoom_in();
+
+            return;
+        }
+        Command::ViewZoomOut => {
+            preferences.editor.zoom_out();
+
+            return;
+        }
+        Command::ViewZoomReset => {
+            preferences.editor.zoom = 1.0;
+
+            return;
+        }
+        _ => return,
+    };
+
+    event_proxy.send_event(event);
+}
+
+fn setup_fonts() -> FontDefinitions {
     let mut fonts = FontDefinitions::default();
 
     load_system_font(&mut fonts);
@@ -467,5 +1752,7 @@ fn setup_fonts(ctx: &mut Context) {
         FontData::from_static(material_icons::FONT),
     );
 
-    ctx.set_fonts(fonts);
+    set_editor_font(&mut fonts, None);
+
+    fonts
 }
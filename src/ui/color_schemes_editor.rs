@@ -0,0 +1,297 @@
+use super::highlight::{CodeTheme, Highlighter, TokenType};
+use crate::{
+    color_scheme::{ColorScheme, ColorSchemes},
+    event::{EventProxy, UserEvent},
+    wgsl::{Symbols, Token, Tokenizer},
+};
+use egui::{
+    Button, ComboBox, Context, Frame, Grid, Id, ScrollArea, TextEdit, TextStyle, Ui, Visuals,
+    Window,
+};
+
+const NAME_WIDTH: f32 = 160.0;
+
+// Shows most token kinds.
+const PREVIEW: &str = "// Rings around the cursor.
+struct Ring {
+    radius: f32,
+    width: f32,
+}
+
+fn ring(uv: vec2<f32>, r: Ring) -> f32 {
+    let d = abs(length(uv) - r.radius);
+    return smoothstep(r.width, 0.0, d);
+}
+
+fn main_image(frag_color: vec4<f32>, frag_coord: vec2<f32>) -> vec4<f32> {
+    let uv = (frag_coord - u.cursor) / u.resolution.y;
+    let r = Ring(0.25 + 0.1 * sin(u.time), 0.02);
+    return vec4<f32>(vec3<f32>(ring(uv, r)), 1.0);
+}";
+
+#[derive(Clone, Default)]
+struct State {
+    // The name of the scheme edited, by default the one in use.
+    editing: Option<String>,
+    // The name of the copy to make of the scheme edited.
+    new_name: String,
+    open: bool,
+}
+
+// Picks the schemes for dark and light mode, and edits their colours, the
+// editor following along.
+pub struct ColorSchemesEditor {
+    id: Id,
+    state: State,
+}
+
+impl ColorSchemesEditor {
+    pub fn new(ctx: &Context) -> Self {
+        let id = Id::new("color_schemes");
+
+        Self {
+            id,
+            state: ctx.data_mut(|d| d.get_temp::<State>(id).unwrap_or_default()),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.state.open = true;
+    }
+
+    pub fn show(
+        mut self,
+        ctx: &Context,
+        color_schemes: &mut ColorSchemes,
+        event_proxy: &impl EventProxy<UserEvent>,
+    ) {
+        let mut open = self.state.open;
+
+        Window::new(fl!("color_schemes_title"))
+            .id(self.id)
+            .open(&mut open)
+            .default_size([360.0, 560.0])
+            .show(ctx, |ui| {
+                self.show_picked(ui, color_schemes);
+
+                ui.separator();
+
+                let current = color_schemes.current(ui.visuals().dark_mode).name.clone();
+                let name = self.state.editing.clone().unwrap_or(current);
+
+                let Some(index) = color_schemes.index(&name) else {
+                    self.state.editing = None;
+
+                    return;
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label(fl!("color_schemes_edit"));
+
+                    ComboBox::from_id_source(self.id.with("editing"))
+                        .selected_text(&name)
+                        .show_ui(ui, |ui| {
+                            for scheme in &color_schemes.schemes {
+                                if ui
+                                    .selectable_label(scheme.name == name, &scheme.name)
+                                    .clicked()
+                                {
+                                    self.state.editing = Some(scheme.name.clone());
+                                }
+                            }
+                        });
+
+                    if ui
+                        .button(fl!("color_schemes_import"))
+                        .on_hover_text(fl!("color_schemes_import_hint"))
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::ImportColorScheme);
+                    }
+                });
+
+                ui.checkbox(
+                    &mut color_schemes.schemes[index].dark,
+                    fl!("color_schemes_is_dark"),
+                );
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        self.show_colors(ui, &mut color_schemes.schemes[index]);
+
+                        ui.add_space(8.0);
+
+                        self.show_preview(ui, &color_schemes.schemes[index]);
+                    });
+
+                ui.separator();
+
+                self.show_actions(ui, color_schemes, index);
+            });
+
+        self.state.open = open;
+
+        ctx.data_mut(|d| d.insert_temp(self.id, self.state));
+    }
+
+    fn show_actions(&mut self, ui: &mut Ui, color_schemes: &mut ColorSchemes, index: usize) {
+        ui.horizontal(|ui| {
+            if ui.button(fl!("color_schemes_save")).clicked() {
+                if let Err(err) = color_schemes.save_scheme(index) {
+                    log::warn!("Failed to save color scheme: {}", err);
+                }
+            }
+
+            let text = if ColorSchemes::is_builtin(&color_schemes.schemes[index].name) {
+                fl!("color_schemes_reset")
+            } else {
+                fl!("color_schemes_delete")
+            };
+
+            if ui.button(text).clicked() {
+                if let Err(err) = color_schemes.remove(index) {
+                    log::warn!("Failed to remove color scheme: {}", err);
+                }
+
+                self.state.editing = None;
+
+                return;
+            }
+
+            ui.separator();
+
+            ui.add(
+                TextEdit::singleline(&mut self.state.new_name)
+                    .hint_text(fl!("color_schemes_new_name"))
+                    .desired_width(NAME_WIDTH),
+            );
+
+            let new_name = self.state.new_name.trim();
+            let can_copy = !new_name.is_empty() && color_schemes.index(new_name).is_none();
+
+            if ui
+                .add_enabled(can_copy, Button::new(fl!("color_schemes_copy")))
+                .clicked()
+            {
+                let scheme = ColorScheme {
+                    name: new_name.to_owned(),
+                    ..color_schemes.schemes[index].clone()
+                };
+
+                self.state.editing = Some(scheme.name.clone());
+                self.state.new_name.clear();
+
+                let index = color_schemes.add(scheme);
+
+                if let Err(err) = color_schemes.save_scheme(index) {
+                    log::warn!("Failed to save color scheme: {}", err);
+                }
+            }
+        });
+    }
+
+    fn show_colors(&self, ui: &mut Ui, scheme: &mut ColorScheme) {
+        Grid::new(self.id.with("colors"))
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for token_type in TokenType::ALL {
+                    let Some(color) = scheme.colors.get_mut(token_type.name()) else {
+                        continue;
+                    };
+
+                    ui.label(token_type.label());
+                    ui.color_edit_button_srgba(color);
+                    ui.end_row();
+                }
+            });
+    }
+
+    // Saves the picks as soon as they change.
+    fn show_picked(&self, ui: &mut Ui, color_schemes: &mut ColorSchemes) {
+        let mut changed = false;
+
+        Grid::new(self.id.with("picked"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for dark in [true, false] {
+                    ui.label(if dark {
+                        fl!("color_schemes_dark")
+                    } else {
+                        fl!("color_schemes_light")
+                    });
+
+                    let picked = color_schemes.current(dark).name.clone();
+                    let mut chosen = None;
+
+                    ComboBox::from_id_source(self.id.with(dark))
+                        .selected_text(&picked)
+                        .show_ui(ui, |ui| {
+                            for scheme in &color_schemes.schemes {
+                                if ui
+                                    .selectable_label(scheme.name == picked, &scheme.name)
+                                    .clicked()
+                                {
+                                    chosen = Some(scheme.name.clone());
+                                }
+                            }
+                        });
+
+                    if let Some(name) = chosen {
+                        if dark {
+                            color_schemes.dark = name;
+                        } else {
+                            color_schemes.light = name;
+                        }
+
+                        changed = true;
+                    }
+
+                    ui.end_row();
+                }
+            });
+
+        if changed {
+            if let Err(err) = color_schemes.save() {
+                log::warn!("Failed to save color schemes: {}", err);
+            }
+        }
+    }
+
+    // On the background of the mode the scheme is meant for.
+    fn show_preview(&self, ui: &mut Ui, scheme: &ColorScheme) {
+        let id = self.id.with("symbols");
+
+        let (symbols, tokens) = ui
+            .data_mut(|d| d.get_temp::<(Symbols, Vec<Token>)>(id))
+            .unwrap_or_else(|| {
+                let symbols = Symbols::parse(PREVIEW, 0).unwrap_or_default();
+                let tokens = Tokenizer::default().update(PREVIEW).to_vec();
+
+                ui.data_mut(|d| d.insert_temp(id, (symbols.clone(), tokens.clone())));
+
+                (symbols, tokens)
+            });
+
+        let theme = CodeTheme::new(scheme, TextStyle::Monospace.resolve(ui.style()), None);
+        let job = Highlighter::layout(ui.ctx(), &theme, PREVIEW, &tokens, &symbols);
+
+        let visuals = if scheme.dark {
+            Visuals::dark()
+        } else {
+            Visuals::light()
+        };
+
+        ui.label(fl!("color_schemes_preview"));
+
+        Frame::none()
+            .fill(visuals.extreme_bg_color)
+            .inner_margin(8.0)
+            .rounding(visuals.widgets.noninteractive.rounding)
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+                ui.label(job);
+            });
+    }
+}
@@ -0,0 +1,319 @@
+use super::UiState;
+use crate::{
+    example::Example,
+    fuzzy::fuzzy_score,
+    i18n::LANGUAGES,
+    preferences::{CompareLayout, Theme},
+    shortcut::{Command, Shortcuts},
+};
+use egui::{
+    Align, Align2, Context, Id, Key, Layout, Modifiers, RichText, ScrollArea, TextEdit, Window,
+};
+use std::cmp::Reverse;
+
+const MAX_HEIGHT: f32 = 320.0;
+
+const MAX_RECENT: usize = 10;
+
+const WIDTH: f32 = 420.0;
+
+// What the palette runs.
+#[derive(Clone, Copy, PartialEq)]
+pub enum PaletteItem {
+    Command(Command),
+    CompareLayout(CompareLayout),
+    CompareTab(usize),
+    Example(Example),
+    // `None` for the system's languages.
+    Language(Option<&'static str>),
+    Theme(Theme),
+}
+
+struct Entry {
+    item: PaletteItem,
+    label: String,
+    shortcut: String,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    open: bool,
+    // The frame it opened on, whose click mustn't close it.
+    opened_frame: u64,
+    query: String,
+    // The most recent first.
+    recent: Vec<PaletteItem>,
+    selected: usize,
+}
+
+// Lists every command of the app to search and run them, those run recently
+// first.
+pub struct CommandPalette {
+    id: Id,
+    state: State,
+}
+
+impl CommandPalette {
+    pub fn new(ctx: &Context) -> Self {
+        let id = Id::new("command_palette");
+
+        Self {
+            id,
+            state: ctx.data_mut(|d| d.get_temp::<State>(id).unwrap_or_default()),
+        }
+    }
+
+    pub fn open(ctx: &Context) {
+        let frame = ctx.frame_nr();
+
+        ctx.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<State>(Id::new("command_palette"));
+
+            state.open = true;
+            state.opened_frame = frame;
+            state.query.clear();
+            state.selected = 0;
+        });
+    }
+
+    // Returns the item picked, if any.
+    pub fn show(
+        mut self,
+        ctx: &Context,
+        shortcuts: &Shortcuts,
+        state: &UiState<'_>,
+    ) -> Option<PaletteItem> {
+        if !self.state.open {
+            return None;
+        }
+
+        let entries = self.matches(ctx, shortcuts, state);
+
+        let (down, up, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+
+        if down {
+            self.state.selected = (self.state.selected + 1).min(entries.len().saturating_sub(1));
+        }
+
+        if up {
+            self.state.selected = self.state.selected.saturating_sub(1);
+        }
+
+        let mut picked = entries
+            .get(self.state.selected)
+            .filter(|_| enter)
+            .map(|entry| entry.item);
+
+        let mut query_changed = false;
+
+        let response = Window::new(fl!("command_palette"))
+            .id(self.id)
+            .title_bar(false)
+            .resizable(false)
+            .collapsible(false)
+            .anchor(Align2::CENTER_TOP, [0.0, 48.0])
+            .show(ctx, |ui| {
+                ui.set_width(WIDTH);
+
+                let response = ui.add(
+                    TextEdit::singleline(&mut self.state.query)
+                        .hint_text(fl!("palette_hint"))
+                        .desired_width(f32::INFINITY),
+                );
+
+                response.request_focus();
+
+                query_changed = response.changed();
+
+                ui.separator();
+
+                if entries.is_empty() {
+                    ui.weak(fl!("edit_find_no_results"));
+
+                    return;
+                }
+
+                ScrollArea::vertical()
+                    .max_height(MAX_HEIGHT)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for (index, entry) in entries.iter().enumerate() {
+                            let is_selected = index == self.state.selected;
+
+                            let response = ui
+                                .horizontal(|ui| {
+                                    let response = ui.selectable_label(is_selected, &entry.label);
+
+                                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                        ui.label(RichText::new(&entry.shortcut).weak());
+                                    });
+
+                                    response
+                                })
+                                .inner;
+
+                            if is_selected && (down || up) {
+                                response.scroll_to_me(None);
+                            }
+
+                            if response.clicked() {
+                                picked = Some(entry.item);
+                            }
+                        }
+                    });
+            });
+
+        let clicked_elsewhere = ctx.frame_nr() > self.state.opened_frame
+            && response.is_some_and(|response| response.response.clicked_elsewhere());
+
+        if escape || clicked_elsewhere {
+            self.state.open = false;
+        }
+
+        if let Some(item) = picked {
+            self.state.open = false;
+
+            self.state.recent.retain(|recent| *recent != item);
+            self.state.recent.insert(0, item);
+            self.state.recent.truncate(MAX_RECENT);
+        }
+
+        // Starts over from the best match as the query changes.
+        if query_changed {
+            self.state.selected = 0;
+        }
+
+        ctx.data_mut(|d| d.insert_temp(self.id, self.state));
+
+        picked
+    }
+
+    // The entries matching the query, those run recently first, then the
+    // best matches.
+    fn matches(&self, ctx: &Context, shortcuts: &Shortcuts, state: &UiState<'_>) -> Vec<Entry> {
+        let mut entries: Vec<(Option<usize>, i32, Entry)> = entries(ctx, shortcuts, state)
+            .into_iter()
+            .filter_map(|entry| {
+                let score = fuzzy_score(&self.state.query, &entry.label)?;
+
+                let recent = self
+                    .state
+                    .recent
+                    .iter()
+                    .position(|recent| *recent == entry.item);
+
+                Some((recent, score, entry))
+            })
+            .collect();
+
+        // A stable sort, so equal matches keep their order.
+        entries.sort_by_key(|(recent, score, _)| (recent.unwrap_or(usize::MAX), Reverse(*score)));
+
+        entries.into_iter().map(|(_, _, entry)| entry).collect()
+    }
+}
+
+fn entries(ctx: &Context, shortcuts: &Shortcuts, state: &UiState<'_>) -> Vec<Entry> {
+    let mut entries = vec![];
+
+    let mut add = |item: PaletteItem, label: String| {
+        let shortcut = match item {
+            PaletteItem::Command(command) => shortcuts.format(ctx, command),
+            _ => String::new(),
+        };
+
+        entries.push(Entry {
+            item,
+            label,
+            shortcut,
+        });
+    };
+
+    for command in Command::ALL {
+        // The palette itself, and those acting on the symbol at the cursor
+        // as it's typed.
+        let excluded = matches!(
+            command,
+            Command::AppCommandPalette
+                | Command::EditComplete
+                | Command::EditFindReferences
+                | Command::EditGoToDefinition
+                | Command::EditRename
+        );
+
+        if !excluded {
+            add(PaletteItem::Command(command), command.label());
+        }
+    }
+
+    for example in [
+        Example::Default,
+        Example::MouseInput,
+        Example::Texture,
+        Example::TwoTexture,
+    ] {
+        add(
+            PaletteItem::Example(example),
+            format!("{}: {}", fl!("menu_open_examples"), example.description()),
+        );
+    }
+
+    for (index, tab) in state.tabs.iter().enumerate() {
+        if index != state.active_tab {
+            add(
+                PaletteItem::CompareTab(index),
+                format!(
+                    "{}: {}",
+                    fl!("menu_compare"),
+                    fl!("menu_compare_tab", name = tab.name.as_str())
+                ),
+            );
+        }
+    }
+
+    for (layout, label) in [
+        (CompareLayout::SideBySide, fl!("menu_compare_side_by_side")),
+        (CompareLayout::Wipe, fl!("menu_compare_wipe")),
+    ] {
+        add(
+            PaletteItem::CompareLayout(layout),
+            format!("{}: {}", fl!("menu_compare"), label),
+        );
+    }
+
+    for (theme, label) in [
+        (Theme::Light, fl!("menu_theme_light")),
+        (Theme::Dark, fl!("menu_theme_dark")),
+    ] {
+        add(
+            PaletteItem::Theme(theme),
+            format!("{}: {}", fl!("menu_theme"), label),
+        );
+    }
+
+    add(
+        PaletteItem::Language(None),
+        format!("{}: {}", fl!("menu_languages"), fl!("menu_language_system")),
+    );
+
+    for language in LANGUAGES {
+        add(
+            PaletteItem::Language(Some(language.id)),
+            format!(
+                "{}: {} [{}]",
+                fl!("menu_languages"),
+                language.label,
+                language.id
+            ),
+        );
+    }
+
+    entries
+}
@@ -0,0 +1,410 @@
+use super::utils::byte_index;
+use crate::{
+    shortcut::{Command, Shortcuts},
+    wgsl::{complete, CompletionItem, CompletionKind, Symbols, Tokenizer},
+};
+use egui::{
+    text::{CCursor, CCursorRange},
+    widgets::text_edit::TextEditOutput,
+    Area, Context, Frame, Id, Key, Modifiers, Order, RichText, ScrollArea, Ui,
+};
+use std::ops::Range;
+
+const MAX_HEIGHT: f32 = 240.0;
+const MIN_WIDTH: f32 = 280.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Action {
+    Accept,
+    Close,
+    Next,
+    NextArgument,
+    Previous,
+    Trigger,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    // Where the cursor was last frame, as a char index.
+    cursor: Option<usize>,
+    explicit: bool,
+    open: bool,
+    selected: usize,
+    // The `(` of a call inserted with parameter placeholders,
+    // as a char index. Tab selects the next argument while inside it.
+    snippet: Option<usize>,
+}
+
+// A completion popup for a `TextEdit`, keeping its state in egui's memory.
+//
+// The popup takes its keys before the editor runs, see `consume_keys`,
+// and is shown after it, from the editor's output.
+pub struct Completion<'a> {
+    action: Option<Action>,
+    editor_id: Id,
+    state: State,
+    symbols: &'a Symbols,
+    texture_count: usize,
+}
+
+impl<'a> Completion<'a> {
+    pub fn new(ctx: &Context, editor_id: Id, symbols: &'a Symbols, texture_count: usize) -> Self {
+        let state = ctx.data_mut(|d| d.get_temp(editor_id.with("completion")).unwrap_or_default());
+
+        Self {
+            action: None,
+            editor_id,
+            state,
+            symbols,
+            texture_count,
+        }
+    }
+
+    pub fn consume_keys(&mut self, ctx: &Context, shortcuts: &Shortcuts) {
+        if !ctx.memory(|mem| mem.has_focus(self.editor_id)) {
+            return;
+        }
+
+        self.action = ctx.input_mut(|i| {
+            if shortcuts.consume(i, Command::EditComplete) {
+                Some(Action::Trigger)
+            } else if self.state.open {
+                if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                    Some(Action::Next)
+                } else if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                    Some(Action::Previous)
+                } else if i.consume_key(Modifiers::NONE, Key::Enter)
+                    || i.consume_key(Modifiers::NONE, Key::Tab)
+                {
+                    Some(Action::Accept)
+                } else if i.consume_key(Modifiers::NONE, Key::Escape) {
+                    Some(Action::Close)
+                } else {
+                    None
+                }
+            } else if self.state.snippet.is_some() && i.consume_key(Modifiers::NONE, Key::Tab) {
+                Some(Action::NextArgument)
+            } else {
+                None
+            }
+        });
+    }
+
+    // `tokenizer` is the one lexing `text`.
+    pub fn show(
+        mut self,
+        ui: &Ui,
+        output: &mut TextEditOutput,
+        text: &mut String,
+        tokenizer: &mut Tokenizer,
+    ) {
+        let ctx = ui.ctx().clone();
+
+        match output.cursor_range {
+            Some(cursor_range) => {
+                self.update(&ctx, output, cursor_range.primary.ccursor, text, tokenizer)
+            }
+            None => self.state = State::default(),
+        }
+
+        ctx.data_mut(|d| d.insert_temp(self.editor_id.with("completion"), self.state));
+    }
+
+    fn update(
+        &mut self,
+        ctx: &Context,
+        output: &mut TextEditOutput,
+        cursor: CCursor,
+        text: &mut String,
+        tokenizer: &mut Tokenizer,
+    ) {
+        let byte = byte_index(text, cursor.index);
+
+        if output.response.changed() {
+            // Typing a name or a `.` keeps the popup open, anything else closes it.
+            let opens = text[..byte]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.');
+
+            self.state.explicit &= self.state.open && opens;
+            self.state.open = opens;
+            self.state.selected = 0;
+        } else if self.state.cursor != Some(cursor.index) {
+            self.state.open = false;
+        }
+
+        self.state.cursor = Some(cursor.index);
+
+        if let Some(open) = self.state.snippet {
+            let is_inside =
+                call_end(text, open).is_some_and(|end| (open + 1..=end).contains(&cursor.index));
+
+            if !is_inside {
+                self.state.snippet = None;
+            }
+        }
+
+        match self.action {
+            Some(Action::Close) => self.state.open = false,
+            Some(Action::NextArgument) => {
+                if let Some(open) = self.state.snippet {
+                    let range = self.next_argument(text, open, cursor.index);
+
+                    self.select(ctx, output, range);
+                }
+            }
+            Some(Action::Trigger) => {
+                self.state.explicit = true;
+                self.state.open = true;
+                self.state.selected = 0;
+            }
+            _ => {}
+        }
+
+        if !self.state.open {
+            return;
+        }
+
+        let Some(completions) = complete(
+            text,
+            tokenizer.update(text),
+            byte,
+            self.symbols,
+            self.texture_count,
+            self.state.explicit,
+        ) else {
+            self.state.open = false;
+
+            return;
+        };
+
+        let count = completions.items.len();
+        self.state.selected = self.state.selected.min(count - 1);
+
+        let mut accepted = None;
+
+        match self.action {
+            Some(Action::Accept) => accepted = Some(self.state.selected),
+            Some(Action::Next) => self.state.selected = (self.state.selected + 1) % count,
+            Some(Action::Previous) => {
+                self.state.selected = (self.state.selected + count - 1) % count
+            }
+            _ => {}
+        }
+
+        let scroll = matches!(self.action, Some(Action::Next) | Some(Action::Previous));
+
+        let pos = output
+            .galley
+            .pos_from_cursor(&output.galley.from_ccursor(cursor))
+            .translate(output.text_draw_pos.to_vec2())
+            .left_bottom();
+
+        let clicked = Area::new(self.editor_id.with("completion_popup"))
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style())
+                    .show(ui, |ui| {
+                        ui.set_min_width(MIN_WIDTH);
+
+                        ScrollArea::vertical()
+                            .max_height(MAX_HEIGHT)
+                            .show(ui, |ui| self.list(ui, &completions.items, scroll))
+                            .inner
+                    })
+                    .inner
+            })
+            .inner;
+
+        if let Some(index) = accepted.or(clicked) {
+            self.accept(
+                ctx,
+                output,
+                text,
+                completions.range,
+                &completions.items[index],
+            );
+        } else if !output.response.has_focus() {
+            self.state.open = false;
+        }
+    }
+
+    // Returns the index of the clicked item, if any.
+    fn list(&self, ui: &mut Ui, items: &[CompletionItem], scroll: bool) -> Option<usize> {
+        let mut clicked = None;
+
+        for (index, item) in items.iter().enumerate() {
+            let selected = index == self.state.selected;
+
+            let response = ui
+                .horizontal(|ui| {
+                    ui.label(RichText::new(kind_label(item.kind)).monospace().weak());
+
+                    let response =
+                        ui.selectable_label(selected, RichText::new(&item.label).monospace());
+
+                    if selected && !item.detail.is_empty() {
+                        ui.label(RichText::new(&item.detail).monospace().small().weak());
+                    }
+
+                    response
+                })
+                .inner;
+
+            if response.clicked() {
+                clicked = Some(index);
+            }
+
+            if selected && scroll {
+                response.scroll_to_me(None);
+            }
+        }
+
+        clicked
+    }
+
+    fn accept(
+        &mut self,
+        ctx: &Context,
+        output: &mut TextEditOutput,
+        text: &mut String,
+        range: Range<usize>,
+        item: &CompletionItem,
+    ) {
+        let (insert, selection) = item.insert_text();
+
+        text.replace_range(range.clone(), &insert);
+
+        let start = text[..range.start].chars().count();
+        let chars = |end: usize| start + insert[..end].chars().count();
+
+        let ccursor_range = match selection {
+            Some(selection) => {
+                self.state.snippet = Some(chars(item.label.len()));
+
+                CCursorRange::two(
+                    CCursor::new(chars(selection.start)),
+                    CCursor::new(chars(selection.end)),
+                )
+            }
+            None => CCursorRange::one(CCursor::new(chars(insert.len()))),
+        };
+
+        self.state.open = false;
+        self.select(ctx, output, ccursor_range);
+    }
+
+    fn select(&mut self, ctx: &Context, output: &mut TextEditOutput, range: CCursorRange) {
+        self.state.cursor = Some(range.primary.index);
+
+        output.state.set_ccursor_range(Some(range));
+        output.state.clone().store(ctx, self.editor_id);
+
+        ctx.memory_mut(|mem| mem.request_focus(self.editor_id));
+    }
+
+    // Selects the argument after the one at `cursor`, or leaves the call
+    // past its `)` after the last one.
+    fn next_argument(&mut self, text: &str, open: usize, cursor: usize) -> CCursorRange {
+        let chars: Vec<char> = text.chars().collect();
+        let end = call_end(text, open).unwrap_or(chars.len());
+
+        let Some(comma) = next_comma(&chars, open, cursor, end) else {
+            self.state.snippet = None;
+
+            return CCursorRange::one(CCursor::new((end + 1).min(chars.len())));
+        };
+
+        let start = (comma + 1..end)
+            .find(|&index| !chars[index].is_whitespace())
+            .unwrap_or(end);
+        let argument_end = next_comma(&chars, open, start, end).unwrap_or(end);
+
+        CCursorRange::two(CCursor::new(start), CCursor::new(argument_end))
+    }
+}
+
+// The char index of the `)` closing the call opened at `open`.
+fn call_end(text: &str, open: usize) -> Option<usize> {
+    let mut chars = text.chars().enumerate().skip(open);
+
+    if chars.next()?.1 != '(' {
+        return None;
+    }
+
+    let mut depth = 0;
+
+    for (index, c) in chars {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' if depth == 0 => return (c == ')').then_some(index),
+            ')' | ']' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// The char index of the first `,` separating the arguments of the call
+// opened at `open`, between `from` and `end`.
+fn next_comma(chars: &[char], open: usize, from: usize, end: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, c) in chars.iter().enumerate().take(end).skip(open + 1) {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 && index >= from => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn kind_label(kind: CompletionKind) -> &'static str {
+    match kind {
+        CompletionKind::Field => "fld",
+        CompletionKind::Function => "fn ",
+        CompletionKind::Keyword => "kw ",
+        CompletionKind::Struct => "st ",
+        CompletionKind::Type => "ty ",
+        CompletionKind::Variable => "var",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{call_end, next_comma};
+
+    #[test]
+    fn call_ends() {
+        let cases: &[(&str, usize, Option<usize>)] = &[
+            ("f(a, (b), c) + 1", 1, Some(11)),
+            ("f(a, g[0], c)", 1, Some(12)),
+            ("é(a)", 1, Some(3)),
+            ("f(a, (b)", 1, None),
+            ("f(a]", 1, None),
+            ("f(a)", 0, None),
+        ];
+
+        for (text, open, end) in cases {
+            assert_eq!(call_end(text, *open), *end, "{}", text);
+        }
+    }
+
+    #[test]
+    fn next_commas() {
+        let chars: Vec<_> = "f(a, g(b, c), d)".chars().collect();
+
+        assert_eq!(next_comma(&chars, 1, 2, 15), Some(3));
+        assert_eq!(next_comma(&chars, 1, 4, 15), Some(12));
+        // Not the one nested in `g(..)`.
+        assert_eq!(next_comma(&chars, 1, 8, 15), Some(12));
+        assert_eq!(next_comma(&chars, 1, 13, 15), None);
+        assert_eq!(next_comma(&chars, 1, 2, 3), None);
+    }
+}
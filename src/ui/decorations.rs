@@ -0,0 +1,69 @@
+use super::utils::byte_index;
+use crate::wgsl::{matching_bracket, Token};
+use egui::{pos2, text::CCursor, widgets::text_edit::TextEditOutput, Color32, Rect, Stroke, Ui};
+
+// Tints the rows of the line the cursor is on, across the whole editor.
+//
+// The editor paints its background under the text itself, so the tint is
+// light enough to go over the text.
+pub fn paint_current_line(ui: &Ui, output: &TextEditOutput, cursor: CCursor) {
+    let galley = &output.galley;
+    let paragraph = galley.from_ccursor(cursor).pcursor.paragraph;
+
+    let color = if ui.visuals().dark_mode {
+        Color32::from_white_alpha(6)
+    } else {
+        Color32::from_black_alpha(8)
+    };
+
+    let mut current = 0;
+
+    for row in &galley.rows {
+        if current == paragraph {
+            let top = output.text_draw_pos.y + row.rect.min.y;
+
+            let rect = Rect::from_min_max(
+                pos2(output.response.rect.left(), top),
+                pos2(output.response.rect.right(), top + row.rect.height()),
+            );
+
+            ui.painter().rect_filled(rect, 0.0, color);
+        }
+
+        if row.ends_with_newline {
+            current += 1;
+        }
+    }
+}
+
+// Outlines the bracket next to the cursor and the one matching it, `tokens`
+// being those of `text`.
+pub fn paint_matching_brackets(
+    ui: &Ui,
+    output: &TextEditOutput,
+    text: &str,
+    tokens: &[Token],
+    cursor: CCursor,
+) {
+    let offset = byte_index(text, cursor.index);
+
+    let Some((bracket, matching)) = matching_bracket(text, tokens, offset) else {
+        return;
+    };
+
+    let galley = &output.galley;
+    let stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+
+    for range in [bracket, matching] {
+        let rect_at = |byte: usize| {
+            let ccursor = CCursor::new(text[..byte].chars().count());
+
+            galley.pos_from_cursor(&galley.from_ccursor(ccursor))
+        };
+
+        let rect = Rect::from_min_max(rect_at(range.start).min, rect_at(range.end).max)
+            .translate(output.text_draw_pos.to_vec2());
+
+        ui.painter().rect_stroke(rect, 1.0, stroke);
+    }
+}
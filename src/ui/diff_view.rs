@@ -0,0 +1,66 @@
+use super::utils::error_color;
+use crate::diff::{diff_lines, DiffLine};
+use egui::{util::cache, Color32, Context, Id, RichText, ScrollArea, Window};
+
+// Keyed on both sources, so they're only diffed again once either changes.
+type DiffCache = cache::FrameCache<Vec<(Kind, String)>, Differ>;
+
+#[derive(Default)]
+struct Differ;
+
+impl cache::ComputerMut<(&str, &str), Vec<(Kind, String)>> for Differ {
+    fn compute(&mut self, (old, new): (&str, &str)) -> Vec<(Kind, String)> {
+        diff_lines(old, new)
+            .into_iter()
+            .map(|line| match line {
+                DiffLine::Added(text) => (Kind::Added, format!("+ {}", text)),
+                DiffLine::Removed(text) => (Kind::Removed, format!("- {}", text)),
+                DiffLine::Unchanged(text) => (Kind::Unchanged, format!("  {}", text)),
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Added,
+    Removed,
+    Unchanged,
+}
+
+// Shows the lines changed from `old` to `new` in a window.
+pub fn show_diff(ctx: &Context, id: Id, title: String, open: &mut bool, old: &str, new: &str) {
+    let lines = ctx.memory_mut(|mem| mem.caches.cache::<DiffCache>().get((old, new)));
+
+    Window::new(title)
+        .id(id)
+        .open(open)
+        .default_size([400.0, 300.0])
+        .show(ctx, |ui| {
+            let added_color = if ui.visuals().dark_mode {
+                Color32::LIGHT_GREEN
+            } else {
+                Color32::DARK_GREEN
+            };
+
+            ScrollArea::both()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    if lines.iter().all(|(kind, _)| *kind == Kind::Unchanged) {
+                        ui.label(fl!("compare_no_changes"));
+
+                        return;
+                    }
+
+                    for (kind, text) in &lines {
+                        let text = RichText::new(text).monospace();
+
+                        ui.label(match kind {
+                            Kind::Added => text.color(added_color),
+                            Kind::Removed => text.color(error_color(ui)),
+                            Kind::Unchanged => text,
+                        });
+                    }
+                });
+        });
+}
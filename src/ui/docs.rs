@@ -0,0 +1,381 @@
+use super::utils::{byte_index, word_at};
+use crate::{
+    i18n::LANGUAGE_LOADER,
+    shader::{IMAGE_FN_SIGNATURE, UNIFORM_FIELDS},
+    wgsl::{
+        enclosing_call, enclosing_function, find_function, parameter_ranges, BuiltinFunction,
+        Section, SymbolKind, Symbols, Token, TokenKind,
+    },
+};
+use egui::{
+    text::{CCursor, LayoutJob},
+    widgets::text_edit::TextEditOutput,
+    Align2, Area, Context, Frame, Id, Order, Pos2, Rect, Stroke, TextFormat, TextStyle, Ui,
+};
+use std::ops::Range;
+
+// How long the pointer rests on a name before its documentation shows, in seconds.
+const HOVER_DELAY: f64 = 0.5;
+const MAX_WIDTH: f32 = 420.0;
+
+#[derive(Clone)]
+enum Topic {
+    Builtin(&'static BuiltinFunction),
+    // The signature of a function of the shader.
+    Function(String),
+    Image,
+    Uniform,
+    // The name and type of a field of the uniform.
+    UniformField(&'static str, &'static str),
+}
+
+impl Topic {
+    fn is_function(&self) -> bool {
+        matches!(self, Topic::Builtin(_) | Topic::Function(_) | Topic::Image)
+    }
+
+    fn signatures(&self) -> Vec<String> {
+        match self {
+            Topic::Builtin(function) => function
+                .signatures
+                .iter()
+                .map(|signature| signature.to_string())
+                .collect(),
+            Topic::Function(signature) => vec![signature.clone()],
+            Topic::Image => vec![IMAGE_FN_SIGNATURE.to_owned()],
+            Topic::Uniform => {
+                let fields: String = UNIFORM_FIELDS
+                    .iter()
+                    .map(|(name, ty)| format!("\n    {}: {},", name, ty))
+                    .collect();
+
+                vec![
+                    "var<uniform> u: Uniform".to_owned(),
+                    format!("struct Uniform {{{}\n}}", fields),
+                ]
+            }
+            Topic::UniformField(name, ty) => vec![format!("u.{}: {}", name, ty)],
+        }
+    }
+
+    fn description(&self) -> Option<String> {
+        match self {
+            Topic::Builtin(function) => {
+                let id = function.description_id();
+
+                // Descriptions missing from every language are left out
+                // rather than shown as an error.
+                LANGUAGE_LOADER.has(&id).then(|| LANGUAGE_LOADER.get(&id))
+            }
+            Topic::Function(_) => None,
+            Topic::Image => Some(fl!("wgsl_image")),
+            Topic::Uniform => Some(fl!("wgsl_uniform")),
+            Topic::UniformField(name, _) => match *name {
+                "cursor" => Some(fl!("wgsl_uniform_cursor")),
+                "mouse_down" => Some(fl!("wgsl_uniform_mouse_down")),
+                "mouse_press" => Some(fl!("wgsl_uniform_mouse_press")),
+                "mouse_release" => Some(fl!("wgsl_uniform_mouse_release")),
+                "resolution" => Some(fl!("wgsl_uniform_resolution")),
+                "time" => Some(fl!("wgsl_uniform_time")),
+                _ => None,
+            },
+        }
+    }
+
+    // `argument` is the index of the argument being typed, for signature help.
+    fn ui(&self, ui: &mut Ui, argument: Option<usize>) {
+        for signature in self.signatures() {
+            ui.label(signature_job(ui, &signature, argument));
+        }
+
+        if let Some(description) = self.description() {
+            ui.separator();
+
+            // Parameter names and code are quoted with backticks.
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+
+                for (index, part) in description.split('`').enumerate() {
+                    if index % 2 == 0 {
+                        ui.label(part);
+                    } else {
+                        ui.code(part);
+                    }
+                }
+            });
+        }
+
+        if let (Topic::Builtin(function), None) = (self, argument) {
+            let section = function.section();
+
+            ui.hyperlink_to(section_title(section), section.url());
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Hover {
+    // The name hovered, in screen space.
+    rect: Rect,
+    // The popup, once shown.
+    popup: Option<Rect>,
+    topic: Topic,
+}
+
+impl Hover {
+    // The popup stays open while the pointer moves from the name onto it.
+    fn contains(&self, pos: Pos2) -> bool {
+        self.popup
+            .map_or(self.rect, |popup| popup.union(self.rect))
+            .contains(pos)
+    }
+}
+
+// Documentation of the builtins, the uniform and the functions of the
+// shader: shown when the pointer rests on a name, and as signature help
+// while typing the arguments of a call.
+pub struct Docs<'a> {
+    editor_id: Id,
+    symbols: &'a Symbols,
+    // Those of the text shown.
+    tokens: &'a [Token],
+}
+
+impl<'a> Docs<'a> {
+    pub fn new(editor_id: Id, symbols: &'a Symbols, tokens: &'a [Token]) -> Self {
+        Self {
+            editor_id,
+            symbols,
+            tokens,
+        }
+    }
+
+    pub fn show(&self, ui: &Ui, output: &TextEditOutput, text: &str) {
+        let ctx = ui.ctx();
+
+        self.show_hover(ctx, output, text);
+
+        if let Some(cursor_range) = output.cursor_range {
+            if output.response.has_focus() {
+                self.show_signature_help(ctx, output, text, cursor_range.primary.ccursor);
+            }
+        }
+    }
+
+    fn show_hover(&self, ctx: &Context, output: &TextEditOutput, text: &str) {
+        let id = self.editor_id.with("hover");
+
+        let (pointer, resting, pressed) = ctx.input(|i| {
+            (
+                i.pointer.hover_pos(),
+                i.pointer.time_since_last_movement() >= HOVER_DELAY,
+                i.pointer.any_down(),
+            )
+        });
+
+        let Some(pointer) = pointer else {
+            ctx.data_mut(|d| d.remove::<Hover>(id));
+
+            return;
+        };
+
+        let mut hover = ctx
+            .data_mut(|d| d.get_temp::<Hover>(id))
+            .filter(|hover| !output.response.changed() && hover.contains(pointer));
+
+        if hover.is_none() && resting && !pressed && output.response.hovered() {
+            hover = self.hover_at(output, text, pointer);
+        }
+
+        let Some(mut hover) = hover else {
+            ctx.data_mut(|d| d.remove::<Hover>(id));
+
+            return;
+        };
+
+        let popup = Area::new(self.editor_id.with("hover_popup"))
+            .order(Order::Tooltip)
+            .fixed_pos(hover.rect.left_bottom())
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(MAX_WIDTH);
+
+                    hover.topic.ui(ui, None);
+                })
+            })
+            .response
+            .rect;
+
+        hover.popup = Some(popup);
+
+        ctx.data_mut(|d| d.insert_temp(id, hover));
+    }
+
+    fn hover_at(&self, output: &TextEditOutput, text: &str, pointer: Pos2) -> Option<Hover> {
+        let galley = &output.galley;
+        let origin = output.text_draw_pos.to_vec2();
+
+        let byte = byte_index(
+            text,
+            galley
+                .cursor_from_pos((pointer - origin).to_vec2())
+                .ccursor
+                .index,
+        );
+        let Range { start, end } = word_at(text, byte)?;
+
+        let rect_at = |byte: usize| {
+            let ccursor = CCursor::new(text[..byte].chars().count());
+
+            galley.pos_from_cursor(&galley.from_ccursor(ccursor))
+        };
+        let rect = Rect::from_min_max(rect_at(start).min, rect_at(end).max).translate(origin);
+
+        // The nearest cursor may be past the end of a line, away from any word.
+        if !rect.contains(pointer) {
+            return None;
+        }
+
+        let tokens = self.tokens;
+
+        let position = tokens.iter().position(|token| token.start == start)?;
+        let token = tokens[position];
+
+        if token.kind != TokenKind::Ident || token.end != end {
+            return None;
+        }
+
+        let mut significant = tokens[..position]
+            .iter()
+            .rev()
+            .filter(|token| !token.is_trivia());
+        let receiver = match significant.next() {
+            Some(previous) if previous.text(text) == "." => Some(
+                significant
+                    .next()
+                    .map_or("", |receiver| receiver.text(text)),
+            ),
+            _ => None,
+        };
+
+        let function = enclosing_function(text, &tokens[..position]);
+        let topic = self.topic(token.text(text), function, receiver)?;
+
+        Some(Hover {
+            rect,
+            popup: None,
+            topic,
+        })
+    }
+
+    fn show_signature_help(
+        &self,
+        ctx: &Context,
+        output: &TextEditOutput,
+        text: &str,
+        cursor: CCursor,
+    ) {
+        let byte = byte_index(text, cursor.index);
+
+        let call = enclosing_call(text, self.tokens, byte);
+
+        // Locals can't be called, so the function the call is in doesn't matter.
+        let Some((call, topic)) = call.and_then(|call| {
+            self.topic(&call.name, None, None)
+                .filter(Topic::is_function)
+                .map(|topic| (call, topic))
+        }) else {
+            return;
+        };
+
+        let pos = output
+            .galley
+            .pos_from_cursor(&output.galley.from_ccursor(cursor))
+            .translate(output.text_draw_pos.to_vec2())
+            .left_top();
+
+        Area::new(self.editor_id.with("signature_help"))
+            .order(Order::Tooltip)
+            .pivot(Align2::LEFT_BOTTOM)
+            .fixed_pos(pos)
+            .interactable(false)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(MAX_WIDTH);
+
+                    topic.ui(ui, Some(call.argument));
+                })
+            });
+    }
+
+    // `receiver` is the name before the `.`, for member accesses.
+    fn topic(&self, name: &str, function: Option<&str>, receiver: Option<&str>) -> Option<Topic> {
+        if let Some(receiver) = receiver {
+            return UNIFORM_FIELDS
+                .iter()
+                .find(|(field, _)| receiver == "u" && *field == name)
+                .map(|&(field, ty)| Topic::UniformField(field, ty));
+        }
+
+        // The uniform and `image` are documented even before the first parse,
+        // unless shadowed by a local.
+        match self.symbols.resolve(name, function, false) {
+            Some(SymbolKind::LocalVariable) => None,
+            _ if name == "u" => Some(Topic::Uniform),
+            _ if name == "image" => Some(Topic::Image),
+            Some(SymbolKind::Function) => {
+                let parameters = self.symbols.parameters(name)?;
+
+                Some(Topic::Function(format!(
+                    "{}({})",
+                    name,
+                    parameters.join(", ")
+                )))
+            }
+            Some(SymbolKind::BuiltinFunction) | None => find_function(name).map(Topic::Builtin),
+            _ => None,
+        }
+    }
+}
+
+fn section_title(section: Section) -> String {
+    match section {
+        Section::Array => fl!("wgsl_section_array"),
+        Section::Atomic => fl!("wgsl_section_atomic"),
+        Section::Derivative => fl!("wgsl_section_derivative"),
+        Section::Logical => fl!("wgsl_section_logical"),
+        Section::Numeric => fl!("wgsl_section_numeric"),
+        Section::Packing => fl!("wgsl_section_packing"),
+        Section::Synchronization => fl!("wgsl_section_synchronization"),
+        Section::Texture => fl!("wgsl_section_texture"),
+        Section::Unpacking => fl!("wgsl_section_unpacking"),
+    }
+}
+
+// A signature in monospace, with the parameter at `argument` underlined.
+fn signature_job(ui: &Ui, signature: &str, argument: Option<usize>) -> LayoutJob {
+    let font_id = TextStyle::Monospace.resolve(ui.style());
+    let color = ui.visuals().text_color();
+    let strong_color = ui.visuals().strong_text_color();
+
+    let mut job = LayoutJob::default();
+    let normal = TextFormat::simple(font_id.clone(), color);
+
+    match argument.and_then(|argument| parameter_ranges(signature).get(argument).cloned()) {
+        Some(range) => {
+            job.append(&signature[..range.start], 0.0, normal.clone());
+            job.append(
+                &signature[range.clone()],
+                0.0,
+                TextFormat {
+                    underline: Stroke::new(1.0, strong_color),
+                    ..TextFormat::simple(font_id, strong_color)
+                },
+            );
+            job.append(&signature[range.end..], 0.0, normal);
+        }
+        None => job.append(signature, 0.0, normal),
+    }
+
+    job
+}
@@ -0,0 +1,213 @@
+use super::utils::byte_index;
+use crate::{
+    preferences::EditorPreferences,
+    shortcut::{Command, Shortcuts},
+    wgsl::{
+        auto_close, dedent_closing, delete_lines, delete_pair, duplicate_lines, format_document,
+        indent_lines, move_lines, newline, outdent_lines, toggle_block_comment,
+        toggle_line_comment, Edit, Selection,
+    },
+};
+use egui::{
+    text::{CCursor, CCursorRange},
+    text_edit::TextEditState,
+    Context, Event, Id, Key,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EditCommand {
+    CommentBlock,
+    CommentLine,
+    DeleteLine,
+    DuplicateLine,
+    Format,
+    Indent,
+    MoveLineDown,
+    MoveLineUp,
+    Outdent,
+}
+
+impl EditCommand {
+    pub fn from_command(command: Command) -> Option<Self> {
+        match command {
+            Command::EditCommentBlock => Some(Self::CommentBlock),
+            Command::EditCommentLine => Some(Self::CommentLine),
+            Command::EditDeleteLine => Some(Self::DeleteLine),
+            Command::EditDuplicateLine => Some(Self::DuplicateLine),
+            Command::EditFormat => Some(Self::Format),
+            Command::EditIndent => Some(Self::Indent),
+            Command::EditMoveLineDown => Some(Self::MoveLineDown),
+            Command::EditMoveLineUp => Some(Self::MoveLineUp),
+            Command::EditOutdent => Some(Self::Outdent),
+            _ => None,
+        }
+    }
+}
+
+// Auto-indent, auto-closing of brackets and line commands for a `TextEdit`.
+//
+// Edits the text before the editor runs, taking the keys it handles from the
+// input so the editor doesn't handle them as well.
+pub struct Editing<'a> {
+    editor_id: Id,
+    preferences: &'a EditorPreferences,
+}
+
+impl<'a> Editing<'a> {
+    pub fn new(editor_id: Id, preferences: &'a EditorPreferences) -> Self {
+        Self {
+            editor_id,
+            preferences,
+        }
+    }
+
+    pub fn consume_keys(&self, ctx: &Context, shortcuts: &Shortcuts, text: &mut String) {
+        if !ctx.memory(|mem| mem.has_focus(self.editor_id)) {
+            return;
+        }
+
+        let command = ctx.input_mut(|i| {
+            Command::ALL.into_iter().find_map(|command| {
+                let edit_command = EditCommand::from_command(command)?;
+
+                shortcuts.consume(i, command).then_some(edit_command)
+            })
+        });
+
+        if let Some(command) = command {
+            self.run(ctx, command, text);
+        }
+
+        let Some(mut selection) = self.selection(ctx, text) else {
+            return;
+        };
+
+        let mut edited = false;
+
+        // Events are handled in order, up to the first one left to the editor.
+        ctx.input_mut(|i| {
+            let mut index = 0;
+
+            while index < i.events.len() {
+                let edit = match &i.events[index] {
+                    Event::Key {
+                        key: Key::Backspace,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } if modifiers.is_none() && self.preferences.auto_close => {
+                        delete_pair(text, selection)
+                    }
+                    Event::Key {
+                        key: Key::Enter,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } if modifiers.is_none() && self.preferences.auto_indent => {
+                        Some(newline(text, selection, &self.preferences.indent()))
+                    }
+                    Event::Text(typed) => self.type_text(text, selection, typed),
+                    Event::Copy
+                    | Event::Cut
+                    | Event::Key { pressed: true, .. }
+                    | Event::Paste(_) => None,
+                    _ => {
+                        index += 1;
+
+                        continue;
+                    }
+                };
+
+                let Some(edit) = edit else {
+                    break;
+                };
+
+                *text = edit.text;
+                selection = edit.selection;
+                edited = true;
+
+                i.events.remove(index);
+            }
+        });
+
+        if edited {
+            self.select(ctx, text, selection);
+        }
+    }
+
+    // Runs a command on the lines of the editor's selection, or at the start
+    // when the editor has none yet.
+    pub fn run(&self, ctx: &Context, command: EditCommand, text: &mut String) {
+        let selection = self.selection(ctx, text).unwrap_or(Selection::caret(0));
+
+        let indent = self.preferences.indent();
+
+        let edit = match command {
+            EditCommand::CommentBlock => Some(toggle_block_comment(text, selection)),
+            EditCommand::CommentLine => Some(toggle_line_comment(text, selection)),
+            EditCommand::DeleteLine => Some(delete_lines(text, selection)),
+            EditCommand::DuplicateLine => Some(duplicate_lines(text, selection)),
+            EditCommand::Format => format_document(text, selection, &indent),
+            EditCommand::Indent => Some(indent_lines(text, selection, &indent)),
+            EditCommand::MoveLineDown => move_lines(text, selection, false),
+            EditCommand::MoveLineUp => move_lines(text, selection, true),
+            EditCommand::Outdent => Some(outdent_lines(text, selection, &indent)),
+        };
+
+        if let Some(Edit {
+            text: edited,
+            selection,
+        }) = edit
+        {
+            *text = edited;
+
+            self.select(ctx, text, selection);
+        }
+
+        // Running from the menu takes the focus away from the editor.
+        ctx.memory_mut(|mem| mem.request_focus(self.editor_id));
+    }
+
+    fn select(&self, ctx: &Context, text: &str, selection: Selection) {
+        let ccursor = |offset: usize| CCursor::new(text[..offset].chars().count());
+
+        let mut state = TextEditState::load(ctx, self.editor_id).unwrap_or_default();
+
+        state.set_ccursor_range(Some(CCursorRange::two(
+            ccursor(selection.anchor),
+            ccursor(selection.cursor),
+        )));
+        state.store(ctx, self.editor_id);
+    }
+
+    fn selection(&self, ctx: &Context, text: &str) -> Option<Selection> {
+        let range = TextEditState::load(ctx, self.editor_id)?.ccursor_range()?;
+
+        Some(Selection {
+            anchor: byte_index(text, range.secondary.index),
+            cursor: byte_index(text, range.primary.index),
+        })
+    }
+
+    fn type_text(&self, text: &str, selection: Selection, typed: &str) -> Option<Edit> {
+        let mut chars = typed.chars();
+
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+
+        let closed = if self.preferences.auto_close {
+            auto_close(text, selection, c)
+        } else {
+            None
+        };
+
+        closed.or_else(|| {
+            if self.preferences.auto_indent {
+                dedent_closing(text, selection, c, &self.preferences.indent())
+            } else {
+                None
+            }
+        })
+    }
+}
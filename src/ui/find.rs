@@ -0,0 +1,682 @@
+use super::{
+    utils::{byte_index, error_color, range_rects, select_range},
+    EditContext,
+};
+use crate::shortcut::{Command, Shortcuts};
+use egui::{
+    pos2, text_edit::TextEditState, util::cache, widgets::text_edit::TextEditOutput, Align2, Area,
+    Color32, Context, Frame, Id, Key, Order, Response, RichText, Stroke, TextEdit, Ui,
+};
+use material_icons::{icon_to_char, Icon};
+use regex::{Regex, RegexBuilder};
+use std::ops::Range;
+
+const FIELD_WIDTH: f32 = 200.0;
+
+// Keyed on the text and the query, so matches are only searched again
+// once either changes.
+type MatchCache = cache::FrameCache<Result<Vec<Range<usize>>, String>, Matcher>;
+
+#[derive(Default)]
+struct Matcher;
+
+impl cache::ComputerMut<(&str, &Query), Result<Vec<Range<usize>>, String>> for Matcher {
+    fn compute(&mut self, (text, query): (&str, &Query)) -> Result<Vec<Range<usize>>, String> {
+        match Search::new(query) {
+            Ok(Some(search)) => Ok(search.matches(text)),
+            Ok(None) => Ok(vec![]),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Action {
+    Next,
+    Previous,
+    Replace,
+    ReplaceAll,
+}
+
+#[derive(Clone, Default, Hash, PartialEq)]
+struct Query {
+    case_sensitive: bool,
+    regex: bool,
+    text: String,
+    whole_word: bool,
+}
+
+// A compiled query.
+struct Search {
+    regex: Regex,
+    // Whether `$1`, `${name}`... in the replacement refer to groups of the match.
+    expands: bool,
+    whole_word: bool,
+}
+
+impl Search {
+    // `None` for an empty query.
+    fn new(query: &Query) -> Result<Option<Self>, regex::Error> {
+        if query.text.is_empty() {
+            return Ok(None);
+        }
+
+        let pattern = if query.regex {
+            query.text.clone()
+        } else {
+            regex::escape(&query.text)
+        };
+
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(!query.case_sensitive)
+            .multi_line(true)
+            .build()?;
+
+        Ok(Some(Self {
+            regex,
+            expands: query.regex,
+            whole_word: query.whole_word,
+        }))
+    }
+
+    fn matches(&self, text: &str) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(text)
+            .map(|found| found.range())
+            .filter(|range| !range.is_empty())
+            .filter(|range| !self.whole_word || is_whole_word(text, range))
+            .collect()
+    }
+
+    fn replacement(&self, text: &str, range: &Range<usize>, replacement: &str) -> String {
+        if !self.expands {
+            return replacement.to_owned();
+        }
+
+        let mut expanded = String::new();
+
+        if let Some(captures) = self.regex.captures_at(text, range.start) {
+            captures.expand(replacement, &mut expanded);
+        }
+
+        expanded
+    }
+
+    // `text` with each of `matches` replaced.
+    fn replace_all(&self, text: &str, matches: &[Range<usize>], replacement: &str) -> String {
+        let mut replaced = String::with_capacity(text.len());
+        let mut end = 0;
+
+        for range in matches {
+            replaced.push_str(&text[end..range.start]);
+            replaced.push_str(&self.replacement(text, range, replacement));
+
+            end = range.end;
+        }
+
+        replaced.push_str(&text[end..]);
+
+        replaced
+    }
+}
+
+#[derive(Clone, Default)]
+struct State {
+    open: bool,
+    query: Query,
+    replace_open: bool,
+    replacement: String,
+}
+
+// A find and replace bar over the top right of a `TextEdit`, keeping its
+// state in egui's memory.
+//
+// Like the completion popup, it takes its keys before the editor runs and
+// acts after it, from the editor's output.
+pub struct Find {
+    action: Option<Action>,
+    editor_id: Id,
+    state: State,
+}
+
+impl Find {
+    pub fn new(ctx: &Context, editor_id: Id) -> Self {
+        let state = ctx.data_mut(|d| d.get_temp(editor_id.with("find")).unwrap_or_default());
+
+        Self {
+            action: None,
+            editor_id,
+            state,
+        }
+    }
+
+    // Opens the bar from outside of the editor, e.g. from a menu.
+    pub fn open(ctx: &Context, editor_id: Id, text: &str, replace: bool) {
+        let mut find = Self::new(ctx, editor_id);
+
+        find.open_bar(ctx, text, replace);
+
+        ctx.data_mut(|d| d.insert_temp(editor_id.with("find"), find.state));
+    }
+
+    pub fn consume_keys(&mut self, ctx: &Context, shortcuts: &Shortcuts, text: &str) {
+        let (find, replace) = ctx.input_mut(|i| {
+            (
+                shortcuts.consume(i, Command::EditFind),
+                shortcuts.consume(i, Command::EditReplace),
+            )
+        });
+
+        if find || replace {
+            self.open_bar(ctx, text, replace);
+        }
+    }
+
+    pub fn show(mut self, ui: &Ui, output: &mut TextEditOutput, edit_context: &mut EditContext) {
+        if !self.state.open {
+            return;
+        }
+
+        let text = &mut edit_context.frag;
+
+        let ctx = ui.ctx().clone();
+
+        let matches = ctx.memory_mut(|mem| {
+            mem.caches
+                .cache::<MatchCache>()
+                .get((text.as_str(), &self.state.query))
+        });
+
+        let selection = output.state.ccursor_range().map_or(0..0, |range| {
+            let [start, end] = range.sorted();
+
+            byte_index(text, start.index)..byte_index(text, end.index)
+        });
+
+        let current = matches
+            .as_ref()
+            .ok()
+            .and_then(|matches| matches.iter().position(|range| *range == selection));
+
+        let query_changed = self.show_bar(ui, output, &matches, current);
+
+        if !self.state.open {
+            ctx.memory_mut(|mem| mem.request_focus(self.editor_id));
+        } else if let Ok(matches) = matches {
+            let target = match self.action {
+                Some(Action::Next) => {
+                    // Past the current match, or from the cursor.
+                    let from = if current.is_some() {
+                        selection.end
+                    } else {
+                        selection.start
+                    };
+
+                    next_match(&matches, from)
+                }
+                Some(Action::Previous) => previous_match(&matches, selection.start),
+                Some(Action::Replace) => match current {
+                    Some(index) => self.replace(output, text, &matches[index]),
+                    None => next_match(&matches, selection.start),
+                },
+                Some(Action::ReplaceAll) => {
+                    self.replace_all(output, text, &matches);
+
+                    edit_context.separate_step = true;
+
+                    None
+                }
+                // Searching as the query is typed.
+                None if query_changed => next_match(&matches, selection.start),
+                None => None,
+            };
+
+            if let Some(range) = target.clone() {
+                select_range(ui, output, self.editor_id, text, range);
+            }
+
+            // Matches are searched again in the replaced text next frame.
+            if !output.response.changed() {
+                let current = target.map_or(current, |target| {
+                    matches.iter().position(|range| *range == target)
+                });
+
+                paint_matches(ui, output, text, &matches, current);
+            }
+        }
+
+        ctx.data_mut(|d| d.insert_temp(self.editor_id.with("find"), self.state));
+    }
+
+    fn open_bar(&mut self, ctx: &Context, text: &str, replace: bool) {
+        self.state.open = true;
+        self.state.replace_open |= replace;
+
+        // A selection within a line is what to look for.
+        let selection = TextEditState::load(ctx, self.editor_id)
+            .and_then(|state| state.ccursor_range())
+            .filter(|range| range.primary != range.secondary);
+
+        if let Some(range) = selection {
+            let [start, end] = range.sorted();
+            let selected = &text[byte_index(text, start.index)..byte_index(text, end.index)];
+
+            if !selected.contains('\n') {
+                self.state.query.text = selected.to_owned();
+            }
+        }
+
+        ctx.memory_mut(|mem| mem.request_focus(self.editor_id.with("find_query")));
+    }
+
+    // Returns whether the query changed.
+    fn show_bar(
+        &mut self,
+        ui: &Ui,
+        output: &TextEditOutput,
+        matches: &Result<Vec<Range<usize>>, String>,
+        current: Option<usize>,
+    ) -> bool {
+        let clip_rect = ui.clip_rect();
+        let editor_rect = output.response.rect;
+
+        let pos = pos2(
+            editor_rect.right().min(clip_rect.right()),
+            editor_rect.top().max(clip_rect.top()),
+        );
+
+        let mut query_changed = false;
+
+        Area::new(self.editor_id.with("find_bar"))
+            .order(Order::Foreground)
+            .pivot(Align2::RIGHT_TOP)
+            .fixed_pos(pos)
+            .show(ui.ctx(), |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        let expand_icon = if self.state.replace_open {
+                            Icon::ExpandMore
+                        } else {
+                            Icon::ChevronRight
+                        };
+
+                        if ui
+                            .small_button(icon_to_char(expand_icon).to_string())
+                            .on_hover_text(fl!("edit_find_toggle_replace"))
+                            .clicked()
+                        {
+                            self.state.replace_open = !self.state.replace_open;
+                        }
+
+                        let query_id = self.editor_id.with("find_query");
+                        let response = ui.add(
+                            TextEdit::singleline(&mut self.state.query.text)
+                                .id(query_id)
+                                .code_editor()
+                                .hint_text(fl!("edit_find"))
+                                .desired_width(FIELD_WIDTH),
+                        );
+
+                        query_changed |= response.changed();
+
+                        self.field_keys(ui, &response, Action::Next);
+
+                        query_changed |= ui
+                            .selectable_label(self.state.query.case_sensitive, "Aa")
+                            .on_hover_text(fl!("edit_find_case_sensitive"))
+                            .clicked();
+                        query_changed |= ui
+                            .selectable_label(
+                                self.state.query.whole_word,
+                                RichText::new("ab").underline(),
+                            )
+                            .on_hover_text(fl!("edit_find_whole_word"))
+                            .clicked();
+                        query_changed |= ui
+                            .selectable_label(self.state.query.regex, ".*")
+                            .on_hover_text(fl!("edit_find_regex"))
+                            .clicked();
+
+                        if query_changed {
+                            self.toggle_options(ui);
+                        }
+
+                        match matches {
+                            Ok(_) if self.state.query.text.is_empty() => {}
+                            Ok(matches) if matches.is_empty() => {
+                                ui.label(fl!("edit_find_no_results"));
+                            }
+                            Ok(matches) => match current {
+                                Some(index) => {
+                                    let position = index + 1;
+
+                                    ui.label(fl!(
+                                        "edit_find_position",
+                                        current = position,
+                                        total = matches.len()
+                                    ));
+                                }
+                                None => {
+                                    ui.label(fl!("edit_find_count", total = matches.len()));
+                                }
+                            },
+                            Err(err) => {
+                                ui.label(
+                                    RichText::new(fl!("edit_find_err_regex"))
+                                        .color(error_color(ui)),
+                                )
+                                .on_hover_text(err);
+                            }
+                        }
+
+                        if ui
+                            .small_button(icon_to_char(Icon::ArrowUpward).to_string())
+                            .on_hover_text(fl!("edit_find_previous"))
+                            .clicked()
+                        {
+                            self.action = Some(Action::Previous);
+                        }
+
+                        if ui
+                            .small_button(icon_to_char(Icon::ArrowDownward).to_string())
+                            .on_hover_text(fl!("edit_find_next"))
+                            .clicked()
+                        {
+                            self.action = Some(Action::Next);
+                        }
+
+                        if ui
+                            .small_button(icon_to_char(Icon::Close).to_string())
+                            .on_hover_text(fl!("edit_find_close"))
+                            .clicked()
+                        {
+                            self.state.open = false;
+                        }
+                    });
+
+                    if self.state.replace_open {
+                        ui.horizontal(|ui| {
+                            let response = ui.add(
+                                TextEdit::singleline(&mut self.state.replacement)
+                                    .id(self.editor_id.with("find_replacement"))
+                                    .code_editor()
+                                    .hint_text(fl!("edit_replace"))
+                                    .desired_width(FIELD_WIDTH),
+                            );
+
+                            self.field_keys(ui, &response, Action::Replace);
+
+                            if ui.button(fl!("edit_replace_one")).clicked() {
+                                self.action = Some(Action::Replace);
+                            }
+
+                            if ui.button(fl!("edit_replace_all")).clicked() {
+                                self.action = Some(Action::ReplaceAll);
+                            }
+                        });
+                    }
+                });
+            });
+
+        query_changed
+    }
+
+    // Enter runs `action` and keeps the field focused, Shift+Enter in the
+    // query goes to the previous match. Escape closes the bar.
+    fn field_keys(&mut self, ui: &Ui, response: &Response, action: Action) {
+        if !response.has_focus() && !response.lost_focus() {
+            return;
+        }
+
+        let (enter, escape, shift) = ui.input(|i| {
+            (
+                i.key_pressed(Key::Enter),
+                i.key_pressed(Key::Escape),
+                i.modifiers.shift,
+            )
+        });
+
+        if escape {
+            self.state.open = false;
+        } else if enter && response.lost_focus() {
+            self.action = Some(if shift && action == Action::Next {
+                Action::Previous
+            } else {
+                action
+            });
+
+            response.request_focus();
+        }
+    }
+
+    // Clicking an option takes the focus away from the query, gives it back.
+    fn toggle_options(&self, ui: &Ui) {
+        ui.memory_mut(|mem| {
+            if mem.focus().is_none() {
+                mem.request_focus(self.editor_id.with("find_query"));
+            }
+        });
+    }
+
+    // Replaces a match, returns the match after it in the new text.
+    fn replace(
+        &self,
+        output: &mut TextEditOutput,
+        text: &mut String,
+        range: &Range<usize>,
+    ) -> Option<Range<usize>> {
+        let search = Search::new(&self.state.query).ok()??;
+        let replacement = search.replacement(text, range, &self.state.replacement);
+
+        text.replace_range(range.clone(), &replacement);
+
+        output.response.mark_changed();
+
+        next_match(&search.matches(text), range.start + replacement.len())
+    }
+
+    // Replaces all matches in a single edit, which one undo reverts.
+    fn replace_all(
+        &self,
+        output: &mut TextEditOutput,
+        text: &mut String,
+        matches: &[Range<usize>],
+    ) {
+        let Ok(Some(search)) = Search::new(&self.state.query) else {
+            return;
+        };
+
+        if matches.is_empty() {
+            return;
+        }
+
+        *text = search.replace_all(text, matches, &self.state.replacement);
+
+        output.response.mark_changed();
+    }
+}
+
+fn is_whole_word(text: &str, range: &Range<usize>) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    !text[..range.start].chars().next_back().is_some_and(is_word)
+        && !text[range.end..].chars().next().is_some_and(is_word)
+}
+
+// The first match starting at or after `offset`, wrapping around.
+fn next_match(matches: &[Range<usize>], offset: usize) -> Option<Range<usize>> {
+    matches
+        .iter()
+        .find(|range| range.start >= offset)
+        .or_else(|| matches.first())
+        .cloned()
+}
+
+// The last match ending at or before `offset`, wrapping around.
+fn previous_match(matches: &[Range<usize>], offset: usize) -> Option<Range<usize>> {
+    matches
+        .iter()
+        .rev()
+        .find(|range| range.end <= offset)
+        .or_else(|| matches.last())
+        .cloned()
+}
+
+// Tints all matches, outlining the current one.
+fn paint_matches(
+    ui: &Ui,
+    output: &TextEditOutput,
+    text: &str,
+    matches: &[Range<usize>],
+    current: Option<usize>,
+) {
+    let visible = ui.clip_rect();
+    let (color, current_color) = if ui.visuals().dark_mode {
+        (
+            Color32::from_rgba_unmultiplied(255, 200, 0, 36),
+            Color32::from_rgba_unmultiplied(255, 160, 0, 96),
+        )
+    } else {
+        (
+            Color32::from_rgba_unmultiplied(255, 200, 0, 72),
+            Color32::from_rgba_unmultiplied(255, 140, 0, 128),
+        )
+    };
+
+    for (index, range) in matches.iter().enumerate() {
+        for rect in range_rects(output, text, range.clone()) {
+            if !visible.intersects(rect) {
+                continue;
+            }
+
+            if current == Some(index) {
+                ui.painter().rect_filled(rect, 1.0, current_color);
+                ui.painter()
+                    .rect_stroke(rect, 1.0, Stroke::new(1.0, current_color.to_opaque()));
+            } else {
+                ui.painter().rect_filled(rect, 1.0, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_whole_word, next_match, previous_match, Query, Search};
+
+    fn search(text: &str, case_sensitive: bool, regex: bool, whole_word: bool) -> Search {
+        let query = Query {
+            case_sensitive,
+            regex,
+            text: text.to_owned(),
+            whole_word,
+        };
+
+        Search::new(&query).unwrap().unwrap()
+    }
+
+    #[test]
+    fn matches() {
+        let text = "let uv = UV * u_v; // uv";
+
+        let cases: &[(&str, bool, bool, bool, &[&str])] = &[
+            ("uv", false, false, false, &["uv", "UV", "uv"]),
+            ("uv", true, false, false, &["uv", "uv"]),
+            ("u", false, false, true, &[]),
+            ("u_v", false, false, true, &["u_v"]),
+            ("uv", false, false, true, &["uv", "UV", "uv"]),
+            ("u.v", false, false, false, &[]),
+            ("u.v", false, true, false, &["u_v"]),
+            (r"\bu\w*", true, true, false, &["uv", "u_v", "uv"]),
+        ];
+
+        for (query, case_sensitive, regex, whole_word, expected) in cases {
+            let found: Vec<_> = search(query, *case_sensitive, *regex, *whole_word)
+                .matches(text)
+                .into_iter()
+                .map(|range| &text[range])
+                .collect();
+
+            assert_eq!(found, *expected, "{}", query);
+        }
+
+        let query = Query::default();
+        assert!(Search::new(&query).unwrap().is_none());
+
+        let query = Query {
+            regex: true,
+            text: "(".to_owned(),
+            ..Query::default()
+        };
+        assert!(Search::new(&query).is_err());
+    }
+
+    #[test]
+    fn replacement() {
+        let text = "a = vec2(x, y);";
+        let calls = search(r"vec(\d)\((\w)", true, true, false);
+        let range = calls.matches(text)[0].clone();
+
+        assert_eq!(
+            calls.replacement(text, &range, "vec$1<f32>(${2}"),
+            "vec2<f32>(x"
+        );
+
+        // Taken literally without regex.
+        let plain = search("vec2", true, false, false);
+        let range = plain.matches(text)[0].clone();
+
+        assert_eq!(plain.replacement(text, &range, "$1"), "$1");
+    }
+
+    #[test]
+    fn replace_all() {
+        let text = "x + x * xx";
+
+        let words = search("x", true, false, true);
+        let matches = words.matches(text);
+        assert_eq!(
+            words.replace_all(text, &matches, "long"),
+            "long + long * xx"
+        );
+
+        // Replacements of other lengths than the matches.
+        let all = search("x", true, false, false);
+        let matches = all.matches(text);
+        assert_eq!(all.replace_all(text, &matches, ""), " +  * ");
+
+        let groups = search(r"(x)(x)?", true, true, false);
+        let matches = groups.matches(text);
+        assert_eq!(
+            groups.replace_all(text, &matches, "[$1$2]"),
+            "[x] + [x] * [xx]"
+        );
+    }
+
+    #[test]
+    fn whole_words() {
+        let text = "é_a a1 (a)";
+
+        assert!(!is_whole_word(text, &(3..4)));
+        assert!(!is_whole_word(text, &(5..6)));
+        assert!(is_whole_word(text, &(5..7)));
+        assert!(is_whole_word(text, &(9..10)));
+    }
+
+    #[test]
+    fn navigation() {
+        let matches = [2..4, 6..8, 10..12];
+
+        assert_eq!(next_match(&matches, 0), Some(2..4));
+        assert_eq!(next_match(&matches, 6), Some(6..8));
+        assert_eq!(next_match(&matches, 7), Some(10..12));
+        assert_eq!(next_match(&matches, 11), Some(2..4));
+
+        assert_eq!(previous_match(&matches, 12), Some(10..12));
+        assert_eq!(previous_match(&matches, 6), Some(2..4));
+        assert_eq!(previous_match(&matches, 3), Some(10..12));
+
+        assert_eq!(next_match(&[], 0), None);
+        assert_eq!(previous_match(&[], 0), None);
+    }
+}
@@ -0,0 +1,301 @@
+use super::utils::byte_index;
+use crate::wgsl::{Outline, Token};
+use egui::{
+    pos2,
+    text::{CCursor, LayoutJob, LayoutSection},
+    text_edit::TextEditState,
+    util::cache,
+    vec2,
+    widgets::text_edit::TextEditOutput,
+    Align2, Color32, Context, CursorIcon, FontId, Id, Rect, Sense, TextFormat, Ui,
+};
+use std::{
+    collections::BTreeSet,
+    ops::{Range, RangeInclusive},
+};
+
+const PLACEHOLDER: &str = "⋯";
+
+// Keyed on the text and its tokens, so it is only found again once they change.
+type OutlineCache = cache::FrameCache<Outline, OutlineComputer>;
+
+#[derive(Default)]
+struct OutlineComputer;
+
+impl cache::ComputerMut<(&str, &[Token]), Outline> for OutlineComputer {
+    fn compute(&mut self, (text, tokens): (&str, &[Token])) -> Outline {
+        Outline::new(text, tokens)
+    }
+}
+
+#[derive(Clone, Default)]
+struct State {
+    // The keys of the folds folded.
+    folded: BTreeSet<String>,
+}
+
+// Folds function bodies, struct declarations and comment blocks of a
+// `TextEdit`.
+//
+// Folded lines stay in the text and are laid out without height, see `hide`.
+// Folds are kept by key per editor in egui's memory, so they last across edits.
+pub struct Folding {
+    editor_id: Id,
+    state: State,
+}
+
+impl Folding {
+    pub fn new(ctx: &Context, editor_id: Id) -> Self {
+        Self {
+            editor_id,
+            state: ctx.data_mut(|d| {
+                d.get_temp::<State>(editor_id.with("folding"))
+                    .unwrap_or_default()
+            }),
+        }
+    }
+
+    // Folds or unfolds everything in the editor next frame.
+    pub fn fold_all(ctx: &Context, fold: bool) {
+        ctx.data_mut(|d| d.insert_temp(Id::new("fold_all"), fold));
+    }
+
+    pub fn folded(&self) -> &BTreeSet<String> {
+        &self.state.folded
+    }
+
+    // Folds or unfolds a fold, taking effect next frame.
+    pub fn toggle(&mut self, ctx: &Context, key: &str) {
+        if !self.state.folded.remove(key) {
+            self.state.folded.insert(key.to_owned());
+        }
+
+        ctx.request_repaint();
+    }
+
+    // Runs a pending fold or unfold all, and unfolds the folds hiding the
+    // cursor, e.g. after moving into one or jumping to a match.
+    pub fn update(&mut self, ctx: &Context, text: &str, outline: &Outline) {
+        let fold_all = ctx.data_mut(|d| {
+            let fold_all = d.get_temp::<bool>(Id::new("fold_all"));
+
+            d.remove::<bool>(Id::new("fold_all"));
+
+            fold_all
+        });
+
+        match fold_all {
+            Some(true) => {
+                self.state
+                    .folded
+                    .extend(outline.folds.iter().map(|fold| fold.key.clone()));
+            }
+            Some(false) => self.state.folded.clear(),
+            None => {}
+        }
+
+        let Some(cursor) = TextEditState::load(ctx, self.editor_id)
+            .and_then(|state| state.ccursor_range())
+            .map(|range| byte_index(text, range.primary.index))
+        else {
+            return;
+        };
+
+        let line_ends = line_ends(text);
+
+        let revealed: Vec<String> = outline
+            .folds
+            .iter()
+            .filter(|fold| self.state.folded.contains(&fold.key))
+            .filter(|fold| {
+                let range = hidden_range(text, &line_ends, fold.lines.clone());
+
+                range.start < cursor && cursor <= range.end
+            })
+            .map(|fold| fold.key.clone())
+            .collect();
+
+        if !revealed.is_empty() {
+            for key in revealed {
+                self.state.folded.remove(&key);
+            }
+
+            ctx.request_repaint();
+        }
+    }
+
+    // Marks the end of folded lines, unfolding them when clicked.
+    pub fn paint(&mut self, ui: &Ui, output: &TextEditOutput, text: &str, outline: &Outline) {
+        let line_ends = line_ends(text);
+        let galley = &output.galley;
+
+        let mut toggled = None;
+
+        for fold in &outline.folds {
+            if !self.state.folded.contains(&fold.key) {
+                continue;
+            }
+
+            let end = line_ends
+                .get(*fold.lines.start())
+                .copied()
+                .unwrap_or(text.len());
+
+            let cursor = galley.from_ccursor(CCursor::new(text[..end].chars().count()));
+            let pos = galley
+                .pos_from_cursor(&cursor)
+                .translate(output.text_draw_pos.to_vec2());
+
+            // Within another fold.
+            if pos.height() <= 0.0 {
+                continue;
+            }
+
+            let font_id = FontId::monospace(pos.height() * 0.6);
+            let width = ui.fonts(|fonts| fonts.glyph_width(&font_id, '⋯')) + pos.height() * 0.5;
+
+            let rect = Rect::from_min_size(
+                pos2(pos.right() + pos.height() * 0.25, pos.top() + 1.0),
+                vec2(width, pos.height() - 2.0),
+            );
+
+            let response = ui
+                .interact(
+                    rect,
+                    self.editor_id.with(("fold", &fold.key)),
+                    Sense::click(),
+                )
+                .on_hover_cursor(CursorIcon::PointingHand);
+
+            let visuals = ui.visuals();
+            let fill = if response.hovered() {
+                visuals.widgets.hovered.bg_fill
+            } else {
+                visuals.faint_bg_color
+            };
+
+            ui.painter()
+                .rect(rect, 3.0, fill, visuals.widgets.noninteractive.bg_stroke);
+            ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                PLACEHOLDER,
+                font_id,
+                visuals.weak_text_color(),
+            );
+
+            if response.clicked() {
+                toggled = Some(fold.key.clone());
+            }
+        }
+
+        if let Some(key) = toggled {
+            self.toggle(ui.ctx(), &key);
+        }
+    }
+
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(self.editor_id.with("folding"), self.state));
+    }
+}
+
+// Lays out the hidden ranges of a job transparent and without height, their
+// rows taking no room.
+pub fn hide(job: &mut LayoutJob, hidden: &[Range<usize>]) {
+    if hidden.is_empty() {
+        return;
+    }
+
+    let mut sections = Vec::with_capacity(job.sections.len());
+
+    for section in job.sections.drain(..) {
+        let Range { mut start, end } = section.byte_range.clone();
+
+        let mut push = |range: Range<usize>, format: TextFormat| {
+            if range.is_empty() {
+                return;
+            }
+
+            let leading_space = if range.start == section.byte_range.start {
+                section.leading_space
+            } else {
+                0.0
+            };
+
+            sections.push(LayoutSection {
+                byte_range: range,
+                format,
+                leading_space,
+            });
+        };
+
+        for range in hidden
+            .iter()
+            .filter(|range| range.start < end && range.end > section.byte_range.start)
+        {
+            push(start..range.start.max(start), section.format.clone());
+
+            let hidden_end = range.end.min(end);
+
+            push(
+                range.start.max(start)..hidden_end,
+                TextFormat {
+                    background: Color32::TRANSPARENT,
+                    color: Color32::TRANSPARENT,
+                    line_height: Some(0.0),
+                    ..section.format.clone()
+                },
+            );
+
+            start = hidden_end;
+        }
+
+        push(start..end, section.format.clone());
+    }
+
+    job.sections = sections;
+}
+
+// The byte ranges the folds folded hide, merged, each from the line break
+// ending the line kept to the end of the last line hidden.
+pub fn hidden_ranges(
+    text: &str,
+    outline: &Outline,
+    folded: &BTreeSet<String>,
+) -> Vec<Range<usize>> {
+    let line_ends = line_ends(text);
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+
+    // Folds are sorted by their first line.
+    for fold in outline
+        .folds
+        .iter()
+        .filter(|fold| folded.contains(&fold.key))
+    {
+        let range = hidden_range(text, &line_ends, fold.lines.clone());
+
+        match ranges.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => ranges.push(range),
+        }
+    }
+
+    ranges
+}
+
+// `tokens` are those of `text`.
+pub fn outline(ctx: &Context, text: &str, tokens: &[Token]) -> Outline {
+    ctx.memory_mut(|mem| mem.caches.cache::<OutlineCache>().get((text, tokens)))
+}
+
+fn hidden_range(text: &str, line_ends: &[usize], lines: RangeInclusive<usize>) -> Range<usize> {
+    let end = |line: usize| line_ends.get(line).copied().unwrap_or(text.len());
+
+    end(*lines.start())..end(*lines.end())
+}
+
+// The byte offsets of the line breaks.
+fn line_ends(text: &str) -> Vec<usize> {
+    text.match_indices('\n').map(|(index, _)| index).collect()
+}
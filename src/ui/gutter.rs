@@ -0,0 +1,191 @@
+use super::utils::error_color;
+use egui::{
+    pos2, show_tooltip_at_pointer, vec2, widgets::text_edit::TextEditOutput, Align2, Color32,
+    CursorIcon, FontId, Rect, Sense, Ui,
+};
+use material_icons::{icon_to_char, Icon};
+
+// The width of the fold toggles, in digits.
+const FOLD_DIGITS: usize = 2;
+
+const MARKER_RADIUS: f32 = 3.0;
+const PADDING: f32 = 8.0;
+
+// Lines that can be folded from the first one, see `Folding`.
+pub struct FoldToggle {
+    pub folded: bool,
+    pub key: String,
+    // 1-based.
+    pub line: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MarkerKind {
+    Error,
+}
+
+// Something to point out on a line, with a message shown when hovered.
+pub struct Marker {
+    pub kind: MarkerKind,
+    // 1-based.
+    pub line: usize,
+    pub message: String,
+}
+
+// A column of line numbers on the left of a `TextEdit`, following its rows
+// when lines wrap, with markers in front of the numbers and fold toggles
+// after them. Rows without height, those of folded lines, are skipped.
+//
+// The gutter takes its room before the editor is shown, see `allocate`,
+// and is painted after it, from the editor's output.
+pub struct Gutter {
+    folds: Vec<FoldToggle>,
+    font_id: FontId,
+    markers: Vec<Marker>,
+}
+
+impl Gutter {
+    pub fn new(font_id: FontId, markers: Vec<Marker>, folds: Vec<FoldToggle>) -> Self {
+        Self {
+            folds,
+            font_id,
+            markers,
+        }
+    }
+
+    // Takes the width needed for the line numbers of `text`,
+    // returns where the gutter starts.
+    pub fn allocate(&self, ui: &mut Ui, text: &str) -> Rect {
+        let digits = (text.lines().count() + 1).to_string().len().max(2);
+        let digit_width = ui.fonts(|fonts| fonts.glyph_width(&self.font_id, '0'));
+
+        let width =
+            (digits + FOLD_DIGITS) as f32 * digit_width + PADDING * 2.0 + MARKER_RADIUS * 2.0;
+
+        ui.allocate_exact_size(vec2(width, 0.0), Sense::hover()).0
+    }
+
+    // `rect` is the one returned by `allocate`, `current_line` is 1-based.
+    // Returns the key of the fold toggled, if any.
+    pub fn paint(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        output: &TextEditOutput,
+        current_line: Option<usize>,
+    ) -> Option<String> {
+        let painter = ui.painter();
+        let visuals = ui.visuals();
+        let origin = output.text_draw_pos;
+
+        let rect = Rect::from_min_max(rect.min, pos2(rect.right(), output.response.rect.bottom()));
+
+        painter.rect_filled(rect, 0.0, visuals.faint_bg_color);
+
+        let pointer = ui.ctx().pointer_hover_pos();
+        let hovered = pointer.is_some_and(|pointer| rect.contains(pointer));
+
+        let fold_width =
+            FOLD_DIGITS as f32 * ui.fonts(|fonts| fonts.glyph_width(&self.font_id, '0'));
+
+        let mut toggled = None;
+
+        let mut line = 1;
+        let mut starts_line = true;
+
+        for row in &output.galley.rows {
+            if starts_line && row.rect.height() > 0.0 {
+                let top = origin.y + row.rect.min.y;
+                let marker = self.markers.iter().find(|marker| marker.line == line);
+
+                let color = match marker {
+                    Some(marker) => marker_color(ui, marker.kind),
+                    None if current_line == Some(line) => visuals.strong_text_color(),
+                    None => visuals.weak_text_color(),
+                };
+
+                painter.text(
+                    pos2(rect.right() - PADDING - fold_width, top),
+                    Align2::RIGHT_TOP,
+                    line.to_string(),
+                    self.font_id.clone(),
+                    color,
+                );
+
+                if let Some(marker) = marker {
+                    let center = pos2(
+                        rect.left() + PADDING / 2.0 + MARKER_RADIUS,
+                        top + row.rect.height() / 2.0,
+                    );
+
+                    painter.circle_filled(center, MARKER_RADIUS, color);
+
+                    let row_rect =
+                        Rect::from_x_y_ranges(rect.x_range(), top..=top + row.rect.height());
+
+                    if pointer.is_some_and(|pointer| row_rect.contains(pointer)) {
+                        show_tooltip_at_pointer(ui.ctx(), ui.id().with("gutter_marker"), |ui| {
+                            ui.label(&marker.message);
+                        });
+                    }
+                }
+
+                let fold = self.folds.iter().find(|fold| fold.line == line);
+
+                // Unfolded ones only while the gutter is hovered.
+                if let Some(fold) = fold.filter(|fold| fold.folded || hovered) {
+                    let toggle_rect = Rect::from_x_y_ranges(
+                        rect.right() - PADDING / 2.0 - fold_width..=rect.right() - PADDING / 2.0,
+                        top..=top + row.rect.height(),
+                    );
+
+                    let response = ui
+                        .interact(
+                            toggle_rect,
+                            ui.id().with(("gutter_fold", line)),
+                            Sense::click(),
+                        )
+                        .on_hover_cursor(CursorIcon::PointingHand);
+
+                    let icon = if fold.folded {
+                        Icon::ChevronRight
+                    } else {
+                        Icon::ExpandMore
+                    };
+
+                    let color = if response.hovered() {
+                        visuals.strong_text_color()
+                    } else {
+                        visuals.weak_text_color()
+                    };
+
+                    painter.text(
+                        toggle_rect.center(),
+                        Align2::CENTER_CENTER,
+                        icon_to_char(icon),
+                        self.font_id.clone(),
+                        color,
+                    );
+
+                    if response.clicked() {
+                        toggled = Some(fold.key.clone());
+                    }
+                }
+            }
+
+            starts_line = row.ends_with_newline;
+
+            if row.ends_with_newline {
+                line += 1;
+            }
+        }
+
+        toggled
+    }
+}
+
+fn marker_color(ui: &Ui, kind: MarkerKind) -> Color32 {
+    match kind {
+        MarkerKind::Error => error_color(ui),
+    }
+}
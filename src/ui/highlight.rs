@@ -1,177 +1,125 @@
 mod code_theme;
 mod token_type;
 
+use crate::wgsl::{SymbolKind, Symbols, Token, TokenKind};
 pub use code_theme::CodeTheme;
-use egui::{text::LayoutJob, util::cache};
-use token_type::TokenType;
+use egui::{text::LayoutJob, util::cache, Context};
+pub use token_type::TokenType;
+
+// Keyed on the theme, text, tokens and symbols, so an unchanged source is
+// not highlighted again.
+type HighlightCache = cache::FrameCache<LayoutJob, Highlighter>;
 
 #[derive(Default)]
-pub struct Highlighter {}
+pub struct Highlighter;
 
-impl cache::ComputerMut<(&CodeTheme, &str), LayoutJob> for Highlighter {
-    fn compute(&mut self, (theme, code): (&CodeTheme, &str)) -> LayoutJob {
-        self.highlight(theme, code)
+impl cache::ComputerMut<(&CodeTheme, &str, &[Token], &Symbols), LayoutJob> for Highlighter {
+    fn compute(
+        &mut self,
+        (theme, code, tokens, symbols): (&CodeTheme, &str, &[Token], &Symbols),
+    ) -> LayoutJob {
+        self.highlight(theme, code, tokens, symbols)
     }
 }
 
 impl Highlighter {
-    pub fn highlight(&self, theme: &CodeTheme, mut text: &str) -> LayoutJob {
+    // `tokens` are those of `text`.
+    pub fn layout(
+        ctx: &Context,
+        theme: &CodeTheme,
+        text: &str,
+        tokens: &[Token],
+        symbols: &Symbols,
+    ) -> LayoutJob {
+        ctx.memory_mut(|mem| {
+            mem.caches
+                .cache::<HighlightCache>()
+                .get((theme, text, tokens, symbols))
+        })
+    }
+
+    // Lexical token types are refined with the symbols of the last parse,
+    // resolving names against the function they appear in.
+    pub fn highlight(
+        &mut self,
+        theme: &CodeTheme,
+        text: &str,
+        tokens: &[Token],
+        symbols: &Symbols,
+    ) -> LayoutJob {
         let mut job = LayoutJob::default();
-        let mut is_function_declaration = false;
-        let mut is_inside_angle_bracket = false;
-
-        while !text.is_empty() {
-            if text.starts_with("//") {
-                let end = text.find("\n").unwrap_or(text.len());
-                job.append(&text[..end], 0.0, theme.format(TokenType::Comment));
-                text = &text[end..];
-            } else if text.starts_with("/*") {
-                if text.contains("*/") {
-                    let end = text.find("*/").unwrap_or(text.len() - 2);
-                    let block = &text[..end + 2];
-                    job.append(block, 0.0, theme.format(TokenType::Comment));
-                    text = &text[end + 2..];
-                } else {
-                    job.append(&text[..text.len()], 0.0, theme.format(TokenType::Comment));
-                    text = &text[text.len()..];
+        let mut previous: Option<Token> = None;
+        let mut function = None;
+        let mut brace_depth = 0;
+
+        for &token in tokens {
+            let word = token.text(text);
+
+            let token_type = match token.kind {
+                TokenKind::Attribute => {
+                    job.append("@", 0.0, theme.format(TokenType::Literal));
+                    job.append(&word[1..], 0.0, theme.format(TokenType::KeywordType));
+
+                    continue;
                 }
-            } else if text.starts_with(|c: char| c.is_ascii_digit()) {
-                let end = text[1..]
-                    .find(|c: char| !c.is_ascii_digit())
-                    .map_or_else(|| text.len(), |i| i + 1);
-                let numeric = &text[..end];
-
-                job.append(numeric, 0.0, theme.format(TokenType::Numeric));
-                text = &text[end..];
-            } else if text.starts_with(|c: char| c.is_ascii_whitespace()) {
-                let end = text[1..]
-                    .find(|c: char| !c.is_ascii_whitespace())
-                    .map_or_else(|| text.len(), |i| i + 1);
-                job.append(&text[..end], 0.0, theme.format(TokenType::Whitespace));
-                text = &text[end..];
-            } else if text.starts_with("@") {
-                job.append("@", 0.0, theme.format(TokenType::Literal));
-                text = &text[1..];
-                let end = text[1..]
-                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
-                    .map_or_else(|| text.len(), |i| i + 1);
-                let word = &text[..end];
-                job.append(word, 0.0, theme.format(TokenType::KeywordType));
-                text = &text[end..];
-            } else if text.starts_with("<") {
-                job.append("<", 0.0, theme.format(TokenType::Literal));
-                text = &text[1..];
-                is_inside_angle_bracket = true;
-            } else if text.starts_with(|c: char| c.is_ascii_alphabetic()) {
-                let end = text[1..]
-                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
-                    .map_or_else(|| text.len(), |i| i + 1);
-                let word = &text[..end];
-                let token_type = if is_function_declaration {
-                    is_function_declaration = false;
-                    TokenType::FunctionDefinition
-                } else if is_inside_angle_bracket {
-                    is_inside_angle_bracket = false;
-                    TokenType::KeywordType
-                } else if is_keyword_other(word) {
-                    if word == "fn" {
-                        is_function_declaration = true;
+                TokenKind::BlockComment | TokenKind::LineComment => TokenType::Comment,
+                TokenKind::Float | TokenKind::Int => TokenType::Numeric,
+                TokenKind::Ident => match previous {
+                    Some(previous) if previous.text(text) == "fn" => {
+                        function = Some(word);
+
+                        TokenType::FunctionDefinition
+                    }
+                    Some(previous) if previous.kind == TokenKind::TemplateStart => {
+                        TokenType::KeywordType
                     }
-                    TokenType::KeywordOther
-                } else if is_keyword_type(word) {
-                    TokenType::KeywordType
-                } else {
-                    TokenType::Literal
-                };
-                job.append(word, 0.0, theme.format(token_type));
-                text = &text[end..];
-            } else {
-                let mut it = text.char_indices();
-                it.next();
-                let end = it.next().map_or(text.len(), |(idx, _chr)| idx);
-                job.append(&text[..end], 0.0, theme.format(TokenType::Literal));
-                text = &text[end..];
+                    _ => {
+                        let is_member = previous.is_some_and(|previous| {
+                            previous.kind == TokenKind::Punctuation && previous.text(text) == "."
+                        });
+
+                        match symbols.resolve(word, function, is_member) {
+                            Some(SymbolKind::BuiltinFunction) => TokenType::BuiltinFunction,
+                            Some(SymbolKind::Field) => TokenType::Field,
+                            Some(SymbolKind::Function) => TokenType::Function,
+                            Some(SymbolKind::GlobalVariable) => TokenType::GlobalVariable,
+                            Some(SymbolKind::LocalVariable) => TokenType::LocalVariable,
+                            Some(SymbolKind::Struct) => TokenType::Struct,
+                            None => TokenType::Literal,
+                        }
+                    }
+                },
+                TokenKind::Keyword | TokenKind::Reserved => TokenType::KeywordOther,
+                TokenKind::Type => TokenType::KeywordType,
+                TokenKind::Whitespace => TokenType::Whitespace,
+                TokenKind::Operator
+                | TokenKind::Punctuation
+                | TokenKind::TemplateEnd
+                | TokenKind::TemplateStart
+                | TokenKind::Unknown => TokenType::Literal,
+            };
+
+            // A function's scope runs from its name to its closing brace.
+            match word {
+                "{" => brace_depth += 1,
+                "}" => {
+                    brace_depth -= 1;
+
+                    if brace_depth <= 0 {
+                        brace_depth = 0;
+                        function = None;
+                    }
+                }
+                _ => {}
+            }
+
+            job.append(word, 0.0, theme.format(token_type));
+
+            if !token.is_trivia() {
+                previous = Some(token);
             }
         }
 
         job
     }
 }
-
-fn is_keyword_other(word: &str) -> bool {
-    matches!(
-        word,
-        // https://www.w3.org/TR/WGSL/#keyword-summary
-        // Other Keywords
-        |"bitcast"| "break"
-            | "case"
-            | "const"
-            | "continue"
-            | "continuing"
-            | "default"
-            | "discard"
-            | "else"
-            | "enable"
-            | "false"
-            | "fn"
-            | "for"
-            | "if"
-            | "let"
-            | "loop"
-            | "override"
-            | "return"
-            | "static_assert"
-            | "struct"
-            | "switch"
-            | "true"
-            | "type"
-            | "var"
-            | "while"
-    )
-}
-
-fn is_keyword_type(word: &str) -> bool {
-    matches!(
-        word,
-        // https://www.w3.org/TR/WGSL/#keyword-summary
-        // Type-defining Keywords
-        "array"
-            | "atomic"
-            | "bool"
-            | "f32"
-            | "f16"
-            | "i32"
-            | "mat2x2"
-            | "mat2x3"
-            | "mat2x4"
-            | "mat3x2"
-            | "mat3x3"
-            | "mat3x4"
-            | "mat4x2"
-            | "mat4x3"
-            | "mat4x4"
-            | "ptr"
-            | "sampler"
-            | "sampler_comparison"
-            | "texture_1d"
-            | "texture_2d"
-            | "texture_2d_array"
-            | "texture_3d"
-            | "texture_cube"
-            | "texture_cube_array"
-            | "texture_multisampled_2d"
-            | "texture_storage_1d"
-            | "texture_storage_2d"
-            | "texture_storage_2d_array"
-            | "texture_storage_3d"
-            | "texture_depth_2d"
-            | "texture_depth_2d_array"
-            | "texture_depth_cube"
-            | "texture_depth_cube_array"
-            | "texture_depth_multisampled_2d"
-            | "u32"
-            | "vec2"
-            | "vec3"
-            | "vec4"
-    )
-}
@@ -1,11 +1,73 @@
 #[derive(Clone, Copy, Debug)]
 pub enum TokenType {
+    BuiltinFunction,
     Comment,
+    Field,
+    Function,
     FunctionDefinition,
+    GlobalVariable,
     KeywordOther,
     KeywordType,
     Literal,
+    LocalVariable,
     Numeric,
+    Struct,
     Whitespace,
     Total,
 }
+
+impl TokenType {
+    // In order, up to `Total`.
+    pub const ALL: [TokenType; TokenType::Total as usize] = [
+        TokenType::BuiltinFunction,
+        TokenType::Comment,
+        TokenType::Field,
+        TokenType::Function,
+        TokenType::FunctionDefinition,
+        TokenType::GlobalVariable,
+        TokenType::KeywordOther,
+        TokenType::KeywordType,
+        TokenType::Literal,
+        TokenType::LocalVariable,
+        TokenType::Numeric,
+        TokenType::Struct,
+        TokenType::Whitespace,
+    ];
+
+    pub fn label(self) -> String {
+        match self {
+            TokenType::BuiltinFunction => fl!("token_builtin_function"),
+            TokenType::Comment => fl!("token_comment"),
+            TokenType::Field => fl!("token_field"),
+            TokenType::Function => fl!("token_function"),
+            TokenType::FunctionDefinition => fl!("token_function_definition"),
+            TokenType::GlobalVariable => fl!("token_global_variable"),
+            TokenType::KeywordOther => fl!("token_keyword_other"),
+            TokenType::KeywordType => fl!("token_keyword_type"),
+            TokenType::Literal => fl!("token_literal"),
+            TokenType::LocalVariable => fl!("token_local_variable"),
+            TokenType::Numeric => fl!("token_numeric"),
+            TokenType::Struct => fl!("token_struct"),
+            TokenType::Whitespace | TokenType::Total => String::new(),
+        }
+    }
+
+    // As color schemes name it.
+    pub fn name(self) -> &'static str {
+        match self {
+            TokenType::BuiltinFunction => "builtin_function",
+            TokenType::Comment => "comment",
+            TokenType::Field => "field",
+            TokenType::Function => "function",
+            TokenType::FunctionDefinition => "function_definition",
+            TokenType::GlobalVariable => "global_variable",
+            TokenType::KeywordOther => "keyword_other",
+            TokenType::KeywordType => "keyword_type",
+            TokenType::Literal => "literal",
+            TokenType::LocalVariable => "local_variable",
+            TokenType::Numeric => "numeric",
+            TokenType::Struct => "struct",
+            TokenType::Whitespace | TokenType::Total => "",
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use super::utils::byte_index;
+use crate::{
+    keymap::{KeyPress, KeymapAction, KeymapState, VimMode},
+    preferences::EditorPreferences,
+    wgsl::Selection,
+};
+use egui::{
+    text::{CCursor, CCursorRange},
+    text_edit::TextEditState,
+    widgets::text_edit::TextEditOutput,
+    Context, Event, Id, Key, Modifiers, Ui,
+};
+
+#[derive(Clone)]
+struct State {
+    keymap: KeymapState,
+    // Whether the keymap moved the cursor, to scroll to it.
+    moved: bool,
+    // The keymap's own selection, which differs from the one shown in Vim's
+    // visual modes.
+    selection: Selection,
+    // The char indices of the selection shown last, to tell when the editor
+    // changed it.
+    shown: Option<(usize, usize)>,
+}
+
+// Vim or Emacs keys for a `TextEdit`.
+//
+// Runs the keymap on the keys pressed before the editor and the app's
+// shortcuts, taking those it handles from the input. Its state is kept per
+// editor in egui's memory.
+pub struct EditorKeymap<'a> {
+    editor_id: Id,
+    preferences: &'a EditorPreferences,
+}
+
+impl<'a> EditorKeymap<'a> {
+    pub fn new(editor_id: Id, preferences: &'a EditorPreferences) -> Self {
+        Self {
+            editor_id,
+            preferences,
+        }
+    }
+
+    // Returns what the keymap asks the app to do.
+    pub fn consume_keys(&self, ctx: &Context, text: &mut String) -> Vec<KeymapAction> {
+        if !ctx.memory(|mem| mem.has_focus(self.editor_id)) {
+            return vec![];
+        }
+
+        let Some(mut state) = self.load(ctx) else {
+            return vec![];
+        };
+
+        let shown = TextEditState::load(ctx, self.editor_id)
+            .and_then(|state| state.ccursor_range())
+            .map(|range| (range.secondary.index, range.primary.index));
+
+        let is_valid = |offset: usize| text.is_char_boundary(offset);
+
+        if shown != state.shown
+            || !is_valid(state.selection.anchor)
+            || !is_valid(state.selection.cursor)
+        {
+            let (anchor, cursor) = shown.unwrap_or_default();
+
+            state.selection = Selection {
+                anchor: byte_index(text, anchor),
+                cursor: byte_index(text, cursor),
+            };
+            state.keymap.sync(text, &mut state.selection);
+        }
+
+        let indent = self.preferences.indent();
+
+        let mut actions = vec![];
+        let mut handled = false;
+
+        // Events are handled in order, up to the first one left to the editor.
+        ctx.input_mut(|i| {
+            let mut index = 0;
+
+            while index < i.events.len() {
+                match &i.events[index] {
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } => match key_press(*key, *modifiers) {
+                        Some(key) => {
+                            let outcome =
+                                state
+                                    .keymap
+                                    .handle(key, text, &mut state.selection, &indent);
+
+                            if !outcome.handled {
+                                break;
+                            }
+
+                            actions.extend(outcome.actions);
+                            handled = true;
+
+                            i.events.remove(index);
+                        }
+                        // Typing, which comes as text as well.
+                        None if is_typing(*key, *modifiers) => index += 1,
+                        None => break,
+                    },
+                    Event::Text(typed) => {
+                        let typed = typed.clone();
+                        let mut taken = 0;
+
+                        for c in typed.chars() {
+                            let outcome = state.keymap.handle(
+                                KeyPress::Char(c),
+                                text,
+                                &mut state.selection,
+                                &indent,
+                            );
+
+                            if !outcome.handled {
+                                break;
+                            }
+
+                            actions.extend(outcome.actions);
+                            taken += c.len_utf8();
+                        }
+
+                        handled |= taken > 0;
+
+                        if taken < typed.len() {
+                            i.events[index] = Event::Text(typed[taken..].to_owned());
+
+                            break;
+                        }
+
+                        i.events.remove(index);
+                    }
+                    Event::Copy | Event::Cut | Event::Paste(_) => break,
+                    _ => index += 1,
+                }
+            }
+        });
+
+        let visible = state.keymap.visible_selection(text, state.selection);
+        let ccursor = |offset: usize| CCursor::new(text[..offset].chars().count());
+
+        let range = CCursorRange::two(ccursor(visible.anchor), ccursor(visible.cursor));
+
+        // Also after the editor's own changes, e.g. for Vim's cursor to stay on
+        // a character after a click.
+        if Some((range.secondary.index, range.primary.index)) != shown {
+            let mut text_edit_state = TextEditState::load(ctx, self.editor_id).unwrap_or_default();
+
+            text_edit_state.set_ccursor_range(Some(range));
+            text_edit_state.store(ctx, self.editor_id);
+        }
+
+        state.moved |= handled;
+        state.shown = Some((range.secondary.index, range.primary.index));
+
+        ctx.data_mut(|d| d.insert_temp(self.editor_id.with("keymap"), state));
+
+        actions
+    }
+
+    // Scrolls to the cursor after the keymap moved it.
+    pub fn show(&self, ui: &Ui, output: &TextEditOutput) {
+        let id = self.editor_id.with("keymap");
+
+        let Some(mut state) = ui.data_mut(|d| d.get_temp::<State>(id)) else {
+            return;
+        };
+
+        if !state.moved {
+            return;
+        }
+
+        if let Some(range) = output.state.ccursor_range() {
+            let cursor = output.galley.from_ccursor(range.primary);
+            let rect = output
+                .galley
+                .pos_from_cursor(&cursor)
+                .translate(output.text_draw_pos.to_vec2());
+
+            ui.scroll_to_rect(rect, None);
+        }
+
+        state.moved = false;
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+    }
+
+    // The mode, or the key sequence typed so far, for the status bar.
+    pub fn status(&self, ctx: &Context) -> Option<String> {
+        let state = self.load(ctx)?;
+
+        let status = match &state.keymap {
+            KeymapState::Emacs(emacs) => {
+                let (mark, prefix) = emacs.status();
+
+                let mut status = fl!("keymap_emacs");
+
+                if prefix {
+                    status.push_str(" C-x-");
+                }
+
+                if mark {
+                    status = format!("{} ({})", status, fl!("keymap_emacs_mark"));
+                }
+
+                status
+            }
+            KeymapState::Vim(vim) => {
+                let mode = match vim.mode() {
+                    VimMode::Command => return Some(vim.pending()),
+                    VimMode::Insert => fl!("keymap_vim_insert"),
+                    VimMode::Normal => fl!("keymap_vim_normal"),
+                    VimMode::Visual => fl!("keymap_vim_visual"),
+                    VimMode::VisualLine => fl!("keymap_vim_visual_line"),
+                };
+
+                format!("{} {}", mode, vim.pending()).trim_end().to_owned()
+            }
+        };
+
+        Some(status)
+    }
+
+    // The state of the keymap picked, started over when another is picked.
+    fn load(&self, ctx: &Context) -> Option<State> {
+        let keymap = self.preferences.keymap;
+
+        let state = ctx
+            .data_mut(|d| d.get_temp::<State>(self.editor_id.with("keymap")))
+            .filter(|state| state.keymap.keymap() == keymap);
+
+        state.or_else(|| {
+            Some(State {
+                keymap: KeymapState::new(keymap)?,
+                moved: false,
+                selection: Selection::caret(0),
+                shown: None,
+            })
+        })
+    }
+}
+
+// Letters, digits and space without Ctrl or Alt.
+fn is_typing(key: Key, modifiers: Modifiers) -> bool {
+    !modifiers.alt && !modifiers.ctrl && !modifiers.mac_cmd && key_char(key).is_some()
+}
+
+fn key_char(key: Key) -> Option<char> {
+    if key == Key::Space {
+        return Some(' ');
+    }
+
+    let mut chars = key.name().chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+        _ => None,
+    }
+}
+
+// The keys keymaps handle, Shift and Cmd being left to the editor.
+fn key_press(key: Key, modifiers: Modifiers) -> Option<KeyPress> {
+    if modifiers.shift || modifiers.mac_cmd {
+        return None;
+    }
+
+    match (modifiers.alt, modifiers.ctrl) {
+        (false, false) => match key {
+            Key::ArrowDown => Some(KeyPress::Down),
+            Key::ArrowLeft => Some(KeyPress::Left),
+            Key::ArrowRight => Some(KeyPress::Right),
+            Key::ArrowUp => Some(KeyPress::Up),
+            Key::Backspace => Some(KeyPress::Backspace),
+            Key::Delete => Some(KeyPress::Delete),
+            Key::End => Some(KeyPress::End),
+            Key::Enter => Some(KeyPress::Enter),
+            Key::Escape => Some(KeyPress::Escape),
+            Key::Home => Some(KeyPress::Home),
+            _ => None,
+        },
+        (false, true) => key_char(key).map(KeyPress::Ctrl),
+        (true, false) => key_char(key).map(KeyPress::Alt),
+        (true, true) => None,
+    }
+}
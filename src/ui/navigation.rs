@@ -0,0 +1,295 @@
+use super::utils::{byte_index, error_color, line_column, select_range, word_at};
+use crate::{
+    shortcut::{Command, Shortcuts},
+    wgsl::{replace_ranges, Navigation as Resolver, RenameError, Tokenizer},
+};
+use egui::{
+    text::CCursor, widgets::text_edit::TextEditOutput, Area, Context, Frame, Id, Key, Order,
+    RichText, ScrollArea, TextEdit, Ui, Window,
+};
+use std::ops::Range;
+
+const REFERENCES_HEIGHT: f32 = 240.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Action {
+    FindReferences,
+    GoToDefinition,
+    Rename,
+}
+
+#[derive(Clone)]
+struct References {
+    name: String,
+    ranges: Vec<Range<usize>>,
+}
+
+#[derive(Clone)]
+struct Rename {
+    // The name being renamed, as a byte range.
+    range: Range<usize>,
+    name: String,
+    error: Option<RenameError>,
+    focused: bool,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    references: Option<References>,
+    rename: Option<Rename>,
+}
+
+// Go to definition, find references and rename for a `TextEdit`,
+// keeping its state in egui's memory.
+//
+// Like the completion popup, it takes its keys before the editor runs and
+// acts after it, from the editor's output.
+pub struct Navigation {
+    action: Option<Action>,
+    editor_id: Id,
+    state: State,
+    texture_count: usize,
+}
+
+impl Navigation {
+    pub fn new(ctx: &Context, editor_id: Id, texture_count: usize) -> Self {
+        let state = ctx.data_mut(|d| d.get_temp(editor_id.with("navigation")).unwrap_or_default());
+
+        Self {
+            action: None,
+            editor_id,
+            state,
+            texture_count,
+        }
+    }
+
+    pub fn consume_keys(&mut self, ctx: &Context, shortcuts: &Shortcuts) {
+        if !ctx.memory(|mem| mem.has_focus(self.editor_id)) {
+            return;
+        }
+
+        self.action = ctx.input_mut(|i| {
+            if shortcuts.consume(i, Command::EditFindReferences) {
+                Some(Action::FindReferences)
+            } else if shortcuts.consume(i, Command::EditGoToDefinition) {
+                Some(Action::GoToDefinition)
+            } else if shortcuts.consume(i, Command::EditRename) {
+                Some(Action::Rename)
+            } else {
+                None
+            }
+        });
+    }
+
+    // `tokenizer` is the one lexing `text`.
+    pub fn show(
+        mut self,
+        ui: &Ui,
+        output: &mut TextEditOutput,
+        text: &mut String,
+        tokenizer: &mut Tokenizer,
+    ) {
+        let ctx = ui.ctx().clone();
+
+        // Ranges are only valid for the text they were found in.
+        if output.response.changed() {
+            self.state = State::default();
+        }
+
+        let clicked_with_command = output.response.clicked() && ctx.input(|i| i.modifiers.command);
+
+        if clicked_with_command {
+            self.action = Some(Action::GoToDefinition);
+        }
+
+        if let (Some(action), Some(cursor_range)) = (self.action, output.cursor_range) {
+            let offset = byte_index(text, cursor_range.primary.ccursor.index);
+            let resolver = Resolver::new(text, tokenizer.update(text), self.texture_count);
+
+            match action {
+                Action::FindReferences => {
+                    self.state.references = resolver
+                        .references(offset)
+                        .map(|(name, ranges)| References { name, ranges });
+                }
+                Action::GoToDefinition => {
+                    if let Some(range) = resolver.definition(offset) {
+                        self.select(ui, output, text, range);
+                    }
+                }
+                Action::Rename => {
+                    let range =
+                        word_at(text, offset).filter(|_| resolver.references(offset).is_some());
+
+                    if let Some(range) = range {
+                        self.state.rename = Some(Rename {
+                            name: text[range.clone()].to_owned(),
+                            range,
+                            error: resolver.renamable(offset).err(),
+                            focused: false,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.show_references(ui, output, text);
+        self.show_rename(ui, output, text, tokenizer);
+
+        ctx.data_mut(|d| d.insert_temp(self.editor_id.with("navigation"), self.state));
+    }
+
+    fn show_references(&mut self, ui: &Ui, output: &mut TextEditOutput, text: &str) {
+        let Some(references) = self.state.references.clone() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut clicked = None;
+
+        Window::new(format!("{}: {}", fl!("edit_references"), references.name))
+            .id(self.editor_id.with("references"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ScrollArea::vertical()
+                    .max_height(REFERENCES_HEIGHT)
+                    .show(ui, |ui| {
+                        for range in &references.ranges {
+                            let (line, column) = line_column(text, range.start);
+                            let line_text = text.lines().nth(line - 1).unwrap_or_default();
+
+                            let label = RichText::new(format!(
+                                "{:>4}:{:<3} {}",
+                                line,
+                                column,
+                                line_text.trim()
+                            ))
+                            .monospace();
+
+                            if ui.selectable_label(false, label).clicked() {
+                                clicked = Some(range.clone());
+                            }
+                        }
+                    });
+            });
+
+        if let Some(range) = clicked {
+            self.select(ui, output, text, range);
+        }
+
+        if !open {
+            self.state.references = None;
+        }
+    }
+
+    fn show_rename(
+        &mut self,
+        ui: &Ui,
+        output: &mut TextEditOutput,
+        text: &mut String,
+        tokenizer: &mut Tokenizer,
+    ) {
+        let Some(mut rename) = self.state.rename.take() else {
+            return;
+        };
+
+        let galley = &output.galley;
+        let ccursor = CCursor::new(text[..rename.range.start].chars().count());
+        let pos = galley
+            .pos_from_cursor(&galley.from_ccursor(ccursor))
+            .translate(output.text_draw_pos.to_vec2())
+            .left_bottom();
+
+        let mut submitted = false;
+        let mut cancelled = false;
+
+        Area::new(self.editor_id.with("rename"))
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .show(ui.ctx(), |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(fl!("edit_rename"));
+
+                    let response = ui.add(
+                        TextEdit::singleline(&mut rename.name)
+                            .code_editor()
+                            .desired_width(200.0),
+                    );
+
+                    if !rename.focused {
+                        response.request_focus();
+
+                        rename.focused = true;
+                    }
+
+                    if response.changed() && rename.error != Some(RenameError::Predeclared) {
+                        rename.error = None;
+                    }
+
+                    if response.lost_focus() {
+                        if ui.input(|i| i.key_pressed(Key::Enter)) {
+                            submitted = true;
+                        } else {
+                            cancelled = true;
+                        }
+                    }
+
+                    if let Some(error) = rename.error {
+                        let message = match error {
+                            RenameError::Conflict => fl!("edit_rename_err_conflict"),
+                            RenameError::InvalidName => fl!("edit_rename_err_invalid_name"),
+                            RenameError::Predeclared => fl!("edit_rename_err_predeclared"),
+                        };
+
+                        ui.label(RichText::new(message).color(error_color(ui)));
+                    }
+                });
+            });
+
+        if cancelled {
+            ui.ctx().memory_mut(|mem| mem.request_focus(self.editor_id));
+
+            return;
+        }
+
+        if !submitted {
+            self.state.rename = Some(rename);
+
+            return;
+        }
+
+        let resolver = Resolver::new(text, tokenizer.update(text), self.texture_count);
+
+        match resolver.rename(rename.range.start, &rename.name) {
+            Ok(ranges) => {
+                let old_len = rename.range.len();
+                let before = ranges
+                    .iter()
+                    .filter(|range| range.end <= rename.range.start)
+                    .count();
+                let start = rename.range.start + before * rename.name.len() - before * old_len;
+
+                *text = replace_ranges(text, &ranges, &rename.name);
+
+                output.response.mark_changed();
+
+                self.select(ui, output, text, start..start + rename.name.len());
+            }
+            Err(error) => {
+                rename.error = Some(error);
+                rename.focused = false;
+
+                self.state.rename = Some(rename);
+            }
+        }
+    }
+
+    // Selects a byte range of the text in the editor, scrolls to it and focuses the editor.
+    fn select(&self, ui: &Ui, output: &mut TextEditOutput, text: &str, range: Range<usize>) {
+        select_range(ui, output, self.editor_id, text, range);
+
+        ui.ctx().memory_mut(|mem| mem.request_focus(self.editor_id));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::wgsl::{Outline, OutlineKind};
+use egui::{Align, Context, Id, RichText, ScrollArea, SidePanel};
+use std::ops::Range;
+
+#[derive(Clone, Default)]
+struct State {
+    open: bool,
+    // The item shown as current last, to scroll to it once it changes.
+    shown: Option<usize>,
+}
+
+// Lists the functions, structs and constants of the shader, jumping to them
+// when clicked.
+pub struct OutlinePanel {
+    id: Id,
+    state: State,
+}
+
+impl OutlinePanel {
+    pub fn new(ctx: &Context) -> Self {
+        let id = Id::new("outline");
+
+        Self {
+            id,
+            state: ctx.data_mut(|d| d.get_temp::<State>(id).unwrap_or_default()),
+        }
+    }
+
+    pub fn is_open(ctx: &Context) -> bool {
+        ctx.data_mut(|d| d.get_temp::<State>(Id::new("outline")))
+            .is_some_and(|state| state.open)
+    }
+
+    pub fn toggle(ctx: &Context) {
+        ctx.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<State>(Id::new("outline"));
+
+            state.open = !state.open;
+        });
+    }
+
+    // To be shown before the central panel. `current` is the index of the
+    // item the cursor is in, returns the name range of the item clicked.
+    pub fn show(
+        mut self,
+        ctx: &Context,
+        outline: &Outline,
+        current: Option<usize>,
+    ) -> Option<Range<usize>> {
+        if !self.state.open {
+            return None;
+        }
+
+        let mut jump = None;
+
+        SidePanel::left(self.id)
+            .resizable(true)
+            .default_width(180.0)
+            .show(ctx, |ui| {
+                ui.heading(fl!("menu_outline"));
+
+                ui.separator();
+
+                if outline.items.is_empty() {
+                    ui.weak(fl!("outline_empty"));
+
+                    return;
+                }
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for (index, item) in outline.items.iter().enumerate() {
+                            let keyword = match item.kind {
+                                OutlineKind::Constant => "const",
+                                OutlineKind::Function => "fn",
+                                OutlineKind::Struct => "struct",
+                            };
+
+                            let response = ui
+                                .horizontal(|ui| {
+                                    ui.label(RichText::new(keyword).monospace().weak());
+
+                                    ui.selectable_label(current == Some(index), &item.name)
+                                })
+                                .inner;
+
+                            if response.clicked() {
+                                jump = Some(item.name_range.clone());
+                            }
+
+                            if current == Some(index) && self.state.shown != current {
+                                response.scroll_to_me(Some(Align::Center));
+                            }
+                        }
+                    });
+            });
+
+        self.state.shown = current;
+
+        ctx.data_mut(|d| d.insert_temp(self.id, self.state));
+
+        jump
+    }
+}
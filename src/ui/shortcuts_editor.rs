@@ -0,0 +1,271 @@
+use crate::shortcut::{pressed, Command, Shortcuts};
+use egui::{
+    Button, Context, Event, Grid, Id, Key, KeyboardShortcut, Modifiers, RichText, ScrollArea,
+    TextEdit, Ui, Window,
+};
+use material_icons::{icon_to_char, Icon};
+
+const FILTER_WIDTH: f32 = 200.0;
+
+// A shortcut pressed for a command that another command is bound to.
+#[derive(Clone, Copy)]
+struct Conflict {
+    command: Command,
+    other: Command,
+    shortcut: KeyboardShortcut,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    // Waits for the user to pick which command keeps the shortcut.
+    conflict: Option<Conflict>,
+    filter: String,
+    open: bool,
+    // The command the next shortcut pressed is bound to.
+    recording: Option<Command>,
+}
+
+// Lists the commands with their shortcuts, to bind them to others.
+pub struct ShortcutsEditor {
+    id: Id,
+    // The shortcut pressed this frame while recording.
+    pressed: Option<KeyboardShortcut>,
+    state: State,
+}
+
+impl ShortcutsEditor {
+    pub fn new(ctx: &Context) -> Self {
+        let id = Id::new("shortcuts");
+
+        Self {
+            id,
+            pressed: None,
+            state: ctx.data_mut(|d| d.get_temp::<State>(id).unwrap_or_default()),
+        }
+    }
+
+    // Takes the shortcut pressed while recording before anything runs it.
+    pub fn consume_keys(&mut self, ctx: &Context) {
+        if self.state.recording.is_none() {
+            return;
+        }
+
+        self.pressed = ctx.input_mut(|i| {
+            let index = i
+                .events
+                .iter()
+                .position(|event| matches!(event, Event::Key { pressed: true, .. }))?;
+
+            match i.events.remove(index) {
+                Event::Key { key, modifiers, .. } => Some(pressed(modifiers, key)),
+                _ => None,
+            }
+        });
+    }
+
+    pub fn open(&mut self) {
+        self.state.open = true;
+    }
+
+    // Returns whether a shortcut changed, to save them.
+    pub fn show(mut self, ctx: &Context, shortcuts: &mut Shortcuts) -> bool {
+        let mut changed = false;
+
+        if let (Some(command), Some(shortcut)) = (self.state.recording, self.pressed) {
+            self.state.recording = None;
+
+            // Escape cancels.
+            if shortcut != KeyboardShortcut::new(Modifiers::NONE, Key::Escape) {
+                changed |= self.bind(shortcuts, command, Some(shortcut));
+            }
+        }
+
+        let mut open = self.state.open;
+
+        Window::new(fl!("shortcuts_title"))
+            .id(self.id)
+            .open(&mut open)
+            .default_size([420.0, 480.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        TextEdit::singleline(&mut self.state.filter)
+                            .hint_text(fl!("shortcuts_filter"))
+                            .desired_width(FILTER_WIDTH),
+                    );
+
+                    if ui.button(fl!("shortcuts_reset_all")).clicked() {
+                        *shortcuts = Shortcuts::default();
+
+                        self.state.conflict = None;
+                        changed = true;
+                    }
+                });
+
+                if let Some(conflict) = self.state.conflict {
+                    changed |= self.show_conflict(ui, shortcuts, conflict);
+                }
+
+                ui.separator();
+
+                let filter = self.state.filter.to_lowercase();
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        Grid::new(self.id.with("commands"))
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for command in Command::ALL {
+                                    let label = command.label();
+                                    let text = shortcuts.format(ui.ctx(), command);
+
+                                    if label.to_lowercase().contains(&filter)
+                                        || text.to_lowercase().contains(&filter)
+                                    {
+                                        changed |=
+                                            self.show_command(ui, shortcuts, command, label, text);
+
+                                        ui.end_row();
+                                    }
+                                }
+                            });
+                    });
+            });
+
+        self.state.open = open;
+
+        if !open {
+            self.state.recording = None;
+        }
+
+        ctx.data_mut(|d| d.insert_temp(self.id, self.state));
+
+        changed
+    }
+
+    // Binds a shortcut unless another command has it, in which case the user
+    // is asked first. Returns whether it was bound.
+    fn bind(
+        &mut self,
+        shortcuts: &mut Shortcuts,
+        command: Command,
+        shortcut: Option<KeyboardShortcut>,
+    ) -> bool {
+        self.state.conflict = None;
+
+        if let Some(shortcut) = shortcut {
+            if let Some(other) = shortcuts.conflict(command, shortcut) {
+                self.state.conflict = Some(Conflict {
+                    command,
+                    other,
+                    shortcut,
+                });
+
+                return false;
+            }
+        }
+
+        shortcuts.set(command, shortcut);
+
+        true
+    }
+
+    fn show_command(
+        &mut self,
+        ui: &mut Ui,
+        shortcuts: &mut Shortcuts,
+        command: Command,
+        label: String,
+        text: String,
+    ) -> bool {
+        let mut changed = false;
+
+        // Those changed from the defaults stand out.
+        ui.label(if shortcuts.is_default(command) {
+            RichText::new(label)
+        } else {
+            RichText::new(label).strong()
+        });
+
+        let recording = self.state.recording == Some(command);
+
+        let text = if recording {
+            fl!("shortcuts_press")
+        } else if text.is_empty() {
+            "-".to_owned()
+        } else {
+            text
+        };
+
+        if ui
+            .add(Button::new(text).selected(recording))
+            .on_hover_text(fl!("shortcuts_record"))
+            .clicked()
+        {
+            self.state.recording = (!recording).then_some(command);
+            self.state.conflict = None;
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    shortcuts.get(command).is_some(),
+                    Button::new(icon_to_char(Icon::Close).to_string()),
+                )
+                .on_hover_text(fl!("shortcuts_clear"))
+                .clicked()
+            {
+                changed |= self.bind(shortcuts, command, None);
+            }
+
+            if ui
+                .add_enabled(
+                    !shortcuts.is_default(command),
+                    Button::new(icon_to_char(Icon::Undo).to_string()),
+                )
+                .on_hover_text(fl!("shortcuts_reset"))
+                .clicked()
+            {
+                changed |= self.bind(shortcuts, command, command.default_shortcut());
+            }
+        });
+
+        changed
+    }
+
+    fn show_conflict(
+        &mut self,
+        ui: &mut Ui,
+        shortcuts: &mut Shortcuts,
+        conflict: Conflict,
+    ) -> bool {
+        let mut changed = false;
+
+        ui.horizontal_wrapped(|ui| {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                fl!(
+                    "shortcuts_conflict",
+                    shortcut = ui.ctx().format_shortcut(&conflict.shortcut),
+                    command = conflict.other.label()
+                ),
+            );
+
+            if ui.button(fl!("shortcuts_reassign")).clicked() {
+                shortcuts.set(conflict.other, None);
+                shortcuts.set(conflict.command, Some(conflict.shortcut));
+
+                self.state.conflict = None;
+                changed = true;
+            }
+
+            if ui.button(fl!("shortcuts_cancel")).clicked() {
+                self.state.conflict = None;
+            }
+        });
+
+        changed
+    }
+}
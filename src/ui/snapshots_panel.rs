@@ -0,0 +1,236 @@
+use super::diff_view::show_diff;
+use crate::{
+    event::{EventProxy, UserEvent},
+    snapshots::Snapshot,
+};
+use chrono::Local;
+use egui::{
+    vec2, Button, ColorImage, Context, Id, RichText, ScrollArea, TextEdit, TextureHandle,
+    TextureOptions, Ui, Window,
+};
+use material_icons::{icon_to_char, Icon};
+use std::{mem, sync::Arc};
+
+const LABEL_WIDTH: f32 = 160.0;
+
+const THUMBNAIL_HEIGHT: f32 = 48.0;
+
+#[derive(Clone, Default)]
+struct State {
+    // The snapshots diffed, the older first.
+    diff: Option<(i64, i64)>,
+    label: String,
+    open: bool,
+    // Picked to be diffed, at most two.
+    selected: Vec<i64>,
+}
+
+// Lists the snapshots of the document, to diff or restore them.
+pub struct SnapshotsPanel {
+    id: Id,
+    state: State,
+}
+
+impl SnapshotsPanel {
+    pub fn new(ctx: &Context) -> Self {
+        let id = Id::new("snapshots");
+
+        Self {
+            id,
+            state: ctx.data_mut(|d| d.get_temp::<State>(id).unwrap_or_default()),
+        }
+    }
+
+    pub fn open(ctx: &Context) {
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<State>(Id::new("snapshots"))
+                .open = true
+        });
+    }
+
+    pub fn show(
+        mut self,
+        ctx: &Context,
+        snapshots: &[Snapshot],
+        event_proxy: &impl EventProxy<UserEvent>,
+    ) {
+        self.state
+            .selected
+            .retain(|id| snapshots.iter().any(|snapshot| snapshot.id == *id));
+
+        let mut open = self.state.open;
+
+        Window::new(fl!("snapshot_history"))
+            .id(self.id)
+            .open(&mut open)
+            .default_size([320.0, 400.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        TextEdit::singleline(&mut self.state.label)
+                            .hint_text(fl!("snapshot_label"))
+                            .desired_width(LABEL_WIDTH),
+                    );
+
+                    if ui.button(fl!("snapshot_take")).clicked() {
+                        event_proxy
+                            .send_event(UserEvent::TakeSnapshot(mem::take(&mut self.state.label)));
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.state.selected.len() == 2,
+                            Button::new(fl!("snapshot_diff")),
+                        )
+                        .on_hover_text(fl!("snapshot_diff_hint"))
+                        .clicked()
+                    {
+                        let (a, b) = (self.state.selected[0], self.state.selected[1]);
+
+                        self.state.diff = Some((a.min(b), a.max(b)));
+                    }
+                });
+
+                ui.separator();
+
+                if snapshots.is_empty() {
+                    ui.label(fl!("snapshot_none"));
+
+                    return;
+                }
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        // Newest first.
+                        for snapshot in snapshots.iter().rev() {
+                            self.show_snapshot(ui, snapshot, event_proxy);
+
+                            ui.separator();
+                        }
+                    });
+            });
+
+        self.state.open = open;
+
+        if let Some((old, new)) = self.state.diff {
+            let find = |id| snapshots.iter().find(|snapshot| snapshot.id == id);
+
+            match (find(old), find(new)) {
+                (Some(old), Some(new)) => {
+                    let mut open = true;
+
+                    show_diff(
+                        ctx,
+                        self.id.with("diff"),
+                        format!("{} → {}", title(old), title(new)),
+                        &mut open,
+                        &old.frag,
+                        &new.frag,
+                    );
+
+                    if !open {
+                        self.state.diff = None;
+                    }
+                }
+                _ => self.state.diff = None,
+            }
+        }
+
+        ctx.data_mut(|d| d.insert_temp(self.id, self.state));
+    }
+
+    fn show_snapshot(
+        &mut self,
+        ui: &mut Ui,
+        snapshot: &Snapshot,
+        event_proxy: &impl EventProxy<UserEvent>,
+    ) {
+        ui.horizontal(|ui| {
+            let mut selected = self.state.selected.contains(&snapshot.id);
+
+            if ui
+                .checkbox(&mut selected, "")
+                .on_hover_text(fl!("snapshot_select"))
+                .changed()
+            {
+                if selected {
+                    self.state.selected.push(snapshot.id);
+
+                    if self.state.selected.len() > 2 {
+                        self.state.selected.remove(0);
+                    }
+                } else {
+                    self.state.selected.retain(|id| *id != snapshot.id);
+                }
+            }
+
+            match thumbnail_texture(ui.ctx(), snapshot) {
+                Some(texture) => {
+                    let size = texture.size_vec2();
+
+                    ui.image((texture.id(), size * (THUMBNAIL_HEIGHT / size.y)));
+                }
+                None => {
+                    ui.allocate_space(vec2(THUMBNAIL_HEIGHT, THUMBNAIL_HEIGHT));
+                }
+            }
+
+            ui.vertical(|ui| {
+                ui.label(RichText::new(title(snapshot)).strong());
+
+                ui.horizontal(|ui| {
+                    if ui.button(fl!("snapshot_restore")).clicked() {
+                        event_proxy.send_event(UserEvent::RestoreSnapshot(snapshot.id));
+                    }
+
+                    if ui
+                        .button(icon_to_char(Icon::Delete).to_string())
+                        .on_hover_text(fl!("snapshot_delete"))
+                        .clicked()
+                    {
+                        event_proxy.send_event(UserEvent::RemoveSnapshot(snapshot.id));
+                    }
+                });
+            });
+        });
+    }
+}
+
+// The label and the time taken, only the time for automatic snapshots.
+fn title(snapshot: &Snapshot) -> String {
+    let time = snapshot
+        .created
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string();
+
+    match &snapshot.label {
+        Some(label) => format!("{} ({})", label, time),
+        None => time,
+    }
+}
+
+// Thumbnails are loaded once and kept in egui's memory.
+fn thumbnail_texture(ctx: &Context, snapshot: &Snapshot) -> Option<TextureHandle> {
+    let thumbnail = snapshot.thumbnail.as_ref()?;
+
+    let id = Id::new("snapshot_thumbnail").with(snapshot.id);
+
+    if let Some(texture) = ctx.data(|d| d.get_temp::<TextureHandle>(id)) {
+        return Some(texture);
+    }
+
+    let texture = ctx.load_texture(
+        "snapshot",
+        Arc::new(ColorImage::from_rgba_unmultiplied(
+            [thumbnail.width as usize, thumbnail.height as usize],
+            &thumbnail.data,
+        )),
+        TextureOptions::LINEAR,
+    );
+
+    ctx.data_mut(|d| d.insert_temp(id, texture.clone()));
+
+    Some(texture)
+}
@@ -1,4 +1,12 @@
-use egui::{vec2, widget_text::WidgetTextGalley, TextStyle, Ui, Vec2, WidgetText};
+use egui::{
+    pos2,
+    text::{CCursor, CCursorRange},
+    vec2,
+    widget_text::WidgetTextGalley,
+    widgets::text_edit::TextEditOutput,
+    Align, Color32, Id, Rect, TextStyle, Ui, Vec2, WidgetText,
+};
+use std::ops::Range;
 
 pub fn layout_text_widget(
     ui: &mut Ui,
@@ -22,3 +30,102 @@ pub fn layout_text_widget(
 
     (text, widget_size)
 }
+
+pub fn error_color(ui: &Ui) -> Color32 {
+    if ui.visuals().dark_mode {
+        Color32::LIGHT_RED
+    } else {
+        Color32::DARK_RED
+    }
+}
+
+// The byte offset of a char index, as used by text cursors.
+pub fn byte_index(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map_or(text.len(), |(index, _)| index)
+}
+
+// The byte range of the identifier around a byte offset, if any.
+pub fn word_at(text: &str, offset: usize) -> Option<Range<usize>> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(index, _)| index);
+    let end = text[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(text.len(), |(index, _)| offset + index);
+
+    (start < end).then_some(start..end)
+}
+
+// 1-based line and column of a byte offset.
+pub fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+// The rects covering a byte range of the text of a `TextEdit`, one per row, in screen space.
+pub fn range_rects(output: &TextEditOutput, text: &str, range: Range<usize>) -> Vec<Rect> {
+    let galley = &output.galley;
+
+    let cursor_at = |byte: usize| galley.from_ccursor(CCursor::new(text[..byte].chars().count()));
+    let start = cursor_at(range.start);
+    let end = cursor_at(range.end);
+
+    (start.rcursor.row..=end.rcursor.row)
+        .filter_map(|index| {
+            let row = galley.rows.get(index)?;
+
+            let left = if index == start.rcursor.row {
+                galley.pos_from_cursor(&start).left()
+            } else {
+                row.rect.left()
+            };
+            let right = if index == end.rcursor.row {
+                galley.pos_from_cursor(&end).left()
+            } else {
+                row.rect.right()
+            };
+
+            Some(
+                Rect::from_min_max(pos2(left, row.rect.top()), pos2(right, row.rect.bottom()))
+                    .translate(output.text_draw_pos.to_vec2()),
+            )
+        })
+        .collect()
+}
+
+// Selects a byte range of the text of a `TextEdit` and scrolls to it.
+pub fn select_range(
+    ui: &Ui,
+    output: &mut TextEditOutput,
+    editor_id: Id,
+    text: &str,
+    range: Range<usize>,
+) {
+    let start = CCursor::new(text[..range.start].chars().count());
+    let end = CCursor::new(start.index + text[range].chars().count());
+
+    output
+        .state
+        .set_ccursor_range(Some(CCursorRange::two(start, end)));
+    output.state.clone().store(ui.ctx(), editor_id);
+
+    let rect = output
+        .galley
+        .pos_from_cursor(&output.galley.from_ccursor(start))
+        .translate(output.text_draw_pos.to_vec2());
+
+    ui.scroll_to_rect(rect, Some(Align::Center));
+}
@@ -0,0 +1,28 @@
+mod analyzer;
+mod brackets;
+mod builtins;
+mod completion;
+mod editing;
+mod formatter;
+mod lexer;
+mod navigation;
+mod outline;
+mod resolution;
+mod signature;
+mod symbols;
+
+pub use analyzer::Analyzer;
+pub use brackets::matching_bracket;
+pub use builtins::{find_function, parameter_ranges, BuiltinFunction, Section};
+pub use completion::{complete, enclosing_function, CompletionItem, CompletionKind};
+pub use editing::{
+    auto_close, dedent_closing, delete_lines, delete_pair, duplicate_lines, format_document,
+    indent_lines, move_lines, newline, outdent_lines, toggle_block_comment, toggle_line_comment,
+    Edit, Selection,
+};
+pub use lexer::{Token, TokenKind, Tokenizer};
+pub use navigation::Navigation;
+pub use outline::{Outline, OutlineKind};
+pub use resolution::{replace_ranges, RenameError};
+pub use signature::enclosing_call;
+pub use symbols::{SymbolKind, Symbols};
@@ -0,0 +1,82 @@
+use super::Symbols;
+use crate::shader::{validate, Diagnostic};
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    thread,
+};
+
+type Analysis = (Option<Symbols>, Option<Diagnostic>);
+
+// Parses and validates the source on a worker thread after edits, skipping
+// the sources edited again before it got to them.
+// While the source does not parse, the symbols of the last one that did are kept.
+pub struct Analyzer {
+    // The first error of the last analysis, if any.
+    diagnostic: Option<Diagnostic>,
+    receiver: Receiver<Analysis>,
+    // The fragment source and texture count of the last request.
+    requested: (String, usize),
+    sender: Sender<(String, usize)>,
+    symbols: Symbols,
+}
+
+impl Analyzer {
+    pub fn new() -> Self {
+        let (sender, requests) = channel::<(String, usize)>();
+        let (results, receiver) = channel();
+
+        // Stops once the analyzer, and so the sender, is dropped.
+        thread::spawn(move || {
+            while let Ok(mut request) = requests.recv() {
+                while let Ok(newer) = requests.try_recv() {
+                    request = newer;
+                }
+
+                let (frag, texture_count) = request;
+
+                let symbols = Symbols::parse(&frag, texture_count);
+                let diagnostic = validate(&frag, texture_count).err();
+
+                if results.send((symbols, diagnostic)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            diagnostic: None,
+            receiver,
+            requested: (String::new(), 0),
+            sender,
+            symbols: Symbols::default(),
+        }
+    }
+
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        self.diagnostic.as_ref()
+    }
+
+    pub fn symbols(&self) -> &Symbols {
+        &self.symbols
+    }
+
+    // Called every frame, picks up finished parses and requests a new one
+    // if the source changed since the last.
+    pub fn update(&mut self, frag: &str, texture_count: usize) {
+        while let Ok((symbols, diagnostic)) = self.receiver.try_recv() {
+            self.diagnostic = diagnostic;
+
+            if let Some(symbols) = symbols {
+                self.symbols = symbols;
+            }
+        }
+
+        if self.requested.0 == frag && self.requested.1 == texture_count {
+            return;
+        }
+
+        self.requested = (frag.to_owned(), texture_count);
+
+        let _ = self.sender.send(self.requested.clone());
+    }
+}
@@ -0,0 +1,96 @@
+use super::{Token, TokenKind};
+use std::ops::Range;
+
+// The bracket right after or right before `offset`, a byte offset into
+// `source`, and the bracket matching it. `tokens` are those of the whole source.
+//
+// `<` and `>` only match as the delimiters of a template list.
+pub fn matching_bracket(
+    source: &str,
+    tokens: &[Token],
+    offset: usize,
+) -> Option<(Range<usize>, Range<usize>)> {
+    let position = tokens
+        .iter()
+        .position(|token| token.start == offset && bracket(source, token).is_some())
+        .or_else(|| {
+            tokens
+                .iter()
+                .position(|token| token.end == offset && bracket(source, token).is_some())
+        })?;
+
+    let (family, opens) = bracket(source, &tokens[position])?;
+
+    let mut depth = 0;
+
+    let candidates: Box<dyn Iterator<Item = &Token>> = if opens {
+        Box::new(tokens[position + 1..].iter())
+    } else {
+        Box::new(tokens[..position].iter().rev())
+    };
+
+    for token in candidates {
+        match bracket(source, token) {
+            Some((other, other_opens)) if other == family => {
+                if other_opens == opens {
+                    depth += 1;
+                } else if depth == 0 {
+                    return Some((tokens[position].range(), token.range()));
+                } else {
+                    depth -= 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// The family of a bracket token, as its opening character, and whether it opens.
+fn bracket(source: &str, token: &Token) -> Option<(char, bool)> {
+    match token.kind {
+        TokenKind::TemplateStart => Some(('<', true)),
+        TokenKind::TemplateEnd => Some(('<', false)),
+        TokenKind::Punctuation => match token.text(source) {
+            "(" => Some(('(', true)),
+            ")" => Some(('(', false)),
+            "[" => Some(('[', true)),
+            "]" => Some(('[', false)),
+            "{" => Some(('{', true)),
+            "}" => Some(('{', false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matching_bracket;
+    use crate::wgsl::Tokenizer;
+
+    #[test]
+    fn brackets() {
+        let source = "fn f(a: array<vec2<f32>, 2>) { let b = a[0].x < 1.0; }";
+        let mut tokenizer = Tokenizer::default();
+        let tokens = tokenizer.update(source);
+
+        let matching = |offset: usize| {
+            matching_bracket(source, tokens, offset).map(|(bracket, matching)| {
+                (&source[bracket.clone()], bracket.start, matching.start)
+            })
+        };
+
+        assert_eq!(matching(4), Some(("(", 4, 27)));
+        assert_eq!(matching(28), Some((")", 27, 4)));
+        assert_eq!(matching(13), Some(("<", 13, 26)));
+        assert_eq!(matching(22), Some((">", 22, 18)));
+        assert_eq!(matching(29), Some(("{", 29, 53)));
+        assert_eq!(matching(40), Some(("[", 40, 42)));
+
+        // A less-than is not a bracket.
+        assert_eq!(matching(46), None);
+        assert_eq!(matching(31), None);
+    }
+}
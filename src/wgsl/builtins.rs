@@ -0,0 +1,593 @@
+use std::ops::Range;
+
+pub const SPEC_URL: &str = "https://www.w3.org/TR/WGSL/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Section {
+    Array,
+    Atomic,
+    Derivative,
+    Logical,
+    Numeric,
+    Packing,
+    Synchronization,
+    Texture,
+    Unpacking,
+}
+
+impl Section {
+    // The section's anchor in the spec.
+    pub fn anchor(&self) -> &'static str {
+        match self {
+            Section::Array => "array-builtin-functions",
+            Section::Atomic => "atomic-builtin-functions",
+            Section::Derivative => "derivative-builtin-functions",
+            Section::Logical => "logical-builtin-functions",
+            Section::Numeric => "numeric-builtin-functions",
+            Section::Packing => "pack-builtin-functions",
+            Section::Synchronization => "sync-builtin-functions",
+            Section::Texture => "texture-builtin-functions",
+            Section::Unpacking => "unpack-builtin-functions",
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("{}#{}", SPEC_URL, self.anchor())
+    }
+}
+
+pub struct BuiltinFunction {
+    pub name: &'static str,
+    // Overloads, in the notation of the spec: `T` stands for any type the
+    // function accepts, `vecN<T>` for a vector of any size.
+    pub signatures: &'static [&'static str],
+}
+
+// https://www.w3.org/TR/WGSL/#builtin-functions
+//
+// Sorted by name, so that names can be binary searched.
+pub const FUNCTIONS: &[BuiltinFunction] = &[
+    BuiltinFunction {
+        name: "abs",
+        signatures: &["abs(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "acos",
+        signatures: &["acos(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "acosh",
+        signatures: &["acosh(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "all",
+        signatures: &["all(e: vecN<bool>) -> bool"],
+    },
+    BuiltinFunction {
+        name: "any",
+        signatures: &["any(e: vecN<bool>) -> bool"],
+    },
+    BuiltinFunction {
+        name: "arrayLength",
+        signatures: &["arrayLength(p: ptr<storage, array<E>>) -> u32"],
+    },
+    BuiltinFunction {
+        name: "asin",
+        signatures: &["asin(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "asinh",
+        signatures: &["asinh(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atan",
+        signatures: &["atan(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atan2",
+        signatures: &["atan2(y: T, x: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atanh",
+        signatures: &["atanh(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicAdd",
+        signatures: &["atomicAdd(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicAnd",
+        signatures: &["atomicAnd(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicCompareExchangeWeak",
+        signatures: &["atomicCompareExchangeWeak(atomic_ptr: ptr<AS, atomic<T>, read_write>, cmp: T, v: T) -> __atomic_compare_exchange_result<T>"],
+    },
+    BuiltinFunction {
+        name: "atomicExchange",
+        signatures: &["atomicExchange(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicLoad",
+        signatures: &["atomicLoad(atomic_ptr: ptr<AS, atomic<T>, read_write>) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicMax",
+        signatures: &["atomicMax(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicMin",
+        signatures: &["atomicMin(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicOr",
+        signatures: &["atomicOr(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicStore",
+        signatures: &["atomicStore(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T)"],
+    },
+    BuiltinFunction {
+        name: "atomicSub",
+        signatures: &["atomicSub(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "atomicXor",
+        signatures: &["atomicXor(atomic_ptr: ptr<AS, atomic<T>, read_write>, v: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "ceil",
+        signatures: &["ceil(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "clamp",
+        signatures: &["clamp(e: T, low: T, high: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "cos",
+        signatures: &["cos(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "cosh",
+        signatures: &["cosh(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "countLeadingZeros",
+        signatures: &["countLeadingZeros(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "countOneBits",
+        signatures: &["countOneBits(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "countTrailingZeros",
+        signatures: &["countTrailingZeros(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "cross",
+        signatures: &["cross(e1: vec3<T>, e2: vec3<T>) -> vec3<T>"],
+    },
+    BuiltinFunction {
+        name: "degrees",
+        signatures: &["degrees(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "determinant",
+        signatures: &["determinant(e: matCxC<T>) -> T"],
+    },
+    BuiltinFunction {
+        name: "distance",
+        signatures: &["distance(e1: T, e2: T) -> S"],
+    },
+    BuiltinFunction {
+        name: "dot",
+        signatures: &["dot(e1: vecN<T>, e2: vecN<T>) -> T"],
+    },
+    BuiltinFunction {
+        name: "dpdx",
+        signatures: &["dpdx(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "dpdxCoarse",
+        signatures: &["dpdxCoarse(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "dpdxFine",
+        signatures: &["dpdxFine(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "dpdy",
+        signatures: &["dpdy(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "dpdyCoarse",
+        signatures: &["dpdyCoarse(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "dpdyFine",
+        signatures: &["dpdyFine(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "exp",
+        signatures: &["exp(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "exp2",
+        signatures: &["exp2(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "extractBits",
+        signatures: &["extractBits(e: T, offset: u32, count: u32) -> T"],
+    },
+    BuiltinFunction {
+        name: "faceForward",
+        signatures: &["faceForward(e1: T, e2: T, e3: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "firstLeadingBit",
+        signatures: &["firstLeadingBit(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "firstTrailingBit",
+        signatures: &["firstTrailingBit(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "floor",
+        signatures: &["floor(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "fma",
+        signatures: &["fma(e1: T, e2: T, e3: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "fract",
+        signatures: &["fract(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "frexp",
+        signatures: &["frexp(e: T) -> __frexp_result<T>"],
+    },
+    BuiltinFunction {
+        name: "fwidth",
+        signatures: &["fwidth(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "fwidthCoarse",
+        signatures: &["fwidthCoarse(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "fwidthFine",
+        signatures: &["fwidthFine(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "insertBits",
+        signatures: &["insertBits(e: T, newbits: T, offset: u32, count: u32) -> T"],
+    },
+    BuiltinFunction {
+        name: "inverseSqrt",
+        signatures: &["inverseSqrt(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "ldexp",
+        signatures: &["ldexp(e1: T, e2: I) -> T"],
+    },
+    BuiltinFunction {
+        name: "length",
+        signatures: &["length(e: T) -> S"],
+    },
+    BuiltinFunction {
+        name: "log",
+        signatures: &["log(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "log2",
+        signatures: &["log2(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "max",
+        signatures: &["max(e1: T, e2: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "min",
+        signatures: &["min(e1: T, e2: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "mix",
+        signatures: &["mix(e1: T, e2: T, e3: T) -> T", "mix(e1: vecN<T>, e2: vecN<T>, e3: T) -> vecN<T>"],
+    },
+    BuiltinFunction {
+        name: "modf",
+        signatures: &["modf(e: T) -> __modf_result<T>"],
+    },
+    BuiltinFunction {
+        name: "normalize",
+        signatures: &["normalize(e: vecN<T>) -> vecN<T>"],
+    },
+    BuiltinFunction {
+        name: "pack2x16float",
+        signatures: &["pack2x16float(e: vec2<f32>) -> u32"],
+    },
+    BuiltinFunction {
+        name: "pack2x16snorm",
+        signatures: &["pack2x16snorm(e: vec2<f32>) -> u32"],
+    },
+    BuiltinFunction {
+        name: "pack2x16unorm",
+        signatures: &["pack2x16unorm(e: vec2<f32>) -> u32"],
+    },
+    BuiltinFunction {
+        name: "pack4x8snorm",
+        signatures: &["pack4x8snorm(e: vec4<f32>) -> u32"],
+    },
+    BuiltinFunction {
+        name: "pack4x8unorm",
+        signatures: &["pack4x8unorm(e: vec4<f32>) -> u32"],
+    },
+    BuiltinFunction {
+        name: "pow",
+        signatures: &["pow(e1: T, e2: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "quantizeToF16",
+        signatures: &["quantizeToF16(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "radians",
+        signatures: &["radians(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "reflect",
+        signatures: &["reflect(e1: T, e2: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "refract",
+        signatures: &["refract(e1: T, e2: T, e3: I) -> T"],
+    },
+    BuiltinFunction {
+        name: "reverseBits",
+        signatures: &["reverseBits(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "round",
+        signatures: &["round(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "saturate",
+        signatures: &["saturate(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "select",
+        signatures: &["select(f: T, t: T, cond: bool) -> T", "select(f: vecN<T>, t: vecN<T>, cond: vecN<bool>) -> vecN<T>"],
+    },
+    BuiltinFunction {
+        name: "sign",
+        signatures: &["sign(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "sin",
+        signatures: &["sin(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "sinh",
+        signatures: &["sinh(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "smoothstep",
+        signatures: &["smoothstep(low: T, high: T, x: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "sqrt",
+        signatures: &["sqrt(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "step",
+        signatures: &["step(edge: T, x: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "storageBarrier",
+        signatures: &["storageBarrier()"],
+    },
+    BuiltinFunction {
+        name: "tan",
+        signatures: &["tan(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "tanh",
+        signatures: &["tanh(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "textureDimensions",
+        signatures: &["textureDimensions(t: T) -> vecN<u32>", "textureDimensions(t: T, level: L) -> vecN<u32>"],
+    },
+    BuiltinFunction {
+        name: "textureGather",
+        signatures: &["textureGather(component: C, t: texture_2d<T>, s: sampler, coords: vec2<f32>) -> vec4<T>", "textureGather(t: texture_depth_2d, s: sampler, coords: vec2<f32>) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureGatherCompare",
+        signatures: &["textureGatherCompare(t: texture_depth_2d, s: sampler_comparison, coords: vec2<f32>, depth_ref: f32) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureLoad",
+        signatures: &["textureLoad(t: texture_2d<T>, coords: vec2<C>, level: L) -> vec4<T>"],
+    },
+    BuiltinFunction {
+        name: "textureNumLayers",
+        signatures: &["textureNumLayers(t: T) -> u32"],
+    },
+    BuiltinFunction {
+        name: "textureNumLevels",
+        signatures: &["textureNumLevels(t: T) -> u32"],
+    },
+    BuiltinFunction {
+        name: "textureNumSamples",
+        signatures: &["textureNumSamples(t: T) -> u32"],
+    },
+    BuiltinFunction {
+        name: "textureSample",
+        signatures: &["textureSample(t: texture_2d<f32>, s: sampler, coords: vec2<f32>) -> vec4<f32>", "textureSample(t: texture_2d<f32>, s: sampler, coords: vec2<f32>, offset: vec2<i32>) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureSampleBaseClampToEdge",
+        signatures: &["textureSampleBaseClampToEdge(t: texture_2d<f32>, s: sampler, coords: vec2<f32>) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureSampleBias",
+        signatures: &["textureSampleBias(t: texture_2d<f32>, s: sampler, coords: vec2<f32>, bias: f32) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureSampleCompare",
+        signatures: &["textureSampleCompare(t: texture_depth_2d, s: sampler_comparison, coords: vec2<f32>, depth_ref: f32) -> f32"],
+    },
+    BuiltinFunction {
+        name: "textureSampleCompareLevel",
+        signatures: &["textureSampleCompareLevel(t: texture_depth_2d, s: sampler_comparison, coords: vec2<f32>, depth_ref: f32) -> f32"],
+    },
+    BuiltinFunction {
+        name: "textureSampleGrad",
+        signatures: &["textureSampleGrad(t: texture_2d<f32>, s: sampler, coords: vec2<f32>, ddx: vec2<f32>, ddy: vec2<f32>) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureSampleLevel",
+        signatures: &["textureSampleLevel(t: texture_2d<f32>, s: sampler, coords: vec2<f32>, level: f32) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "textureStore",
+        signatures: &["textureStore(t: texture_storage_2d<F, write>, coords: vec2<C>, value: vec4<T>)"],
+    },
+    BuiltinFunction {
+        name: "transpose",
+        signatures: &["transpose(e: matRxC<T>) -> matCxR<T>"],
+    },
+    BuiltinFunction {
+        name: "trunc",
+        signatures: &["trunc(e: T) -> T"],
+    },
+    BuiltinFunction {
+        name: "unpack2x16float",
+        signatures: &["unpack2x16float(e: u32) -> vec2<f32>"],
+    },
+    BuiltinFunction {
+        name: "unpack2x16snorm",
+        signatures: &["unpack2x16snorm(e: u32) -> vec2<f32>"],
+    },
+    BuiltinFunction {
+        name: "unpack2x16unorm",
+        signatures: &["unpack2x16unorm(e: u32) -> vec2<f32>"],
+    },
+    BuiltinFunction {
+        name: "unpack4x8snorm",
+        signatures: &["unpack4x8snorm(e: u32) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "unpack4x8unorm",
+        signatures: &["unpack4x8unorm(e: u32) -> vec4<f32>"],
+    },
+    BuiltinFunction {
+        name: "workgroupBarrier",
+        signatures: &["workgroupBarrier()"],
+    },
+    BuiltinFunction {
+        name: "workgroupUniformLoad",
+        signatures: &["workgroupUniformLoad(p: ptr<workgroup, T>) -> T"],
+    },
+];
+
+impl BuiltinFunction {
+    // The Fluent message describing the function.
+    pub fn description_id(&self) -> String {
+        format!("wgsl_fn_{}", self.name)
+    }
+
+    pub fn section(&self) -> Section {
+        let name = self.name;
+
+        if name.starts_with("atomic") {
+            Section::Atomic
+        } else if name.starts_with("texture") {
+            Section::Texture
+        } else if name.starts_with("pack") {
+            Section::Packing
+        } else if name.starts_with("unpack") {
+            Section::Unpacking
+        } else if name.starts_with("dpd") || name.starts_with("fwidth") {
+            Section::Derivative
+        } else if name.ends_with("Barrier") || name == "workgroupUniformLoad" {
+            Section::Synchronization
+        } else if matches!(name, "all" | "any" | "select") {
+            Section::Logical
+        } else if name == "arrayLength" {
+            Section::Array
+        } else {
+            Section::Numeric
+        }
+    }
+}
+
+pub fn find_function(name: &str) -> Option<&'static BuiltinFunction> {
+    FUNCTIONS
+        .binary_search_by(|function| function.name.cmp(name))
+        .ok()
+        .map(|index| &FUNCTIONS[index])
+}
+
+pub fn is_function(name: &str) -> bool {
+    find_function(name).is_some()
+}
+
+// Parameter names of a signature, e.g. `["e", "low", "high"]` for
+// `clamp(e: T, low: T, high: T) -> T`.
+pub fn parameters(signature: &str) -> Vec<&str> {
+    parameter_ranges(signature)
+        .into_iter()
+        .map(|range| {
+            let parameter = &signature[range];
+
+            parameter
+                .split_once(':')
+                .map_or(parameter, |(name, _)| name.trim_end())
+        })
+        .collect()
+}
+
+// Byte ranges of the parameters of a signature, e.g. of `e: T`, `low: T`
+// and `high: T` in `clamp(e: T, low: T, high: T) -> T`.
+pub fn parameter_ranges(signature: &str) -> Vec<Range<usize>> {
+    let Some(open) = signature.find('(') else {
+        return vec![];
+    };
+
+    let mut ranges = vec![];
+    let mut depth = 0;
+    let mut start = open + 1;
+
+    // Commas inside template lists, as in `ptr<AS, atomic<T>>`, separate nothing.
+    for (index, c) in signature
+        .char_indices()
+        .skip_while(|(index, _)| *index <= open)
+    {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' | ')' if depth == 0 => {
+                let parameter = &signature[start..index];
+                let trimmed = parameter.trim_start();
+
+                if !trimmed.trim_end().is_empty() {
+                    let trimmed_start = start + parameter.len() - trimmed.len();
+
+                    ranges.push(trimmed_start..trimmed_start + trimmed.trim_end().len());
+                }
+
+                if c == ')' {
+                    break;
+                }
+
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    ranges
+}
@@ -0,0 +1,453 @@
+use super::{
+    builtins,
+    lexer::{KEYWORDS, TYPES},
+    Symbols, Token, TokenKind,
+};
+use crate::{
+    fuzzy::fuzzy_score,
+    shader::{IMAGE_FN_SIGNATURE, UNIFORM_FIELDS},
+};
+use std::{collections::HashSet, ops::Range};
+
+const MAX_ITEMS: usize = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    Field,
+    Function,
+    Keyword,
+    Struct,
+    Type,
+    Variable,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompletionItem {
+    pub kind: CompletionKind,
+    pub label: String,
+    // A signature or type shown next to the label.
+    pub detail: String,
+    // Placeholders inserted as call arguments, for functions.
+    pub parameters: Option<Vec<String>>,
+}
+
+impl CompletionItem {
+    fn new(kind: CompletionKind, label: &str, detail: &str) -> Self {
+        Self {
+            kind,
+            label: label.to_owned(),
+            detail: detail.to_owned(),
+            parameters: None,
+        }
+    }
+
+    fn function(label: &str, detail: &str, parameters: Vec<String>) -> Self {
+        Self {
+            parameters: Some(parameters),
+            ..Self::new(CompletionKind::Function, label, detail)
+        }
+    }
+
+    // The text to insert, and the range within it to select afterwards:
+    // the first parameter placeholder, if any.
+    pub fn insert_text(&self) -> (String, Option<Range<usize>>) {
+        match &self.parameters {
+            Some(parameters) => {
+                let text = format!("{}({})", self.label, parameters.join(", "));
+
+                let selection = parameters.first().map(|first| {
+                    let start = self.label.len() + 1;
+
+                    start..start + first.len()
+                });
+
+                (text, selection)
+            }
+            None => (self.label.clone(), None),
+        }
+    }
+}
+
+pub struct Completions {
+    // Byte range of the partial word the completion replaces.
+    pub range: Range<usize>,
+    pub items: Vec<CompletionItem>,
+}
+
+// Completes the word before `cursor`, a byte offset into `source`, which
+// `tokens` cover.
+//
+// While typing, an empty word is only completed after a `.`;
+// `explicit` requests, from the shortcut, complete it anyway.
+pub fn complete(
+    source: &str,
+    tokens: &[Token],
+    cursor: usize,
+    symbols: &Symbols,
+    texture_count: usize,
+    explicit: bool,
+) -> Option<Completions> {
+    let start = source[..cursor]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map_or(cursor, |(index, _)| index);
+    let word = &source[start..cursor];
+
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    // Those before the word, the last one possibly going on past it.
+    let tokens = &tokens[..tokens.partition_point(|token| token.start < start)];
+
+    if let Some(last) = tokens.last() {
+        let text = last.text(source);
+
+        let in_comment = match last.kind {
+            TokenKind::BlockComment => last.end > start || text.len() < 4 || !text.ends_with("*/"),
+            TokenKind::LineComment => true,
+            _ => false,
+        };
+
+        if in_comment {
+            return None;
+        }
+    }
+
+    let mut significant = tokens.iter().rev().filter(|token| !token.is_trivia());
+    let is_member = significant
+        .next()
+        .is_some_and(|token| token.text(source) == ".");
+
+    let candidates = if is_member {
+        match significant.next() {
+            Some(receiver) if receiver.kind == TokenKind::Ident && receiver.text(source) == "u" => {
+                UNIFORM_FIELDS
+                    .iter()
+                    .map(|(name, ty)| CompletionItem::new(CompletionKind::Field, name, ty))
+                    .collect()
+            }
+            Some(receiver)
+                if matches!(
+                    receiver.kind,
+                    TokenKind::Ident | TokenKind::Punctuation | TokenKind::TemplateEnd
+                ) =>
+            {
+                symbols
+                    .fields()
+                    .map(|name| CompletionItem::new(CompletionKind::Field, name, ""))
+                    .collect()
+            }
+            _ => return None,
+        }
+    } else {
+        if word.is_empty() && !explicit {
+            return None;
+        }
+
+        let function = enclosing_function(source, tokens);
+
+        candidates(symbols, texture_count, function)
+    };
+
+    let mut seen = HashSet::new();
+    let mut scored: Vec<_> = candidates
+        .into_iter()
+        .filter(|item| seen.insert(item.label.clone()))
+        .filter_map(|item| fuzzy_score(word, &item.label).map(|score| (score, item)))
+        .collect();
+
+    // Stable, so that equal scores keep the most specific candidates first.
+    scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    let items: Vec<_> = scored
+        .into_iter()
+        .map(|(_, item)| item)
+        .take(MAX_ITEMS)
+        .collect();
+
+    if items.is_empty() {
+        return None;
+    }
+
+    Some(Completions {
+        range: start..cursor,
+        items,
+    })
+}
+
+// Everything nameable outside of a member access, most specific first,
+// since duplicates keep the first entry.
+fn candidates(
+    symbols: &Symbols,
+    texture_count: usize,
+    function: Option<&str>,
+) -> Vec<CompletionItem> {
+    let mut items = vec![];
+
+    if let Some(function) = function {
+        items.extend(
+            symbols
+                .locals(function)
+                .map(|name| CompletionItem::new(CompletionKind::Variable, name, "")),
+        );
+    }
+
+    items.push(CompletionItem::new(
+        CompletionKind::Variable,
+        "u",
+        "Uniform",
+    ));
+
+    for index in 0..texture_count {
+        items.push(CompletionItem::new(
+            CompletionKind::Variable,
+            &format!("texture{}", index),
+            "texture_2d<f32>",
+        ));
+        items.push(CompletionItem::new(
+            CompletionKind::Variable,
+            &format!("sampler{}", index),
+            "sampler",
+        ));
+    }
+
+    items.push(CompletionItem::function(
+        "image",
+        IMAGE_FN_SIGNATURE,
+        builtins::parameters(IMAGE_FN_SIGNATURE)
+            .into_iter()
+            .map(str::to_owned)
+            .collect(),
+    ));
+
+    for (name, parameters) in symbols.functions() {
+        let detail = format!("fn {}({})", name, parameters.join(", "));
+
+        items.push(CompletionItem::function(name, &detail, parameters.to_vec()));
+    }
+
+    items.extend(
+        symbols
+            .structs()
+            .map(|name| CompletionItem::new(CompletionKind::Struct, name, "struct")),
+    );
+
+    items.extend(
+        symbols
+            .globals()
+            .map(|name| CompletionItem::new(CompletionKind::Variable, name, "")),
+    );
+
+    for function in builtins::FUNCTIONS {
+        let signature = function.signatures[0];
+
+        items.push(CompletionItem::function(
+            function.name,
+            signature,
+            builtins::parameters(signature)
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+        ));
+    }
+
+    items.extend(
+        KEYWORDS
+            .iter()
+            .map(|keyword| CompletionItem::new(CompletionKind::Keyword, keyword, "")),
+    );
+
+    items.extend(
+        TYPES
+            .iter()
+            .map(|ty| CompletionItem::new(CompletionKind::Type, ty, "")),
+    );
+
+    for size in 2..=4 {
+        for suffix in ["f", "h", "i", "u"] {
+            let alias = format!("vec{}{}", size, suffix);
+
+            items.push(CompletionItem::new(CompletionKind::Type, &alias, ""));
+        }
+    }
+
+    for columns in 2..=4 {
+        for rows in 2..=4 {
+            for suffix in ["f", "h"] {
+                let alias = format!("mat{}x{}{}", columns, rows, suffix);
+
+                items.push(CompletionItem::new(CompletionKind::Type, &alias, ""));
+            }
+        }
+    }
+
+    items
+}
+
+// The function whose signature or body ends the tokens, if any.
+pub fn enclosing_function<'a>(source: &'a str, tokens: &[Token]) -> Option<&'a str> {
+    let mut function = None;
+    let mut previous: Option<&str> = None;
+    let mut brace_depth = 0;
+
+    for token in tokens.iter().filter(|token| !token.is_trivia()) {
+        let text = token.text(source);
+
+        match text {
+            "{" => brace_depth += 1,
+            "}" => {
+                brace_depth -= 1;
+
+                if brace_depth <= 0 {
+                    brace_depth = 0;
+                    function = None;
+                }
+            }
+            _ if previous == Some("fn") && token.kind == TokenKind::Ident => {
+                function = Some(text);
+            }
+            _ => {}
+        }
+
+        previous = Some(text);
+    }
+
+    function
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{complete, CompletionItem, CompletionKind, Symbols};
+    use crate::wgsl::Tokenizer;
+
+    const CURSOR: char = '$';
+
+    // The labels completed at the `$` in `source`, whose symbols come from
+    // `source` without it, when it parses.
+    fn labels(source: &str, texture_count: usize, explicit: bool) -> Option<Vec<String>> {
+        let cursor = source.find(CURSOR).unwrap();
+        let source = source.replace(CURSOR, "");
+        let symbols = Symbols::parse(&source, texture_count).unwrap_or_default();
+        let mut tokenizer = Tokenizer::default();
+        let tokens = tokenizer.update(&source);
+
+        complete(&source, tokens, cursor, &symbols, texture_count, explicit).map(|completions| {
+            assert_eq!(completions.range.end, cursor);
+
+            completions
+                .items
+                .into_iter()
+                .map(|item| item.label)
+                .collect()
+        })
+    }
+
+    fn body(statements: &str) -> String {
+        format!(
+            "fn main_image(frag_color: vec4<f32>, frag_coord: vec2<f32>) -> vec4<f32> {{\n{}\n}}\n",
+            statements
+        )
+    }
+
+    #[test]
+    fn members() {
+        let fields = labels(&body("return u.$"), 0, false).unwrap();
+        assert_eq!(
+            fields,
+            [
+                "cursor",
+                "mouse_down",
+                "mouse_press",
+                "mouse_release",
+                "resolution",
+                "time"
+            ]
+        );
+
+        let fields = labels(&body("return vec4(u.ti$me);"), 0, false).unwrap();
+        assert_eq!(fields[0], "time");
+        assert!(!fields.contains(&"cursor".to_owned()));
+
+        let source = format!(
+            "struct Ray {{ origin: vec3<f32>, direction: vec3<f32> }}\n{}",
+            body("let ray = Ray(vec3(0.0), vec3(1.0));\nreturn vec4(ray.di$rection, 1.0);")
+        );
+        assert_eq!(labels(&source, 0, false).unwrap()[0], "direction");
+
+        // `1.` is a number, not a member access.
+        assert_eq!(labels(&body("return 1.$"), 0, false), None);
+    }
+
+    #[test]
+    fn triggers() {
+        let source = body("$return frag_color;");
+
+        assert_eq!(labels(&source, 0, false), None);
+        assert!(labels(&source, 0, true)
+            .unwrap()
+            .contains(&"frag_color".to_owned()));
+
+        // Typing completes without asking.
+        let typed = labels(&body("return frag_$color;"), 0, false).unwrap();
+        assert_eq!(typed[0], "frag_color");
+
+        // Never in comments or numbers.
+        assert_eq!(labels(&body("// fra$\nreturn frag_color;"), 0, true), None);
+        assert_eq!(
+            labels(&body("/* fra$ */\nreturn frag_color;"), 0, true),
+            None
+        );
+        assert_eq!(labels(&body("return vec4(1$.0);"), 0, true), None);
+    }
+
+    #[test]
+    fn textures() {
+        let source = body("return textureSample(tex$ture0, sampler0, vec2(0.0));");
+
+        let none = labels(&source, 0, false).unwrap_or_default();
+        assert!(!none.contains(&"texture0".to_owned()));
+
+        let two = labels(&source, 2, false).unwrap();
+        assert!(two.contains(&"texture0".to_owned()));
+        assert!(two.contains(&"texture1".to_owned()));
+        assert!(!two.contains(&"texture2".to_owned()));
+
+        let samplers = labels(
+            &body("return image(texture0, sam$pler0, vec2(0.0));"),
+            1,
+            false,
+        );
+        let samplers = samplers.unwrap();
+        assert!(samplers.contains(&"sampler0".to_owned()));
+        assert!(!samplers.contains(&"sampler1".to_owned()));
+    }
+
+    #[test]
+    fn insert_text() {
+        let cases: &[(&str, &[&str], &str, Option<&str>)] = &[
+            (
+                "clamp",
+                &["e", "low", "high"],
+                "clamp(e, low, high)",
+                Some("e"),
+            ),
+            ("image", &["t", "spl", "uv"], "image(t, spl, uv)", Some("t")),
+            ("f", &[], "f()", None),
+        ];
+
+        for (label, parameters, text, selected) in cases {
+            let parameters = parameters.iter().map(|p| p.to_string()).collect();
+            let item = CompletionItem::function(label, "", parameters);
+            let (inserted, selection) = item.insert_text();
+
+            assert_eq!(inserted, *text);
+            assert_eq!(selection.map(|range| &inserted[range]), *selected);
+        }
+
+        let item = CompletionItem::new(CompletionKind::Variable, "u", "Uniform");
+        assert_eq!(item.insert_text(), ("u".to_owned(), None));
+    }
+}
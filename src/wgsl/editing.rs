@@ -0,0 +1,600 @@
+use super::formatter::format;
+use std::ops::Range;
+
+const PAIRS: [(char, char); 4] = [('"', '"'), ('(', ')'), ('[', ']'), ('{', '}')];
+
+// Byte offsets into the text, the selection goes from `anchor` to `cursor`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Selection {
+    pub anchor: usize,
+    pub cursor: usize,
+}
+
+impl Selection {
+    pub fn caret(offset: usize) -> Self {
+        Self {
+            anchor: offset,
+            cursor: offset,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.cursor
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.cursor)..self.anchor.max(self.cursor)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Edit {
+    pub text: String,
+    pub selection: Selection,
+}
+
+// Typing an opening bracket or a quote inserts the closing one too, or wraps
+// the selection. Typing a closing one right before the same one steps over it.
+pub fn auto_close(text: &str, selection: Selection, c: char) -> Option<Edit> {
+    let range = selection.range();
+    let next = text[range.end..].chars().next();
+
+    if selection.is_empty() && next == Some(c) && PAIRS.iter().any(|&(_, close)| close == c) {
+        return Some(Edit {
+            text: text.to_owned(),
+            selection: Selection::caret(range.end + c.len_utf8()),
+        });
+    }
+
+    let &(open, close) = PAIRS.iter().find(|&&(open, _)| open == c)?;
+
+    if !selection.is_empty() {
+        let wrapped = format!("{}{}{}", open, &text[range.clone()], close);
+        let shift = open.len_utf8();
+
+        return Some(Edit {
+            text: replace(text, range, &wrapped),
+            selection: Selection {
+                anchor: selection.anchor + shift,
+                cursor: selection.cursor + shift,
+            },
+        });
+    }
+
+    // Only where the pair can't be meant to wrap what follows.
+    let before_closing =
+        next.is_none_or(|next| next.is_whitespace() || matches!(next, ')' | ']' | '}' | ',' | ';'));
+    let after_word = text[..range.start]
+        .chars()
+        .next_back()
+        .is_some_and(|previous| previous.is_alphanumeric() || previous == '_');
+
+    if !before_closing || (open == '"' && after_word) {
+        return None;
+    }
+
+    Some(Edit {
+        text: replace(text, range.clone(), &format!("{}{}", open, close)),
+        selection: Selection::caret(range.start + open.len_utf8()),
+    })
+}
+
+// Backspace between an empty pair deletes both.
+pub fn delete_pair(text: &str, selection: Selection) -> Option<Edit> {
+    if !selection.is_empty() {
+        return None;
+    }
+
+    let offset = selection.cursor;
+    let previous = text[..offset].chars().next_back()?;
+    let next = text[offset..].chars().next()?;
+
+    if !PAIRS.contains(&(previous, next)) {
+        return None;
+    }
+
+    let start = offset - previous.len_utf8();
+
+    Some(Edit {
+        text: replace(text, start..offset + next.len_utf8(), ""),
+        selection: Selection::caret(start),
+    })
+}
+
+// Typing `}` first on a line takes away one level of indentation.
+pub fn dedent_closing(text: &str, selection: Selection, c: char, indent: &str) -> Option<Edit> {
+    let range = selection.range();
+    let start = line_start(text, range.start);
+    let before = &text[start..range.start];
+
+    if c != '}' || !before.chars().all(|c| c == ' ' || c == '\t') || before.is_empty() {
+        return None;
+    }
+
+    let indentation = outdent(before, indent);
+    let inserted = format!("{}}}", indentation);
+
+    Some(Edit {
+        text: replace(text, start..range.end, &inserted),
+        selection: Selection::caret(start + inserted.len()),
+    })
+}
+
+// Deletes the lines of the selection.
+pub fn delete_lines(text: &str, selection: Selection) -> Edit {
+    let lines = line_range(text, selection);
+
+    // With the line break after them, or the one before the last line.
+    let removed = if lines.end < text.len() {
+        lines.start..lines.end + 1
+    } else {
+        lines.start.saturating_sub(1)..lines.end
+    };
+
+    Edit {
+        text: replace(text, removed.clone(), ""),
+        selection: Selection::caret(removed.start),
+    }
+}
+
+// Inserts a copy of the lines of the selection after them, selecting the copy.
+pub fn duplicate_lines(text: &str, selection: Selection) -> Edit {
+    let lines = line_range(text, selection);
+    let copy = format!("\n{}", &text[lines.clone()]);
+
+    Edit {
+        text: replace(text, lines.end..lines.end, &copy),
+        selection: Selection {
+            anchor: selection.anchor + copy.len(),
+            cursor: selection.cursor + copy.len(),
+        },
+    }
+}
+
+// Formats the whole text, keeping the selection on the same characters,
+// counting all but whitespace.
+pub fn format_document(text: &str, selection: Selection, indent: &str) -> Option<Edit> {
+    let formatted = format(text, indent)?;
+
+    let map = |offset: usize| {
+        let count = text[..offset]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .count();
+
+        formatted
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .nth(count)
+            .map_or(formatted.len(), |(index, _)| index)
+    };
+
+    Some(Edit {
+        selection: Selection {
+            anchor: map(selection.anchor),
+            cursor: map(selection.cursor),
+        },
+        text: formatted,
+    })
+}
+
+// Indents the lines of a selection over lines, or inserts spaces up to the
+// next indentation stop.
+pub fn indent_lines(text: &str, selection: Selection, indent: &str) -> Edit {
+    let range = selection.range();
+
+    if !text[range.clone()].contains('\n') {
+        let column = text[line_start(text, range.start)..range.start]
+            .chars()
+            .count();
+        let spaces = " ".repeat(indent.len() - column % indent.len().max(1));
+
+        return Edit {
+            text: replace(text, range.clone(), &spaces),
+            selection: Selection::caret(range.start + spaces.len()),
+        };
+    }
+
+    map_lines(text, selection, |line| {
+        if line.trim().is_empty() {
+            line.to_owned()
+        } else {
+            format!("{}{}", indent, line)
+        }
+    })
+}
+
+// Swaps the lines of the selection with the line above or below them.
+pub fn move_lines(text: &str, selection: Selection, up: bool) -> Option<Edit> {
+    let lines = line_range(text, selection);
+    let block = &text[lines.clone()];
+
+    let (whole, swapped, shift) = if up {
+        if lines.start == 0 {
+            return None;
+        }
+
+        let other = &text[line_start(text, lines.start - 1)..lines.start - 1];
+
+        (
+            lines.start - other.len() - 1..lines.end,
+            format!("{}\n{}", block, other),
+            -(other.len() as isize + 1),
+        )
+    } else {
+        if lines.end == text.len() {
+            return None;
+        }
+
+        let other = &text[lines.end + 1..line_end(text, lines.end + 1)];
+
+        (
+            lines.start..lines.end + other.len() + 1,
+            format!("{}\n{}", other, block),
+            other.len() as isize + 1,
+        )
+    };
+
+    Some(Edit {
+        text: replace(text, whole, &swapped),
+        selection: Selection {
+            anchor: (selection.anchor as isize + shift) as usize,
+            cursor: (selection.cursor as isize + shift) as usize,
+        },
+    })
+}
+
+// Enter keeps the indentation of the line, one more level after an opening
+// bracket, and puts a closing bracket right after the cursor on a line of its own.
+pub fn newline(text: &str, selection: Selection, indent: &str) -> Edit {
+    let range = selection.range();
+    let start = line_start(text, range.start);
+
+    let indentation: String = text[start..range.start]
+        .chars()
+        .take_while(|&c| c == ' ' || c == '\t')
+        .collect();
+
+    let previous = text[..range.start]
+        .trim_end_matches([' ', '\t'])
+        .chars()
+        .next_back();
+    let next = text[range.end..].chars().next();
+
+    let opening = PAIRS
+        .iter()
+        .find(|&&(open, _)| open != '"' && Some(open) == previous);
+
+    let (inserted, caret) = match opening {
+        Some(&(_, close)) => {
+            let inner = format!("\n{}{}", indentation, indent);
+
+            if next == Some(close) {
+                (format!("{}\n{}", inner, indentation), inner.len())
+            } else {
+                (inner.clone(), inner.len())
+            }
+        }
+        None => {
+            let inserted = format!("\n{}", indentation);
+            let caret = inserted.len();
+
+            (inserted, caret)
+        }
+    };
+
+    Edit {
+        text: replace(text, range.clone(), &inserted),
+        selection: Selection::caret(range.start + caret),
+    }
+}
+
+// Takes away one level of indentation from the lines of the selection.
+pub fn outdent_lines(text: &str, selection: Selection, indent: &str) -> Edit {
+    map_lines(text, selection, |line| {
+        let indentation = line.len() - line.trim_start_matches([' ', '\t']).len();
+
+        format!(
+            "{}{}",
+            outdent(&line[..indentation], indent),
+            &line[indentation..]
+        )
+    })
+}
+
+// Wraps the selection, or the current line, in a block comment, or unwraps it.
+pub fn toggle_block_comment(text: &str, selection: Selection) -> Edit {
+    let mut range = selection.range();
+
+    if range.is_empty() {
+        let line = line_range(text, selection);
+        let content = &text[line.clone()];
+        let indentation = content.len() - content.trim_start().len();
+
+        range = line.start + indentation..line.start + content.trim_end().len();
+    }
+
+    let selected = &text[range.clone()];
+
+    let (replacement, inner) = match selected
+        .strip_prefix("/*")
+        .and_then(|rest| rest.strip_suffix("*/"))
+    {
+        Some(inner) => {
+            let inner = inner.strip_prefix(' ').unwrap_or(inner);
+            let inner = inner.strip_suffix(' ').unwrap_or(inner);
+
+            (inner.to_owned(), 0..inner.len())
+        }
+        None => (format!("/* {} */", selected), 3..3 + selected.len()),
+    };
+
+    Edit {
+        text: replace(text, range.clone(), &replacement),
+        selection: Selection {
+            anchor: range.start + inner.start,
+            cursor: range.start + inner.end,
+        },
+    }
+}
+
+// Comments out the lines of the selection, or uncomments them when they all are.
+pub fn toggle_line_comment(text: &str, selection: Selection) -> Edit {
+    let lines = line_range(text, selection);
+
+    let contents = text[lines].lines().filter(|line| !line.trim().is_empty());
+    let commented = contents
+        .clone()
+        .all(|line| line.trim_start().starts_with("//"));
+    let column = contents
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    map_lines(text, selection, |line| {
+        if line.trim().is_empty() {
+            return line.to_owned();
+        }
+
+        if commented {
+            let indentation = line.len() - line.trim_start().len();
+            let rest = &line[indentation + 2..];
+
+            format!(
+                "{}{}",
+                &line[..indentation],
+                rest.strip_prefix(' ').unwrap_or(rest)
+            )
+        } else {
+            format!("{}// {}", &line[..column], &line[column..])
+        }
+    })
+}
+
+// The offset after the line break before `offset`.
+fn line_start(text: &str, offset: usize) -> usize {
+    text[..offset].rfind('\n').map_or(0, |index| index + 1)
+}
+
+// The offset of the line break after `offset`, or the end of the text.
+fn line_end(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .find('\n')
+        .map_or(text.len(), |index| offset + index)
+}
+
+// The lines of a selection, without the last line break. A selection ending
+// at the start of a line doesn't include that line.
+fn line_range(text: &str, selection: Selection) -> Range<usize> {
+    let range = selection.range();
+    let start = line_start(text, range.start);
+
+    let end = if range.end > start && text[..range.end].ends_with('\n') {
+        range.end - 1
+    } else {
+        range.end
+    };
+
+    start..line_end(text, end)
+}
+
+// Replaces each line of the selection, keeping the selection on the same
+// characters where it can.
+fn map_lines(text: &str, selection: Selection, f: impl Fn(&str) -> String) -> Edit {
+    let lines = line_range(text, selection);
+
+    let mut replaced = String::new();
+    let mut positions = [selection.anchor, selection.cursor];
+
+    let mut offset = lines.start;
+
+    for (index, line) in text[lines.clone()].split('\n').enumerate() {
+        if index > 0 {
+            replaced.push('\n');
+        }
+
+        let new_line = f(line);
+        let new_start = lines.start + replaced.len();
+        let delta = new_line.len() as isize - line.len() as isize;
+
+        for (position, old) in positions
+            .iter_mut()
+            .zip([selection.anchor, selection.cursor])
+        {
+            if (offset..=offset + line.len()).contains(&old) {
+                let column = (old - offset) as isize + delta;
+
+                *position = new_start + column.clamp(0, new_line.len() as isize) as usize;
+            }
+        }
+
+        replaced.push_str(&new_line);
+
+        offset += line.len() + 1;
+    }
+
+    // Positions past the lines, at the end of a selection of whole lines.
+    let delta = replaced.len() as isize - lines.len() as isize;
+
+    for (position, old) in positions
+        .iter_mut()
+        .zip([selection.anchor, selection.cursor])
+    {
+        if old > lines.end {
+            *position = (old as isize + delta) as usize;
+        }
+    }
+
+    let [anchor, cursor] = positions;
+
+    Edit {
+        text: replace(text, lines, &replaced),
+        selection: Selection { anchor, cursor },
+    }
+}
+
+// Takes away one `indent`, or one tab, from the end of an indentation.
+fn outdent<'a>(indentation: &'a str, indent: &str) -> &'a str {
+    if let Some(outdented) = indentation.strip_suffix('\t') {
+        return outdented;
+    }
+
+    let spaces = indentation.len() - indentation.trim_end_matches(' ').len();
+
+    &indentation[..indentation.len() - spaces.min(indent.len())]
+}
+
+fn replace(text: &str, range: Range<usize>, with: &str) -> String {
+    let mut replaced = text.to_owned();
+
+    replaced.replace_range(range, with);
+
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INDENT: &str = "    ";
+
+    // `|` marks the cursor, `[` and `]` the anchor and the cursor of a selection.
+    fn parse(marked: &str) -> (String, Selection) {
+        let text: String = marked.chars().filter(|c| !"|[]".contains(*c)).collect();
+
+        let selection = match marked.find('|') {
+            Some(offset) => Selection::caret(offset),
+            None => {
+                let anchor = marked.find('[').unwrap();
+                let cursor = marked.find(']').unwrap() - 1;
+
+                Selection { anchor, cursor }
+            }
+        };
+
+        (text, selection)
+    }
+
+    fn mark(edit: &Edit) -> String {
+        let mut marked = edit.text.clone();
+        let Selection { anchor, cursor } = edit.selection;
+
+        if anchor == cursor {
+            marked.insert(cursor, '|');
+        } else {
+            marked.insert(cursor, ']');
+            marked.insert(anchor, '[');
+        }
+
+        marked
+    }
+
+    fn run(marked: &str, f: impl Fn(&str, Selection) -> Edit) -> String {
+        let (text, selection) = parse(marked);
+
+        mark(&f(&text, selection))
+    }
+
+    #[test]
+    fn typing() {
+        let close = |c| move |text: &str, selection| auto_close(text, selection, c).unwrap();
+
+        assert_eq!(run("f|", close('(')), "f(|)");
+        assert_eq!(run("f(|)", close(')')), "f()|");
+        assert_eq!(run("[a]", close('(')), "([a])");
+        assert_eq!(auto_close("f|a", Selection::caret(1), '('), None);
+
+        assert_eq!(
+            run("f(|)", |text, selection| delete_pair(text, selection)
+                .unwrap()),
+            "f|"
+        );
+
+        assert_eq!(
+            run("fn f() {|}", |text, selection| newline(
+                text, selection, INDENT
+            )),
+            "fn f() {\n    |\n}"
+        );
+        assert_eq!(
+            run("    let a = 1;|", |text, selection| newline(
+                text, selection, INDENT
+            )),
+            "    let a = 1;\n    |"
+        );
+        assert_eq!(
+            run("{\n        |", |text, selection| {
+                dedent_closing(text, selection, '}', INDENT).unwrap()
+            }),
+            "{\n    }|"
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let source = "a\n  [b\nc]\nd";
+
+        assert_eq!(run(source, toggle_line_comment), "a\n//   [b\n// c]\nd");
+        assert_eq!(run("  // [b\n  // c]", toggle_line_comment), "  [b\n  c]");
+        assert_eq!(
+            run(source, |text, selection| indent_lines(
+                text, selection, INDENT
+            )),
+            "a\n      [b\n    c]\nd"
+        );
+        assert_eq!(
+            run(source, |text, selection| outdent_lines(
+                text, selection, INDENT
+            )),
+            "a\n[b\nc]\nd"
+        );
+        assert_eq!(
+            run("a|b", |text, selection| indent_lines(
+                text, selection, INDENT
+            )),
+            "a   |b"
+        );
+
+        assert_eq!(run("a\nb|\nc", duplicate_lines), "a\nb\nb|\nc");
+        assert_eq!(run("a\nb|\nc", delete_lines), "a\n|c");
+        assert_eq!(run("a\nb|", delete_lines), "a|");
+        assert_eq!(
+            run("a\nb|\nc", |text, selection| move_lines(
+                text, selection, true
+            )
+            .unwrap()),
+            "b|\na\nc"
+        );
+        assert_eq!(
+            run("a\nb|\nc", |text, selection| move_lines(
+                text, selection, false
+            )
+            .unwrap()),
+            "a\nc\nb|"
+        );
+        assert_eq!(move_lines("a", Selection::caret(0), true), None);
+
+        assert_eq!(run("  a|", toggle_block_comment), "  /* [a] */");
+        assert_eq!(run("[/* a */]", toggle_block_comment), "[a]");
+    }
+}
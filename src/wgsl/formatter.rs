@@ -0,0 +1,360 @@
+use super::lexer::{Token, TokenKind, Tokenizer};
+
+// What goes between two tokens.
+#[derive(Clone, Copy, PartialEq)]
+enum Break {
+    BlankLine,
+    Newline,
+    None,
+    Space,
+}
+
+// The brackets the formatter is within.
+#[derive(Clone, Copy, PartialEq)]
+enum Group {
+    // The arguments of an attribute, e.g. `@location(0)`.
+    Attribute,
+    Block,
+    Index,
+    Paren,
+    // Members are one per line.
+    Struct,
+    Template,
+}
+
+struct Formatter<'a> {
+    source: &'a str,
+    indent: &'a str,
+    output: String,
+    groups: Vec<Group>,
+    // The last token written, whether it was a unary operator and whether it
+    // ended an attribute.
+    previous: Option<(TokenKind, &'a str)>,
+    previous_unary: bool,
+    after_attribute: bool,
+    // Between `struct` and the opening brace of its members.
+    in_struct_header: bool,
+}
+
+// Reformats WGSL source in the canonical style: blocks indented by `indent`,
+// one statement per line, spaces around binary operators and after commas,
+// and function attributes on a line of their own. Comments are kept, as are
+// single blank lines between statements.
+//
+// Returns nothing when the brackets don't balance, e.g. in the middle of an edit.
+pub fn format(source: &str, indent: &str) -> Option<String> {
+    let tokens = Tokenizer::default().update(source).to_vec();
+
+    let mut formatter = Formatter {
+        source,
+        indent,
+        output: String::with_capacity(source.len()),
+        groups: vec![],
+        previous: None,
+        previous_unary: false,
+        after_attribute: false,
+        in_struct_header: false,
+    };
+
+    let mut newlines = 0;
+
+    for token in tokens {
+        if token.kind == TokenKind::Whitespace {
+            newlines += token.text(source).matches('\n').count();
+        } else {
+            formatter.write(token, newlines)?;
+
+            newlines = 0;
+        }
+    }
+
+    if !formatter.groups.is_empty() {
+        return None;
+    }
+
+    let mut output = formatter.output;
+
+    if source.ends_with('\n') && !output.is_empty() {
+        output.push('\n');
+    }
+
+    Some(output)
+}
+
+impl<'a> Formatter<'a> {
+    fn write(&mut self, token: Token, newlines: usize) -> Option<()> {
+        let kind = token.kind;
+        let text = match kind {
+            TokenKind::LineComment => token.text(self.source).trim_end(),
+            _ => token.text(self.source),
+        };
+
+        let closed = if matches!(text, ")" | "]" | "}") || kind == TokenKind::TemplateEnd {
+            let group = self.groups.pop()?;
+
+            let matches = match text {
+                ")" => matches!(group, Group::Attribute | Group::Paren),
+                "]" => group == Group::Index,
+                "}" => matches!(group, Group::Block | Group::Struct),
+                _ => group == Group::Template,
+            };
+
+            if !matches {
+                return None;
+            }
+
+            Some(group)
+        } else {
+            None
+        };
+
+        let unary = self.is_unary(kind, text);
+
+        let separator = self.separator(kind, text, newlines);
+
+        self.write_break(separator);
+        self.output.push_str(text);
+
+        match text {
+            "(" if self.previous.map(|(kind, _)| kind) == Some(TokenKind::Attribute) => {
+                self.groups.push(Group::Attribute)
+            }
+            "(" => self.groups.push(Group::Paren),
+            "[" => self.groups.push(Group::Index),
+            "{" if self.in_struct_header => self.groups.push(Group::Struct),
+            "{" => self.groups.push(Group::Block),
+            _ if kind == TokenKind::TemplateStart => self.groups.push(Group::Template),
+            _ => {}
+        }
+
+        if kind == TokenKind::Keyword && text == "struct" {
+            self.in_struct_header = true;
+        } else if matches!(text, "{" | ";") {
+            self.in_struct_header = false;
+        }
+
+        if !kind_is_comment(kind) {
+            self.after_attribute = kind == TokenKind::Attribute || closed == Some(Group::Attribute);
+        }
+
+        self.previous = Some((kind, text));
+        self.previous_unary = unary;
+
+        Some(())
+    }
+
+    fn is_unary(&self, kind: TokenKind, text: &str) -> bool {
+        if kind != TokenKind::Operator {
+            return false;
+        }
+
+        match text {
+            "!" | "~" => true,
+            "-" | "&" | "*" => match self.previous {
+                Some((TokenKind::Keyword, previous)) => !matches!(previous, "false" | "true"),
+                Some((TokenKind::Punctuation, previous)) => !matches!(previous, ")" | "]"),
+                Some((TokenKind::Attribute | TokenKind::Operator, _)) | None => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn separator(&self, kind: TokenKind, text: &str, newlines: usize) -> Break {
+        let Some((previous_kind, previous)) = self.previous else {
+            return Break::None;
+        };
+
+        let line_break = if newlines > 1 {
+            Break::BlankLine
+        } else {
+            Break::Newline
+        };
+
+        // Comments stay at the end of their line, or on a line of their own.
+        if previous_kind == TokenKind::LineComment
+            || ((kind_is_comment(kind) || previous_kind == TokenKind::BlockComment) && newlines > 0)
+        {
+            return if previous == "{" || text == "}" {
+                Break::Newline
+            } else {
+                line_break
+            };
+        }
+
+        if kind_is_comment(kind) || previous_kind == TokenKind::BlockComment {
+            return Break::Space;
+        }
+
+        if text == "}" {
+            return if previous == "{" {
+                Break::None
+            } else {
+                Break::Newline
+            };
+        }
+
+        match previous {
+            "{" => return Break::Newline,
+            "}" => {
+                return match text {
+                    "else" => Break::Space,
+                    ")" | "," | ";" => Break::None,
+                    // Between declarations.
+                    _ if self.depth() == 0 => Break::BlankLine,
+                    _ => line_break,
+                };
+            }
+            // Not between the clauses of a `for`.
+            ";" if self.in_block() => return line_break,
+            "," if self.groups.last() == Some(&Group::Struct) => return line_break,
+            _ => {}
+        }
+
+        if self.after_attribute && text != "(" {
+            return if text == "fn" {
+                Break::Newline
+            } else {
+                Break::Space
+            };
+        }
+
+        if matches!(text, ")" | "," | "." | ":" | ";" | "]" | "++" | "--")
+            || kind == TokenKind::TemplateEnd
+        {
+            return Break::None;
+        }
+
+        // Keeps operators from running together, as in `- -a`.
+        if self.previous_unary {
+            return if kind == TokenKind::Operator {
+                Break::Space
+            } else {
+                Break::None
+            };
+        }
+
+        if matches!(previous, "(" | "." | "[") || previous_kind == TokenKind::TemplateStart {
+            return Break::None;
+        }
+
+        let follows_operand = matches!(
+            previous_kind,
+            TokenKind::Ident | TokenKind::Reserved | TokenKind::TemplateEnd | TokenKind::Type
+        ) || matches!(previous, ")" | "]");
+
+        match text {
+            // Calls and attribute arguments, but not `if (`.
+            "(" if follows_operand || previous_kind == TokenKind::Attribute => Break::None,
+            "[" if follows_operand => Break::None,
+            _ if kind == TokenKind::TemplateStart => Break::None,
+            _ => Break::Space,
+        }
+    }
+
+    fn write_break(&mut self, separator: Break) {
+        match separator {
+            Break::BlankLine | Break::Newline => {
+                let trimmed = self.output.trim_end_matches(' ').len();
+                self.output.truncate(trimmed);
+
+                if separator == Break::BlankLine {
+                    self.output.push('\n');
+                }
+
+                self.output.push('\n');
+
+                for _ in 0..self.depth() {
+                    self.output.push_str(self.indent);
+                }
+            }
+            Break::None => {}
+            Break::Space => self.output.push(' '),
+        }
+    }
+
+    // How many blocks deep the formatter is.
+    fn depth(&self) -> usize {
+        self.groups
+            .iter()
+            .filter(|group| matches!(group, Group::Block | Group::Struct))
+            .count()
+    }
+
+    fn in_block(&self) -> bool {
+        matches!(
+            self.groups.last(),
+            None | Some(Group::Block) | Some(Group::Struct)
+        )
+    }
+}
+
+fn kind_is_comment(kind: TokenKind) -> bool {
+    matches!(kind, TokenKind::BlockComment | TokenKind::LineComment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format;
+    use crate::{
+        fs::load_wgs_from_file,
+        wgsl::{TokenKind, Tokenizer},
+    };
+    use std::{fs::read_dir, path::Path};
+
+    const INDENT: &str = "    ";
+
+    fn significant(source: &str) -> Vec<String> {
+        Tokenizer::default()
+            .update(source)
+            .iter()
+            .filter(|token| token.kind != TokenKind::Whitespace)
+            .map(|token| token.text(source).trim_end().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn style() {
+        let source = "// Scale.\nconst  SCALE=2.0 ;\nstruct Light{position:vec3<f32>,\n\
+            color : vec3f}\n@fragment fn main( @location(0) uv:vec2<f32> )->@location(0) vec4<f32>{\n\
+            \tvar c=vec3( -uv.x,uv.y*SCALE , 0.0);// tint\n\n\n\
+            for(var i=0;i<4;i++){c+=-c[i]*0.5;}\n\
+            if c.x>0.0{return vec4(c,1.0);}else{discard;}\n\
+            /* unreachable */ return vec4(0.0);}\n";
+
+        let formatted = "// Scale.\nconst SCALE = 2.0;\nstruct Light {\n    position: vec3<f32>,\n    \
+            color: vec3f\n}\n\n@fragment\nfn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {\n    \
+            var c = vec3(-uv.x, uv.y * SCALE, 0.0); // tint\n\n    \
+            for (var i = 0; i < 4; i++) {\n        c += -c[i] * 0.5;\n    }\n    \
+            if c.x > 0.0 {\n        return vec4(c, 1.0);\n    } else {\n        discard;\n    }\n    \
+            /* unreachable */ return vec4(0.0);\n}\n";
+
+        assert_eq!(format(source, INDENT).as_deref(), Some(formatted));
+
+        // Unbalanced.
+        assert_eq!(format("fn f() {", INDENT), None);
+        assert_eq!(format("fn f(]", INDENT), None);
+    }
+
+    // Formatting the bundled examples keeps their tokens and is stable.
+    #[test]
+    fn examples() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+
+        for entry in read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let (wgs, _) = load_wgs_from_file(&path).unwrap();
+            let source = wgs.frag();
+
+            let formatted = format(&source, INDENT).unwrap();
+
+            assert_eq!(significant(&formatted), significant(&source), "{:?}", path);
+            assert_eq!(
+                format(&formatted, INDENT),
+                Some(formatted.clone()),
+                "{:?}",
+                path
+            );
+        }
+    }
+}
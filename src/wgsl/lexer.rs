@@ -0,0 +1,1007 @@
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    // `@` followed by a name, e.g. `@fragment`.
+    Attribute,
+    BlockComment,
+    Float,
+    Ident,
+    Int,
+    Keyword,
+    LineComment,
+    Operator,
+    Punctuation,
+    // Words the spec reserves for future use.
+    Reserved,
+    // The `>` closing a template list, e.g. in `vec4<f32>`.
+    TemplateEnd,
+    // The `<` opening a template list.
+    TemplateStart,
+    // Predeclared type names, e.g. `f32`, `vec4f` or `texture_2d`.
+    Type,
+    Unknown,
+    Whitespace,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Token {
+    pub fn is_trivia(&self) -> bool {
+        matches!(
+            self.kind,
+            TokenKind::BlockComment | TokenKind::LineComment | TokenKind::Whitespace
+        )
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.range()]
+    }
+}
+
+// Operators, longest first so that they are matched greedily.
+const OPERATORS: [&str; 34] = [
+    "<<=", ">>=", "&&", "||", "==", "!=", "<=", ">=", "<<", ">>", "->", "++", "--", "+=", "-=",
+    "*=", "/=", "%=", "&=", "|=", "^=", "+", "-", "*", "/", "%", "&", "|", "^", "~", "!", "<", ">",
+    "=",
+];
+
+// How far past its end the lexer may look to decide on a token, as in `0x.8`.
+const LOOKAHEAD: usize = 3;
+
+const PUNCTUATIONS: [char; 10] = ['(', ')', '[', ']', '{', '}', ',', ';', ':', '.'];
+
+// Splits WGSL source into tokens covering every byte of the input, trivia
+// (whitespace and comments) included.
+//
+// The tokens of the last source are kept, so that after an edit only the
+// tokens from the changed region up to the first unchanged token boundary
+// are lexed again.
+#[derive(Default)]
+pub struct Tokenizer {
+    source: String,
+    // Tokens before template discovery, which is not local to the edit.
+    lexed: Vec<Token>,
+    tokens: Vec<Token>,
+}
+
+impl Tokenizer {
+    pub fn update(&mut self, source: &str) -> &[Token] {
+        if source == self.source {
+            return &self.tokens;
+        }
+
+        let prefix = common_prefix_len(&self.source, source);
+        let suffix = common_suffix_len(&self.source[prefix..], &source[prefix..]);
+        let edit_end = source.len() - suffix;
+
+        // Tokens ending shortly before the edit may lex differently, e.g. `1e`
+        // becomes a float when typing `+5`, so lexing restarts at the first
+        // one within lookahead distance.
+        let first = self
+            .lexed
+            .partition_point(|token| token.end + LOOKAHEAD < prefix);
+        let mut start = self.lexed.get(first).map_or(0, |token| token.start);
+
+        let mut old = self.lexed.split_off(first).into_iter().peekable();
+
+        while start < source.len() {
+            // Past the edit the text is unchanged, so once a new token starts
+            // where an old one did, all the following tokens are the same.
+            if start >= edit_end {
+                let old_start = start + self.source.len() - source.len();
+
+                while old.next_if(|token| token.start < old_start).is_some() {}
+
+                if old.peek().is_some_and(|token| token.start == old_start) {
+                    self.lexed.extend(old.map(|token| Token {
+                        kind: token.kind,
+                        start: token.start + source.len() - self.source.len(),
+                        end: token.end + source.len() - self.source.len(),
+                    }));
+
+                    break;
+                }
+            }
+
+            start = lex_one(source, start, &mut self.lexed);
+        }
+
+        self.source = source.to_owned();
+        self.tokens = self.lexed.clone();
+        disambiguate_templates(source, &mut self.tokens);
+
+        &self.tokens
+    }
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((index, _), _)| index)
+}
+
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((index, c), _)| {
+            a.len() - index - c.len_utf8()
+        })
+}
+
+// Pushes the token at `start` and returns where the next one begins.
+fn lex_one(source: &str, start: usize, tokens: &mut Vec<Token>) -> usize {
+    let (kind, len) = next_token(&source[start..]);
+
+    tokens.push(Token {
+        kind,
+        start,
+        end: start + len,
+    });
+
+    start + len
+}
+
+// The kind and length of the token `text`, which isn't empty, starts with.
+pub fn next_token(text: &str) -> (TokenKind, usize) {
+    let bytes = text.as_bytes();
+    let first = text.chars().next().unwrap();
+
+    if first.is_whitespace() {
+        let len = text
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(text.len());
+
+        return (TokenKind::Whitespace, len);
+    }
+
+    if text.starts_with("//") {
+        let len = text.find('\n').unwrap_or(text.len());
+
+        return (TokenKind::LineComment, len);
+    }
+
+    if text.starts_with("/*") {
+        return (TokenKind::BlockComment, block_comment_len(bytes));
+    }
+
+    if first.is_ascii_digit() || (first == '.' && bytes.get(1).is_some_and(u8::is_ascii_digit)) {
+        return number(bytes);
+    }
+
+    if first == '@' {
+        let len = ident_len(&text[1..]);
+
+        return if len > 0 {
+            (TokenKind::Attribute, len + 1)
+        } else {
+            (TokenKind::Punctuation, 1)
+        };
+    }
+
+    let len = ident_len(text);
+    if len > 0 {
+        let word = &text[..len];
+
+        let kind = if is_keyword(word) {
+            TokenKind::Keyword
+        } else if is_type(word) {
+            TokenKind::Type
+        } else if is_reserved(word) {
+            TokenKind::Reserved
+        } else {
+            TokenKind::Ident
+        };
+
+        return (kind, len);
+    }
+
+    if let Some(operator) = OPERATORS.iter().find(|op| text.starts_with(*op)) {
+        return (TokenKind::Operator, operator.len());
+    }
+
+    if PUNCTUATIONS.contains(&first) {
+        return (TokenKind::Punctuation, 1);
+    }
+
+    (TokenKind::Unknown, first.len_utf8())
+}
+
+// Block comments nest, and an unterminated one runs to the end of the input.
+fn block_comment_len(bytes: &[u8]) -> usize {
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index..].starts_with(b"/*") {
+            depth += 1;
+            index += 2;
+        } else if bytes[index..].starts_with(b"*/") {
+            depth -= 1;
+            index += 2;
+
+            if depth == 0 {
+                return index;
+            }
+        } else {
+            index += 1;
+        }
+    }
+
+    bytes.len()
+}
+
+fn ident_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+
+    match chars.next() {
+        Some((_, c)) if c.is_alphabetic() || c == '_' => {}
+        _ => return 0,
+    }
+
+    let len = chars
+        .find(|(_, c)| !c.is_alphanumeric() && *c != '_')
+        .map_or(text.len(), |(index, _)| index);
+
+    // A lone `_` is the placeholder of phony assignments, not an identifier.
+    if &text[..len] == "_" {
+        0
+    } else {
+        len
+    }
+}
+
+// https://www.w3.org/TR/WGSL/#numeric-literals
+fn number(bytes: &[u8]) -> (TokenKind, usize) {
+    let count =
+        |from: usize, pred: fn(&u8) -> bool| bytes[from..].iter().take_while(|b| pred(b)).count();
+
+    let is_hex = bytes.len() > 2
+        && bytes[0] == b'0'
+        && (bytes[1] == b'x' || bytes[1] == b'X')
+        && (bytes[2].is_ascii_hexdigit()
+            || (bytes[2] == b'.' && bytes.get(3).is_some_and(u8::is_ascii_hexdigit)));
+
+    let (digit, exponent): (fn(&u8) -> bool, &[u8]) = if is_hex {
+        (u8::is_ascii_hexdigit, b"pP")
+    } else {
+        (u8::is_ascii_digit, b"eE")
+    };
+
+    let mut index = if is_hex { 2 } else { 0 };
+    let mut is_float = false;
+
+    index += count(index, digit);
+
+    if bytes.get(index) == Some(&b'.') {
+        is_float = true;
+        index += 1;
+        index += count(index, digit);
+    }
+
+    if bytes.get(index).is_some_and(|b| exponent.contains(b)) {
+        let mut exponent_end = index + 1;
+
+        if matches!(bytes.get(exponent_end), Some(b'+') | Some(b'-')) {
+            exponent_end += 1;
+        }
+
+        let exponent_digits = count(exponent_end, u8::is_ascii_digit);
+        if exponent_digits > 0 {
+            is_float = true;
+            index = exponent_end + exponent_digits;
+        }
+    }
+
+    match bytes.get(index) {
+        // Hexadecimal floats only take a suffix after an exponent,
+        // otherwise `f` would be a digit.
+        Some(b'f') | Some(b'h') if !is_hex || is_float => {
+            is_float = true;
+            index += 1;
+        }
+        Some(b'i') | Some(b'u') if !is_float => {
+            index += 1;
+        }
+        _ => {}
+    }
+
+    let kind = if is_float {
+        TokenKind::Float
+    } else {
+        TokenKind::Int
+    };
+
+    (kind, index)
+}
+
+// https://www.w3.org/TR/WGSL/#template-list-discovery
+//
+// A `<` directly after a name opens a template list if a matching `>` follows
+// before the expression ends. Brackets nest, and a `>>` closing a list is split
+// into two tokens.
+fn disambiguate_templates(source: &str, tokens: &mut Vec<Token>) {
+    struct Pending {
+        token_index: usize,
+        nesting_depth: usize,
+    }
+
+    let mut pending: Vec<Pending> = vec![];
+    let mut nesting_depth = 0;
+    let mut last = None;
+
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = tokens[index];
+
+        if token.is_trivia() {
+            index += 1;
+
+            continue;
+        }
+
+        let text = token.text(source);
+
+        let closes_template = pending
+            .last()
+            .is_some_and(|last| last.nesting_depth == nesting_depth);
+
+        match (token.kind, text) {
+            (TokenKind::Operator, "<")
+                if matches!(
+                    last,
+                    Some((TokenKind::Ident, _))
+                        | Some((TokenKind::Type, _))
+                        | Some((TokenKind::Keyword, "var"))
+                ) =>
+            {
+                pending.push(Pending {
+                    token_index: index,
+                    nesting_depth,
+                });
+            }
+            (TokenKind::Operator, ">>") if closes_template => {
+                // Split and look at both halves again.
+                tokens[index].end = token.start + 1;
+                tokens.insert(
+                    index + 1,
+                    Token {
+                        kind: TokenKind::Operator,
+                        start: token.start + 1,
+                        end: token.end,
+                    },
+                );
+
+                continue;
+            }
+            (TokenKind::Operator, ">") if closes_template => {
+                let start = pending.pop().unwrap();
+
+                tokens[start.token_index].kind = TokenKind::TemplateStart;
+                tokens[index].kind = TokenKind::TemplateEnd;
+            }
+            (TokenKind::Punctuation, "(") | (TokenKind::Punctuation, "[") => {
+                nesting_depth += 1;
+            }
+            (TokenKind::Punctuation, ")") | (TokenKind::Punctuation, "]") => {
+                while pending
+                    .last()
+                    .is_some_and(|last| last.nesting_depth == nesting_depth)
+                {
+                    pending.pop();
+                }
+
+                nesting_depth = nesting_depth.saturating_sub(1);
+            }
+            (TokenKind::Punctuation, ";")
+            | (TokenKind::Punctuation, "{")
+            | (TokenKind::Punctuation, "}")
+            | (TokenKind::Punctuation, ":")
+            | (TokenKind::Operator, "=")
+            | (TokenKind::Operator, "&&")
+            | (TokenKind::Operator, "||") => {
+                pending.clear();
+                nesting_depth = 0;
+            }
+            _ => {}
+        }
+
+        last = Some((tokens[index].kind, text));
+        index += 1;
+    }
+}
+
+// https://www.w3.org/TR/WGSL/#keyword-summary
+pub const KEYWORDS: &[&str] = &[
+    "alias",
+    "bitcast",
+    "break",
+    "case",
+    "const",
+    "const_assert",
+    "continue",
+    "continuing",
+    "default",
+    "diagnostic",
+    "discard",
+    "else",
+    "enable",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "let",
+    "loop",
+    "override",
+    "requires",
+    "return",
+    "static_assert",
+    "struct",
+    "switch",
+    "true",
+    "type",
+    "var",
+    "while",
+];
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS.contains(&word)
+}
+
+// https://www.w3.org/TR/WGSL/#predeclared-types
+pub const TYPES: &[&str] = &[
+    "array",
+    "atomic",
+    "bool",
+    "f16",
+    "f32",
+    "i32",
+    "u32",
+    "mat2x2",
+    "mat2x3",
+    "mat2x4",
+    "mat3x2",
+    "mat3x3",
+    "mat3x4",
+    "mat4x2",
+    "mat4x3",
+    "mat4x4",
+    "ptr",
+    "sampler",
+    "sampler_comparison",
+    "texture_1d",
+    "texture_2d",
+    "texture_2d_array",
+    "texture_3d",
+    "texture_cube",
+    "texture_cube_array",
+    "texture_multisampled_2d",
+    "texture_external",
+    "texture_storage_1d",
+    "texture_storage_2d",
+    "texture_storage_2d_array",
+    "texture_storage_3d",
+    "texture_depth_2d",
+    "texture_depth_2d_array",
+    "texture_depth_cube",
+    "texture_depth_cube_array",
+    "texture_depth_multisampled_2d",
+    "vec2",
+    "vec3",
+    "vec4",
+];
+
+fn is_type(word: &str) -> bool {
+    TYPES.contains(&word) || is_type_alias(word)
+}
+
+// Predeclared aliases such as `vec3f`, `vec2i` or `mat4x4h`.
+fn is_type_alias(word: &str) -> bool {
+    let Some(base) = word
+        .strip_suffix('f')
+        .or_else(|| word.strip_suffix('h'))
+        .or_else(|| word.strip_suffix('i'))
+        .or_else(|| word.strip_suffix('u'))
+    else {
+        return false;
+    };
+
+    let is_vector = matches!(base, "vec2" | "vec3" | "vec4");
+    let is_matrix = base.len() == 6
+        && base.starts_with("mat")
+        && matches!(base.as_bytes()[3], b'2'..=b'4')
+        && base.as_bytes()[4] == b'x'
+        && matches!(base.as_bytes()[5], b'2'..=b'4');
+
+    is_vector || (is_matrix && (word.ends_with('f') || word.ends_with('h')))
+}
+
+fn is_reserved(word: &str) -> bool {
+    matches!(
+        word,
+        // https://www.w3.org/TR/WGSL/#reserved-words
+        "NULL"
+            | "Self"
+            | "abstract"
+            | "active"
+            | "alignas"
+            | "alignof"
+            | "as"
+            | "asm"
+            | "asm_fragment"
+            | "async"
+            | "attribute"
+            | "auto"
+            | "await"
+            | "become"
+            | "binding_array"
+            | "cast"
+            | "catch"
+            | "class"
+            | "co_await"
+            | "co_return"
+            | "co_yield"
+            | "coherent"
+            | "column_major"
+            | "common"
+            | "compile"
+            | "compile_fragment"
+            | "concept"
+            | "const_cast"
+            | "consteval"
+            | "constexpr"
+            | "constinit"
+            | "crate"
+            | "debugger"
+            | "decltype"
+            | "delete"
+            | "demote"
+            | "demote_to_helper"
+            | "do"
+            | "dynamic_cast"
+            | "enum"
+            | "explicit"
+            | "export"
+            | "extends"
+            | "extern"
+            | "external"
+            | "fallthrough"
+            | "filter"
+            | "final"
+            | "finally"
+            | "friend"
+            | "from"
+            | "fxgroup"
+            | "get"
+            | "goto"
+            | "groupshared"
+            | "highp"
+            | "impl"
+            | "implements"
+            | "import"
+            | "inline"
+            | "instanceof"
+            | "interface"
+            | "layout"
+            | "lowp"
+            | "macro"
+            | "macro_rules"
+            | "match"
+            | "mediump"
+            | "meta"
+            | "mod"
+            | "module"
+            | "move"
+            | "mut"
+            | "mutable"
+            | "namespace"
+            | "new"
+            | "nil"
+            | "noexcept"
+            | "noinline"
+            | "nointerpolation"
+            | "noperspective"
+            | "null"
+            | "nullptr"
+            | "of"
+            | "operator"
+            | "package"
+            | "packoffset"
+            | "partition"
+            | "pass"
+            | "patch"
+            | "pixelfragment"
+            | "precise"
+            | "precision"
+            | "premerge"
+            | "priv"
+            | "protected"
+            | "pub"
+            | "public"
+            | "readonly"
+            | "ref"
+            | "regardless"
+            | "register"
+            | "reinterpret_cast"
+            | "require"
+            | "resource"
+            | "restrict"
+            | "self"
+            | "set"
+            | "shared"
+            | "sizeof"
+            | "smooth"
+            | "snorm"
+            | "static"
+            | "static_cast"
+            | "std"
+            | "subroutine"
+            | "super"
+            | "target"
+            | "template"
+            | "this"
+            | "thread_local"
+            | "throw"
+            | "trait"
+            | "try"
+            | "typedef"
+            | "typeid"
+            | "typename"
+            | "typeof"
+            | "union"
+            | "unless"
+            | "unorm"
+            | "unsafe"
+            | "unsized"
+            | "use"
+            | "using"
+            | "varying"
+            | "virtual"
+            | "volatile"
+            | "wgsl"
+            | "where"
+            | "with"
+            | "writeonly"
+            | "yield"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Token, TokenKind, TokenKind::*, Tokenizer};
+
+    fn tokenize(source: &str) -> Vec<Token> {
+        Tokenizer::default().update(source).to_vec()
+    }
+
+    fn significant(source: &str) -> Vec<(TokenKind, &str)> {
+        tokenize(source)
+            .into_iter()
+            .filter(|token| token.kind != Whitespace)
+            .map(|token| (token.kind, token.text(source)))
+            .collect()
+    }
+
+    #[test]
+    fn covers_every_byte() {
+        let source = "fn main() -> vec4<f32> { /* a /* b */ c */ return vec4(1.5e-3); } // é";
+        let tokens = tokenize(source);
+
+        let mut end = 0;
+        for token in &tokens {
+            assert_eq!(token.start, end);
+            end = token.end;
+        }
+        assert_eq!(end, source.len());
+    }
+
+    #[test]
+    fn incremental_matches_full() {
+        let base = "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1.0; // tail\n}\n";
+
+        let edits: &[&str] = &[
+            "",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1.0; // tail\n}\n",
+            "fn ff(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1.0; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.xy + 1.05; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1.0; // tail",
+            "/* fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1.0; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* è */ return a.x + 1.0; // tail\n}\n",
+            "fn f(a: vec2<vec2<f32>>) -> f32 {\n    /* é */ return a.x + 1.0; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x < 1.0; // tail\n}\n",
+            "// fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1.0; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é  return a.x + 1.0; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1e; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 1e+5; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 0x.; // tail\n}\n",
+            "fn f(a: vec2<f32>) -> f32 {\n    /* é */ return a.x + 0x.8; // tail\n}\n",
+        ];
+
+        for edit in edits {
+            let mut tokenizer = Tokenizer::default();
+            tokenizer.update(base);
+
+            assert_eq!(tokenizer.update(edit), tokenize(edit), "{}", edit);
+        }
+
+        // Successive edits, each starting from the previous state.
+        let mut tokenizer = Tokenizer::default();
+        for edit in edits {
+            assert_eq!(tokenizer.update(edit), tokenize(edit), "{}", edit);
+        }
+    }
+
+    #[test]
+    fn numeric_literals() {
+        let cases: &[(&str, TokenKind)] = &[
+            ("0", Int),
+            ("123", Int),
+            ("1u", Int),
+            ("42i", Int),
+            ("0x1F", Int),
+            ("0x1Fu", Int),
+            ("0XABCi", Int),
+            ("0.0", Float),
+            ("1.", Float),
+            (".5", Float),
+            ("1.5e-3", Float),
+            ("1e10", Float),
+            ("1E+2f", Float),
+            ("2.0f", Float),
+            ("2f", Float),
+            ("0h", Float),
+            ("1.5h", Float),
+            ("0x1.8p1", Float),
+            ("0x.8p-2f", Float),
+            ("0x1p4h", Float),
+            ("0xA.", Float),
+        ];
+
+        for (source, kind) in cases {
+            assert_eq!(significant(source), vec![(*kind, *source)], "{}", source);
+        }
+    }
+
+    #[test]
+    fn numbers_stop_at_invalid_characters() {
+        let cases: &[(&str, &[(TokenKind, &str)])] = &[
+            ("1.5.x", &[(Float, "1.5"), (Punctuation, "."), (Ident, "x")]),
+            ("1e", &[(Int, "1"), (Ident, "e")]),
+            ("0xfu", &[(Int, "0xfu")]),
+            ("0xf.0f", &[(Float, "0xf.0f")]),
+            ("1ux", &[(Int, "1u"), (Ident, "x")]),
+            ("1.0u", &[(Float, "1.0"), (Ident, "u")]),
+            ("uv.x", &[(Ident, "uv"), (Punctuation, "."), (Ident, "x")]),
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(significant(source), expected.to_vec(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn comments() {
+        let cases: &[(&str, &[(TokenKind, &str)])] = &[
+            ("// line", &[(LineComment, "// line")]),
+            (
+                "a // b\nc",
+                &[(Ident, "a"), (LineComment, "// b"), (Ident, "c")],
+            ),
+            ("/* a */ b", &[(BlockComment, "/* a */"), (Ident, "b")]),
+            (
+                "/* a /* b */ c */ d",
+                &[(BlockComment, "/* a /* b */ c */"), (Ident, "d")],
+            ),
+            ("/* a /* b */", &[(BlockComment, "/* a /* b */")]),
+            ("/* a\nb */x", &[(BlockComment, "/* a\nb */"), (Ident, "x")]),
+            ("a/b", &[(Ident, "a"), (Operator, "/"), (Ident, "b")]),
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(significant(source), expected.to_vec(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn words() {
+        let cases: &[(&str, TokenKind)] = &[
+            ("fn", Keyword),
+            ("let", Keyword),
+            ("const_assert", Keyword),
+            ("true", Keyword),
+            ("f32", Type),
+            ("vec4", Type),
+            ("vec3f", Type),
+            ("vec2i", Type),
+            ("mat4x4f", Type),
+            ("texture_2d", Type),
+            ("sampler", Type),
+            ("mat4x4i", Ident),
+            ("vec5", Ident),
+            ("main_image", Ident),
+            ("_private", Ident),
+            ("x1", Ident),
+            ("résumé", Ident),
+            ("class", Reserved),
+            ("NULL", Reserved),
+        ];
+
+        for (source, kind) in cases {
+            assert_eq!(significant(source), vec![(*kind, *source)], "{}", source);
+        }
+    }
+
+    #[test]
+    fn attributes() {
+        let cases: &[(&str, &[(TokenKind, &str)])] = &[
+            ("@fragment", &[(Attribute, "@fragment")]),
+            (
+                "@group(0)",
+                &[
+                    (Attribute, "@group"),
+                    (Punctuation, "("),
+                    (Int, "0"),
+                    (Punctuation, ")"),
+                ],
+            ),
+            (
+                "@builtin(position)",
+                &[
+                    (Attribute, "@builtin"),
+                    (Punctuation, "("),
+                    (Ident, "position"),
+                    (Punctuation, ")"),
+                ],
+            ),
+            ("@", &[(Punctuation, "@")]),
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(significant(source), expected.to_vec(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let cases: &[(&str, &[(TokenKind, &str)])] = &[
+            ("a<<=b", &[(Ident, "a"), (Operator, "<<="), (Ident, "b")]),
+            ("a->b", &[(Ident, "a"), (Operator, "->"), (Ident, "b")]),
+            ("a&&b", &[(Ident, "a"), (Operator, "&&"), (Ident, "b")]),
+            ("a!=b", &[(Ident, "a"), (Operator, "!="), (Ident, "b")]),
+            ("-1", &[(Operator, "-"), (Int, "1")]),
+            ("i++", &[(Ident, "i"), (Operator, "++")]),
+            ("_ = a", &[(Unknown, "_"), (Operator, "="), (Ident, "a")]),
+            ("a#", &[(Ident, "a"), (Unknown, "#")]),
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(significant(source), expected.to_vec(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn templates() {
+        let cases: &[(&str, &[(TokenKind, &str)])] = &[
+            (
+                "vec4<f32>",
+                &[
+                    (Type, "vec4"),
+                    (TemplateStart, "<"),
+                    (Type, "f32"),
+                    (TemplateEnd, ">"),
+                ],
+            ),
+            (
+                "array<vec2<f32>>",
+                &[
+                    (Type, "array"),
+                    (TemplateStart, "<"),
+                    (Type, "vec2"),
+                    (TemplateStart, "<"),
+                    (Type, "f32"),
+                    (TemplateEnd, ">"),
+                    (TemplateEnd, ">"),
+                ],
+            ),
+            (
+                "var<uniform> u",
+                &[
+                    (Keyword, "var"),
+                    (TemplateStart, "<"),
+                    (Ident, "uniform"),
+                    (TemplateEnd, ">"),
+                    (Ident, "u"),
+                ],
+            ),
+            (
+                "a<b>>c",
+                &[
+                    (Ident, "a"),
+                    (TemplateStart, "<"),
+                    (Ident, "b"),
+                    (TemplateEnd, ">"),
+                    (Operator, ">"),
+                    (Ident, "c"),
+                ],
+            ),
+            ("a < b", &[(Ident, "a"), (Operator, "<"), (Ident, "b")]),
+            (
+                "a < b > c",
+                &[
+                    (Ident, "a"),
+                    (TemplateStart, "<"),
+                    (Ident, "b"),
+                    (TemplateEnd, ">"),
+                    (Ident, "c"),
+                ],
+            ),
+            (
+                "a < b; c > d",
+                &[
+                    (Ident, "a"),
+                    (Operator, "<"),
+                    (Ident, "b"),
+                    (Punctuation, ";"),
+                    (Ident, "c"),
+                    (Operator, ">"),
+                    (Ident, "d"),
+                ],
+            ),
+            (
+                "f(a < b, c > d)",
+                &[
+                    (Ident, "f"),
+                    (Punctuation, "("),
+                    (Ident, "a"),
+                    (TemplateStart, "<"),
+                    (Ident, "b"),
+                    (Punctuation, ","),
+                    (Ident, "c"),
+                    (TemplateEnd, ">"),
+                    (Ident, "d"),
+                    (Punctuation, ")"),
+                ],
+            ),
+            (
+                "x < (y > z)",
+                &[
+                    (Ident, "x"),
+                    (Operator, "<"),
+                    (Punctuation, "("),
+                    (Ident, "y"),
+                    (Operator, ">"),
+                    (Ident, "z"),
+                    (Punctuation, ")"),
+                ],
+            ),
+            ("a >> b", &[(Ident, "a"), (Operator, ">>"), (Ident, "b")]),
+            (
+                "array<i32, 4>",
+                &[
+                    (Type, "array"),
+                    (TemplateStart, "<"),
+                    (Type, "i32"),
+                    (Punctuation, ","),
+                    (Int, "4"),
+                    (TemplateEnd, ">"),
+                ],
+            ),
+        ];
+
+        for (source, expected) in cases {
+            assert_eq!(significant(source), expected.to_vec(), "{}", source);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::{
+    resolution::{RenameError, Resolution},
+    Token, Tokenizer,
+};
+use crate::shader::{concat_shader, prelude};
+use std::ops::Range;
+
+// Name resolution for the fragment source, run over the whole shader so that
+// names of the prelude and of the entry points resolve too.
+//
+// Offsets in and out are byte offsets into the fragment source.
+pub struct Navigation {
+    resolution: Resolution,
+    source: String,
+    // Where the fragment source starts and ends in the whole shader.
+    frag: Range<usize>,
+}
+
+impl Navigation {
+    // `tokens` are those of `frag`, only the code around it being lexed.
+    pub fn new(frag: &str, tokens: &[Token], texture_count: usize) -> Self {
+        let source = concat_shader(frag, texture_count);
+        let start = prelude(texture_count).len() + 1;
+        let end = start + frag.trim_end().len();
+
+        let mut prefix = Tokenizer::default();
+        let mut suffix = Tokenizer::default();
+
+        let mut shader_tokens = prefix.update(&source[..start]).to_vec();
+
+        // Trailing whitespace is trimmed from the fragment source.
+        for (tokens, offset, limit) in [
+            (tokens, start, end),
+            (suffix.update(&source[end..]), end, source.len()),
+        ] {
+            shader_tokens.extend(
+                tokens
+                    .iter()
+                    .filter(|token| offset + token.start < limit)
+                    .map(|token| Token {
+                        kind: token.kind,
+                        start: offset + token.start,
+                        end: (offset + token.end).min(limit),
+                    }),
+            );
+        }
+
+        Self {
+            resolution: Resolution::new(&source, &shader_tokens),
+            source,
+            frag: start..end,
+        }
+    }
+
+    // The name declaring the name at `offset`, if declared in the fragment source.
+    pub fn definition(&self, offset: usize) -> Option<Range<usize>> {
+        let declaration = self.declaration_at(offset)?;
+
+        self.to_frag(&self.resolution.declaration(declaration).range)
+    }
+
+    // The name at `offset` and where it occurs in the fragment source,
+    // declaration included.
+    pub fn references(&self, offset: usize) -> Option<(String, Vec<Range<usize>>)> {
+        let declaration = self.declaration_at(offset)?;
+
+        let occurrences = self
+            .resolution
+            .occurrences(declaration)
+            .iter()
+            .filter_map(|range| self.to_frag(range))
+            .collect();
+
+        Some((
+            self.resolution.declaration(declaration).name.clone(),
+            occurrences,
+        ))
+    }
+
+    // The occurrences to replace with `name` to rename the symbol at `offset`.
+    //
+    // Symbols of the prelude, or used by the entry points, can't be renamed.
+    pub fn rename(&self, offset: usize, name: &str) -> Result<Vec<Range<usize>>, RenameError> {
+        let declaration = self
+            .declaration_at(offset)
+            .ok_or(RenameError::Predeclared)?;
+
+        self.check_renamable(declaration)?;
+
+        let occurrences = self.resolution.rename(&self.source, declaration, name)?;
+
+        Ok(occurrences
+            .iter()
+            .filter_map(|range| self.to_frag(range))
+            .collect())
+    }
+
+    // Whether the symbol at `offset` is declared in the fragment source
+    // and only used there.
+    pub fn renamable(&self, offset: usize) -> Result<(), RenameError> {
+        let declaration = self
+            .declaration_at(offset)
+            .ok_or(RenameError::Predeclared)?;
+
+        self.check_renamable(declaration)
+    }
+
+    fn check_renamable(&self, declaration: usize) -> Result<(), RenameError> {
+        let in_frag = self
+            .resolution
+            .occurrences(declaration)
+            .iter()
+            .all(|range| self.to_frag(range).is_some());
+
+        if in_frag {
+            Ok(())
+        } else {
+            Err(RenameError::Predeclared)
+        }
+    }
+
+    fn declaration_at(&self, offset: usize) -> Option<usize> {
+        self.resolution.declaration_at(self.frag.start + offset)
+    }
+
+    fn to_frag(&self, range: &Range<usize>) -> Option<Range<usize>> {
+        (self.frag.start <= range.start && range.end <= self.frag.end)
+            .then(|| range.start - self.frag.start..range.end - self.frag.start)
+    }
+}
@@ -0,0 +1,293 @@
+use super::{Token, TokenKind};
+use std::ops::{Range, RangeInclusive};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutlineKind {
+    // Also `override` declarations.
+    Constant,
+    Function,
+    Struct,
+}
+
+// A module-scope declaration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutlineItem {
+    pub kind: OutlineKind,
+    pub name: String,
+    // Byte range of the name.
+    pub name_range: Range<usize>,
+    // From the keyword to the closing brace or semicolon.
+    pub range: Range<usize>,
+}
+
+// Lines that can be folded, all but the first being hidden.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fold {
+    // Names the fold across edits, e.g. `fn main_image`.
+    pub key: String,
+    // 0-based, from the line kept to the last one hidden.
+    pub lines: RangeInclusive<usize>,
+}
+
+// The declarations of a source, and the function bodies, struct declarations
+// and comment blocks that can be folded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Outline {
+    pub folds: Vec<Fold>,
+    pub items: Vec<OutlineItem>,
+}
+
+impl Outline {
+    pub fn new(source: &str, tokens: &[Token]) -> Self {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        let line = |offset: usize| line_starts.partition_point(|start| *start <= offset) - 1;
+
+        let mut outline = Self::default();
+
+        // Runs of line comments on lines of their own, as their start and
+        // lines, at any depth.
+        let mut block: Option<(usize, RangeInclusive<usize>)> = None;
+
+        for token in tokens {
+            let start = line(token.start);
+
+            match token.kind {
+                TokenKind::BlockComment => {
+                    outline.push_comment_fold(source, block.take());
+                    outline.push_comment_fold(
+                        source,
+                        Some((token.start, start..=line(token.end - 1))),
+                    );
+                }
+                TokenKind::LineComment
+                    if source[line_starts[start]..token.start].trim().is_empty() =>
+                {
+                    block = match block.take() {
+                        Some((first, lines)) if *lines.end() + 1 == start => {
+                            Some((first, *lines.start()..=start))
+                        }
+                        previous => {
+                            outline.push_comment_fold(source, previous);
+
+                            Some((token.start, start..=start))
+                        }
+                    };
+                }
+                TokenKind::Whitespace => {}
+                _ => outline.push_comment_fold(source, block.take()),
+            }
+        }
+
+        outline.push_comment_fold(source, block);
+
+        let tokens: Vec<&Token> = tokens.iter().filter(|token| !token.is_trivia()).collect();
+        let text = |index: usize| tokens.get(index).map_or("", |token| token.text(source));
+
+        let mut index = 0;
+
+        while let Some(&token) = tokens.get(index) {
+            let kind = match (token.kind, text(index)) {
+                (TokenKind::Keyword, "const" | "override") => OutlineKind::Constant,
+                (TokenKind::Keyword, "fn") => OutlineKind::Function,
+                (TokenKind::Keyword, "struct") => OutlineKind::Struct,
+                (_, "{") => {
+                    // Skips blocks outside of declarations.
+                    index = closing_brace(&tokens, source, index) + 1;
+
+                    continue;
+                }
+                _ => {
+                    index += 1;
+
+                    continue;
+                }
+            };
+
+            let Some(&name) = tokens
+                .get(index + 1)
+                .filter(|name| name.kind == TokenKind::Ident)
+            else {
+                index += 1;
+
+                continue;
+            };
+
+            // Through the body, or up to the semicolon.
+            let mut end = index + 2;
+
+            while end < tokens.len() && !matches!(text(end), "{" | ";") {
+                end += 1;
+            }
+
+            let has_body = text(end) == "{" && kind != OutlineKind::Constant;
+
+            if has_body {
+                end = closing_brace(&tokens, source, end);
+            }
+
+            let range = token.start..tokens.get(end).map_or(source.len(), |token| token.end);
+
+            let keyword = text(index);
+            let lines = line(range.start)..=line(range.end.saturating_sub(1).max(range.start));
+
+            if has_body && lines.start() < lines.end() {
+                outline.folds.push(Fold {
+                    key: format!("{} {}", keyword, name.text(source)),
+                    lines,
+                });
+            }
+
+            outline.items.push(OutlineItem {
+                kind,
+                name: name.text(source).to_owned(),
+                name_range: name.range(),
+                range,
+            });
+
+            index = end + 1;
+        }
+
+        outline.folds.sort_by_key(|fold| *fold.lines.start());
+
+        outline
+    }
+
+    // The index of the item `offset` is in.
+    pub fn item_at(&self, offset: usize) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| item.range.start <= offset && offset <= item.range.end)
+    }
+
+    // Comments of more than one line, keyed by their first line.
+    fn push_comment_fold(&mut self, source: &str, block: Option<(usize, RangeInclusive<usize>)>) {
+        let Some((start, lines)) = block else {
+            return;
+        };
+
+        if lines.start() < lines.end() {
+            let first_line = source[start..].lines().next().unwrap_or_default();
+
+            self.folds.push(Fold {
+                key: first_line.trim().to_owned(),
+                lines,
+            });
+        }
+    }
+}
+
+// The index of the `}` closing the `{` at `open`, or the last token when
+// unclosed.
+fn closing_brace(tokens: &[&Token], source: &str, open: usize) -> usize {
+    let mut depth = 0;
+
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        match token.text(source) {
+            "{" => depth += 1,
+            "}" => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tokens.len().saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgsl::Tokenizer;
+
+    fn outline(source: &str) -> Outline {
+        let mut tokenizer = Tokenizer::default();
+        let tokens = tokenizer.update(source);
+
+        Outline::new(source, tokens)
+    }
+
+    #[test]
+    fn folds() {
+        let source = "// A
+// shader.
+struct S {
+    a: f32,
+}
+
+/* One
+   two */
+fn f() -> f32 {
+    // Alone.
+    let a = 1.0; // After code.
+    // Not folded
+    return a;
+}
+
+fn g() {}
+";
+
+        let outline = outline(source);
+
+        let folds: Vec<(&str, RangeInclusive<usize>)> = outline
+            .folds
+            .iter()
+            .map(|fold| (fold.key.as_str(), fold.lines.clone()))
+            .collect();
+
+        assert_eq!(
+            folds,
+            [
+                ("// A", 0..=1),
+                ("struct S", 2..=4),
+                ("/* One", 6..=7),
+                ("fn f", 8..=13),
+            ]
+        );
+    }
+
+    #[test]
+    fn items() {
+        let source = "const A = array(1, 2);
+struct S { a: f32 }
+
+override B: f32;
+fn f(s: S) -> f32 {
+    const C = 1.0;
+    { let d = 2; }
+    return s.a;
+}";
+
+        let outline = outline(source);
+
+        let items: Vec<(OutlineKind, &str)> = outline
+            .items
+            .iter()
+            .map(|item| (item.kind, item.name.as_str()))
+            .collect();
+
+        assert_eq!(
+            items,
+            [
+                (OutlineKind::Constant, "A"),
+                (OutlineKind::Struct, "S"),
+                (OutlineKind::Constant, "B"),
+                (OutlineKind::Function, "f"),
+            ]
+        );
+
+        assert_eq!(
+            &source[outline.items[0].range.clone()],
+            "const A = array(1, 2);"
+        );
+        assert_eq!(outline.items[3].range.end, source.len());
+
+        assert_eq!(outline.item_at(source.find("return").unwrap()), Some(3));
+        assert_eq!(outline.item_at(source.find("override").unwrap() - 1), None);
+    }
+}
@@ -0,0 +1,459 @@
+use super::{lexer::next_token, Token, TokenKind, Tokenizer};
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenameError {
+    // The new name conflicts with another declaration or captures a name
+    // that referred to something else.
+    Conflict,
+    InvalidName,
+    // The name is declared or used outside of the source being edited.
+    Predeclared,
+}
+
+#[derive(Clone, Debug)]
+pub struct Declaration {
+    pub name: String,
+    // Byte range of the name.
+    pub range: Range<usize>,
+    scope: usize,
+    // Where the name starts being visible: module-scope names are visible
+    // everywhere, others right after their declaration.
+    visible_from: usize,
+}
+
+struct Scope {
+    parent: Option<usize>,
+    // Whether the next `{` opens the body of this scope rather than a new one,
+    // as for the parameters of a function or the header of a `for`.
+    awaits_body: bool,
+}
+
+// Which declaration every name of a source refers to, following the scoping
+// rules of WGSL: module-scope declarations are visible everywhere, others
+// until the end of their block, and inner declarations shadow outer ones.
+//
+// Members and swizzles, which need types to resolve, are left out.
+pub struct Resolution {
+    declarations: Vec<Declaration>,
+    // Byte ranges of names, and the index of the declaration they refer to.
+    references: Vec<(Range<usize>, usize)>,
+}
+
+impl Resolution {
+    pub fn new(source: &str, tokens: &[Token]) -> Self {
+        let tokens: Vec<&Token> = tokens.iter().filter(|token| !token.is_trivia()).collect();
+        let text = |index: usize| tokens.get(index).map_or("", |token| token.text(source));
+
+        let mut scopes = vec![Scope {
+            parent: None,
+            awaits_body: false,
+        }];
+        let mut stack = vec![0];
+        let mut declarations = vec![];
+        let mut names = vec![];
+
+        let mut in_struct = false;
+        // Depth of parentheses within the parameters of a function, if in them.
+        let mut parameter_depth: Option<usize> = None;
+
+        let mut index = 0;
+
+        while let Some(&token) = tokens.get(index) {
+            let scope = *stack.last().unwrap();
+            let word = token.text(source);
+
+            if let Some(depth) = parameter_depth.as_mut() {
+                match word {
+                    "(" => *depth += 1,
+                    ")" => {
+                        *depth -= 1;
+
+                        if *depth == 0 {
+                            parameter_depth = None;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            match (token.kind, word) {
+                // Their arguments are enumerants, not names.
+                (TokenKind::Attribute, "@builtin" | "@diagnostic" | "@interpolate")
+                    if text(index + 1) == "(" =>
+                {
+                    index = skip_group(&tokens, source, index + 1, "(", ")");
+                }
+                (
+                    TokenKind::Keyword,
+                    "alias" | "const" | "fn" | "let" | "override" | "struct" | "var",
+                ) => {
+                    let mut name = index + 1;
+
+                    if word == "var"
+                        && tokens.get(name).map(|token| token.kind)
+                            == Some(TokenKind::TemplateStart)
+                    {
+                        name = skip_group(&tokens, source, name, "<", ">") + 1;
+                    }
+
+                    let Some(&name_token) = tokens.get(name) else {
+                        break;
+                    };
+
+                    if name_token.kind != TokenKind::Ident {
+                        index += 1;
+
+                        continue;
+                    }
+
+                    // Locals are visible after their statement, so that
+                    // `let x = x;` refers to an outer `x`.
+                    let visible_from = if scope == 0 {
+                        0
+                    } else {
+                        statement_end(&tokens, source, name)
+                    };
+
+                    declarations.push(Declaration {
+                        name: name_token.text(source).to_owned(),
+                        range: name_token.range(),
+                        scope,
+                        visible_from,
+                    });
+
+                    match word {
+                        "fn" => {
+                            scopes.push(Scope {
+                                parent: Some(scope),
+                                awaits_body: true,
+                            });
+                            stack.push(scopes.len() - 1);
+
+                            parameter_depth = Some(0);
+                        }
+                        "struct" => in_struct = true,
+                        _ => {}
+                    }
+
+                    index = name;
+                }
+                (TokenKind::Keyword, "for") => {
+                    scopes.push(Scope {
+                        parent: Some(scope),
+                        awaits_body: true,
+                    });
+                    stack.push(scopes.len() - 1);
+                }
+                (TokenKind::Ident, _) => {
+                    let is_member = index > 0 && text(index - 1) == ".";
+                    let is_typed = text(index + 1) == ":";
+
+                    if parameter_depth == Some(1) && is_typed {
+                        declarations.push(Declaration {
+                            name: word.to_owned(),
+                            range: token.range(),
+                            scope,
+                            visible_from: token.end,
+                        });
+                    } else if !is_member && (!in_struct || !is_typed) {
+                        names.push((token.range(), word, scope));
+                    }
+                }
+                (_, "{") => {
+                    if scopes[scope].awaits_body {
+                        scopes[scope].awaits_body = false;
+                    } else {
+                        scopes.push(Scope {
+                            parent: Some(scope),
+                            awaits_body: false,
+                        });
+                        stack.push(scopes.len() - 1);
+                    }
+                }
+                (_, "}") => {
+                    in_struct = false;
+
+                    if stack.len() > 1 {
+                        stack.pop();
+                    }
+                }
+                _ => {}
+            }
+
+            index += 1;
+        }
+
+        let references = names
+            .into_iter()
+            .filter_map(|(range, name, scope)| {
+                lookup(&scopes, &declarations, name, scope, range.start)
+                    .map(|declaration| (range, declaration))
+            })
+            .collect();
+
+        Self {
+            declarations,
+            references,
+        }
+    }
+
+    pub fn declaration(&self, index: usize) -> &Declaration {
+        &self.declarations[index]
+    }
+
+    // The declaration of the name at `offset`, whether the name declares it or refers to it.
+    pub fn declaration_at(&self, offset: usize) -> Option<usize> {
+        let contains = |range: &Range<usize>| range.start <= offset && offset <= range.end;
+
+        self.declarations
+            .iter()
+            .position(|declaration| contains(&declaration.range))
+            .or_else(|| {
+                self.references
+                    .iter()
+                    .find(|(range, _)| contains(range))
+                    .map(|(_, declaration)| *declaration)
+            })
+    }
+
+    // The name of a declaration and all the names referring to it, in source order.
+    pub fn occurrences(&self, declaration: usize) -> Vec<Range<usize>> {
+        let mut occurrences: Vec<_> = self
+            .references
+            .iter()
+            .filter(|(_, index)| *index == declaration)
+            .map(|(range, _)| range.clone())
+            .chain([self.declarations[declaration].range.clone()])
+            .collect();
+
+        occurrences.sort_by_key(|range| range.start);
+
+        occurrences
+    }
+
+    // The occurrences to replace to rename a declaration, if every name would
+    // still refer to the same declaration afterwards.
+    pub fn rename(
+        &self,
+        source: &str,
+        declaration: usize,
+        name: &str,
+    ) -> Result<Vec<Range<usize>>, RenameError> {
+        if !is_identifier(name) {
+            return Err(RenameError::InvalidName);
+        }
+
+        let occurrences = self.occurrences(declaration);
+        let renamed = replace_ranges(source, &occurrences, name);
+
+        let mut tokenizer = Tokenizer::default();
+        let after = Resolution::new(&renamed, tokenizer.update(&renamed));
+
+        let old_len = self.declarations[declaration].range.len();
+        let shift = |range: &Range<usize>| {
+            let before = occurrences
+                .iter()
+                .filter(|occurrence| occurrence.end <= range.start)
+                .count();
+            let start = range.start + before * name.len() - before * old_len;
+
+            if occurrences.contains(range) {
+                start..start + name.len()
+            } else {
+                start..start + range.len()
+            }
+        };
+
+        let bindings = |resolution: &Resolution, map: &dyn Fn(&Range<usize>) -> Range<usize>| {
+            let mut bindings: Vec<_> = resolution
+                .references
+                .iter()
+                .map(|(range, index)| {
+                    (
+                        map(range).start,
+                        map(&resolution.declarations[*index].range).start,
+                    )
+                })
+                .collect();
+
+            bindings.sort();
+
+            bindings
+        };
+
+        let conflicts = after.declarations.iter().enumerate().any(|(index, a)| {
+            after.declarations[..index]
+                .iter()
+                .any(|b| a.scope == b.scope && a.name == b.name)
+        });
+
+        if conflicts || bindings(self, &shift) != bindings(&after, &|range| range.clone()) {
+            return Err(RenameError::Conflict);
+        }
+
+        Ok(occurrences)
+    }
+}
+
+// Whether `name` can name a declaration.
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && next_token(name) == (TokenKind::Ident, name.len())
+        && name != "_"
+        && !name.starts_with("__")
+}
+
+// `source` with every range replaced by `text`. Ranges must be sorted.
+pub fn replace_ranges(source: &str, ranges: &[Range<usize>], text: &str) -> String {
+    let mut replaced = String::with_capacity(source.len());
+    let mut last = 0;
+
+    for range in ranges {
+        replaced.push_str(&source[last..range.start]);
+        replaced.push_str(text);
+
+        last = range.end;
+    }
+
+    replaced.push_str(&source[last..]);
+
+    replaced
+}
+
+fn lookup(
+    scopes: &[Scope],
+    declarations: &[Declaration],
+    name: &str,
+    scope: usize,
+    offset: usize,
+) -> Option<usize> {
+    let mut scope = Some(scope);
+
+    while let Some(current) = scope {
+        let found = declarations.iter().rposition(|declaration| {
+            declaration.scope == current
+                && declaration.visible_from <= offset
+                && declaration.name == name
+        });
+
+        if found.is_some() {
+            return found;
+        }
+
+        scope = scopes[current].parent;
+    }
+
+    None
+}
+
+// The index of the token closing the group opened at `open`.
+fn skip_group(tokens: &[&Token], source: &str, open: usize, opening: &str, closing: &str) -> usize {
+    let mut depth = 0;
+
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        let text = token.text(source);
+
+        if text == opening {
+            depth += 1;
+        } else if text == closing {
+            depth -= 1;
+
+            if depth == 0 {
+                return index;
+            }
+        }
+    }
+
+    tokens.len()
+}
+
+// The byte offset ending the statement that contains the token at `index`.
+fn statement_end(tokens: &[&Token], source: &str, index: usize) -> usize {
+    let mut depth = 0;
+
+    for token in &tokens[index..] {
+        match token.text(source) {
+            "(" | "[" | "{" => depth += 1,
+            ")" | "]" | "}" if depth == 0 => return token.start,
+            ")" | "]" | "}" => depth -= 1,
+            ";" if depth == 0 => return token.end,
+            _ => {}
+        }
+    }
+
+    source.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RenameError, Resolution, Tokenizer};
+
+    const SOURCE: &str = "const k = 1.0;
+fn f(a: f32) -> f32 {
+    let b = a * k;
+    for (var i = 0; i < 2; i++) {
+        let b = b + f32(i);
+    }
+    return b;
+}
+";
+
+    fn resolve(source: &str) -> Resolution {
+        let mut tokenizer = Tokenizer::default();
+
+        Resolution::new(source, tokenizer.update(source))
+    }
+
+    // Offsets of the occurrences of the name starting `needle`.
+    fn occurrences(needle: &str) -> Vec<usize> {
+        let resolution = resolve(SOURCE);
+        let declaration = resolution.declaration_at(at(needle)).unwrap();
+
+        resolution
+            .occurrences(declaration)
+            .into_iter()
+            .map(|range| range.start)
+            .collect()
+    }
+
+    fn at(needle: &str) -> usize {
+        SOURCE.find(needle).unwrap()
+    }
+
+    #[test]
+    fn scopes() {
+        // The inner `b` is initialized from the outer one, and shadows it in the loop only.
+        assert_eq!(
+            occurrences("b = a"),
+            vec![at("b = a"), at("b + f32"), at("b;\n}")]
+        );
+        assert_eq!(occurrences("b = b"), vec![at("b = b")]);
+        assert_eq!(occurrences("i = 0").len(), 4);
+        assert_eq!(occurrences("k = 1").len(), 2);
+        assert_eq!(occurrences("a: f32").len(), 2);
+    }
+
+    #[test]
+    fn rename() {
+        let resolution = resolve(SOURCE);
+        let rename = |needle: &str, name: &str| {
+            let offset = SOURCE.find(needle).unwrap();
+            let declaration = resolution.declaration_at(offset).unwrap();
+
+            resolution
+                .rename(SOURCE, declaration, name)
+                .map(|ranges| ranges.len())
+        };
+
+        assert_eq!(rename("k =", "scale"), Ok(2));
+        assert_eq!(rename("a:", "x"), Ok(2));
+        // Would capture `k` in `a * k`.
+        assert_eq!(rename("a:", "k"), Err(RenameError::Conflict));
+        // Would be declared twice in the same scope.
+        assert_eq!(rename("k =", "f"), Err(RenameError::Conflict));
+        assert_eq!(rename("k =", "let"), Err(RenameError::InvalidName));
+        assert_eq!(rename("k =", "vec2f"), Err(RenameError::InvalidName));
+        assert_eq!(rename("k =", "__k"), Err(RenameError::InvalidName));
+    }
+}
@@ -0,0 +1,77 @@
+use super::{Token, TokenKind};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    pub name: String,
+    // Index of the argument being typed.
+    pub argument: usize,
+}
+
+// The innermost function call whose argument list contains `cursor`,
+// a byte offset into `source`. `tokens` are those of the whole source.
+//
+// Inside a nested bracket, template list or constructor there's no call,
+// so that the argument index is never that of an outer one.
+pub fn enclosing_call(source: &str, tokens: &[Token], cursor: usize) -> Option<Call> {
+    // Open brackets, with the function they call, if any,
+    // and the commas they contain so far.
+    let mut open: Vec<(Option<&str>, usize)> = vec![];
+    let mut previous: [Option<&Token>; 2] = [None, None];
+
+    for token in tokens.iter().take_while(|token| token.start < cursor) {
+        match token.kind {
+            TokenKind::BlockComment | TokenKind::LineComment => {
+                let text = token.text(source);
+
+                let is_open =
+                    token.kind == TokenKind::LineComment || text.len() < 4 || !text.ends_with("*/");
+
+                if cursor < token.end || (cursor == token.end && is_open) {
+                    return None;
+                }
+
+                continue;
+            }
+            TokenKind::Whitespace => continue,
+            TokenKind::TemplateStart => open.push((None, 0)),
+            TokenKind::TemplateEnd => {
+                open.pop();
+            }
+            _ => match token.text(source) {
+                "(" => {
+                    let name = match previous {
+                        [Some(name), before]
+                            if name.kind == TokenKind::Ident
+                                && before.is_none_or(|before| before.text(source) != "fn") =>
+                        {
+                            Some(name.text(source))
+                        }
+                        _ => None,
+                    };
+
+                    open.push((name, 0));
+                }
+                "[" => open.push((None, 0)),
+                ")" | "]" => {
+                    open.pop();
+                }
+                "," => {
+                    if let Some((_, commas)) = open.last_mut() {
+                        *commas += 1;
+                    }
+                }
+                ";" | "{" | "}" => open.clear(),
+                _ => {}
+            },
+        }
+
+        previous = [Some(token), previous[0]];
+    }
+
+    let (name, commas) = open.last()?;
+
+    Some(Call {
+        name: (*name)?.to_owned(),
+        argument: *commas,
+    })
+}
@@ -0,0 +1,163 @@
+use super::builtins;
+use crate::shader::concat_shader;
+use naga::{Module, TypeInner};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolKind {
+    BuiltinFunction,
+    Field,
+    Function,
+    GlobalVariable,
+    LocalVariable,
+    Struct,
+}
+
+// The names a shader declares, as seen by naga.
+//
+// Naga keeps no span for most names, so locations are left to the lexer:
+// a name is resolved against the function it appears in.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Symbols {
+    fields: BTreeSet<String>,
+    // Parameter names, by function name.
+    functions: BTreeMap<String, Vec<String>>,
+    globals: BTreeSet<String>,
+    // Arguments and local variables, by function name.
+    locals: BTreeMap<String, BTreeSet<String>>,
+    structs: BTreeSet<String>,
+}
+
+impl Symbols {
+    // The fragment source is parsed along with the prelude,
+    // so that the uniform and texture bindings resolve.
+    pub fn parse(frag: &str, texture_count: usize) -> Option<Self> {
+        let module = naga::front::wgsl::parse_str(&concat_shader(frag, texture_count)).ok()?;
+
+        Some(Self::from_module(&module))
+    }
+
+    pub fn from_module(module: &Module) -> Self {
+        let mut symbols = Self::default();
+
+        for (_, ty) in module.types.iter() {
+            if let TypeInner::Struct { members, .. } = &ty.inner {
+                symbols.structs.extend(ty.name.clone());
+                symbols
+                    .fields
+                    .extend(members.iter().filter_map(|member| member.name.clone()));
+            }
+        }
+
+        for (_, constant) in module.constants.iter() {
+            symbols.globals.extend(constant.name.clone());
+        }
+
+        for (_, variable) in module.global_variables.iter() {
+            symbols.globals.extend(variable.name.clone());
+        }
+
+        for (_, function) in module.functions.iter() {
+            if let Some(name) = &function.name {
+                let parameters = function
+                    .arguments
+                    .iter()
+                    .filter_map(|argument| argument.name.clone())
+                    .collect();
+
+                symbols.functions.insert(name.clone(), parameters);
+            }
+        }
+
+        // Entry points can't be called, but their locals are still highlighted.
+        let functions = module
+            .functions
+            .iter()
+            .map(|(_, function)| function)
+            .chain(module.entry_points.iter().map(|entry| &entry.function));
+
+        for function in functions {
+            let Some(name) = &function.name else {
+                continue;
+            };
+
+            let locals = function
+                .arguments
+                .iter()
+                .filter_map(|argument| argument.name.clone())
+                .chain(
+                    function
+                        .local_variables
+                        .iter()
+                        .filter_map(|(_, variable)| variable.name.clone()),
+                )
+                .chain(function.named_expressions.values().cloned())
+                .collect();
+
+            symbols.locals.insert(name.clone(), locals);
+        }
+
+        symbols
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(String::as_str)
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.functions
+            .iter()
+            .map(|(name, parameters)| (name.as_str(), parameters.as_slice()))
+    }
+
+    pub fn globals(&self) -> impl Iterator<Item = &str> {
+        self.globals.iter().map(String::as_str)
+    }
+
+    pub fn locals(&self, function: &str) -> impl Iterator<Item = &str> {
+        self.locals
+            .get(function)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    pub fn parameters(&self, function: &str) -> Option<&[String]> {
+        self.functions.get(function).map(Vec::as_slice)
+    }
+
+    pub fn structs(&self) -> impl Iterator<Item = &str> {
+        self.structs.iter().map(String::as_str)
+    }
+
+    // `function` is the function the name appears in, if any,
+    // and `is_member` whether it follows a `.`.
+    pub fn resolve(
+        &self,
+        name: &str,
+        function: Option<&str>,
+        is_member: bool,
+    ) -> Option<SymbolKind> {
+        if is_member {
+            return self.fields.contains(name).then_some(SymbolKind::Field);
+        }
+
+        let is_local = function
+            .and_then(|function| self.locals.get(function))
+            .is_some_and(|locals| locals.contains(name));
+
+        if is_local {
+            Some(SymbolKind::LocalVariable)
+        } else if self.globals.contains(name) {
+            Some(SymbolKind::GlobalVariable)
+        } else if self.functions.contains_key(name) {
+            Some(SymbolKind::Function)
+        } else if self.structs.contains(name) {
+            Some(SymbolKind::Struct)
+        } else if builtins::is_function(name) {
+            Some(SymbolKind::BuiltinFunction)
+        } else {
+            None
+        }
+    }
+}